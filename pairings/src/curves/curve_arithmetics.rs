@@ -2,14 +2,10 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
-use crate::{fields::prime_fields::FieldElement, tools::arithmetic_interface::ArithmeticOperations};
+use crate::{fields::prime_fields::{FieldConfig, FieldElement}, tools::arithmetic_interface::ArithmeticOperations};
 use crate::tools::recoders::*;
-use num_bigint::BigUint;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use std::fmt::Display;
-use std::ops::BitAnd;
-use std::str::FromStr;
-use num_traits::{One,ToPrimitive, Zero};
-
 
 #[derive(Clone, Copy, Debug)]
 pub struct EcPoint<T> {
@@ -20,7 +16,7 @@ pub struct EcPoint<T> {
 
 impl <T> EcPoint <T>
     where 
-    T :ArithmeticOperations + Clone + Copy + Display,    
+    T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Clone + Copy + Display,    
 {      
     pub fn negate(&self) -> EcPoint<T>
     {
@@ -71,6 +67,12 @@ impl <T> EcPoint <T>
                     }
                 }
 
+    pub fn add_jacobian_safe(&self, rhs :&EcPoint<T>) -> EcPoint<T>{
+                // Jacobian addition that also covers P+P : the doubling is always computed and selected when both inputs are the same point,
+                // which is cheaper than the complete formulas when exceptions cannot be ruled out but most inputs are distinct
+                EcPoint::conditional_select(&self.add_jacobian(rhs), &self.double_jacobian(), self.ct_eq(rhs))
+                }
+
     pub fn mixed_jacobian(&self, rhs :&EcPoint<T>) -> EcPoint<T>{
                 // https://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#addition-madd-2007-bl
                 let z1_2 = self.z.sqr();
@@ -85,10 +87,62 @@ impl <T> EcPoint <T>
                 let x3 = r.sqr().substract(&j).substract(&v.double());
                 Self { x : x3.clone(),
                        y : r.multiply(&v.substract(&x3)).substract(&self.y.multiply(&j).double()),
-                       z : self.z.addto(&h).sqr().substract(&z1_2).substract(&hh)  
-                     } 
+                       z : self.z.addto(&h).sqr().substract(&z1_2).substract(&hh)
+                     }
+                }
+
+    pub fn add_mixed(&self, rhs :&EcPoint<T>) -> EcPoint<T>{
+                // Addition of a normalized point (z = 1, or z = 0 for the infinity point) to a Jacobian one using mixed_jacobian.
+                // The exceptions are handled with constant-time selections : P+P is replaced by the doubling, and the infinity point
+                // on either side by the other operand (P+(-P) needs no correction, since h = 0 gives z3 = 0)
+                let zero = self.z.zero();
+                let sum = EcPoint::conditional_select(&self.mixed_jacobian(rhs), &self.double_jacobian(), self.ct_eq(rhs));
+                let sum = EcPoint::conditional_select(&sum, rhs, self.z.ct_eq(&zero));
+                EcPoint::conditional_select(&sum, self, rhs.z.ct_eq(&zero))
                 }
                 
+    pub fn add_complete(&self, rhs :&EcPoint<T>, b3 :&T) -> EcPoint<T>{
+                // Exception-free addition for a=0 short Weierstrass curves (b3 = 3*b), valid for any pair of inputs
+                // (P+P, P+(-P), P+Infinity, ...), based on Algorithm 7 from https://eprint.iacr.org/2015/1060.pdf
+                // The formulas work on homogeneous projective coordinates, so inputs are mapped from Jacobian (X,Y,Z) to (XZ,Y,Z^3),
+                // and the result back from (X,Y,Z) to (XZ,YZ^2,Z). The infinity point is taken as (0,1,0) on input and returned as (1,1,0), without branching.
+                let one = self.x.one();
+                let zero = self.x.zero();
+                let (x1, y1, z1) = (self.x.multiply(&self.z), T::conditional_select(&self.y, &one, self.z.ct_eq(&zero)), self.z.sqr().multiply(&self.z));
+                let (x2, y2, z2) = (rhs.x.multiply(&rhs.z), T::conditional_select(&rhs.y, &one, rhs.z.ct_eq(&zero)), rhs.z.sqr().multiply(&rhs.z));
+                let mut t0 = x1.multiply(&x2);
+                let mut t1 = y1.multiply(&y2);
+                let mut t2 = z1.multiply(&z2);
+                let mut t3 = x1.addto(&y1).multiply(&x2.addto(&y2));
+                let mut t4 = t0.addto(&t1);
+                t3 = t3.substract(&t4);
+                t4 = y1.addto(&z1).multiply(&y2.addto(&z2));
+                t4 = t4.substract(&t1.addto(&t2));
+                let mut x3 = x1.addto(&z1).multiply(&x2.addto(&z2));
+                let mut y3 = x3.substract(&t0.addto(&t2));
+                x3 = t0.double();
+                t0 = x3.addto(&t0);
+                t2 = b3.multiply(&t2);
+                let mut z3 = t1.addto(&t2);
+                t1 = t1.substract(&t2);
+                y3 = b3.multiply(&y3);
+                x3 = t4.multiply(&y3);
+                t2 = t3.multiply(&t1);
+                x3 = t2.substract(&x3);
+                y3 = y3.multiply(&t0);
+                t1 = t1.multiply(&z3);
+                y3 = t1.addto(&y3);
+                t0 = t0.multiply(&t3);
+                z3 = z3.multiply(&t4);
+                z3 = z3.addto(&t0);
+                let zz = z3.sqr();
+                let infinit = z3.ct_eq(&zero);
+                Self {  x : T::conditional_select(&x3.multiply(&z3), &one, infinit),
+                        y : T::conditional_select(&y3.multiply(&zz), &one, infinit),
+                        z : z3
+                     }
+                }
+
     pub fn multiply_with_const(&self , scalar :i128) -> EcPoint<T>{
         //  not Constant-time multiplication, used when multiplying with small constant
         //  no need for resistance to side-channel attacks !, so can do faster
@@ -106,41 +160,55 @@ impl <T> EcPoint <T>
                 }
         }
 
-    pub fn multiply<const N:usize>(&self , scalar :&FieldElement<N>) -> EcPoint<T>{
-                // Constant-time multiplication using w-sliding window (w=3)
-                let infinit = Self {x:self.x.one(), y : self.x.one(), z: self.x.zero() };
-                let ff: BigUint = BigUint::from_str("255").unwrap();
-                if self.z.is_zero()  {infinit}
-                else {  let s = scalar.to_big_uint();
-                        if s == BigUint::zero() {infinit.clone()}
-                        else {  if s == BigUint::one() {self.clone()}
-                                else {  if s == (BigUint::from(2u8))  { self.double_jacobian()}
-                                        else {  let mut lookup = [infinit;5];
-                                                lookup[0] = self.double_jacobian();
-                                                lookup[1] = self.clone();
-                                                lookup[2] = self.add_jacobian(&lookup[0]);  
-                                                lookup[3] = lookup[0].add_jacobian(&lookup[2]);  
-                                                lookup[4] = lookup[0].add_jacobian(&lookup[3]);  
-                                                let mut code = recod_one_scalar(&(&s + ((&s).bitand(BigUint::one()) + BigUint::one())));                                                
-                                                let mut result = lookup[(((&code).bitand((ff).clone()).to_u8().unwrap() & (WMASK >> 1)) + 1) as usize];
-                                                code = code >> (WSIZE -1);
-                                                while code != BigUint::one() {  let limb = (&code).bitand((ff).clone()).to_u8().unwrap();
-                                                                                let sig : i8 = (2 * (limb & 1) as i8) - 1; //2 * ((limb as i8) & 1) - 1;                                                                                
-                                                                                let idx: usize = (((limb & WMASK) >> 1) + 1) as usize;                                                                                
-                                                                                result = result.double_jacobian(); 
-                                                                                result = result.double_jacobian(); 
-                                                                                result = result.double_jacobian(); 
-                                                                                if sig == 1 {result = result.add_jacobian(&lookup[idx])}
-                                                                                else {result = result.add_jacobian(&lookup[idx].negate())}
-                                                                                code = code >> WSIZE;
-                                                                             }                
-                                                result.to_affine();
-                                                result = result.add_jacobian(&lookup[1 - (&s).bitand(BigUint::one()).to_usize().unwrap()].negate());                                  
-                                                result 
-                                                } 
-                                      }    
+    pub fn multiply<const N:usize, P: FieldConfig<N>>(&self , scalar :&FieldElement<N,P>) -> EcPoint<T>{
+                // Constant-time multiplication using w-sliding window (w=3) over signed odd digits, with a regular recoding of fixed length
+                // (Algorithm 6 from https://eprint.iacr.org/2013/158.pdf) : the table is read with a full scan (ct_lookup), and the sign of
+                // each digit is applied with a conditional negation
+                if self.z.is_zero()  { return *self }
+                self.windowed_multiply(&scalar.to_canonical_limbs(), scalar.fieldparams.num_of_bits, false)
+                }
+
+    pub fn multiply_hardened<const N:usize, P: FieldConfig<N>>(&self , scalar :&FieldElement<N,P>, blinding :u64, randomizer :&T) -> EcPoint<T>{
+                // Side-channel hardened multiplication (Coron's countermeasures, https://doi.org/10.1007/3-540-48059-5_25) : the scalar is
+                // blinded as k + blinding*r (k*P is unchanged, but the recoded digits differ at each call), and the Jacobian coordinates of P
+                // are randomized with a non-zero randomizer, so that neither the digits nor the intermediate coordinates are predictable.
+                // The randomizer and the blinding factor are expected to be fresh random values
+                if self.z.is_zero()  { return *self }
+                let (k, r) = (scalar.to_canonical_limbs(), scalar.fieldparams.modulo);
+                let mut blinded = vec![0u64; N + 1];
+                let mut carry :u128 = 0;
+                for i in 0..N { let t = (r[i] as u128) * (blinding as u128) + (k[i] as u128) + carry;
+                                blinded[i] = t as u64;
+                                carry = t >> 64;
                               }
-                     }
+                blinded[N] = carry as u64;
+                // The blinded scalar exceeds r, so partial sums can meet the table entries : the additions have to handle P+P
+                self.randomize(randomizer).windowed_multiply(&blinded, scalar.fieldparams.num_of_bits + 64, true)
+                }
+
+    pub fn randomize(&self, randomizer :&T) -> EcPoint<T>{
+                // (x,y,z) and (λ^2*x, λ^3*y, λ*z) represent the same point for any non-zero λ
+                let l2 = randomizer.sqr();
+                Self { x: self.x.multiply(&l2), y: self.y.multiply(&l2.multiply(randomizer)), z: self.z.multiply(randomizer) }
+                }
+
+    fn windowed_multiply(&self , scalar :&[u64], bits :usize, blinded :bool) -> EcPoint<T>{
+                // Multiplication by a scalar given as limbs of at most "bits" bits (both lengths are public). Even scalars are recoded
+                // as k+1, and P is removed at the end (always computed). For scalars below r, the partial sums never meet the added digits
+                // (nor the opposite of P in the correction, except for k = r-2 which is odd), so the plain Jacobian addition is enough
+                let add = |a :&EcPoint<T>, b :&EcPoint<T>| if blinded {a.add_jacobian_safe(b)} else {a.add_jacobian(b)};
+                let mut k = scalar.to_vec();
+                let even = Choice::from(((k[0] & 1) ^ 1) as u8);
+                k[0] |= 1;
+                let code = recod_regular(&k, WSIZE, regular_digits_count(bits, WSIZE));
+                let double = self.double_jacobian();
+                let mut lookup = [*self;1 << (WSIZE - 1)];
+                for i in 1..lookup.len() { lookup[i] = lookup[i - 1].add_jacobian(&double) };
+                let mut result = signed_digit_lookup(&lookup, code[code.len() - 1]);
+                for digit in code.iter().rev().skip(1) {   for _ in 0..WSIZE { result = result.double_jacobian() };
+                                                            result = add(&result, &signed_digit_lookup(&lookup, *digit));
+                                                        }
+                EcPoint::conditional_select(&result, &add(&result, &self.negate()), even)
                 }
 
     pub fn to_affine (& mut self) {
@@ -152,6 +220,39 @@ impl <T> EcPoint <T>
                                        }
                 }
 
+    pub fn to_affine_vartime (& mut self) {
+                 // Same as to_affine with the variable-time inversion : only for points whose coordinates are public
+                 // (pairing inputs, multi-scalar multiplication, decoded points), never for outputs of secret computations
+                 if ! self.z.is_zero(){ let d = self.z.invert_vartime();
+                                        let d2 = d.sqr();
+                                        self.x = self.x.multiply(&d2);
+                                        self.y = self.y.multiply(&d.multiply(&d2));
+                                        self.z = self.x.one();
+                                       }
+                }
+
+    pub fn batch_normalize(points :&mut [EcPoint<T>]) {
+                // Normalization (z = 1) of all the points with a single inversion : the z coordinates are inverted together 
+                // (Montgomery's trick), the infinity points being left unchanged
+                Self::batch_normalize_with(points, T::batch_invert)
+                }
+
+    pub fn batch_normalize_vartime(points :&mut [EcPoint<T>]) {
+                // Same as batch_normalize with the variable-time inversion (public points only, as for to_affine_vartime)
+                Self::batch_normalize_with(points, T::batch_invert_vartime)
+                }
+
+    fn batch_normalize_with(points :&mut [EcPoint<T>], batch_invert :fn(&mut [T])) {
+                let mut zinv : Vec<T> = points.iter().map(|p| p.z).collect();
+                batch_invert(&mut zinv);
+                for (p, d) in points.iter_mut().zip(zinv) { if !p.z.is_zero() { let d2 = d.sqr();
+                                                                                 p.x = p.x.multiply(&d2);
+                                                                                 p.y = p.y.multiply(&d2.multiply(&d));
+                                                                                 p.z = d.one();
+                                                                               }
+                                                          }
+                }
+
     pub fn to_string(&self) -> String
                 {  let mut out= String::new();
                    if self.z.is_zero() {out.push_str("Infinit");}
@@ -182,11 +283,7 @@ impl <T> EcPoint <T>
     
     pub fn equal(&self, other :&EcPoint<T>) -> bool
                 {
-                    let z1_2 = self.z.sqr();
-                    let z1_3 = z1_2.multiply(&self.z);
-                    let z2_2 = other.z.sqr();
-                    let z2_3 = z2_2.multiply(&other.z);
-                    self.x.multiply(&z2_2).equal(&other.x.multiply(&z1_2)) & self.y.multiply(&z2_3).equal(&other.y.multiply(&z1_3))                   
+                    self.ct_eq(other).into()
                 }
     
     pub fn is_infinit(&self) -> bool
@@ -194,4 +291,37 @@ impl <T> EcPoint <T>
                       self.z.is_zero()   
                 }
     
-}
\ No newline at end of file
+}
+
+impl <T> ConstantTimeEq for EcPoint<T>
+    where 
+    T :ArithmeticOperations + ConstantTimeEq + Copy,
+{
+    fn ct_eq(&self, other :&EcPoint<T>) -> Choice
+                {   // Two points are equal if both are at infinity, or if none is and their Jacobian coordinates are proportional
+                    let zero = self.z.zero();
+                    let (inf1, inf2) = (self.z.ct_eq(&zero), other.z.ct_eq(&zero));
+                    let z1_2 = self.z.sqr();
+                    let z1_3 = z1_2.multiply(&self.z);
+                    let z2_2 = other.z.sqr();
+                    let z2_3 = z2_2.multiply(&other.z);
+                    let same = self.x.multiply(&z2_2).ct_eq(&other.x.multiply(&z1_2)) & self.y.multiply(&z2_3).ct_eq(&other.y.multiply(&z1_3));
+                    (inf1 & inf2) | (!inf1 & !inf2 & same)
+                }
+}
+
+impl <T :ConditionallySelectable> ConditionallySelectable for EcPoint<T> {
+    fn conditional_select(a :&EcPoint<T>, b :&EcPoint<T>, choice :Choice) -> EcPoint<T>
+                {
+                    EcPoint {   x : T::conditional_select(&a.x, &b.x, choice),
+                                y : T::conditional_select(&a.y, &b.y, choice),
+                                z : T::conditional_select(&a.z, &b.z, choice) }
+                }
+}
+
+impl <T :ConditionallyNegatable> ConditionallyNegatable for EcPoint<T> {
+    fn conditional_negate(&mut self, choice :Choice)
+                {   // -(X,Y,Z) = (X,-Y,Z)
+                    self.y.conditional_negate(choice)
+                }
+}
@@ -0,0 +1,75 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use crate::curves::curve_arithmetics::EcPoint;
+use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::tools::recoders::{recod_regular, regular_digits_count, signed_digit_lookup};
+use std::fmt::{self, Display};
+
+pub const FIXED_BASE_WINDOW : u8 = 5;       // Window of the cached tables of the default generators (16 points per digit)
+
+pub struct FixedBaseTable<E, T>
+{   // Precomputed odd multiples of a fixed point B : row i holds (2j+1) * 2^(w*i) * B for j < 2^(w-1), all normalized (z = 1),
+    // so that k*B is a sum of one signed entry per row, without any doubling (the memory grows as (bits/w + 1) * 2^(w-1) points)
+    pub base   : E,
+    pub window : u8,
+    rows       : Vec<Vec<EcPoint<T>>>,
+    exact_rows : usize,
+}
+
+impl <E, T> FixedBaseTable<E, T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display
+{
+    pub fn from_point(base :E, point :&EcPoint<T>, bits :usize, window :u8) -> Self
+    {
+        // Table for scalars of at most "bits" bits : one row per digit of the regular recoding of window w (1 <= w <= 8,
+        // as the digits index the rows on 7 bits). The base is public, so the rows are built with the (P+P safe) Jacobian
+        // addition and normalized all together with a single inversion
+        if !(1..=8).contains(&window) {panic!("Window of the fixed-base table should be between 1 and 8 ...")}
+        let count = regular_digits_count(bits, window);
+        let width = 1 << (window - 1);
+        let mut points = Vec::with_capacity(count * width);
+        let mut current = *point;
+        for _ in 0..count { let double = current.double_jacobian();
+                            points.push(current);
+                            for _ in 1..width { points.push(points[points.len() - 1].add_jacobian_safe(&double)) }
+                            for _ in 0..window { current = current.double_jacobian() }
+                          }
+        EcPoint::batch_normalize(&mut points);
+        // The partial sum of the first i rows is an odd multiple of B of absolute value below 2^(w*i), so it can not meet the entries
+        // of row i (nor their opposites) as long as 2^(w*(i+1)) <= 2^(bits-1) < r : only the last rows need the exception-free addition
+        FixedBaseTable { base, window, rows : points.chunks(width).map(|r| r.to_vec()).collect(), exact_rows : (bits - 1) / window as usize }
+    }
+
+    pub fn size(&self) -> usize
+    {
+        self.rows.len() * self.rows[0].len()
+    }
+
+    pub fn multiply_point(&self, scalar :&[u64]) -> EcPoint<T>
+    {
+        // Constant-time k*B for k < r given as canonical limbs : even scalars are recoded as k+1 and B is removed at the end (always computed),
+        // each row is read with a full scan (signed_digit_lookup) and accumulated with the mixed addition. In the last rows, the exceptions
+        // (partial sum equal to, or opposite of, the entry) are handled by constant-time selections (add_mixed)
+        if self.rows[0][0].z.is_zero() { return self.rows[0][0] }
+        let mut k = scalar.to_vec();
+        let even = Choice::from(((k[0] & 1) ^ 1) as u8);
+        k[0] |= 1;
+        let code = recod_regular(&k, self.window, self.rows.len());
+        let mut result = signed_digit_lookup(&self.rows[0], code[0]);
+        for (i, (row, digit)) in self.rows.iter().zip(code.iter()).enumerate().skip(1) 
+                                    {   let entry = signed_digit_lookup(row, *digit);
+                                        result = if i < self.exact_rows {result.mixed_jacobian(&entry)} else {result.add_mixed(&entry)};
+                                    }
+        EcPoint::conditional_select(&result, &result.add_mixed(&self.rows[0][0].negate()), even)
+    }
+}
+
+impl <E, T> fmt::Debug for FixedBaseTable<E, T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedBaseTable").field("window", &self.window).field("rows", &self.rows.len()).finish()
+    }
+}
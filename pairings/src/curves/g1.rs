@@ -3,73 +3,98 @@
 // During May 2024.
 
 use base64::engine::general_purpose;
-use num_bigint::{BigUint, ToBigInt};
-use num_traits::{Zero,One,Num,ToPrimitive};
+use once_cell::sync::OnceCell;
 use std::fmt;
-use std::ops::{Add, BitAnd, BitOr, Mul, Neg, Sub};
-use std::str::FromStr;
+use std::ops::{Add, Mul, Neg, Sub};
 use crate::curves::curve_arithmetics::*;
-use crate::fields::prime_fields::{FieldElement, PrimeField};
-use crate::tools::hashs::{i2osp, i2osp_pf, os2ip};
+use crate::curves::fixed_base::{FixedBaseTable, FIXED_BASE_WINDOW};
+use crate::curves::msm::multi_scalar_multiply;
+use crate::errors::{DecodingMode, PairingError};
+use crate::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
+use crate::tools::hashs::Expander;
 use crate::tools::arithmetic_interface::ArithmeticOperations;
-use crate::tools::recoders::{recod_scalar_glv2, WDMASK, WDSIZE, WSIZE};
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+use crate::tools::codecs::{decode_point, encode_point, to_hex, try_from_hex, EncodedPoint, PointFormat};
+use crate::tools::decomposition::LatticeDecomposer;
+use crate::tools::recoders::{recod_regular, regular_digits_count, signed_digit_lookup, WSIZE};
 use base64::{self, Engine};
+use rand::{rngs::OsRng, RngCore};
 
 #[derive(Debug)]
-pub struct G1SwuIsogeniesConsts<const N:usize,const MAX_COEFS_COUNT:usize> {
-        pub z:FieldElement<N>,
-        pub swu_a:FieldElement<N>,
-        pub swu_b:FieldElement<N>,
-        pub xnum :[FieldElement<N>;MAX_COEFS_COUNT],
-        pub xden :[FieldElement<N>;MAX_COEFS_COUNT],
-        pub ynum :[FieldElement<N>;MAX_COEFS_COUNT],
-        pub yden :[FieldElement<N>;MAX_COEFS_COUNT],
-        pub inv_z:FieldElement<N>,
-        pub b_div_a:FieldElement<N>,        
+pub struct G1SwuIsogeniesConsts<const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> {
+        pub z:FieldElement<N,P>,
+        pub swu_a:FieldElement<N,P>,
+        pub swu_b:FieldElement<N,P>,
+        pub xnum :[FieldElement<N,P>;MAX_COEFS_COUNT],
+        pub xden :[FieldElement<N,P>;MAX_COEFS_COUNT],
+        pub ynum :[FieldElement<N,P>;MAX_COEFS_COUNT],
+        pub yden :[FieldElement<N,P>;MAX_COEFS_COUNT],
+        pub inv_z:FieldElement<N,P>,
+        pub b_div_a:FieldElement<N,P>,        
         }
 
 #[derive(Debug)]
-pub struct G1Consts<const R:usize,const N:usize,const MAX_COEFS_COUNT :usize>
-    {   pub b:  FieldElement<N>,
-        pub a : FieldElement<N>,
+pub struct G1Consts<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize>
+    {   pub b:  FieldElement<N,P>,
+        pub a : FieldElement<N,P>,
         pub h1: u128,
-        pub w : FieldElement<N>,
-        pub swu_consts :G1SwuIsogeniesConsts<N,MAX_COEFS_COUNT>,
-        pub lambda: FieldElement<R>,
-        pub lambda_big: BigUint,
+        pub w : FieldElement<N,P>,
+        pub swu_consts :G1SwuIsogeniesConsts<N,P,MAX_COEFS_COUNT>,
+        pub lambda: FieldElement<R,S>,
+        pub glv_decomposer: &'static LatticeDecomposer<R>,
+        pub generator_table: OnceCell<G1FixedBaseTable<R,S,N,P,MAX_COEFS_COUNT>>,
         pub base_field_numbits:usize,
         pub security_level:usize,
-        pub default_generator : EcPoint<FieldElement<N>>
+        pub expander:Expander,
+        pub default_generator : EcPoint<FieldElement<N,P>>
     }
 
 #[derive(Clone,Copy)]
-pub struct G1Element<const R:usize ,const N:usize,const MAX_COEFS_COUNT :usize>
-{   pub consts : &'static G1Consts<R,N,MAX_COEFS_COUNT>,
-    pub point  : EcPoint<FieldElement<N>>,
+pub struct G1Element<const R:usize , S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize>
+{   pub consts : &'static G1Consts<R,S,N,P,MAX_COEFS_COUNT>,
+    pub point  : EcPoint<FieldElement<N,P>>,
 }
 
+#[derive(Clone,Copy)]
+pub struct G1Affine<const R:usize , S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize>
+{   // Normalized representation : z = 1, or z = 0 for the infinity point (G1Element holds any Jacobian representation)
+    pub consts : &'static G1Consts<R,S,N,P,MAX_COEFS_COUNT>,
+    pub point  : EcPoint<FieldElement<N,P>>,
+}
+
+// Jacobian points of G1, as returned by the group operations
+pub type G1Projective<const R:usize, S, const N:usize, P, const MAX_COEFS_COUNT:usize> = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+
+// Fixed-base table of a G1 point, with the representation of the rows
+pub type G1FixedBaseTable<const R:usize, S, const N:usize, P, const MAX_COEFS_COUNT :usize> = FixedBaseTable<G1Element<R,S,N,P,MAX_COEFS_COUNT>, FieldElement<N,P>>;
+
 #[derive(Debug)]
-pub struct G1Field<const R:usize,const N:usize,const MAX_COEFS_COUNT:usize>
-{   pub consts : &'static G1Consts<R,N,MAX_COEFS_COUNT>,
-    pub base_field: &'static PrimeField<N>,
-    pub fr_field :  &'static PrimeField<R>,
+pub struct G1Field<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize>
+{   pub consts : &'static G1Consts<R,S,N,P,MAX_COEFS_COUNT>,
+    pub base_field: &'static PrimeField<N,P>,
+    pub fr_field :  &'static PrimeField<R,S>,
 }
 
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> G1Element<R,N,MAX_COEFS_COUNT>
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> G1Element<R,S,N,P,MAX_COEFS_COUNT>
         {   
-            pub fn addto(&self, other: &G1Element<R,N,MAX_COEFS_COUNT>) -> G1Element<R,N,MAX_COEFS_COUNT>
+            pub fn addto(&self, other: &G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
-                G1Element { point :self.point.add_jacobian(&other.point),
+                //  Complete (exception-free) addition, correct for P+P, P+(-P) and the infinity point
+                G1Element { point :self.point.add_complete(&other.point, &self.b3()),
                             consts : self.consts,
                             }
             }
-            pub fn substract(&self, other: &G1Element<R,N,MAX_COEFS_COUNT>) -> G1Element<R,N,MAX_COEFS_COUNT>
+            pub fn substract(&self, other: &G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
-                G1Element { point :self.point.add_jacobian(&other.point.negate()),
+                G1Element { point :self.point.add_complete(&other.point.negate(), &self.b3()),
                             consts : self.consts,
                             }
             }
-            pub fn negate(&self) -> G1Element<R,N,MAX_COEFS_COUNT>
+            fn b3(&self) -> FieldElement<N,P>
+            {
+                self.consts.b.double().addto(&self.consts.b)
+            }
+            pub fn negate(&self) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
                 G1Element { point :self.point.negate(),
                             consts : self.consts,
@@ -79,62 +104,63 @@ impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> G1Element<R,N,MAX
             {
                 G1Element { point :self.point.multiply_with_const(scalar), consts :self.consts }
             }
-            pub fn multiply(&self , scalar :&FieldElement<R>) -> G1Element<R,N,MAX_COEFS_COUNT>{
+            pub fn multiply(&self , scalar :&FieldElement<R,S>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>{
                 G1Element { point :self.point.multiply(&scalar),
                             consts :self.consts
                           }
             }
-            pub fn equal(&self, other : &G1Element<R,N,MAX_COEFS_COUNT>) -> bool 
+            pub fn multiply_hardened(&self , scalar :&FieldElement<R,S>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   // Opt-in side-channel hardened multiplication : fresh scalar blinding (k + m*r, m on 64 bits) and randomized Jacobian coordinates
+                // on top of the constant-time windowed multiplication (slower than glv_multiply, meant for long-term secret keys)
+                let randomizer = PrimeField::<N,P>::new().random_nonzero_element();
+                G1Element { point :self.point.multiply_hardened(scalar, OsRng.next_u64(), &randomizer),
+                            consts :self.consts
+                          }
+            }
+            pub fn equal(&self, other : &G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> bool 
             {
                 self.point.equal(&other.point)
             }
-            pub fn  glv_multiply(&self, scalar : &FieldElement<R>) -> G1Element<R,N,MAX_COEFS_COUNT>
-            {   let s = scalar.to_big_uint();
-                if s.bits()<(scalar.fieldparams.num_of_bits /2).try_into().unwrap() {self.multiply(&scalar)}
-                else {  let _2p     = self.point.double_jacobian();
-                        let ff: BigUint = BigUint::from_str("255").unwrap();
-                        let mut _2phi   = EcPoint{x: _2p.x.multiply(&self.consts.w), y: _2p.y, z: _2p.z.clone() };
-                        let infinit = EcPoint {x:self.point.x.one(), y : self.point.x.one(), z: self.point.x.zero() };                
-                        const TABLESIZE :usize = 1 << (WDSIZE - 1);
-                        const BLOCKSIZE :usize = 1 << (WSIZE - 1);
-                        let mut lookup = [infinit;TABLESIZE >> 1];    
-                        lookup[0] = EcPoint {   x: self.point.x.multiply(&self.consts.w.addto(&self.point.x.one())).negate(),
-                                                y: self.point.y.negate(),
-                                                z: self.point.z.clone()};
-                        lookup[1] = _2p.add_jacobian(&lookup[0]);
-                        lookup[2] = _2p.add_jacobian(&lookup[1]);
-                        lookup[3] = _2p.add_jacobian(&lookup[2]);
-                        for i in BLOCKSIZE..(TABLESIZE >> 1)  { lookup[i] = _2phi.add_jacobian(&lookup[i - BLOCKSIZE]) };
-                        let alpha = (&s).bitor(&self.consts.lambda_big).bitand(BigUint::one());
-                        let r = BigUint::from_str_radix(&scalar.fieldparams.modulo_as_strhex[2..], 16).unwrap(); 
-                        let a = (&r - &s) * (BigUint::one() - &alpha) + &s * &alpha;
-                        let mut code = recod_scalar_glv2(&a, &self.consts.lambda_big);                
-                        let mut limb = (&code).bitand((ff).clone()).to_u8().unwrap();
-                        let mut fi   = limb & 1;
-                        let mut sig : i8  = (limb & 2) as i8 - 1;
-                        let mut idx = ((limb & WDMASK) >> 2) as usize;
-                        let mut result =  if fi == 0 { G1Element {point :lookup[idx], consts : self.consts}} 
-                                                                            else { G1Element {point :lookup[idx], consts : self.consts}.phi().negate()};
-                        if sig == -1 {result.point =  result.point.negate()};                                                
-                        code = code >> WDSIZE;
-                        while code != BigUint::one() {  limb = (&code).bitand((ff).clone()).to_u8().unwrap();
-                                                        fi   = limb & 1; 
-                                                        sig  = (limb & 2) as i8 - 1;
-                                                        idx  = ((limb & WDMASK) >> 2) as usize;
-                                                        result.point = result.point.double_jacobian(); 
-                                                        result.point = result.point.double_jacobian(); 
-                                                        result.point = result.point.double_jacobian(); 
-                                                        let tmp =  if fi == 0 { G1Element {point :lookup[idx], consts : self.consts}} 
-                                                                                                    else { G1Element {point :lookup[idx], consts : self.consts}.phi().negate()};
-                                                        result.point = if sig ==1 {result.point.add_jacobian(&tmp.point)}
-                                                                    else {result.point.add_jacobian(&tmp.point.negate())};
-                                                        code = code >> WDSIZE;
-                                                    }           
-                        if alpha.is_zero() { G1Element {point : result.point.negate(), consts:self.consts}}
-                        else {result}}
-            }
-
-            pub fn phi(&self) -> G1Element<R,N,MAX_COEFS_COUNT>
+            pub fn  glv_multiply(&self, scalar : &FieldElement<R,S>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   // Constant-time GLV multiplication : k is decomposed as k = k0 + k1*λ (mod r) with |ki| ~ sqrt(r) (Babai rounding on fixed limbs),
+                // then k*P = k0*P + k1*φ(P) is evaluated with the regular recoding of both (made odd) sub-scalars, sharing the doublings.
+                // Faz-Hernández, Longa and Sánchez https://eprint.iacr.org/2013/158.pdf, and Algorithm 12 from the paper:
+                // "Optimizing and securing GLV multiplication over BLS pairings-friendly curves"
+                if self.point.z.is_zero() { return *self }
+                let subscalars = self.consts.glv_decomposer.decompose(&scalar.to_canonical_limbs());
+                let count = regular_digits_count(self.consts.glv_decomposer.bound_bits, WSIZE);
+                let double = self.point.double_jacobian();
+                let mut lookup0 = [self.point;1 << (WSIZE - 1)];
+                for i in 1..lookup0.len() { lookup0[i] = lookup0[i - 1].add_jacobian(&double) };
+                // The tables of k0*P and k1*φ(P) absorb the signs of the sub-scalars
+                let mut lookup1 = lookup0.map(|p| EcPoint{x: p.x.multiply(&self.consts.w), y: p.y, z: p.z });
+                for p in lookup0.iter_mut() { p.conditional_negate(subscalars[0].1) };
+                for p in lookup1.iter_mut() { p.conditional_negate(subscalars[1].1) };
+                let (mut k0, mut k1) = (subscalars[0].0, subscalars[1].0);
+                let (even0, even1) = (Choice::from(((k0[0] & 1) ^ 1) as u8), Choice::from(((k1[0] & 1) ^ 1) as u8));
+                k0[0] |= 1;
+                k1[0] |= 1;
+                let (code0, code1) = (recod_regular(&k0, WSIZE, count), recod_regular(&k1, WSIZE, count));
+                let mut result = signed_digit_lookup(&lookup0, code0[count - 1]).add_jacobian(&signed_digit_lookup(&lookup1, code1[count - 1]));
+                for i in (1..count - 1).rev() { for _ in 0..WSIZE { result = result.double_jacobian() };
+                                                result = result.add_jacobian(&signed_digit_lookup(&lookup0, code0[i]));
+                                                result = result.add_jacobian(&signed_digit_lookup(&lookup1, code1[i]));
+                                              }
+                // Only the last additions can meet the partial sums (or their opposites), since the accumulated sub-scalars are then
+                // of the size of the shortest vectors of the lattice : they use the complete formulas, as the corrections below
+                for _ in 0..WSIZE { result = result.double_jacobian() };
+                let mut result = G1Element {point : result, consts : self.consts};
+                result = result.addto(&G1Element {point : signed_digit_lookup(&lookup0, code0[0]), consts : self.consts});
+                result = result.addto(&G1Element {point : signed_digit_lookup(&lookup1, code1[0]), consts : self.consts});
+                // Remove the contributions added to make the sub-scalars odd (always computed)
+                let corrected = result.substract(&G1Element {point : lookup0[0], consts : self.consts});
+                result.conditional_assign(&corrected, even0);
+                let corrected = result.substract(&G1Element {point : lookup1[0], consts : self.consts});
+                result.conditional_assign(&corrected, even1);
+                result
+            }
+
+            pub fn phi(&self) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
                 G1Element{ point : EcPoint{x: self.point.x.multiply(&self.consts.w), y: self.point.y, z: self.point.z.clone() }, 
                            consts : self.consts}    
@@ -164,60 +190,154 @@ impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> G1Element<R,N,MAX
             {   
                 //  Point compression/Serialization as described by ZCach serialization format
                 //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
-                let mut p = self.point.clone();
-                p.to_affine();
-                let c_bit: u8 = 1;
-                let i_bit: u8 = if self.point.z.is_zero() {1} else {0};
-                let s_bit: i8 = if self.point.z.is_zero() {0} else {if self.point.y.sign()==1 {1} else {0}};                
-                let m_byte: u8 = (c_bit << 7) | (i_bit << 6) | (((s_bit + 1) as u8 >> 1) << 5);
-                let numbits = self.point.x.fieldparams.num_of_bits;                
-                let sizeinbytes = (numbits >> 3) + if (numbits % 8) ==0 {0} else {1};
-                let mut x_string = if self.point.z.is_zero() {i2osp(0, sizeinbytes)}
-                                            else {i2osp_pf(&p.x, sizeinbytes)};
-                if self.consts.base_field_numbits % 8 <=5 {x_string[0] = x_string[0] | m_byte;}
-                else {x_string.insert(0, m_byte);}
-                x_string
-            }
-            pub fn to_affine(&self) -> Self
+                self.encode(PointFormat::ZcashCompressed)
+            }
+            pub fn to_uncompressed_bytearray(&self) -> Vec<u8>
+            {   
+                self.encode(PointFormat::ZcashUncompressed)
+            }
+            pub fn encode(&self, format :PointFormat) -> Vec<u8>
+            {   
+                self.to_affine().encode(format)
+            }
+            pub fn encode_to_hex(&self, format :PointFormat) -> String
+            {   
+                to_hex(&self.encode(format))
+            }
+            pub fn to_affine(&self) -> G1Affine<R,S,N,P,MAX_COEFS_COUNT>
             {   
-                if ! self.point.z.is_one() {    let mut tmp = self.point.clone();
-                                                tmp.to_affine();
-                                                G1Element { point :tmp, consts :self.consts }  
-                                            }    
-                else {self.clone()}
+                let mut point = self.point;
+                if ! point.z.is_one() { point.to_affine() };
+                G1Affine { point, consts :self.consts }
+            }
+            pub fn batch_to_affine(points :&[G1Element<R,S,N,P,MAX_COEFS_COUNT>]) -> Vec<G1Affine<R,S,N,P,MAX_COEFS_COUNT>>
+            {   
+                //  Normalization of all the points with a single inversion (instead of one per point with to_affine)
+                let mut normalized : Vec<_> = points.iter().map(|p| p.point).collect();
+                EcPoint::batch_normalize(&mut normalized);
+                points.iter().zip(normalized).map(|(p, point)| G1Affine { point, consts :p.consts }).collect()
+            }
+            pub fn to_affine_vartime(&self) -> G1Affine<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                //  Normalization with the variable-time inversion, for public points only (pairing inputs, verification)
+                let mut point = self.point;
+                if ! point.z.is_one() { point.to_affine_vartime() };
+                G1Affine { point, consts :self.consts }
+            }
+            pub fn batch_to_affine_vartime(points :&[G1Element<R,S,N,P,MAX_COEFS_COUNT>]) -> Vec<G1Affine<R,S,N,P,MAX_COEFS_COUNT>>
+            {   
+                let mut normalized : Vec<_> = points.iter().map(|p| p.point).collect();
+                EcPoint::batch_normalize_vartime(&mut normalized);
+                points.iter().zip(normalized).map(|(p, point)| G1Affine { point, consts :p.consts }).collect()
+            }
+            pub fn add_affine(&self, other: &G1Affine<R,S,N,P,MAX_COEFS_COUNT>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                //  Mixed addition (z2 = 1 saves 4 multiplications and 1 squaring over add_jacobian), exception-free 
+                G1Element { point :self.point.add_mixed(&other.point), consts :self.consts }
+            }
+            pub fn substract_affine(&self, other: &G1Affine<R,S,N,P,MAX_COEFS_COUNT>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                G1Element { point :self.point.add_mixed(&other.point.negate()), consts :self.consts }
+            }
+        }
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> G1Affine<R,S,N,P,MAX_COEFS_COUNT>
+        {
+            pub fn to_projective(&self) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {
+                G1Element { point :self.point, consts :self.consts }
+            }
+            pub fn negate(&self) -> Self
+            {
+                G1Affine { point :self.point.negate(), consts :self.consts }
+            }
+            pub fn is_infinit(&self) -> bool
+            {
+                self.point.z.is_zero()
+            }
+            pub fn equal(&self, other :&Self) -> bool
+            {   // Both points are normalized, so the coordinates are compared without any multiplication
+                let (inf1, inf2) = (self.point.z.ct_eq(&self.point.z.zero()), other.point.z.ct_eq(&other.point.z.zero()));
+                let same = self.point.x.ct_eq(&other.point.x) & self.point.y.ct_eq(&other.point.y);
+                ((inf1 & inf2) | (!inf1 & !inf2 & same)).into()
+            }
+            pub fn is_on_curve(&self) -> bool
+            {
+                self.is_infinit() || self.point.y.sqr().equal(&self.point.x.sqr().multiply(&self.point.x).addto(&self.consts.b))
+            }
+            pub fn is_torsion(&self) -> bool
+            {
+                self.to_projective().is_torsion()
+            }
+            pub fn to_hex_string(&self) -> String
+            {
+                self.point.to_hex_string()
+            }
+            pub fn encode_to_base64(&self) ->String
+            {
+                general_purpose::STANDARD.encode(self.to_compressed_bytearray())
+            }
+            pub fn to_compressed_bytearray(&self) -> Vec<u8>
+            {
+                //  Point compression/Serialization as described by ZCach serialization format
+                //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
+                self.encode(PointFormat::ZcashCompressed)
+            }
+            pub fn to_uncompressed_bytearray(&self) -> Vec<u8>
+            {
+                self.encode(PointFormat::ZcashUncompressed)
+            }
+            pub fn encode(&self, format :PointFormat) -> Vec<u8>
+            {
+                encode_point(&[self.point.x], &[self.point.y], self.point.y.sign() == -1, self.point.z.is_zero(), format)
+            }
+            pub fn encode_to_hex(&self, format :PointFormat) -> String
+            {
+                to_hex(&self.encode(format))
             }
         }
 
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> FixedBaseTable<G1Element<R,S,N,P,MAX_COEFS_COUNT>, FieldElement<N,P>>
+        {
+            pub fn new(base :&G1Element<R,S,N,P,MAX_COEFS_COUNT>, window :u8) -> Self
+            {   // Precomputation for the scalars of Fr, with (bits(r)/w + 1) * 2^(w-1) points
+                FixedBaseTable::from_point(*base, &base.point, S::PARAMS.num_of_bits, window)
+            }
+            pub fn mul(&self, scalar :&FieldElement<R,S>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   // Constant-time multiplication of the fixed base, without any doubling
+                G1Element { point :self.multiply_point(&scalar.to_canonical_limbs()), consts :self.base.consts }
+            }
+        }
 
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT : usize> G1Field<R,N,MAX_COEFS_COUNT> 
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT : usize> G1Field<R,S,N,P,MAX_COEFS_COUNT> 
     {
-        fn random_point_using_swu(&self,seed :FieldElement<N>) -> G1Element<R,N,MAX_COEFS_COUNT>{ 
-            //     Simplified Shallue-van de Woestijne-Ulas Method (Simplified SWU for AB == 0)
-            //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#section-6.6.3
-            //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#appendix-C.2            
+        fn random_point_using_swu(&self,seed :FieldElement<N,P>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>{ 
+            //     A zero seed is replaced by a random field element
             let mut u = self.base_field.random_element();
             if !seed.is_zero() {u=seed};            
+            self.sswu_map(&u)
+        }
+
+        fn sswu_map(&self,u :&FieldElement<N,P>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>{ 
+            //     Simplified Shallue-van de Woestijne-Ulas Method (Simplified SWU for AB == 0)
+            //     https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-shallue-van-de-w
+            //     https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0
+            //     All the branches are replaced by constant-time selections (exceptional case, choice of the candidate, and sign of y)
             let t1 = self.consts.swu_consts.z.multiply(&u.sqr());
             let mut t2 = t1.sqr();
-            let mut x1 = t1.addto(&t2).invert();
-            if x1.is_zero() {x1 = self.consts.swu_consts.inv_z.clone() }
-            else {x1 = x1.addto(&self.base_field.one())}
-            x1 = x1.multiply(&self.consts.swu_consts.b_div_a);            
+            let inv = t1.addto(&t2).invert_ct();
+            let x1 = FieldElement::conditional_select(&self.consts.swu_consts.swu_b.multiply(&self.consts.swu_consts.z.multiply(&self.consts.swu_consts.swu_a).invert()),
+                                                      &inv.unwrap_or(self.base_field.zero()).addto(&self.base_field.one()).multiply(&self.consts.swu_consts.b_div_a),
+                                                      inv.is_some());
             let gx1 = x1.sqr().addto(&self.consts.swu_consts.swu_a).multiply(&x1).addto(&self.consts.swu_consts.swu_b);
-            let ty = gx1.sqrt();
-            let mut x: FieldElement<N>;
-            let mut y: FieldElement<N>;
-            if !ty.is_none() {  y = ty.unwrap();
-                                if u.sign()!= ty.unwrap().sign() { y = y.negate()}
-                                x = x1.clone();  
-                            }
-            else { let x2 = t1.multiply(&x1);
-                   t2 = t2.multiply(&t1);                   
-                   let gx2 = gx1.multiply(&t2);                 
-                   y  = gx2.sqrt().unwrap();
-                   if u.sign() != y.sign() {  y = y.negate();} 
-                   x = x2.clone(); 
-                }
+            // Both candidates are always computed, and the square root is extracted only once from the selected one
+            let x2 = t1.multiply(&x1);
+            t2 = t2.multiply(&t1);
+            let gx2 = gx1.multiply(&t2);
+            let e1 = gx1.is_square();
+            let mut x = FieldElement::conditional_select(&x2, &x1, e1);
+            let mut y = FieldElement::conditional_select(&gx2, &gx1, e1).sqrt_ct().expect("SWU: the selected candidate is always a square");
+            y.conditional_negate(Choice::from(u.sgn0() ^ y.sgn0()));
             let mut pow = x.clone();
             let mut xnum = self.consts.swu_consts.xnum[0];
             let mut xden = self.consts.swu_consts.xden[0];
@@ -239,159 +359,307 @@ impl <const R:usize,const N:usize,const MAX_COEFS_COUNT : usize> G1Field<R,N,MAX
                       }
 
         }
-        pub fn map_to_curve(&self,seed1 : FieldElement<N>) ->G1Element<R,N,MAX_COEFS_COUNT> 
+        pub fn map_to_curve(&self,seed1 : FieldElement<N,P>) ->G1Element<R,S,N,P,MAX_COEFS_COUNT> 
         {  
              self.random_point_using_swu(seed1)
              
         }
-        pub fn random_point_withseed(&self,seed1 : FieldElement<N>) -> G1Element<R,N,MAX_COEFS_COUNT>
+        pub fn random_point_withseed(&self,seed1 : FieldElement<N,P>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {   
                 let mut rp = self.map_to_curve(seed1);
                 rp = rp.multiply_by_const(self.consts.h1 as i128);
                 rp    
             }
-        pub fn random_point(&self) -> G1Element<R,N,MAX_COEFS_COUNT>
+        pub fn random_point(&self) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {   
                 let mut rp = self.map_to_curve(self.base_field.zero());
                 rp = rp.multiply_by_const(self.consts.h1 as i128);                
-                rp.to_affine()    
+                rp.to_affine().to_projective()
+            }
+        pub fn hash_to_field(&self,msg :&[u8], dst :&[u8], count :usize) -> Vec<FieldElement<N,P>>
+            {   
+                //  Hash a message to "count" elements of the base field (with L = ceil((ceil(log2(p)) + k) / 8) bytes per element)
+                //  https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
+                self.hash_to_field_with(msg, dst, count, self.consts.expander)
             }
-        pub fn hash_to_field(&self,id :&str,mode :u8) -> G1Element<R,N,MAX_COEFS_COUNT>
+
+        pub fn hash_to_field_with(&self,msg :&[u8], dst :&[u8], count :usize, expander :Expander) -> Vec<FieldElement<N,P>>
+            {   
+                //  Same as hash_to_field, with an explicit expander instead of the default one of the engine (consts.expander)
+                self.base_field.hash_to_field(msg, dst, self.consts.security_level, expander, count)
+            }
+
+        pub fn clear_cofactor(&self, p :&G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {   
-                //  mode=0: Encode to Curve (NU-encode-to-curve), mode=1: Random Oracle model (RO-hash-to-curve)
-                //  https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-06#name-roadmap
-                let hashs = self.base_field.hash_to_field(id,self.consts.security_level, 2);
-                if mode ==0 {self.random_point_withseed(hashs[0]).to_affine()}   
-                else {  let p1=self.random_point_withseed(hashs[0]);
-                        let p2=self.random_point_withseed(hashs[1]);
-                        p1.addto(&p2).to_affine()                        
-                     } 
+                //  Multiplication by the effective cofactor h_eff = 1-u (https://www.rfc-editor.org/rfc/rfc9380.html#name-clearing-the-cofactor)
+                p.multiply_by_const(self.consts.h1 as i128)
+            }
+
+        pub fn encode_to_curve(&self,msg :&[u8], dst :&[u8]) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                //  Nonuniform encoding (NU-encode-to-curve) : encode_to_curve(msg) = clear_cofactor(map_to_curve(hash_to_field(msg, 1)))
+                //  https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el
+                self.encode_to_curve_with(msg, dst, self.consts.expander)
+            }
+
+        pub fn encode_to_curve_with(&self,msg :&[u8], dst :&[u8], expander :Expander) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                let u = self.hash_to_field_with(msg, dst, 1, expander);
+                self.clear_cofactor(&self.sswu_map(&u[0]))
+            }
+
+        pub fn hash_to_curve(&self,msg :&[u8], dst :&[u8]) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                //  Random Oracle encoding (RO-hash-to-curve) : 
+                //  hash_to_curve(msg) = clear_cofactor(map_to_curve(u0) + map_to_curve(u1)) with (u0, u1) = hash_to_field(msg, 2)
+                //  https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el
+                self.hash_to_curve_with(msg, dst, self.consts.expander)
+            }
+
+        pub fn hash_to_curve_with(&self,msg :&[u8], dst :&[u8], expander :Expander) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {   
+                let u = self.hash_to_field_with(msg, dst, 2, expander);
+                self.clear_cofactor(&self.sswu_map(&u[0]).addto(&self.sswu_map(&u[1])))
             }
         
-        pub fn from_bytearray(&self,inbytes : &Vec<u8>) -> G1Element<R,N,MAX_COEFS_COUNT>
+        pub fn from_bytearray(&self,inbytes : &Vec<u8>) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
-                //  Point de-compression/de-Serialization as described by ZCach serialization format
-                //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
-                let mut input = inbytes.clone();
-                let m_byte = input[0] & 0xE0;
-                let numbits = self.base_field.parametres.num_of_bits;
-                let sizeinbytes = (numbits >> 3) + if (numbits % 8) ==0 {0} else {1};
-                if self.consts.base_field_numbits % 8 <=5 {input[0] = input[0] & 0x1F;}
-                else {input.remove(0);};
-                if m_byte == 0xE0 {panic!("Invalide compressed point format ...")};
-                if m_byte & 0x80 !=0 { if input.len() != sizeinbytes {panic!("Invalide compressed point format ...")} 
-                                     }
-                else {if input.len() != (sizeinbytes*2) {panic!("Invalide compressed point format ...")}}
-                if m_byte & 0x40 !=0 { if input.iter().any(|&e| e != 0) {panic!("Invalid compression of an infinity point...");} 
-                                       else {G1Element {  point : EcPoint {x:self.base_field.one(), y : self.base_field.one(), z: self.base_field.zero() },
-                                                          consts :self.consts}
-                                            } 
-                                     }
-                else {  if input.len() == (sizeinbytes*2){ let x = self.base_field.from_bigint(&os2ip(&input[0..sizeinbytes]).to_bigint().unwrap());
-                                                          let y = self.base_field.from_bigint(&os2ip(&input[sizeinbytes..]).to_bigint().unwrap());
-                                                          G1Element {  point : EcPoint { x, y, z: self.base_field.one() },
-                                                          consts :self.consts}  
-                                                         }
-                        else { let x = self.base_field.from_bigint(&os2ip(&input[0..sizeinbytes]).to_bigint().unwrap());
-                               let y = x.sqr().multiply(&x).addto(&&self.consts.b).sqrt();
-                               if y.is_none() {panic!("Invalide point: not in the curve ...")}
-                               else { let y = y.unwrap();
-                                      let r_sign = if m_byte & 0x20 !=0 {1} else {0}; 
-                                      if (y.sign()+1) >> 1 == r_sign {G1Element {  point : EcPoint { x, y, z: self.base_field.one() },
-                                                                                   consts :self.consts}  }
-                                      else {G1Element {  point : EcPoint { x, y: y.negate(), z: self.base_field.one() },
-                                                         consts :self.consts}  }
-                                    }
-                             }                                 
-                     }
-            }
-            pub fn from_base64(&self,input :&str) -> G1Element<R,N,MAX_COEFS_COUNT>
-            {
-                let decoded_bytes = match general_purpose::STANDARD.decode(input) {
-                    Ok(bytes) => bytes,
-                    Err(_) => {
-                        panic!("Failed to decode base64 string");
-                    }
-                };
-                self.from_bytearray(&decoded_bytes)
-            }
-
-            pub fn default_generator(&self)->G1Element<R,N,MAX_COEFS_COUNT>
+                match self.try_from_bytearray(inbytes) { Ok(p) => p,
+                                                         Err(e) => panic!("{}",e)}
+            }
+
+        pub fn try_from_bytearray(&self,inbytes : &[u8]) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                self.try_from_bytearray_with(inbytes, DecodingMode::Subgroup)
+            }
+
+        pub fn try_from_bytearray_with(&self,inbytes : &[u8], mode :DecodingMode) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                //  ZCash compressed or uncompressed input, according to the compression bit
+                let format = if inbytes.first().is_some_and(|b| b & 0x80 != 0) {PointFormat::ZcashCompressed} else {PointFormat::ZcashUncompressed};
+                self.try_decode_with(inbytes, format, mode)
+            }
+
+        pub fn decode(&self,inbytes : &[u8], format :PointFormat) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {
+                match self.try_decode(inbytes, format) { Ok(p) => p,
+                                                         Err(e) => panic!("{}",e)}
+            }
+
+        pub fn try_decode(&self,inbytes : &[u8], format :PointFormat) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                self.try_decode_with(inbytes, format, DecodingMode::Subgroup)
+            }
+
+        pub fn try_decode_hex(&self,input :&str, format :PointFormat) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                self.try_decode(&try_from_hex(input)?, format)
+            }
+
+        pub fn try_decode_with(&self,inbytes : &[u8], format :PointFormat, mode :DecodingMode) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                self.try_decode_affine_with(inbytes, format, mode).map(|p| p.to_projective())
+            }
+
+        pub fn try_decode_affine_with(&self,inbytes : &[u8], format :PointFormat, mode :DecodingMode) -> Result<G1Affine<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                //  Every malformed input is reported as an error: size, flag bits, padding, non-canonical coordinates, 
+                //  points outside the curve and points outside the prime-order sub-group.
+                //  The curve and sub-group checks follow the decoding mode (a compressed point always has to be on the curve)
+                let result = match decode_point(self.base_field, 1, inbytes, format)? {
+                        EncodedPoint::Infinity => return Ok(G1Affine { point : EcPoint {x:self.base_field.one(), y : self.base_field.one(), z: self.base_field.zero() },
+                                                                       consts :self.consts}),
+                        EncodedPoint::Affine(x, y) => { let p = G1Affine {  point : EcPoint { x: x[0], y: y[0], z: self.base_field.one() }, consts :self.consts};
+                                                        if mode != DecodingMode::Unchecked && !p.is_on_curve() {return Err(PairingError::NotOnCurve)}
+                                                        p
+                                                      },
+                        EncodedPoint::Compressed(x, largest) => {   let x = x[0];
+                                                                    let y = match x.sqr().multiply(&x).addto(&self.consts.b).sqrt() { Some(y) => y,
+                                                                                                                                     None => return Err(PairingError::NotOnCurve)};
+                                                                    let y = if (y.sign() == -1) == largest {y} else {y.negate()};
+                                                                    G1Affine {  point : EcPoint { x, y, z: self.base_field.one() }, consts :self.consts}
+                                                                },
+                    };
+                if mode == DecodingMode::Subgroup && !result.is_torsion() {return Err(PairingError::NotInSubgroup)}
+                Ok(result)
+            }
+
+            pub fn from_base64(&self,input :&str) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {
+                match self.try_from_base64(input) { Ok(p) => p,
+                                                    Err(e) => panic!("{}",e)}
+            }
+
+            pub fn try_from_base64(&self,input :&str) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                let decoded_bytes = general_purpose::STANDARD.decode(input).map_err(|_| PairingError::InvalidBase64)?;
+                self.try_from_bytearray(&decoded_bytes)
+            }
+
+            pub fn default_generator(&self)->G1Element<R,S,N,P,MAX_COEFS_COUNT>
             {
                 G1Element { point : EcPoint { x : self.consts.default_generator.x, 
                                                y : self.consts.default_generator.y, 
                                                z : self.consts.default_generator.z },
-                            consts :self.consts} 
+                            consts :self.consts}
+            }
+
+            pub fn generator_table(&self) -> &'static G1FixedBaseTable<R,S,N,P,MAX_COEFS_COUNT>
+            {   // Fixed-base table of the default generator, built on the first use and shared by all the threads
+                let consts = self.consts;
+                consts.generator_table.get_or_init(|| G1FixedBaseTable::new(&self.default_generator(), FIXED_BASE_WINDOW))
+            }
+
+            pub fn msm(&self, points :&[G1Element<R,S,N,P,MAX_COEFS_COUNT>], scalars :&[FieldElement<R,S>]) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {
+                match self.try_msm(points, scalars, 1) { Ok(p) => p,
+                                                         Err(e) => panic!("{}",e)}
+            }
+
+            pub fn msm_parallel(&self, points :&[G1Element<R,S,N,P,MAX_COEFS_COUNT>], scalars :&[FieldElement<R,S>], threads :usize) -> G1Element<R,S,N,P,MAX_COEFS_COUNT>
+            {
+                match self.try_msm(points, scalars, threads) { Ok(p) => p,
+                                                               Err(e) => panic!("{}",e)}
+            }
+
+            pub fn try_msm(&self, points :&[G1Element<R,S,N,P,MAX_COEFS_COUNT>], scalars :&[FieldElement<R,S>], threads :usize) -> Result<G1Element<R,S,N,P,MAX_COEFS_COUNT>, PairingError>
+            {
+                //  Multi-scalar multiplication sum(scalars[i]*points[i]) (not Constant-time : for public scalars as in KZG/aggregation)
+                //  Each scalar is split with the GLV decomposition of the multiplication (consts.glv_decomposer) as k = k0 + k1*λ (|ki| ~ sqrt(r)),
+                //  so k*P = k0*P + k1*φ(P), then the 2n half-size multiplications are evaluated with Straus (small n) or Pippenger (large n)
+                if points.len() != scalars.len() {return Err(PairingError::LengthMismatch { left: points.len(), right: scalars.len() })}
+                let mut expoints = Vec::with_capacity(2 * points.len());
+                let mut exscalars = Vec::with_capacity(2 * points.len());
+                for (p, k) in points.iter().zip(scalars.iter()) {   if p.point.is_infinit() {continue}
+                                                                    let subscalars = self.consts.glv_decomposer.decompose(&k.to_canonical_limbs());
+                                                                    for ((v, negative), q) in subscalars.iter().zip([p.point, p.phi().point]) {
+                                                                        // The scalars are public : zero sub-scalars are skipped and the signs are applied to the points
+                                                                        if v.iter().all(|l| *l == 0) {continue}
+                                                                        expoints.push(if bool::from(*negative) {q.negate()} else {q});
+                                                                        exscalars.push(*v);
+                                                                    }
+                                                                }
+                let infinit = G1Element { point : EcPoint {x:self.base_field.one(), y : self.base_field.one(), z: self.base_field.zero() },
+                                          consts :self.consts};
+                if expoints.is_empty() {return Ok(infinit)}
+                Ok(G1Element { point : multi_scalar_multiply(&expoints, &exscalars, &self.consts.b.double().addto(&self.consts.b), threads),
+                               consts :self.consts})
             }
     }
     
 
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> fmt::Display for G1Element<R,N,MAX_COEFS_COUNT> {
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> ConditionallySelectable for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            G1Element { point : EcPoint::conditional_select(&a.point, &b.point, choice), consts : a.consts }
+        }
+    }
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> ConditionallyNegatable for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        fn conditional_negate(&mut self, choice: Choice) {
+            self.point.conditional_negate(choice)
+        }
+    }
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> fmt::Display for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             write!(f, "{:}", &self.to_string())
         }
     }
 
-impl  <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Add for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output =  G1Element<R,N,MAX_COEFS_COUNT>;
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> ConditionallySelectable for G1Affine<R,S,N,P,MAX_COEFS_COUNT> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            G1Affine { point : EcPoint::conditional_select(&a.point, &b.point, choice), consts : a.consts }
+        }
+    }
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> fmt::Display for G1Affine<R,S,N,P,MAX_COEFS_COUNT> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:}", &self.point.to_string())
+        }
+    }
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> From<G1Affine<R,S,N,P,MAX_COEFS_COUNT>> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        fn from(p: G1Affine<R,S,N,P,MAX_COEFS_COUNT>) -> Self {   p.to_projective() }
+    }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> From<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for G1Affine<R,S,N,P,MAX_COEFS_COUNT> {
+        fn from(p: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self {   p.to_affine() }
+    }
+
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Add<G1Affine<R,S,N,P,MAX_COEFS_COUNT>> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn add(self, rhs: G1Affine<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {   self.add_affine(&rhs) }
+    }
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Sub<G1Affine<R,S,N,P,MAX_COEFS_COUNT>> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn sub(self, rhs: G1Affine<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {   self.substract_affine(&rhs) }
+    }
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Neg for G1Affine<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Affine<R,S,N,P,MAX_COEFS_COUNT>;
+            fn neg(self) -> Self::Output { self.negate() }
+        }
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> PartialEq for G1Affine<R,S,N,P,MAX_COEFS_COUNT> {
+            fn eq(&self, other: &Self) -> bool {    self.equal(other) }
+        }
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Add for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Element<R,S,N,P,MAX_COEFS_COUNT>;
             fn add(self, rhs: Self) -> Self::Output {   self.addto(&rhs) }
     }
-impl  <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Sub for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output =  G1Element<R,N,MAX_COEFS_COUNT>;
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Sub for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Element<R,S,N,P,MAX_COEFS_COUNT>;
             fn sub(self, rhs: Self) -> Self::Output {   self.substract(&rhs) }
     }
-impl  <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Neg for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output =  G1Element<R,N,MAX_COEFS_COUNT>;
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Neg for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output =  G1Element<R,S,N,P,MAX_COEFS_COUNT>;
             fn neg(self) -> Self::Output { self.negate() }
         }   
-impl  <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> PartialEq for G1Element<R,N,MAX_COEFS_COUNT> {
+impl  <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> PartialEq for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
             fn eq(&self, other: &Self) -> bool {    self.equal(other) }
         }
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i128> for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;    
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<i128> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;    
             fn mul(self, rhs: i128) -> Self::Output {     self.multiply_by_const(rhs)   }
         }
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for i128 {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-        fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for i128 {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+        fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self)  }
         }
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<u64> for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;    
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<u64> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;    
             fn mul(self, rhs: u64) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for u64 {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for u64 {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i64> for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;    
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<i64> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;    
             fn mul(self, rhs: i64) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for i64 {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for i64 {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<u8> for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;    
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<u8> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;    
             fn mul(self, rhs: u8) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for u8 {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for u8 {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i8> for G1Element<R,N,MAX_COEFS_COUNT> {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;    
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<i8> for G1Element<R,S,N,P,MAX_COEFS_COUNT> {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;    
             fn mul(self, rhs: i8) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for i8 {
-        type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for i8 {
+        type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+            fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,N,MAX_COEFS_COUNT>> for FieldElement<R> {
-            type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-                fn mul(self, rhs: G1Element<R,N,MAX_COEFS_COUNT>) -> Self::Output {  (&rhs).glv_multiply(&self)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<G1Element<R,S,N,P,MAX_COEFS_COUNT>> for FieldElement<R,S> {
+            type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+                fn mul(self, rhs: G1Element<R,S,N,P,MAX_COEFS_COUNT>) -> Self::Output {  (&rhs).glv_multiply(&self)  }
             }    
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<FieldElement<R>> for G1Element<R,N,MAX_COEFS_COUNT>  {
-                type Output = G1Element<R,N,MAX_COEFS_COUNT>;
-                    fn mul(self, rhs: FieldElement<R>) -> Self::Output {  self.glv_multiply(&rhs)  }
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize> Mul<FieldElement<R,S>> for G1Element<R,S,N,P,MAX_COEFS_COUNT>  {
+                type Output = G1Element<R,S,N,P,MAX_COEFS_COUNT>;
+                    fn mul(self, rhs: FieldElement<R,S>) -> Self::Output {  self.glv_multiply(&rhs)  }
                 }                
\ No newline at end of file
@@ -2,82 +2,123 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
-use std::{fmt, ops::{Add, Mul, Neg, Sub}};
+use std::{fmt, marker::PhantomData, ops::{Add, Mul, Neg, Sub}};
 use base64::{engine::general_purpose, Engine};
-use num_bigint::BigUint;
-use crate::{extensions::{ext_fields::ExFieldConsts, g2_extfields::{ExtFieldG2Element, ExtG2Field}}, 
-            fields::prime_fields::{FieldElement, PrimeField}, 
-            tools::{arithmetic_interface::ArithmeticOperations, hashs::i2osp}};
-use super::{curve_arithmetics::EcPoint, g2_primitives::{cofactor_clean::{clean_cofactor_bls12, clean_cofactor_bls24, clean_cofactor_bls48}, 
-            gls_multiplication::{gls16_multiply_bls48, gls4_multiply_bls12, gls8_multiply_bls24}, 
+use once_cell::sync::OnceCell;
+use rand::{rngs::OsRng, RngCore};
+use crate::{errors::{DecodingMode, PairingError},
+            extensions::{ext_fields::ExFieldConsts, g2_extfields::{ExtG2Field, G2ExtElement}}, 
+            fields::prime_fields::{FieldConfig, FieldElement, PrimeField}, 
+            tools::{constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable}, codecs::{decode_point, encode_point, to_hex, try_from_hex, EncodedPoint, PointFormat}, decomposition::LatticeDecomposer, exponent::Exponent, hashs::Expander}};
+use super::{curve_arithmetics::EcPoint, fixed_base::{FixedBaseTable, FIXED_BASE_WINDOW}, msm::multi_scalar_multiply, g2_primitives::{cofactor_clean::{clean_cofactor_bls12, clean_cofactor_bls24, clean_cofactor_bls48}, 
+            gls_multiplication::gls_multiply, 
             phi::{ phi_bls12, phi_bls24, phi_bls48}}};
 
 
             
 #[derive(Debug)]
-pub struct G2SwuIsogeniesConsts<const PRAMASIZE:usize,const N:usize, const MAX_COEFS_COUNT:usize> 
-        {   pub z: ExtFieldG2Element<N,PRAMASIZE>,
-            pub swu_a:ExtFieldG2Element<N,PRAMASIZE>,
-            pub swu_b:ExtFieldG2Element<N,PRAMASIZE>,
-            pub xnum :[ExtFieldG2Element<N,PRAMASIZE>;MAX_COEFS_COUNT],
-            pub xden :[ExtFieldG2Element<N,PRAMASIZE>;MAX_COEFS_COUNT],
-            pub ynum :[ExtFieldG2Element<N,PRAMASIZE>;MAX_COEFS_COUNT],
-            pub yden :[ExtFieldG2Element<N,PRAMASIZE>;MAX_COEFS_COUNT],
-            pub inv_z:ExtFieldG2Element<N,PRAMASIZE>,
-            pub j_inv_z:ExtFieldG2Element<N,PRAMASIZE>,
-            pub b_div_a:ExtFieldG2Element<N,PRAMASIZE>,        
+pub struct G2SwuIsogeniesConsts<const PRAMASIZE:usize,const N:usize, const MAX_COEFS_COUNT:usize, F> 
+        {   pub z: F,
+            pub swu_a:F,
+            pub swu_b:F,
+            pub xnum :[F;MAX_COEFS_COUNT],
+            pub xden :[F;MAX_COEFS_COUNT],
+            pub ynum :[F;MAX_COEFS_COUNT],
+            pub yden :[F;MAX_COEFS_COUNT],
+            pub inv_z:F,
+            pub j_inv_z:F,
+            pub b_div_a:F,        
         }
 
 #[derive(Debug)]
-pub struct G2Consts<const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :usize>
-    {   pub b:  ExtFieldG2Element<N,PRAMASIZE>,
-        pub a : ExtFieldG2Element<N,PRAMASIZE>,
-        pub swu_consts :G2SwuIsogeniesConsts<PRAMASIZE,N,MAX_COEFS_COUNT>,
-        pub extfieldparams:ExFieldConsts<PRAMASIZE,N>,
+pub struct G2Consts<const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize, F: 'static>
+    {   pub b:  F,
+        pub a : F,
+        pub swu_consts :G2SwuIsogeniesConsts<PRAMASIZE,N,MAX_COEFS_COUNT,F>,
+        pub extfieldparams:ExFieldConsts<PRAMASIZE,N,P>,
         pub u :i128,
-        pub lambda: FieldElement<R>,
-        pub lambda_big: BigUint,
+        pub lambda: FieldElement<R,S>,
+        pub gls_decomposer: &'static LatticeDecomposer<R>,
+        pub generator_table: OnceCell<G2FixedBaseTable<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>>,
         pub base_field_numbits:usize,
         pub security_level:usize,
+        pub expander:Expander,
         pub twist_type :char,
         pub order : usize,
-        pub default_generator :EcPoint<ExtFieldG2Element<N,PRAMASIZE>>
+        pub default_generator :EcPoint<F>
     }
 
 #[derive(Clone,Copy)]
-pub struct G2Element<const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :usize>    
-    {   pub consts : &'static G2Consts<PRAMASIZE,R,N,MAX_COEFS_COUNT>,
-        pub point  : EcPoint<ExtFieldG2Element<N,PRAMASIZE>>,
+pub struct G2Element<const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize, F: 'static>    
+    {   pub consts : &'static G2Consts<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>,
+        pub point  : EcPoint<F>,
     }
 
+#[derive(Clone,Copy)]
+pub struct G2Affine<const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize, F: 'static>
+    {  // Normalized representation : z = 1, or z = 0 for the infinity point (G2Element holds any Jacobian representation)
+        pub consts : &'static G2Consts<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>,
+        pub point  : EcPoint<F>,
+    }
+
+// Jacobian points of G2, as returned by the group operations
+pub type G2Projective<const PRAMASIZE:usize,const R:usize, S, const N:usize, P, const MAX_COEFS_COUNT :usize, F> = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+
+// Fixed-base table of a G2 point, with the representation of the rows
+pub type G2FixedBaseTable<const PRAMASIZE:usize,const R:usize, S, const N:usize, P, const MAX_COEFS_COUNT :usize, F> = FixedBaseTable<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, F>;
+
 #[derive(Debug)]
-pub struct G2Field<const PRAMASIZE:usize, const R:usize,const N:usize,const MAX_COEFS_COUNT :usize>
-{   pub consts :  &'static G2Consts<PRAMASIZE,R,N,MAX_COEFS_COUNT>,
-    pub base_field: &'static ExtG2Field<N,PRAMASIZE>,
-    pub fr_field :  &'static PrimeField<R>,
+pub struct G2Field<const PRAMASIZE:usize, const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize, F: 'static>
+{   pub consts :  &'static G2Consts<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>,
+    pub base_field: &'static ExtG2Field<N,P,PRAMASIZE>,
+    pub fr_field :  &'static PrimeField<R,S>,
 }
 
+#[derive(Clone,Debug)]
+pub struct G2Prepared<const N:usize, P: FieldConfig<N>,const PRAMASIZE:usize, F>
+{   // Line coefficients of the Miller loop for a fixed point Q (one entry per doubling/addition step of the NAF loop of u),
+    // with Q in affine coordinates (serialized with the lines, so that they can be checked when loaded)
+    pub point : EcPoint<F>,
+    pub lines : Vec<[F;3]>,
+    pub infinity : bool,
+    pub field : PhantomData<P>,
+}
 
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :usize> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
-        {   
-            fn getorder(&self)->usize
-            {   match self.point.x {    ExtFieldG2Element::Fp2_1(_) => 2,
-                                        ExtFieldG2Element::Fp4_1(_) => 4,
-                                        ExtFieldG2Element::Fp8_1(_) => 8,
-                                        ExtFieldG2Element::Fp2_2(_) => 2,
-                                        ExtFieldG2Element::Fp4_2(_) => 4,
-                                        ExtFieldG2Element::Fp8_2(_) => 8,
-                                        ExtFieldG2Element::Fp4_3(_) => 4,
-                                        ExtFieldG2Element::Fp8_3(_) => 8,
-                                    } 
+impl <const N:usize, P: FieldConfig<N>,const PRAMASIZE:usize, F: G2ExtElement<N,P,PRAMASIZE>> G2Prepared<N,P,PRAMASIZE,F>
+        {
+            pub fn to_bytearray(&self) -> Vec<u8>
+            {   
+                //  Serialization of the prepared point: one flag byte (0x40 for the infinity point, 0x00 otherwise),
+                //  followed by the I2OSP encoding of the affine coordinates of Q and of the three coefficients of every line
+                let mut out = vec![if self.infinity {0x40u8} else {0u8}];
+                if self.infinity {return out}
+                out.extend(self.point.x.to_i2osp_bytearray());
+                out.extend(self.point.y.to_i2osp_bytearray());
+                for l in &self.lines { for c in l { out.extend(c.to_i2osp_bytearray()) } }
+                out
             }
+
+            pub fn encode_to_base64(&self) ->String
+            {   
+                general_purpose::STANDARD.encode(self.to_bytearray())
+            }
+        }
+
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT :usize, F: G2ExtElement<N,P,PRAMASIZE>> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+        {   
             pub fn addto(&self, other: &Self) -> Self
             {
-                G2Element { point :self.point.add_jacobian(&other.point), consts : self.consts }
+                //  Complete (exception-free) addition, correct for P+P, P+(-P) and the infinity point
+                G2Element { point :self.point.add_complete(&other.point, &self.b3()), consts : self.consts }
             }
             pub fn substract(&self, other: &Self) -> Self
             {
-                G2Element { point :self.point.add_jacobian(&other.point.negate()), consts : self.consts }
+                G2Element { point :self.point.add_complete(&other.point.negate(), &self.b3()), consts : self.consts }
+            }
+            fn b3(&self) -> F
+            {
+                self.consts.b.double().addto(&self.consts.b)
             }
             pub fn double(&self) -> Self
             {
@@ -87,26 +128,21 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :u
             {
                 G2Element { point :self.point.negate(), consts : self.consts }
             }
-            pub fn multiply(&self , scalar :&FieldElement<R>) -> Self
+            pub fn multiply(&self , scalar :&FieldElement<R,S>) -> Self
             {
                 G2Element { point :self.point.multiply(&scalar),consts :self.consts }
             }
-            pub fn multiply_gls(&self , scalar :&FieldElement<R>) -> Self
+            pub fn multiply_gls(&self , scalar :&FieldElement<R,S>) -> Self
             {
-                let _scalar: BigUint = scalar.to_big_uint();
-                if _scalar.bits()>((scalar.fieldparams.num_of_bits - (scalar.fieldparams.num_of_bits / self.consts.order))).try_into().unwrap()
-                {match self.point.x {   ExtFieldG2Element::Fp2_1(_) => gls4_multiply_bls12(&self, scalar),
-                                        ExtFieldG2Element::Fp4_1(_) => gls8_multiply_bls24(&self, scalar),
-                                        ExtFieldG2Element::Fp8_1(_) => gls16_multiply_bls48(&self, scalar),
-                                        ExtFieldG2Element::Fp2_2(_) => gls4_multiply_bls12(&self, scalar),
-                                        ExtFieldG2Element::Fp4_2(_) => gls8_multiply_bls24(&self, scalar),
-                                        ExtFieldG2Element::Fp8_2(_) => gls16_multiply_bls48(&self, scalar),
-                                        ExtFieldG2Element::Fp4_3(_) => gls8_multiply_bls24(&self, scalar),
-                                        ExtFieldG2Element::Fp8_3(_) => gls16_multiply_bls48(&self, scalar),
-                                   }  
-                    }
-                else {self.multiply( scalar)}  
+                gls_multiply(self, scalar)
             }            
+            pub fn multiply_hardened(&self , scalar :&FieldElement<R,S>) -> Self
+            {   // Opt-in side-channel hardened multiplication : fresh scalar blinding (k + m*r, m on 64 bits) and randomized Jacobian coordinates
+                // (with a random λ of Fp, embedded in the extension) on top of the constant-time windowed multiplication
+                let lambda = PrimeField::<N,P>::new().random_nonzero_element();
+                let randomizer = self.point.x.one().mulby_fp_element(&lambda);
+                G2Element { point :self.point.multiply_hardened(scalar, OsRng.next_u64(), &randomizer), consts :self.consts }
+            }
             pub fn multiply_by_const(&self , scalar :i128) -> Self
             {
                 G2Element { point :self.point.multiply_with_const(scalar), consts :self.consts }
@@ -117,27 +153,17 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :u
             }
             fn clean_cofactor(&self) -> Self
             {
-                match self.point.x {    ExtFieldG2Element::Fp2_1(_) => clean_cofactor_bls12(&self),
-                                        ExtFieldG2Element::Fp4_1(_) => clean_cofactor_bls24(&self),
-                                        ExtFieldG2Element::Fp8_1(_) => clean_cofactor_bls48(&self),
-                                        ExtFieldG2Element::Fp2_2(_) => clean_cofactor_bls12(&self),
-                                        ExtFieldG2Element::Fp4_2(_) => clean_cofactor_bls24(&self),
-                                        ExtFieldG2Element::Fp8_2(_) => clean_cofactor_bls48(&self),
-                                        ExtFieldG2Element::Fp4_3(_) => clean_cofactor_bls24(&self),
-                                        ExtFieldG2Element::Fp8_3(_) => clean_cofactor_bls48(&self),
-                                   }               
+                match self.consts.order {   2 => clean_cofactor_bls12(self),
+                                            4 => clean_cofactor_bls24(self),
+                                            _ => clean_cofactor_bls48(self),
+                                        }               
             }
             pub fn phi(&self) -> Self
             {   
-                match self.point.x {    ExtFieldG2Element::Fp2_1(_) => phi_bls12(&self),
-                                        ExtFieldG2Element::Fp4_1(_) => phi_bls24(&self,1),
-                                        ExtFieldG2Element::Fp8_1(_) => phi_bls48(&self,1),
-                                        ExtFieldG2Element::Fp2_2(_) => phi_bls12(&self),
-                                        ExtFieldG2Element::Fp4_2(_) => phi_bls24(&self,1),
-                                        ExtFieldG2Element::Fp8_2(_) => phi_bls48(&self,1),
-                                        ExtFieldG2Element::Fp4_3(_) => phi_bls24(&self,1),
-                                        ExtFieldG2Element::Fp8_3(_) => phi_bls48(&self,1)
-                                   }       
+                match self.consts.order {   2 => phi_bls12(self),
+                                            4 => phi_bls24(self,1),
+                                            _ => phi_bls48(self,1),
+                                        }       
             }
             pub fn is_torsion(&self) -> bool
             {   
@@ -161,14 +187,41 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :u
             {
                 self.point.to_hex_string()
             } 
-            pub fn to_affine(&self) -> Self
+            pub fn to_affine(&self) -> G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
             {   
-                if ! self.point.z.is_one() {    let mut tmp = self.point.clone();
-                                                tmp.to_affine();
-                                                G2Element { point :tmp, consts :self.consts }  
-                                            }     
-                else {  self.clone()}
-            }             
+                let mut point = self.point;
+                if ! point.z.is_one() { point.to_affine() };
+                G2Affine { point, consts :self.consts }
+            }
+            pub fn batch_to_affine(points :&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>]) -> Vec<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>>
+            {   
+                //  Normalization of all the points with a single inversion (instead of one per point with to_affine)
+                let mut normalized : Vec<_> = points.iter().map(|p| p.point).collect();
+                EcPoint::batch_normalize(&mut normalized);
+                points.iter().zip(normalized).map(|(p, point)| G2Affine { point, consts :p.consts }).collect()
+            }
+            pub fn to_affine_vartime(&self) -> G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+            {   
+                //  Normalization with the variable-time inversion, for public points only (pairing inputs, verification)
+                let mut point = self.point;
+                if ! point.z.is_one() { point.to_affine_vartime() };
+                G2Affine { point, consts :self.consts }
+            }
+            pub fn batch_to_affine_vartime(points :&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>]) -> Vec<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>>
+            {   
+                let mut normalized : Vec<_> = points.iter().map(|p| p.point).collect();
+                EcPoint::batch_normalize_vartime(&mut normalized);
+                points.iter().zip(normalized).map(|(p, point)| G2Affine { point, consts :p.consts }).collect()
+            }
+            pub fn add_affine(&self, other: &G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self
+            {   
+                //  Mixed addition (z2 = 1 saves 4 multiplications and 1 squaring over add_jacobian), exception-free 
+                G2Element { point :self.point.add_mixed(&other.point), consts :self.consts }
+            }
+            pub fn substract_affine(&self, other: &G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self
+            {   
+                G2Element { point :self.point.add_mixed(&other.point.negate()), consts :self.consts }
+            }
             pub fn encode_to_base64(&self) ->String
             {   
                 general_purpose::STANDARD.encode(self.encode_to_compressed_bytearray())
@@ -178,22 +231,90 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :u
             {   
                 //  Point compression/Serialization as described by ZCach serialization format
                 //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
-                let p = self.to_affine();
-                let c_bit: u8 = 1;
-                let i_bit: u8 = if p.point.z.is_zero() {1} else {0};
-                let s_bit: i8 = if p.point.z.is_zero() {0} else {if p.point.y.sign() == 1 {1} else {0}};   
-                let m_byte: u8 = (c_bit << 7) | (i_bit << 6) | (((s_bit + 1) as u8 >> 1) << 5);
-                let numbits = p.consts.base_field_numbits;                
-                let sizeinbytes = (numbits >> 3) + if (numbits % 8) ==0 {0} else {1};
-                let mut x_string = if self.point.z.is_zero() {i2osp(0, sizeinbytes * self.getorder())}
-                                            else {p.point.x.to_i2osp_bytearray()};
-                if self.consts.base_field_numbits % 8 <=5 {x_string[0] = x_string[0] | m_byte;}
-                else {x_string.insert(0, m_byte);}
-                x_string
+                self.encode(PointFormat::ZcashCompressed)
+            }
+            pub fn encode_to_uncompressed_bytearray(&self) -> Vec<u8>
+            {   
+                self.encode(PointFormat::ZcashUncompressed)
+            }
+            pub fn encode(&self, format :PointFormat) -> Vec<u8>
+            {   
+                self.to_affine().encode(format)
+            }
+            pub fn encode_to_hex(&self, format :PointFormat) -> String
+            {   
+                to_hex(&self.encode(format))
+            }
+        }
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+        {
+            pub fn to_projective(&self) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+            {
+                G2Element { point :self.point, consts :self.consts }
+            }
+            pub fn negate(&self) -> Self
+            {
+                G2Affine { point :self.point.negate(), consts :self.consts }
+            }
+            pub fn is_infinit(&self) -> bool
+            {
+                self.point.z.is_zero()
+            }
+            pub fn equal(&self, other :&Self) -> bool
+            {   // Both points are normalized, so the coordinates are compared without any multiplication
+                let (inf1, inf2) = (self.point.z.ct_eq(&self.point.z.zero()), other.point.z.ct_eq(&other.point.z.zero()));
+                let same = self.point.x.ct_eq(&other.point.x) & self.point.y.ct_eq(&other.point.y);
+                ((inf1 & inf2) | (!inf1 & !inf2 & same)).into()
+            }
+            pub fn is_on_curve(&self) -> bool
+            {
+                self.is_infinit() || self.point.y.sqr().equal(&self.point.x.sqr().multiply(&self.point.x).addto(&self.consts.b))
+            }
+            pub fn is_torsion(&self) -> bool
+            {
+                self.to_projective().is_torsion()
+            }
+            pub fn to_hex_string(&self) -> String
+            {
+                self.point.to_hex_string()
+            }
+            pub fn encode_to_base64(&self) ->String
+            {
+                general_purpose::STANDARD.encode(self.encode_to_compressed_bytearray())
+            }
+            pub fn encode_to_compressed_bytearray(&self) -> Vec<u8>
+            {
+                //  Point compression/Serialization as described by ZCach serialization format
+                //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
+                self.encode(PointFormat::ZcashCompressed)
+            }
+            pub fn encode_to_uncompressed_bytearray(&self) -> Vec<u8>
+            {
+                self.encode(PointFormat::ZcashUncompressed)
+            }
+            pub fn encode(&self, format :PointFormat) -> Vec<u8>
+            {
+                encode_point(self.point.x.content(), self.point.y.content(), self.point.y.sign() == -1, self.point.z.is_zero(), format)
+            }
+            pub fn encode_to_hex(&self, format :PointFormat) -> String
+            {
+                to_hex(&self.encode(format))
             }
         }
 
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT : usize> G2Field<PRAMASIZE,R,N,MAX_COEFS_COUNT> 
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> FixedBaseTable<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, F>
+            {
+                pub fn new(base :&G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, window :u8) -> Self
+                {   // Precomputation for the scalars of Fr, with (bits(r)/w + 1) * 2^(w-1) points
+                    FixedBaseTable::from_point(*base, &base.point, S::PARAMS.num_of_bits, window)
+                }
+                pub fn mul(&self, scalar :&FieldElement<R,S>) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {   // Constant-time multiplication of the fixed base, without any doubling (nor any ψ, unlike gls_multiply)
+                    G2Element { point :self.multiply_point(&scalar.to_canonical_limbs()), consts :self.base.consts }
+                }
+            }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT : usize, F: G2ExtElement<N,P,PRAMASIZE>> G2Field<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> 
         {   
             pub fn getorder(&self)->usize
             {   match self.base_field {    ExtG2Field::Fp2_1(_) => 2,
@@ -206,27 +327,37 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
                                            ExtG2Field::Fp8_3(_) => 8                                           
                                       } 
             }
+            // Elements of the extension field, given in the representation F of the engine
+            pub fn one(&self) -> F
+            {   F::from_g2_element(&self.base_field.one())
+            }
+            pub fn zero(&self) -> F
+            {   F::from_g2_element(&self.base_field.zero())
+            }
+            pub fn random_element(&self) -> F
+            {   F::from_g2_element(&self.base_field.random_element())
+            }
            
-            // pub fn map_to_curve(&self,seed :&ExtFieldG2Element<N, PRAMASIZE>) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            // pub fn map_to_curve(&self,seed :&F) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
             // { 
             //     //     Simplified Shallue-van de Woestijne-Ulas Method (Simplified SWU for AB == 0)
             //     //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#section-6.6.3
             //     //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#appendix-C.2
-            //     let mut u: &ExtFieldG2Element<N, PRAMASIZE> = &self.base_field.random_element();
+            //     let mut u: &F = &self.random_element();
             //     if !seed.is_zero() {u = seed};
             //     let t1 = self.consts.swu_consts.z.multiply(&u.sqr());
             //     let mut t2 = t1.sqr();
             //     let mut x1 = t1.addto(&t2).invert();
             //     if x1.is_zero() {x1 = self.consts.swu_consts.inv_z.clone() }
-            //     else {x1 = x1.addto(&self.base_field.one())}
+            //     else {x1 = x1.addto(&self.one())}
             //     x1 = x1.multiply(&self.consts.swu_consts.b_div_a);            
             //     let gx1 = x1.sqr().addto(&self.consts.swu_consts.swu_a).multiply(&x1).addto(&self.consts.swu_consts.swu_b);                               
             //     let x2 = t1.multiply(&x1);
             //     t2 = t2.multiply(&t1);
             //     let gx2 = gx1.multiply(&t2);       
             //     let isqr = gx1.is_qr();
-            //     let mut x: ExtFieldG2Element<N, PRAMASIZE>;
-            //     let mut y: ExtFieldG2Element<N, PRAMASIZE>;
+            //     let mut x: F;
+            //     let mut y: F;
             //     if isqr { 
             //               y = gx1.sqrt().unwrap();
             //               x = x1.clone();  
@@ -254,13 +385,49 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
             //               }
             // }
 
-            pub fn map_to_curve(&self,seed :&ExtFieldG2Element<N, PRAMASIZE>) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            fn sswu_map(&self,u :&F) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+            { 
+                //     Simplified Shallue-van de Woestijne-Ulas Method (Simplified SWU for AB == 0), exactly as specified by RFC 9380 
+                //     (the sign of y is fixed with sgn0, which needs affine coordinates): used for hashing to G2 
+                //     https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-shallue-van-de-w
+                //     https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0
+                //     The exceptional case, the choice between the two candidates and the sign of y are handled with constant-time selections
+                let c = &self.consts.swu_consts;
+                let t1 = c.z.multiply(&u.sqr());
+                let t2 = t1.sqr().addto(&t1);
+                let x1 = F::conditional_select(&t2.invert().addto(&self.one()).multiply(&c.b_div_a),
+                                                               &c.swu_b.multiply(&c.z.multiply(&c.swu_a).invert()),
+                                                               t2.ct_eq(&t2.zero()));
+                let gx1 = x1.sqr().addto(&c.swu_a).multiply(&x1).addto(&c.swu_b);
+                let x2 = t1.multiply(&x1);
+                let gx2 = x2.sqr().addto(&c.swu_a).multiply(&x2).addto(&c.swu_b);
+                let e1 = Choice::from(gx1.is_qr());
+                let x = F::conditional_select(&x2, &x1, e1);
+                let mut y = F::conditional_select(&gx2, &gx1, e1).sqrt_ct().expect("SWU: the selected candidate is always a square");
+                y.conditional_negate(Choice::from(u.sgn0() ^ y.sgn0()));
+                let mut xnum = c.xnum[MAX_COEFS_COUNT - 1];
+                let mut xden = c.xden[MAX_COEFS_COUNT - 1];
+                let mut ynum = c.ynum[MAX_COEFS_COUNT - 1];
+                let mut yden = c.yden[MAX_COEFS_COUNT - 1];
+                for i in (0..MAX_COEFS_COUNT - 1).rev() {   xnum = xnum.multiply(&x).addto(&c.xnum[i]);
+                                                            xden = xden.multiply(&x).addto(&c.xden[i]);
+                                                            ynum = ynum.multiply(&x).addto(&c.ynum[i]);
+                                                            yden = yden.multiply(&x).addto(&c.yden[i]);
+                                                        }
+                // Convert from affine coordinates (xnum/xden, y*ynum/yden) to Jacobian coordinates
+                let z = xden.multiply(&yden);
+                G2Element { point : EcPoint { x : xnum.multiply(&z).multiply(&yden), y : ynum.multiply(&z.sqr()).multiply(&xden).multiply(&y), z },
+                            consts :self.consts,
+                          }
+            }
+
+            pub fn map_to_curve(&self,seed :&F) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
             { 
                  //     Simplified Shallue-van de Woestijne-Ulas Method (Simplified SWU for AB == 0)
                 //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#section-6.6.3
                 //     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-05#appendix-C.2
                 //      Prpposed variant that avoid inversion whenever is the targted extension field
-                let mut u: &ExtFieldG2Element<N, PRAMASIZE> = &self.base_field.random_element();
+                let mut u: &F = &self.random_element();
                 if !seed.is_zero() {u = seed};
                 let t1 = self.consts.swu_consts.z.multiply(&u.sqr());
                 let t2 = t1.sqr();
@@ -272,8 +439,8 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
                 let x1 = x0.multiply(&t1);
                 let f0 = x0.sqr().multiply(&x0).substract(&d2.multiply(&d3).multiply(&self.consts.swu_consts.swu_b));    
                 let f1 = f0.multiply(&t1).multiply(&t2);
-                let mut x: ExtFieldG2Element<N, PRAMASIZE>;
-                let mut y: ExtFieldG2Element<N, PRAMASIZE>;
+                let mut x: F;
+                let mut y: F;
                 if f0.is_qr() { y = f0.sqrt().unwrap();
                                 x = x0.clone();
                               }
@@ -281,9 +448,9 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
                         x = x1.clone();
                      }                              
                 if (u.sign()!= y.sign())&(d.sign()!=-1) { y = y.negate()}   
-                let mut pow_x :[ExtFieldG2Element<N, PRAMASIZE>;MAX_COEFS_COUNT] = [self.base_field.one();MAX_COEFS_COUNT];           
-                let mut pow_z2 :[ExtFieldG2Element<N, PRAMASIZE>;MAX_COEFS_COUNT] = [self.base_field.one();MAX_COEFS_COUNT];           
-                let mut pow_x_z2 :[ExtFieldG2Element<N, PRAMASIZE>;MAX_COEFS_COUNT] = [self.base_field.one();MAX_COEFS_COUNT];                      
+                let mut pow_x :[F;MAX_COEFS_COUNT] = [self.one();MAX_COEFS_COUNT];           
+                let mut pow_z2 :[F;MAX_COEFS_COUNT] = [self.one();MAX_COEFS_COUNT];           
+                let mut pow_x_z2 :[F;MAX_COEFS_COUNT] = [self.one();MAX_COEFS_COUNT];                      
                 for i in 1..MAX_COEFS_COUNT{ pow_x[i]  = pow_x[i-1].multiply(&x);
                                                     pow_z2[i] = pow_z2[i-1].multiply(&d2); 
                                                   }
@@ -308,25 +475,25 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
                           }
             }
 
-            pub fn random_point_withseed(&self,seed1 : &ExtFieldG2Element<N, PRAMASIZE>) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            pub fn random_point_withseed(&self,seed1 : &F) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {   
                     let rp = self.map_to_curve(seed1);
                     rp.clean_cofactor()
                 }
             
-            pub fn random_point(&self) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            pub fn random_point(&self) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {   
-                    self.map_to_curve(&self.base_field.zero()).clean_cofactor().to_affine()                    
+                    self.map_to_curve(&self.zero()).clean_cofactor().to_affine().to_projective()                    
                 }
 
-            pub fn random_point_trys(&self) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            pub fn random_point_trys(&self) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
             {   
                 let mut found =false;
-                let mut x =  self.base_field.zero();
-                let mut y =  self.base_field.zero();
-                let z =  self.base_field.one();
+                let mut x =  self.zero();
+                let mut y =  self.zero();
+                let z =  self.one();
                 while !found {
-                    x =  self.base_field.random_element();
+                    x =  self.random_element();
                     y = x.multiply(&x.sqr()).addto(&self.consts.b);  
                     found = y.is_qr();                    
                 }
@@ -336,141 +503,302 @@ impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT :
                           }
             }
     
-            pub fn hash_to_field(&self,id :&str, mode :u8) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            pub fn hash_to_field(&self,msg :&[u8], dst :&[u8], count :usize) -> Vec<F>
+                {   
+                    //  Hash a message to "count" elements of the extension field (m coefficients of L bytes for each element)
+                    //  https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
+                    self.hash_to_field_with(msg, dst, count, self.consts.expander)
+                }
+
+            pub fn hash_to_field_with(&self,msg :&[u8], dst :&[u8], count :usize, expander :Expander) -> Vec<F>
+                {   
+                    //  Same as hash_to_field, with an explicit expander instead of the default one of the engine (consts.expander)
+                    self.base_field.hash_to_field(msg, dst, self.consts.security_level, expander, count).iter().map(F::from_g2_element).collect()
+                }
+
+            pub fn clear_cofactor(&self, p :&G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {   
+                    //  Multiplication by the effective cofactor using the endomorphism ψ (https://www.rfc-editor.org/rfc/rfc9380.html#name-clearing-the-cofactor)
+                    p.clean_cofactor()
+                }
+
+            pub fn encode_to_curve(&self,msg :&[u8], dst :&[u8]) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {                   
+                    //  Nonuniform encoding (NU-encode-to-curve) : encode_to_curve(msg) = clear_cofactor(map_to_curve(hash_to_field(msg, 1)))
+                    //  https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el
+                    self.encode_to_curve_with(msg, dst, self.consts.expander)
+                }
+
+            pub fn encode_to_curve_with(&self,msg :&[u8], dst :&[u8], expander :Expander) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {   
+                    let u = self.hash_to_field_with(msg, dst, 1, expander);
+                    self.clear_cofactor(&self.sswu_map(&u[0]))
+                }
+
+            pub fn hash_to_curve(&self,msg :&[u8], dst :&[u8]) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {                   
-                    //  mode=0: Encode to Curve (NU-encode-to-curve), mode=1: Random Oracle model (RO-hash-to-curve)
-                    //  https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-06#name-roadmap                    
-                    if mode ==0 {   let hashs = self.base_field.hash_to_field(id,self.consts.security_level,1);
-                                    self.random_point_withseed(&hashs[0]).to_affine()}
-                    else {  let hashs = self.base_field.hash_to_field(id,self.consts.security_level,2);
-                            let p1=self.random_point_withseed(&hashs[0]);
-                            let p2=self.random_point_withseed(&hashs[1]);
-                            p1.addto(&p2).to_affine()
-                         } 
+                    //  Random Oracle encoding (RO-hash-to-curve) : 
+                    //  hash_to_curve(msg) = clear_cofactor(map_to_curve(u0) + map_to_curve(u1)) with (u0, u1) = hash_to_field(msg, 2)
+                    //  https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el
+                    self.hash_to_curve_with(msg, dst, self.consts.expander)
+                }
+
+            pub fn hash_to_curve_with(&self,msg :&[u8], dst :&[u8], expander :Expander) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {   
+                    let u = self.hash_to_field_with(msg, dst, 2, expander);
+                    self.clear_cofactor(&self.sswu_map(&u[0]).addto(&self.sswu_map(&u[1])))
                 }
             
-            pub fn from_bytearray(&self,inbytes : &Vec<u8>) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+            pub fn from_bytearray(&self,inbytes : &Vec<u8>) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {
-                    //  Point de-compression/de-Serialization as described by ZCach serialization format
-                    //  https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-
-                    let mut input = inbytes.clone();
-                    let m_byte = input[0] & 0xE0;
-                    let numbits = self.consts.base_field_numbits;
-                    let sizeinbytes = (numbits >> 3) + if (numbits % 8) ==0 {0} else {1};
-                    if self.consts.base_field_numbits % 8 <=5 {input[0] = input[0] & 0x1F;}
-                    else {input.remove(0);};
-                    if m_byte == 0xE0 {panic!("Invalide compressed point format ...")};
-                    if m_byte & 0x80 !=0 { if input.len() != sizeinbytes * self.getorder() {panic!("Invalide compressed point format ...")} 
-                                         }
-                    else {if input.len() != (sizeinbytes * 2 * self.getorder()) {panic!("Invalide compressed point format ...")}}
-                    if m_byte & 0x40 !=0 { if input.iter().any(|&e| e != 0) {panic!("Invalid compression of an infinity point...");} 
-                                           else {G2Element {  point : EcPoint {x:self.base_field.one(), y : self.base_field.one(), z: self.base_field.zero() },
-                                                              consts :self.consts}
-                                                } 
-                                         }
-                    else {  if input.len() == (sizeinbytes * 2 * self.getorder()){ let x = self.base_field.from_i2osp_bytearray(&input[0..sizeinbytes * self.getorder()]);
-                                                               let y = self.base_field.from_i2osp_bytearray(&input[sizeinbytes * self.getorder()..]);
-                                                               G2Element {  point : EcPoint { x, y, z: self.base_field.one() },
-                                                               consts :self.consts}  
-                                                             }
-                            else { let x = self.base_field.from_i2osp_bytearray(&input[0..]);
-                                   let y = x.sqr().multiply(&x).addto(&&self.consts.b).sqrt();
-                                   if y.is_none() {panic!("Invalide point: not in the curve ...")}
-                                   else { let y = y.unwrap();
-                                          let r_sign = if m_byte & 0x20 !=0 {1} else {0}; 
-                                          if (y.sign()+1) >> 1 == r_sign {G2Element {  point : EcPoint { x, y, z: self.base_field.one() },
-                                                                                       consts :self.consts}  }
-                                          else {G2Element {  point : EcPoint { x, y: y.negate(), z: self.base_field.one() },
-                                                             consts :self.consts}  }
-                                        }
-                                 }                                 
-                         }
+                    match self.try_from_bytearray(inbytes) { Ok(p) => p,
+                                                             Err(e) => panic!("{}",e)}
+                }
+
+            pub fn try_from_bytearray(&self,inbytes : &[u8]) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    self.try_from_bytearray_with(inbytes, DecodingMode::Subgroup)
+                }
+
+            pub fn try_from_bytearray_with(&self,inbytes : &[u8], mode :DecodingMode) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    //  ZCash compressed or uncompressed input, according to the compression bit
+                    let format = if inbytes.first().is_some_and(|b| b & 0x80 != 0) {PointFormat::ZcashCompressed} else {PointFormat::ZcashUncompressed};
+                    self.try_decode_with(inbytes, format, mode)
+                }
+
+            pub fn decode(&self,inbytes : &[u8], format :PointFormat) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {
+                    match self.try_decode(inbytes, format) { Ok(p) => p,
+                                                             Err(e) => panic!("{}",e)}
+                }
+
+            pub fn try_decode(&self,inbytes : &[u8], format :PointFormat) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    self.try_decode_with(inbytes, format, DecodingMode::Subgroup)
+                }
+
+            pub fn try_decode_hex(&self,input :&str, format :PointFormat) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    self.try_decode(&try_from_hex(input)?, format)
+                }
+
+            pub fn try_decode_with(&self,inbytes : &[u8], format :PointFormat, mode :DecodingMode) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    self.try_decode_affine_with(inbytes, format, mode).map(|p| p.to_projective())
+                }
+
+            pub fn try_decode_affine_with(&self,inbytes : &[u8], format :PointFormat, mode :DecodingMode) -> Result<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    //  Every malformed input is reported as an error: size, flag bits, padding, non-canonical coordinates, 
+                    //  points outside the twisted curve and points outside the prime-order sub-group.
+                    //  The curve and sub-group checks follow the decoding mode (a compressed point always has to be on the curve)
+                    let result = match decode_point(&self.base_field.basefield(), self.getorder(), inbytes, format)? {
+                            EncodedPoint::Infinity => return Ok(G2Affine {  point : EcPoint {x:self.one(), y : self.one(), z: self.zero() },
+                                                                            consts :self.consts}),
+                            EncodedPoint::Affine(x, y) => { let p = G2Affine {  point : EcPoint { x: F::from_g2_element(&self.base_field.from_basefield_elements(&x)), 
+                                                                                                   y: F::from_g2_element(&self.base_field.from_basefield_elements(&y)), 
+                                                                                                   z: self.one() }, 
+                                                                                 consts :self.consts};
+                                                            if mode != DecodingMode::Unchecked && !p.is_on_curve() {return Err(PairingError::NotOnCurve)}
+                                                            p
+                                                          },
+                            EncodedPoint::Compressed(x, largest) => {   let x = F::from_g2_element(&self.base_field.from_basefield_elements(&x));
+                                                                        let y = match x.sqr().multiply(&x).addto(&self.consts.b).sqrt() { Some(y) => y,
+                                                                                                                                         None => return Err(PairingError::NotOnCurve)};
+                                                                        let y = if (y.sign() == -1) == largest {y} else {y.negate()};
+                                                                        G2Affine {  point : EcPoint { x, y, z: self.one() }, consts :self.consts}
+                                                                    },
+                        };
+                    if mode == DecodingMode::Subgroup && !result.is_torsion() {return Err(PairingError::NotInSubgroup)}
+                    Ok(result)
                 }
     
-                pub fn from_base64(&self,input :&str) -> G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+                pub fn from_base64(&self,input :&str) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {
-                    let decoded_bytes = match general_purpose::STANDARD.decode(input) {
-                        Ok(bytes) => bytes,
-                        Err(_) => {panic!("Failed to decode base64 string");}
-                    };
-                    self.from_bytearray(&decoded_bytes)
+                    match self.try_from_base64(input) { Ok(p) => p,
+                                                        Err(e) => panic!("{}",e)}
                 }
 
-                pub fn default_generator(&self)->G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>
+                pub fn try_from_base64(&self,input :&str) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    let decoded_bytes = general_purpose::STANDARD.decode(input).map_err(|_| PairingError::InvalidBase64)?;
+                    self.try_from_bytearray(&decoded_bytes)
+                }
+
+                pub fn default_generator(&self)->G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
                 {
                     {G2Element {  point : EcPoint { x :self.consts.default_generator.x, 
                                                     y: self.consts.default_generator.y, 
                                                     z: self.consts.default_generator.z },
-                                  consts :self.consts}  } 
+                                  consts :self.consts}  }
+                }
+
+                pub fn generator_table(&self) -> &'static G2FixedBaseTable<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {   // Fixed-base table of the default generator, built on the first use and shared by all the threads
+                    let consts = self.consts;
+                    consts.generator_table.get_or_init(|| G2FixedBaseTable::new(&self.default_generator(), FIXED_BASE_WINDOW))
+                }
+
+                pub fn prepared_lines_count(&self) -> usize
+                {   
+                    // One line per doubling, and one more per non-zero digit of the NAF of u
+                    let naf = <u128 as Exponent<N>>::to_naf(&(self.consts.u.unsigned_abs()));
+                    naf[1..].len() + naf[1..].iter().filter(|&&d| d != 0).count()
+                }
+
+                pub fn msm(&self, points :&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>], scalars :&[FieldElement<R,S>]) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {
+                    match self.try_msm(points, scalars, 1) { Ok(p) => p,
+                                                             Err(e) => panic!("{}",e)}
+                }
+
+                pub fn msm_parallel(&self, points :&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>], scalars :&[FieldElement<R,S>], threads :usize) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>
+                {
+                    match self.try_msm(points, scalars, threads) { Ok(p) => p,
+                                                                   Err(e) => panic!("{}",e)}
+                }
+
+                pub fn try_msm(&self, points :&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>], scalars :&[FieldElement<R,S>], threads :usize) -> Result<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>, PairingError>
+                {
+                    //  Multi-scalar multiplication sum(scalars[i]*points[i]) (not Constant-time : for public scalars as in KZG/aggregation)
+                    //  Each scalar is split with the GLS decomposition of gls_multiply (consts.gls_decomposer) as k = sum(ki*u^i) (mod r), with
+                    //  4, 8 or 16 short sub-scalars for BLS12, BLS24 and BLS48, so k*Q = sum(ki*ψ^i(Q)) since ψ(Q) = u*Q on G2
+                    //  https://eprint.iacr.org/2013/458.pdf
+                    if points.len() != scalars.len() {return Err(PairingError::LengthMismatch { left: points.len(), right: scalars.len() })}
+                    let decomposer = self.consts.gls_decomposer;
+                    let mut expoints = Vec::with_capacity(decomposer.dimension * points.len());
+                    let mut exscalars = Vec::with_capacity(decomposer.dimension * points.len());
+                    for (p, k) in points.iter().zip(scalars.iter()) {   if p.point.is_infinit() {continue}
+                                                                        let mut q = *p;
+                                                                        for (v, negative) in decomposer.decompose(&k.to_canonical_limbs()) {
+                                                                            // The scalars are public : zero sub-scalars are skipped and the signs are applied to the points
+                                                                            if v.iter().any(|l| *l != 0) {  expoints.push(if bool::from(negative) {q.point.negate()} else {q.point});
+                                                                                                            exscalars.push(v);
+                                                                                                         }
+                                                                            q = q.phi();
+                                                                        }
+                                                                    }
+                    let infinit = G2Element { point : EcPoint { x : self.one(), y: self.one(), z: self.zero() },
+                                              consts :self.consts};
+                    if expoints.is_empty() {return Ok(infinit)}
+                    Ok(G2Element { point : multi_scalar_multiply(&expoints, &exscalars, &self.consts.b.double().addto(&self.consts.b), threads),
+                                   consts :self.consts})
                 }
         }
         
     
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> fmt::Display for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> ConditionallySelectable for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            G2Element { point : EcPoint::conditional_select(&a.point, &b.point, choice), consts : a.consts }
+        }
+    }
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> ConditionallyNegatable for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn conditional_negate(&mut self, choice: Choice) {
+            self.point.conditional_negate(choice)
+        }
+    }
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> fmt::Display for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             write!(f, "{:}", &self.to_string())
         }
     }
-impl  <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Add for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output =  G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> ConditionallySelectable for G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            G2Affine { point : EcPoint::conditional_select(&a.point, &b.point, choice), consts : a.consts }
+        }
+    }
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> fmt::Display for G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:}", &self.point.to_string())
+        }
+    }
+
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> From<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn from(p: G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self {   p.to_projective() }
+    }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> From<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        fn from(p: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self {   p.to_affine() }
+    }
+
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Add<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn add(self, rhs: G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {   self.add_affine(&rhs) }
+    }
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Sub<G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn sub(self, rhs: G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {   self.substract_affine(&rhs) }
+    }
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Neg for G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn neg(self) -> Self::Output { self.negate() }
+        }
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> PartialEq for G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+            fn eq(&self, other: &Self) -> bool {    self.equal(other) }
+        }
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Add for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
             fn add(self, rhs: Self) -> Self::Output {   self.addto(&rhs) }
     }
-impl  <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Sub for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output =  G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Sub for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
             fn sub(self, rhs: Self) -> Self::Output {   self.substract(&rhs) }
     }
-impl  <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Neg for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output =  G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Neg for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output =  G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
             fn neg(self) -> Self::Output { self.negate() }
         }   
-impl  <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> PartialEq for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
+impl  <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> PartialEq for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
             fn eq(&self, other: &Self) -> bool {    self.equal(other) }
         }
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i128> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;    
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<i128> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;    
             fn mul(self, rhs: i128) -> Self::Output {     self.multiply_by_const(rhs)   }
         }
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for i128 {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-        fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for i128 {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+        fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_by_const(self)  }
         }
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<u64> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;    
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<u64> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;    
             fn mul(self, rhs: u64) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for u64 {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for u64 {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i64> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;    
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<i64> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;    
             fn mul(self, rhs: i64) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for i64 {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for i64 {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<u8> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;    
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<u8> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;    
             fn mul(self, rhs: u8) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for u8 {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for u8 {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<i8> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;    
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<i8> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;    
             fn mul(self, rhs: i8) -> Self::Output {     self.multiply_by_const(rhs as i128)   }
         }            
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for i8 {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for i8 {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_by_const(self as i128)  }
         }    
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>> for FieldElement<R> {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>) -> Self::Output {  rhs.multiply_gls(&self)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>> for FieldElement<R,S> {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>) -> Self::Output {  rhs.multiply_gls(&self)  }
         }            
-impl <const PRAMASIZE:usize,const R:usize,const N:usize,const MAX_COEFS_COUNT:usize> Mul<FieldElement<R>> for G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>   {
-        type Output = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT>;
-            fn mul(self, rhs: FieldElement<R>) -> Self::Output {  self.multiply_gls(&rhs)  }
+impl <const PRAMASIZE:usize,const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT:usize, F: G2ExtElement<N,P,PRAMASIZE>> Mul<FieldElement<R,S>> for G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>   {
+        type Output = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT,F>;
+            fn mul(self, rhs: FieldElement<R,S>) -> Self::Output {  self.multiply_gls(&rhs)  }
         }                        
\ No newline at end of file
@@ -2,11 +2,11 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
-use crate::curves::g2::G2Element;
+use crate::{curves::g2::G2Element, extensions::g2_extfields::G2ExtElement, fields::prime_fields::FieldConfig};
 use super::phi::{phi_bls24,phi_bls48};
 
-pub fn clean_cofactor_bls12<const PRAMASIZE:usize, const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-                        (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+pub fn clean_cofactor_bls12<const PRAMASIZE:usize, const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+                        (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
 {   //  Fast way to clean Cofactor on G2 for the BLS12 curves using Endomorphisme 
     //  based on 'Budroni-Pintore' approach (https://ia.cr/2017/419).
     let up = input.multiply_by_const(input.consts.u);
@@ -14,8 +14,8 @@ pub fn clean_cofactor_bls12<const PRAMASIZE:usize, const R: usize, const N: usiz
     phip.phi().double().addto(&up.addto(&phip).multiply_by_const(input.consts.u).substract(&up).substract(&phip).substract(&input))   
 }
 
-pub fn clean_cofactor_bls24<const PRAMASIZE:usize, const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-                        (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+pub fn clean_cofactor_bls24<const PRAMASIZE:usize, const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+                        (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
 {   //  Fast way to clean Cofactor on G2 for BLS24 curves using Endomorphisme 
     //  based on 'Budroni-Pintore' approach (https://ia.cr/2017/419).(personal use of inverted frobenius for optimization). 
     let phi4 = phi_bls24(input,4);
@@ -28,8 +28,8 @@ pub fn clean_cofactor_bls24<const PRAMASIZE:usize, const R: usize, const N: usiz
     phip
 }
 
-pub fn clean_cofactor_bls48<const PRAMASIZE:usize, const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-                        (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+pub fn clean_cofactor_bls48<const PRAMASIZE:usize, const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+                        (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
 {   
     //  Fast way to clean Cofactor on G2 for BLS48 curves using Endomorphisme
     // based on "Budroni-Pintore" approach (https://ia.cr/2017/419).
@@ -12,12 +12,12 @@ use crate::{curves::{curve_arithmetics::EcPoint, g2::G2Element},
             extensions::towering2::fp4::Fp4Element as Fp4Element_2, 
             extensions::towering2::fp8::Fp8Element as Fp8Element_2, 
             extensions::towering3::fp8::Fp8Element as Fp8Element_3, 
-            extensions::g2_extfields::ExtFieldG2Element, 
-            fields::prime_fields::FieldElement, 
+            extensions::g2_extfields::{ExtFieldG2Element, G2ExtElement}, 
+            fields::prime_fields::{FieldConfig, FieldElement}, 
             tools::arithmetic_interface::ArithmeticOperations};
 
-pub fn phi_bls12<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-            (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+pub fn phi_bls12<const PRAMASIZE:usize,const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+            (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
     {   
         // Endomorphisme Phi(P)=u*P: Twist->Frobinus->Un-twist (in M-Type Mode for BLS12)
         // Twist : (x,y)->(x/w^2,y/w^3)=(x/(1+u)^(1/3), y/(1+u)^(1/2))
@@ -31,16 +31,26 @@ pub fn phi_bls12<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
         let phiconst1 = Fp2Element_1 {content :[ zero ,frobs[0].addto(&one)]};
         let phiconst2 = Fp2Element_1 {content :[ frobs[2].negate() ,frobs[2]]};
         G2Element{  consts: input.consts,
-                    point: EcPoint{ x: input.point.x.conjugate().multiply(&ExtFieldG2Element::Fp2_1(phiconst1)),
-                                    y: input.point.y.conjugate().multiply(&ExtFieldG2Element::Fp2_1(phiconst2)),
+                    point: EcPoint{ x: input.point.x.conjugate().multiply(&F::from_g2_element(&ExtFieldG2Element::Fp2_1(phiconst1))),
+                                    y: input.point.y.conjugate().multiply(&F::from_g2_element(&ExtFieldG2Element::Fp2_1(phiconst2))),
                                     z: input.point.z.conjugate(),
                                 },
                  }
     }
           
 
-pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-    (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>, order :i8) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+fn to_ext_point<const N: usize, P: FieldConfig<N>, const PRAMASIZE:usize, F: G2ExtElement<N,P,PRAMASIZE>>(p :&EcPoint<F>) -> EcPoint<ExtFieldG2Element<N,P,PRAMASIZE>>
+    {   // The BLS24 and BLS48 maps work directly on the coefficients of the tower elements of each construction
+        EcPoint{ x: p.x.to_g2_element(), y: p.y.to_g2_element(), z: p.z.to_g2_element() }
+    }
+
+fn from_ext_point<const N: usize, P: FieldConfig<N>, const PRAMASIZE:usize, F: G2ExtElement<N,P,PRAMASIZE>>(p :EcPoint<ExtFieldG2Element<N,P,PRAMASIZE>>) -> EcPoint<F>
+    {
+        EcPoint{ x: F::from_g2_element(&p.x), y: F::from_g2_element(&p.y), z: F::from_g2_element(&p.z) }
+    }
+
+pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+    (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>, order :i8) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
     {
         // Endomorphisme Phi^n((P))))=(u^n)*P (for n =1,4 and -1): Twist->Frobinus->Un-twist (in D-Type/M-Type Mode for BLS24-construction-1 and M-type for BLS24-construction-2 ) 
         // Example M-Type
@@ -49,26 +59,27 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
         // Un-Twist : (x,y)->(x/z^2,y/z^3)
         // Combinaison of the three maps is equivalent to one multiplication by constants: (c1,c2)=(z^(2*(prime-1))),1/(z^(3*(prime-1))))
         let frobs = &input.consts.extfieldparams.frobinus_consts;
-        let x = if let ExtFieldG2Element::Fp4_1(_x) = input.point.x { _x.content } 
-                                      else {    if let ExtFieldG2Element::Fp4_2(_x) = input.point.x { _x.content } 
+        let point = to_ext_point(&input.point);
+        let x = if let ExtFieldG2Element::Fp4_1(_x) = point.x { _x.content } 
+                                      else {    if let ExtFieldG2Element::Fp4_2(_x) = point.x { _x.content } 
                                                 else { panic!("error handeling unsupported type ...") }};
-        let y = if let ExtFieldG2Element::Fp4_1(_y) = input.point.y { _y.content } 
-                                      else {    if let ExtFieldG2Element::Fp4_2(_y) = input.point.y { _y.content } 
+        let y = if let ExtFieldG2Element::Fp4_1(_y) = point.y { _y.content } 
+                                      else {    if let ExtFieldG2Element::Fp4_2(_y) = point.y { _y.content } 
                                                 else { panic!("error handeling unsupported type ...") }};
-        let z = if let ExtFieldG2Element::Fp4_1(_z) = input.point.z { _z.content} 
-                                      else {     if let ExtFieldG2Element::Fp4_2(_z) = input.point.z { _z.content} 
+        let z = if let ExtFieldG2Element::Fp4_1(_z) = point.z { _z.content} 
+                                      else {     if let ExtFieldG2Element::Fp4_2(_z) = point.z { _z.content} 
                                                  else { panic!("error handeling unsupported type ...") } };
-        let inconsts = if let ExtFieldG2Element::Fp4_1(_y) = input.point.y { _y.constants } 
-                                                     else {     if let ExtFieldG2Element::Fp4_2(_y) = input.point.y { _y.constants } 
+        let inconsts = if let ExtFieldG2Element::Fp4_1(_y) = point.y { _y.constants } 
+                                                     else {     if let ExtFieldG2Element::Fp4_2(_y) = point.y { _y.constants } 
                                                                 else { panic!("error handeling unsupported type ...") } };
         let construction;
-        match input.point.x {
+        match point.x {
             ExtFieldG2Element::Fp4_1(_) => {construction =1},
             ExtFieldG2Element::Fp4_2(_) => {construction =2},
             _ => {unimplemented!("error handeling unsupported type ...")},
         }                                                                 
-        match order {   1 =>{   if construction ==1 {   let phix :[FieldElement<N>; 4];
-                                                        let phiy :[FieldElement<N>; 4];
+        match order {   1 =>{   if construction ==1 {   let phix :[FieldElement<N,P>; 4];
+                                                        let phiy :[FieldElement<N,P>; 4];
                                                         if input.consts.twist_type=='D'{  // D-Type Twiste
                                                                     phix = [x[0].substract(&x[1]).multiply(&frobs[7]),x[0].addto(&x[1]).multiply(&frobs[7]).negate(),
                                                                                                       x[2].multiply(&frobs[5].add(1u64)), x[3].multiply(&frobs[5].add(1u64)).negate()];
@@ -82,22 +93,22 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                         y[0].addto(&y[1]).multiply(&frobs[10]),y[0].substract(&y[1]).multiply(&frobs[10])];
                                                                         }        
                                                         G2Element { consts: input.consts , 
-                                                                    point: EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phix, constants: inconsts }), 
+                                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phix, constants: inconsts }), 
                                                                                     y: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phiy, constants: inconsts }), 
-                                                                                    z: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: z, constants: inconsts }.frobinus()) }
+                                                                                    z: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: z, constants: inconsts }.frobinus()) })
                                                                  }}
                                 else {  let phix = [x[0].multiply(&frobs[7]) ,x[1].negate().multiply(&frobs[7]),x[2].multiply(&frobs[8]),
                                                                             x[3].negate().multiply(&frobs[8])];
                                         let phiy = [y[0].multiply(&frobs[1]) ,y[1].negate().multiply(&frobs[1]),y[2].multiply(&frobs[2]),
                                                                             y[3].negate().multiply(&frobs[2])];                                   
                                         G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phix, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phix, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phiy, constants: inconsts }), 
-                                                                    z: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: z, constants: inconsts }.frobinus()) }
+                                                                    z: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: z, constants: inconsts }.frobinus()) })
                                                 }}
                                 }
                              
-                        4 => {  if construction ==1 {   let phi4x :[FieldElement<N>; 4];
+                        4 => {  if construction ==1 {   let phi4x :[FieldElement<N,P>; 4];
                                                         if input.consts.twist_type=='D'{  // D-Type Twiste
                                                                         let fb5plus1 = frobs[5].add(1u64);          
                                                                         phi4x = [x[0].negate().multiply(&fb5plus1),x[1].negate().multiply(&fb5plus1),
@@ -108,22 +119,22 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                          x[2].multiply(&frobs[5]), x[3].multiply(&frobs[5])];
                                                                 }                                                                        
                                                         G2Element { consts: input.consts , 
-                                                                  point: EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phi4x, constants: inconsts }), 
+                                                                  point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phi4x, constants: inconsts }), 
                                                                                   y: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: y, constants: inconsts }.negate()), 
-                                                                                  z: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: z, constants: inconsts }) }
+                                                                                  z: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: z, constants: inconsts }) })
                                                                     }
                                                     }
                                 else {  let phi4x = [x[0].multiply(&frobs[8]) ,x[1].multiply(&frobs[8]),x[2].multiply(&frobs[8]),
                                                                             x[3].multiply(&frobs[8])];
                                         G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phi4x, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phi4x, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: y, constants: inconsts }.negate()), 
-                                                                    z: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: z, constants: inconsts }) }
+                                                                    z: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: z, constants: inconsts }) })
                                                     }
                                 }                                    
                               }    
-                        -1 =>{  if construction ==1 {   let phix :[FieldElement<N>; 4];
-                                                        let phiy :[FieldElement<N>; 4];
+                        -1 =>{  if construction ==1 {   let phix :[FieldElement<N,P>; 4];
+                                                        let phiy :[FieldElement<N,P>; 4];
                                                         if input.consts.twist_type=='D'{  // D-Type Twiste
                                                                                         phix = [x[1].substract(&x[0]).multiply(&frobs[6]),x[0].addto(&x[1]).multiply(&frobs[6]),
                                                                                                                     x[2].multiply(&frobs[5]).negate(), x[3].multiply(&frobs[5])];
@@ -138,10 +149,10 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                         y[0].substract(&y[1]).multiply(&frobs[10]),y[0].addto(&y[1]).multiply(&frobs[10]).negate()];
                                                                 }                                                                                        
                                                         G2Element { consts: input.consts , 
-                                                                    point: EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phix, constants: inconsts }), 
+                                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phix, constants: inconsts }), 
                                                                                     y: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: phiy, constants: inconsts }), 
                                                                                     z: ExtFieldG2Element::Fp4_1(Fp4Element_1 { content: z, constants: inconsts }.frobinus().conjugate()) //invert of the frobinus is its conjugate//
-                                                                                }
+                                                                                })
                                                                     }
                                                     }
                                 else {  let phix = [x[0].multiply(&frobs[6]).negate(),x[1].multiply(&frobs[6]),
@@ -149,10 +160,10 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                         let phiy = [y[0].multiply(&frobs[2]).negate(), y[1].multiply(&frobs[2]), 
                                                                             y[2].multiply(&frobs[1]).negate(),y[3].multiply(&frobs[1])];
                                         G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phix, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phix, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: phiy, constants: inconsts }), 
                                                                     z: ExtFieldG2Element::Fp4_2(Fp4Element_2 { content: z, constants: inconsts }.frobinus().conjugate()) //invert of the frobinus is its conjugate//
-                                                                }
+                                                                })
                                                     }
                                      }
                              }
@@ -161,30 +172,31 @@ pub fn phi_bls24<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                     }    
                 
 
-pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX_COEFS_COUNT: usize>
-            (input :&G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT> , order: i8) -> G2Element<PRAMASIZE, R,N,MAX_COEFS_COUNT>
+pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, S: FieldConfig<R>, const N: usize, P: FieldConfig<N>, const MAX_COEFS_COUNT: usize, F: G2ExtElement<N,P,PRAMASIZE>>
+            (input :&G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F> , order: i8) -> G2Element<PRAMASIZE, R,S,N,P,MAX_COEFS_COUNT,F>
 {
     // Endomorphisme Phi^n((P))))=(u^n)*P (for n =1,4,8 and -1): Twist->Frobinus->Un-twist (in D-Type Mode for BLS48)   
     let frobs = &input.consts.extfieldparams.frobinus_consts;
+    let point = to_ext_point(&input.point);
     let fb_id= &input.consts.extfieldparams.fb_id;
-    let x = if let ExtFieldG2Element::Fp8_1(_x) = input.point.x { _x.content } 
-                                  else { if let ExtFieldG2Element::Fp8_2(_x) = input.point.x { _x.content } 
-                                         else {if let ExtFieldG2Element::Fp8_3(_x) = input.point.x { _x.content } 
+    let x = if let ExtFieldG2Element::Fp8_1(_x) = point.x { _x.content } 
+                                  else { if let ExtFieldG2Element::Fp8_2(_x) = point.x { _x.content } 
+                                         else {if let ExtFieldG2Element::Fp8_3(_x) = point.x { _x.content } 
                                                  else {panic!("error handeling unsupported type ...")} } };
-    let y = if let ExtFieldG2Element::Fp8_1(_y) = input.point.y { _y.content } 
-                                  else { if let ExtFieldG2Element::Fp8_2(_y) = input.point.y { _y.content } 
-                                         else { if let ExtFieldG2Element::Fp8_3(_y) = input.point.y { _y.content } 
+    let y = if let ExtFieldG2Element::Fp8_1(_y) = point.y { _y.content } 
+                                  else { if let ExtFieldG2Element::Fp8_2(_y) = point.y { _y.content } 
+                                         else { if let ExtFieldG2Element::Fp8_3(_y) = point.y { _y.content } 
                                                 else {panic!("error handeling unsupported type ...")} } };
-    let z = if let ExtFieldG2Element::Fp8_1(_z) = input.point.z { _z.content} 
-                                  else { if let ExtFieldG2Element::Fp8_2(_z) = input.point.z { _z.content} 
-                                         else { if let ExtFieldG2Element::Fp8_3(_z) = input.point.z { _z.content } 
+    let z = if let ExtFieldG2Element::Fp8_1(_z) = point.z { _z.content} 
+                                  else { if let ExtFieldG2Element::Fp8_2(_z) = point.z { _z.content} 
+                                         else { if let ExtFieldG2Element::Fp8_3(_z) = point.z { _z.content } 
                                                 else {panic!("error handeling unsupported type ...")} }};
-    let inconsts = if let ExtFieldG2Element::Fp8_1(_y) = input.point.y { _y.constants } 
-                                                 else { if let ExtFieldG2Element::Fp8_2(_y) = input.point.y { _y.constants } 
-                                                        else { if let ExtFieldG2Element::Fp8_3(_y) = input.point.y { _y.constants } 
+    let inconsts = if let ExtFieldG2Element::Fp8_1(_y) = point.y { _y.constants } 
+                                                 else { if let ExtFieldG2Element::Fp8_2(_y) = point.y { _y.constants } 
+                                                        else { if let ExtFieldG2Element::Fp8_3(_y) = point.y { _y.constants } 
                                                                else {panic!("error handeling unsupported type ...")} } };
     let construction;
-    match input.point.x {
+    match point.x {
         ExtFieldG2Element::Fp8_1(_) => {construction =1},
         ExtFieldG2Element::Fp8_2(_) => {construction =2},
         ExtFieldG2Element::Fp8_3(_) => {construction =3},
@@ -199,13 +211,13 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                                         y[2].multiply(&frobs[14]).negate(),y[3].multiply(&frobs[14]), 
                                                                                         y[1].multiply(&frobs[15]).negate(),y[0].multiply(&frobs[15]).negate()];
                                                     G2Element { consts: input.consts , 
-                                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phix, constants: inconsts }), 
+                                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phix, constants: inconsts }), 
                                                                                     y: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phiy, constants: inconsts }), 
-                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }.frobinus()) }
+                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }.frobinus()) })
                                                                     }
                                                 }
-                            else {  if construction ==3 {   let phix: [FieldElement<N>; 8] ;
-                                                            let phiy: [FieldElement<N>; 8] ;
+                            else {  if construction ==3 {   let phix: [FieldElement<N,P>; 8] ;
+                                                            let phiy: [FieldElement<N,P>; 8] ;
                                                             if input.consts.twist_type=='D'
                                                                     {// D-Type (with negation) twist 
                                                                     phix = [  x[2].addto(&x[3]).multiply(&frobs[3]).negate(),x[3].substract(&x[2]).multiply(&frobs[3]),
@@ -233,14 +245,14 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                                     y[1].multiply(&frobs[15]),y[0].multiply(&frobs[15])];                                                                                                                         
                                                                         }
                                                                     G2Element { consts: input.consts , 
-                                                                        point: EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix, constants: inconsts }), 
+                                                                        point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix, constants: inconsts }), 
                                                                                         y: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phiy, constants: inconsts }), 
-                                                                                        z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }.frobinus()) }
+                                                                                        z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }.frobinus()) })
                                                                 }
                                                     }
                                     else {  // construction ==2
-                                            let phix: [FieldElement<N>; 8] ;
-                                            let phiy: [FieldElement<N>; 8] ;
+                                            let phix: [FieldElement<N,P>; 8] ;
+                                            let phiy: [FieldElement<N,P>; 8] ;
                                             if *fb_id == 1{ phix = [  x[1].multiply(&frobs[6]).negate(),x[0].multiply(&frobs[5]),
                                                                     x[3].multiply(&frobs[8]).negate(),x[2].multiply(&frobs[7]),
                                                                     x[4].multiply(&frobs[10]),x[5].multiply(&frobs[10]).negate(),
@@ -259,9 +271,9 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                       y[5].multiply(&frobs[24]),y[4].multiply(&frobs[23]), 
                                                                       y[7].multiply(&frobs[26]),y[6].multiply(&frobs[25])];}                                                                                                                         
                                             G2Element { consts: input.consts , 
-                                                        point: EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix, constants: inconsts }), 
+                                                        point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix, constants: inconsts }), 
                                                                         y: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phiy, constants: inconsts }), 
-                                                                        z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }.frobinus()) }
+                                                                        z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }.frobinus()) })
                                                         }
                                         }
                             }
@@ -274,13 +286,13 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                     let phi4y = [ y[1],y[0].negate(),y[3],y[2].negate(),
                                                                                         y[5].negate(),y[4],y[7].negate(),y[6]]; // y' =-y.conjugate * u                                                                 
                                                     G2Element { consts: input.consts , 
-                                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phi4x, constants: inconsts }), 
+                                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phi4x, constants: inconsts }), 
                                                                                     y: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phi4y, constants: inconsts }), 
-                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }.conjugate()) } // frobinus at order 4 for an Fp8 is simply the conjugate
+                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }.conjugate()) }) // frobinus at order 4 for an Fp8 is simply the conjugate
                                                                     }
                                                 }
-                            else {if construction ==3 { let phix4: [FieldElement<N>; 8] ;
-                                                        let phiy4: [FieldElement<N>; 8] ;
+                            else {if construction ==3 { let phix4: [FieldElement<N,P>; 8] ;
+                                                        let phiy4: [FieldElement<N,P>; 8] ;
                                                         if input.consts.twist_type=='D'{// D-Type twist 
                                                                                         phix4 = [  x[0].multiply(&frobs[5]),x[1].multiply(&frobs[5]),
                                                                                                 x[2].multiply(&frobs[5]),x[3].multiply(&frobs[5]),
@@ -301,14 +313,14 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                             y[5].negate(),y[4], y[7].negate(),y[6]];                                
                                                             }
                                                     G2Element { consts: input.consts , 
-                                                        point: EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix4, constants: inconsts }), 
+                                                        point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix4, constants: inconsts }), 
                                                                         y: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phiy4, constants: inconsts }), 
-                                                                        z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }.conjugate()) }
+                                                                        z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }.conjugate()) })
                                                         }
                                                     }                                
                                 else {  //construction ==2
-                                    let phix4: [FieldElement<N>; 8] ;
-                                    let phiy4: [FieldElement<N>; 8] ;
+                                    let phix4: [FieldElement<N,P>; 8] ;
+                                    let phiy4: [FieldElement<N,P>; 8] ;
                                     if *fb_id == 1{ phix4 = [  x[0].multiply(&frobs[10]),x[1].multiply(&frobs[10]),
                                                                x[2].multiply(&frobs[10]),x[3].multiply(&frobs[10]),
                                                                x[4].multiply(&frobs[10]).negate(),x[5].multiply(&frobs[10]).negate(),
@@ -328,9 +340,9 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                        y[6].negate().multiply(&frobs[0]),y[7].negate().multiply(&frobs[0])];
                                          }                                                
                                     G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix4, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix4, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phiy4, constants: inconsts }), 
-                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }.conjugate()) } // frobinus at order 4 for an Fp8 is simply the conjugate
+                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }.conjugate()) }) // frobinus at order 4 for an Fp8 is simply the conjugate
                                             }
                                     }
                                 }
@@ -340,12 +352,12 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                                             x[4].multiply(&frobs[5]),x[5].multiply(&frobs[5]),
                                                                                             x[6].multiply(&frobs[5]),x[7].multiply(&frobs[5])];
                                                     G2Element { consts: input.consts , 
-                                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phi8x, constants: inconsts }), 
+                                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: phi8x, constants: inconsts }), 
                                                                                     y: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: y, constants: inconsts }.negate()), 
-                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }) } // frobinus at order 4 for an Fp8 is simply the identity
+                                                                                    z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: z, constants: inconsts }) }) // frobinus at order 4 for an Fp8 is simply the identity
                                                                     }
                                                 }
-                            else {if construction ==3 { let phix8: [FieldElement<N>; 8] ;
+                            else {if construction ==3 { let phix8: [FieldElement<N,P>; 8] ;
                                                         if input.consts.twist_type=='D'{//D-Type twist 
                                                                                         phix8 = [ x[0].multiply(&frobs[5].sub(1u64)),x[1].multiply(&frobs[5].sub(1u64)),
                                                                                                 x[2].multiply(&frobs[5].sub(1u64)),x[3].multiply(&frobs[5].sub(1u64)),
@@ -361,13 +373,13 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                             x[6].multiply(&frobs[5]).negate(),x[7].multiply(&frobs[5]).negate()];                      
                                                              }                                                                                        
                                                         G2Element { consts: input.consts , 
-                                                            point: EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix8, constants: inconsts }), 
+                                                            point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: phix8, constants: inconsts }), 
                                                                             y: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: y, constants: inconsts }.negate()), 
-                                                                            z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }) }
+                                                                            z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: z, constants: inconsts }) })
                                                             }
                                                         }
                                 else {  // construction ==2  
-                                        let phix8: [FieldElement<N>; 8] ;                  
+                                        let phix8: [FieldElement<N,P>; 8] ;                  
                                         if *fb_id == 1{phix8 = [ x[0].multiply(&frobs[13]),x[1].multiply(&frobs[13]),
                                                                  x[2].multiply(&frobs[13]),x[3].multiply(&frobs[13]),
                                                                  x[4].multiply(&frobs[13]),x[5].multiply(&frobs[13]),
@@ -380,9 +392,9 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                           x[6].multiply(&frobs[8]),x[7].multiply(&frobs[8])];
                                         } 
                                         G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix8, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: phix8, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: y, constants: inconsts }.negate()), 
-                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }) } // frobinus at order 4 for an Fp8 is simply the identity
+                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: z, constants: inconsts }) }) // frobinus at order 4 for an Fp8 is simply the identity
                                                     }
                                     }
                             }
@@ -400,14 +412,14 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                                                         z[3].substract(&z[2]).multiply(&frobs[0]),z[7].multiply(&frobs[1]),
                                                                                                         z[6].multiply(&frobs[1]),z[4].multiply(&frobs[2]).negate(),z[5].multiply(&frobs[2])];  
                                                     G2Element { consts: input.consts , 
-                                                                point: EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: invphix, constants: inconsts }), 
+                                                                point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: invphix, constants: inconsts }), 
                                                                                 y: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: invphiy, constants: inconsts }), 
-                                                                                z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: invphiz, constants: inconsts }) } 
+                                                                                z: ExtFieldG2Element::Fp8_1(Fp8Element_1 { content: invphiz, constants: inconsts }) }) 
                                                             }
                                                 }
-                            else {  if construction ==3 {  let invphix: [FieldElement<N>; 8] ;
-                                                           let invphiy: [FieldElement<N>; 8] ;
-                                                           let invphiz: [FieldElement<N>; 8] ;
+                            else {  if construction ==3 {  let invphix: [FieldElement<N,P>; 8] ;
+                                                           let invphiy: [FieldElement<N,P>; 8] ;
+                                                           let invphiz: [FieldElement<N,P>; 8] ;
                                                            if input.consts.twist_type=='D'{// D-Type twist 
                                                                                             invphix = [x[2].substract(&x[3]).multiply(&frobs[8]),x[2].addto(&x[3]).multiply(&frobs[8]).negate(),
                                                                                                         x[0].addto(&x[1]).multiply(&frobs[9]).negate(),x[1].substract(&x[0]).multiply(&frobs[9]),
@@ -445,15 +457,15 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                                             z[6].multiply(&frobs[1]).negate(),z[4].multiply(&frobs[2]).negate(),z[5].multiply(&frobs[2])];  
                                                                 }                                                                                        
                                                         G2Element { consts: input.consts , 
-                                                            point: EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: invphix, constants: inconsts }), 
+                                                            point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: invphix, constants: inconsts }), 
                                                                             y: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: invphiy, constants: inconsts }), 
-                                                                            z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: invphiz, constants: inconsts }) } 
+                                                                            z: ExtFieldG2Element::Fp8_3(Fp8Element_3 { content: invphiz, constants: inconsts }) }) 
                                                         }                                                                                                        
                                                     }
                                     else {// construction ==2
-                                        let invphix: [FieldElement<N>; 8] ;                  
-                                        let invphiy: [FieldElement<N>; 8] ;                  
-                                        let invphiz: [FieldElement<N>; 8] ;                  
+                                        let invphix: [FieldElement<N,P>; 8] ;                  
+                                        let invphiy: [FieldElement<N,P>; 8] ;                  
+                                        let invphiz: [FieldElement<N,P>; 8] ;                  
                                         if *fb_id == 1{invphix = [x[1].multiply(&frobs[17]).negate(),x[0].multiply(&frobs[16]),x[3].multiply(&frobs[15]).negate(),
                                                                              x[2].multiply(&frobs[14]),x[4].multiply(&frobs[13]).negate(),x[5].multiply(&frobs[13]),
                                                                              x[6].multiply(&frobs[12]).negate(),x[7].multiply(&frobs[12])];
@@ -480,9 +492,9 @@ pub fn phi_bls48<const PRAMASIZE:usize,const R: usize, const N: usize, const MAX
                                                            z[6].multiply(&frobs[1]).negate(),z[7].multiply(&frobs[1])];                                          
                                              }
                                         G2Element { consts: input.consts , 
-                                                    point: EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: invphix, constants: inconsts }), 
+                                                    point: from_ext_point(EcPoint{ x: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: invphix, constants: inconsts }), 
                                                                     y: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: invphiy, constants: inconsts }), 
-                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: invphiz, constants: inconsts }) } 
+                                                                    z: ExtFieldG2Element::Fp8_2(Fp8Element_2 { content: invphiz, constants: inconsts }) }) 
                                                 }
                                             }
                             }
@@ -2,43 +2,72 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
-use std::{fmt, ops::{Add, Div, Mul, Neg, Sub}};
+use std::{fmt, marker::PhantomData, ops::{Add, Deref, Div, Mul, Neg, Sub}};
+use base64::{engine::general_purpose, Engine};
 use hmac::{Hmac, Mac};
 use num_bigint::BigInt;
 use sha2::Sha256;
 
-use crate::{extensions::{ext_fields::{ExFieldConsts, ExtElement, ExtField}, 
+use crate::{errors::{DecodingMode, PairingError},
+            extensions::{ext_fields::{ExFieldConsts, ExtElement, ExtField}, 
                         towering1::{fp12::{Fp12Element as Fp12Element_1, Fp12Field as Fp12Field_1}, 
                                      fp24::{Fp24Element as Fp24Element_1, Fp24Field as Fp24Field_1}, 
                                      fp48::{Fp48Element as Fp48Element_1, Fp48Field as Fp48Field_1}}, 
                         towering2::{fp24::{Fp24Element as Fp24Element_2, Fp24Field as Fp24Field_2}, 
                                      fp48::{Fp48Element as Fp48Element_2, Fp48Field as Fp48Field_2}},
                         towering3::fp48::{Fp48Element as Fp48Element_3, Fp48Field as Fp48Field_3}}, 
-             fields::prime_fields::{FieldElement, PrimeField}, tools::{arithmetic_interface::ArithmeticOperations, exponent::Exponent},
+             fields::prime_fields::{FieldConfig, FieldElement, PrimeField}, tools::{arithmetic_interface::ArithmeticOperations, constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq}, 
+                                                                                 decomposition::LatticeDecomposer, exponent::{Exponent, HardPartStrategy}, recoders::{recod_sign_aligned, signed_digit_lookup}},
             };
 
 
 #[derive(Clone, Copy)]
-pub enum GTElement <const N:usize, const PARAMSIZE:usize>
-            {   Fp12_1(Fp12Element_1<PARAMSIZE,N>),
-                Fp24_1(Fp24Element_1<PARAMSIZE,N>),
-                Fp48_1(Fp48Element_1<PARAMSIZE,N>),
-                Fp24_2(Fp24Element_2<PARAMSIZE,N>),
-                Fp48_2(Fp48Element_2<PARAMSIZE,N>),
-                Fp48_3(Fp48Element_3<PARAMSIZE,N>)
+pub enum GTElement <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize>
+            {   Fp12_1(Fp12Element_1<PARAMSIZE,N,P>),
+                Fp24_1(Fp24Element_1<PARAMSIZE,N,P>),
+                Fp48_1(Fp48Element_1<PARAMSIZE,N,P>),
+                Fp24_2(Fp24Element_2<PARAMSIZE,N,P>),
+                Fp48_2(Fp48Element_2<PARAMSIZE,N,P>),
+                Fp48_3(Fp48Element_3<PARAMSIZE,N,P>)
             }
 
-#[derive(Debug)]
-pub enum GTField <const N:usize, const PARAMSIZE:usize>
-            {   Fp12_1(&'static Fp12Field_1<PARAMSIZE,N>),
-                Fp24_1(&'static Fp24Field_1<PARAMSIZE,N>),
-                Fp48_1(&'static Fp48Field_1<PARAMSIZE,N>),
-                Fp24_2(&'static Fp24Field_2<PARAMSIZE,N>),
-                Fp48_2(&'static Fp48Field_2<PARAMSIZE,N>),
-                Fp48_3(&'static Fp48Field_3<PARAMSIZE,N>)                                                
+#[derive(Clone, Copy, Debug)]
+pub enum GTExtField <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize>
+            {   Fp12_1(&'static Fp12Field_1<PARAMSIZE,N,P>),
+                Fp24_1(&'static Fp24Field_1<PARAMSIZE,N,P>),
+                Fp48_1(&'static Fp48Field_1<PARAMSIZE,N,P>),
+                Fp24_2(&'static Fp24Field_2<PARAMSIZE,N,P>),
+                Fp48_2(&'static Fp48Field_2<PARAMSIZE,N,P>),
+                Fp48_3(&'static Fp48Field_3<PARAMSIZE,N,P>)                                                
             }
 
-impl <const N:usize, const PARAMSIZE:usize> ArithmeticOperations for GTElement<N,PARAMSIZE>{
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ConstantTimeEq for GTElement<N,P,PARAMSIZE>{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        match (self, other) {   (GTElement::Fp12_1(x), GTElement::Fp12_1(y)) => x.ct_eq(y),
+                                (GTElement::Fp24_1(x), GTElement::Fp24_1(y)) => x.ct_eq(y),
+                                (GTElement::Fp48_1(x), GTElement::Fp48_1(y)) => x.ct_eq(y),
+                                (GTElement::Fp24_2(x), GTElement::Fp24_2(y)) => x.ct_eq(y),
+                                (GTElement::Fp48_2(x), GTElement::Fp48_2(y)) => x.ct_eq(y),
+                                (GTElement::Fp48_3(x), GTElement::Fp48_3(y)) => x.ct_eq(y),
+                                _ => unimplemented!("Comparison not implemented for different types"),
+                            }
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ConditionallySelectable for GTElement<N,P,PARAMSIZE>{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        match (a, b) {   (GTElement::Fp12_1(x), GTElement::Fp12_1(y)) => GTElement::Fp12_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (GTElement::Fp24_1(x), GTElement::Fp24_1(y)) => GTElement::Fp24_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (GTElement::Fp48_1(x), GTElement::Fp48_1(y)) => GTElement::Fp48_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (GTElement::Fp24_2(x), GTElement::Fp24_2(y)) => GTElement::Fp24_2(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (GTElement::Fp48_2(x), GTElement::Fp48_2(y)) => GTElement::Fp48_2(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (GTElement::Fp48_3(x), GTElement::Fp48_3(y)) => GTElement::Fp48_3(ConditionallySelectable::conditional_select(x, y, choice)),
+                         _ => unimplemented!("Selection not implemented for different types"),
+                    }
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ArithmeticOperations for GTElement<N,P,PARAMSIZE>{
     fn addto(&self, other: &Self) -> Self {
         match (self, other) {   (GTElement::Fp12_1(x), GTElement::Fp12_1(y)) => x.addto(y).into(),
                                 (GTElement::Fp24_1(x), GTElement::Fp24_1(y)) => x.addto(y).into(),
@@ -178,7 +207,7 @@ impl <const N:usize, const PARAMSIZE:usize> ArithmeticOperations for GTElement<N
     }
 }
 
-impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize>  GTElement<N,P,PARAMSIZE>{
     fn mulbyu8(&self, other: u8) -> Self {
         match  self  {   GTElement::Fp12_1(x) => x.mulbyu8(other).into(),
                          GTElement::Fp24_1(x) => x.mulbyu8(other).into(),
@@ -198,6 +227,16 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                             GTElement::Fp48_3(x)=> x.unisqr().into(),
                         }  
         }
+    pub fn compressed_power(&self, naf :&[i8]) -> Self 
+        {   // Power of an element of the cyclotomic sub-group by a NAF exponent (most significant digit first), with compressed squarings
+            match self {    GTElement::Fp12_1(x) => x.compressed_power(naf).into(),
+                            GTElement::Fp24_1(x)=> x.compressed_power(naf).into(),
+                            GTElement::Fp48_1(x)=> x.compressed_power(naf).into(),
+                            GTElement::Fp24_2(x)=> x.compressed_power(naf).into(),
+                            GTElement::Fp48_2(x)=> x.compressed_power(naf).into(),
+                            GTElement::Fp48_3(x)=> x.compressed_power(naf).into(),
+                        }  
+        }
     pub fn conjugate(&self) -> Self 
         {
             match self {    GTElement::Fp12_1(x) => x.conjugate().into(),
@@ -208,15 +247,6 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                             GTElement::Fp48_3(x)=> x.conjugate().into(),
                         }  
         }
-    pub fn encode_to_base64(&self) -> String {
-            match  self  {   GTElement::Fp12_1(x) => x.encode_to_base64(),
-                             GTElement::Fp24_1(x) => x.encode_to_base64(),
-                             GTElement::Fp48_1(x) => x.encode_to_base64(),
-                             GTElement::Fp24_2(x) => x.encode_to_base64(),
-                             GTElement::Fp48_2(x) => x.encode_to_base64(),
-                             GTElement::Fp48_3(x) => x.encode_to_base64(),
-                         }
-            }
     pub fn pow(&self,e : & dyn Exponent<N>) -> Self {
                 match  self  {   GTElement::Fp12_1(x) => {if !x.conjugate().multiply(&x).is_one() {x.pow(e).into()}
                                                                                     else {x.cyclotomic_power(e, false, &None).into()}},
@@ -232,7 +262,18 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                                                                                     else {x.cyclotomic_power(e, false, &None).into()}},                                                                                                                                                                        
                              }
                 }
-    pub fn sparse_multiply(&self,rhs : &[&[FieldElement<N>];3],twist_type:char) -> Self {
+    pub fn cyclotomic_pow(&self,e : & dyn Exponent<N>) -> Self {
+                // Power of an element known to be in the cyclotomic sub-group (no membership test), along the NAF of the exponent
+                let naf = Some(e.to_naf());
+                match  self  {   GTElement::Fp12_1(x) => x.cyclotomic_power(e, false, &naf).into(),
+                                 GTElement::Fp24_1(x) => x.cyclotomic_power(e, false, &naf).into(),
+                                 GTElement::Fp48_1(x) => x.cyclotomic_power(e, false, &naf).into(),
+                                 GTElement::Fp24_2(x) => x.cyclotomic_power(e, false, &naf).into(),
+                                 GTElement::Fp48_2(x) => x.cyclotomic_power(e, false, &naf).into(),
+                                 GTElement::Fp48_3(x) => x.cyclotomic_power(e, false, &naf).into(),
+                             }
+                }
+    pub fn sparse_multiply(&self,rhs : &[&[FieldElement<N,P>];3],twist_type:char) -> Self {
                 match  self  {   GTElement::Fp12_1(x) => x.sparse_multiply(rhs).into(),
                                  GTElement::Fp24_1(x) => x.sparse_multiply(rhs,twist_type).into(),
                                  GTElement::Fp48_1(x) => x.sparse_multiply(rhs).into(),
@@ -250,7 +291,7 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                                  GTElement::Fp48_3(x) => x.one().into(),
                              }
                 }       
-    pub fn content(&self) -> &[FieldElement<N>] {
+    pub fn content(&self) -> &[FieldElement<N,P>] {
                     match  self  {   GTElement::Fp12_1(x) => &x.content,
                                      GTElement::Fp24_1(x) => &x.content,
                                      GTElement::Fp48_1(x) => &x.content,
@@ -260,12 +301,15 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                                  }
                     }       
     pub fn final_exponentiation(&self) -> Self {
-                        match  self  {   GTElement::Fp12_1(x) => x.final_exponentiation(true).into(),
-                                         GTElement::Fp24_1(x) => x.final_exponentiation(true).into(),
-                                         GTElement::Fp48_1(x) => x.final_exponentiation(true).into(),
-                                         GTElement::Fp24_2(x) => x.final_exponentiation(true).into(),
-                                         GTElement::Fp48_2(x) => x.final_exponentiation(true).into(),                                         
-                                         GTElement::Fp48_3(x) => x.final_exponentiation(true).into(),                                         
+                        self.final_exponentiation_with(HardPartStrategy::default())
+                        }    
+    pub fn final_exponentiation_with(&self, strategy :HardPartStrategy) -> Self {
+                        match  self  {   GTElement::Fp12_1(x) => x.final_exponentiation(true, strategy).into(),
+                                         GTElement::Fp24_1(x) => x.final_exponentiation(true, strategy).into(),
+                                         GTElement::Fp48_1(x) => x.final_exponentiation(true, strategy).into(),
+                                         GTElement::Fp24_2(x) => x.final_exponentiation(true, strategy).into(),
+                                         GTElement::Fp48_2(x) => x.final_exponentiation(true, strategy).into(),                                         
+                                         GTElement::Fp48_3(x) => x.final_exponentiation(true, strategy).into(),                                         
                                      }
                         }    
     pub fn to_byte_array(&self) -> Vec<u8> {
@@ -286,7 +330,48 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
                                             GTElement::Fp48_3(x)=> GTElement::Fp48_3(x.frobinus(order)),
                                                         }       
                         }
-    pub fn derive_hkdf(&self,sizeinbits:usize,salt :Option<&[u8]>) -> Vec<u8>
+}
+
+pub trait GTExtElement<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> : ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + PartialEq 
+                                                              + fmt::Display + Send + Sync + 'static
+{   
+    // Common interface of the GT representations : the run-time dispatched GTElement enum (sized for the largest Fp48 variant), 
+    // and the TowerElement wrappers of extensions/towers.rs that hold only the extension of one family (Fp12 for BLS12, ...)
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self;
+    fn to_gt_element(&self) -> GTElement<N,P,PARAMSIZE>;
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>;
+    fn content(&self) -> &[FieldElement<N,P>];
+    fn unisqr(&self) -> Self;
+    fn conjugate(&self) -> Self;
+    fn frobinus(&self, order :u8) -> Self;
+    fn pow(&self,e : & dyn Exponent<N>) -> Self;
+    fn cyclotomic_pow(&self,e : & dyn Exponent<N>) -> Self;
+    fn sparse_multiply(&self,rhs : &[&[FieldElement<N,P>];3],twist_type:char) -> Self;
+    fn final_exponentiation_with(&self, strategy :HardPartStrategy) -> Self;
+    fn to_byte_array(&self) -> Vec<u8>;
+
+    fn encode_to_base64(&self) -> String {
+            general_purpose::STANDARD.encode(self.to_byte_array())
+            }
+    fn final_exponentiation(&self) -> Self {
+            self.final_exponentiation_with(HardPartStrategy::default())
+            }
+    fn is_cyclotomic(&self) -> bool {
+                            // f belongs to the cyclotomic sub-group iff f^Φk(p) = 1, and Φk(p) = p^(k/3) - p^(k/6) + 1 for k = 12, 24, 48,
+                            // so the check reduces to f^(p^(k/3)) * f = f^(p^(k/6)), using only Frobenius maps
+                            let order = (self.content().len() / 6) as u8;
+                            let t = self.frobinus(order);
+                            !self.is_zero() && t.frobinus(order).multiply(self).equal(&t)
+                        }
+    fn is_torsion(&self) -> bool {
+                            // Frobenius-based membership test from https://eprint.iacr.org/2021/1130.pdf (Scott) : for BLS curves p = u mod r 
+                            // and r = Φk(u), so gcd(p-u, Φk(p)) = r and a cyclotomic f is in GT iff f^p = f^u
+                            let u = self.constants_interface().unwrap().u;
+                            if !self.is_cyclotomic() {return false}
+                            let fu = self.pow(&u.unsigned_abs());
+                            self.frobinus(1).equal(&if u < 0 {fu.conjugate()} else {fu})
+                        }
+    fn derive_hkdf(&self,sizeinbits:usize,salt :Option<&[u8]>) -> Vec<u8>
     {
         const DSIZE :usize = 16; // length of sha256 output in bytes
         let size_in_bytes = (sizeinbits / 8) + ((sizeinbits % 8)!=0) as usize;
@@ -315,92 +400,123 @@ impl <const N:usize, const PARAMSIZE:usize>  GTElement<N,PARAMSIZE>{
         okm
     }                
 
+    fn try_derive_hkdf(&self,sizeinbits:usize,salt :Option<&[u8]>) -> Result<Vec<u8>, PairingError>
+    {
+        // Same as derive_hkdf, but reports unsupported output sizes as an error instead of panicking
+        const DSIZE :usize = 16; 
+        let size_in_bytes = sizeinbits.div_ceil(8);
+        if !(DSIZE..=DSIZE * 255).contains(&size_in_bytes) {Err(PairingError::InvalidOutputLength)}
+        else {Ok(self.derive_hkdf(sizeinbits, salt))}
+    }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp12Element_1<PARAMSIZE,N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp12: Fp12Element_1<PARAMSIZE,N>) -> Self {
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTExtElement<N,P,PARAMSIZE> for GTElement<N,P,PARAMSIZE>{
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self { *element }
+    fn to_gt_element(&self) -> GTElement<N,P,PARAMSIZE> { *self }
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
+        match self {    GTElement::Fp12_1(x) => x.constants_interface(),
+                        GTElement::Fp24_1(x)=> x.constants_interface(),
+                        GTElement::Fp48_1(x)=> x.constants_interface(),
+                        GTElement::Fp24_2(x)=> x.constants_interface(),
+                        GTElement::Fp48_2(x)=> x.constants_interface(),
+                        GTElement::Fp48_3(x)=> x.constants_interface(),
+                   }
+        }
+    fn content(&self) -> &[FieldElement<N,P>] { GTElement::content(self) }
+    fn unisqr(&self) -> Self { GTElement::unisqr(self) }
+    fn conjugate(&self) -> Self { GTElement::conjugate(self) }
+    fn frobinus(&self, order :u8) -> Self { GTElement::frobinus(self, order) }
+    fn pow(&self,e : & dyn Exponent<N>) -> Self { GTElement::pow(self, e) }
+    fn cyclotomic_pow(&self,e : & dyn Exponent<N>) -> Self { GTElement::cyclotomic_pow(self, e) }
+    fn sparse_multiply(&self,rhs : &[&[FieldElement<N,P>];3],twist_type:char) -> Self { GTElement::sparse_multiply(self, rhs, twist_type) }
+    fn final_exponentiation_with(&self, strategy :HardPartStrategy) -> Self { GTElement::final_exponentiation_with(self, strategy) }
+    fn to_byte_array(&self) -> Vec<u8> { GTElement::to_byte_array(self) }
+}
+
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp12Element_1<PARAMSIZE,N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp12: Fp12Element_1<PARAMSIZE,N,P>) -> Self {
         GTElement::Fp12_1(fp12)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp24Element_1<PARAMSIZE, N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp24: Fp24Element_1<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp24Element_1<PARAMSIZE, N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp24: Fp24Element_1<PARAMSIZE, N,P>) -> Self {
         GTElement::Fp24_1(fp24)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp24Element_2<PARAMSIZE, N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp24: Fp24Element_2<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp24Element_2<PARAMSIZE, N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp24: Fp24Element_2<PARAMSIZE, N,P>) -> Self {
         GTElement::Fp24_2(fp24)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp48Element_1<PARAMSIZE, N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp48: Fp48Element_1<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp48Element_1<PARAMSIZE, N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp48: Fp48Element_1<PARAMSIZE, N,P>) -> Self {
         GTElement::Fp48_1(fp48)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp48Element_2<PARAMSIZE, N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp48: Fp48Element_2<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp48Element_2<PARAMSIZE, N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp48: Fp48Element_2<PARAMSIZE, N,P>) -> Self {
         GTElement::Fp48_2(fp48)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp48Element_3<PARAMSIZE, N>> for GTElement<N, PARAMSIZE> {
-    fn from(fp48: Fp48Element_3<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp48Element_3<PARAMSIZE, N,P>> for GTElement<N,P, PARAMSIZE> {
+    fn from(fp48: Fp48Element_3<PARAMSIZE, N,P>) -> Self {
         GTElement::Fp48_3(fp48)
     }
 }
-impl<'a, const N: usize, const PARAMSIZE: usize> fmt::Display for GTElement<N,PARAMSIZE> {
+impl<'a, const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> fmt::Display for GTElement<N,P,PARAMSIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:}", &self.to_dec_string())
     }
     }
 
-impl  <const PARAMSIZE:usize,const N:usize> Add for GTElement<PARAMSIZE,N> {
-    type Output =  GTElement<PARAMSIZE,N>;
+impl  <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Add for GTElement<N,P,PARAMSIZE> {
+    type Output =  GTElement<N,P,PARAMSIZE>;
         fn add(self, rhs: Self) -> Self::Output {   self.addto(&rhs) }
     }
 
-impl  <const PARAMSIZE:usize,const N:usize> Sub for GTElement<PARAMSIZE,N> {
-    type Output =  GTElement<PARAMSIZE,N>;
+impl  <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Sub for GTElement<N,P,PARAMSIZE> {
+    type Output =  GTElement<N,P,PARAMSIZE>;
         fn sub(self, rhs: Self) -> Self::Output {   self.substract(&rhs) }
     }
 
-impl  <const PARAMSIZE:usize,const N:usize> Neg for GTElement<PARAMSIZE,N> {
-    type Output =  GTElement<PARAMSIZE,N>;
+impl  <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Neg for GTElement<N,P,PARAMSIZE> {
+    type Output =  GTElement<N,P,PARAMSIZE>;
         fn neg(self) -> Self::Output { self.negate() }
     }   
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for GTElement<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for GTElement<N,P,PARAMSIZE> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> Mul<u8> for GTElement<PARAMSIZE,N> {
-        type Output = GTElement<PARAMSIZE,N>;    
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Mul<u8> for GTElement<N,P,PARAMSIZE> {
+        type Output = GTElement<N,P,PARAMSIZE>;    
         fn mul(self, rhs: u8) -> Self::Output {     self.mulbyu8(rhs)   }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> Mul<GTElement<PARAMSIZE,N>> for u8 {
-    type Output = GTElement<PARAMSIZE,N>;
-    fn mul(self, rhs: GTElement<PARAMSIZE,N>) -> Self::Output {  rhs.mulbyu8(self)  }
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Mul<GTElement<N,P,PARAMSIZE>> for u8 {
+    type Output = GTElement<N,P,PARAMSIZE>;
+    fn mul(self, rhs: GTElement<N,P,PARAMSIZE>) -> Self::Output {  rhs.mulbyu8(self)  }
     }
     
 
-impl<const PARAMSIZE:usize,const N:usize> Mul for GTElement<PARAMSIZE,N> {
-        type Output =  GTElement<PARAMSIZE,N>;
+impl<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Mul for GTElement<N,P,PARAMSIZE> {
+        type Output =  GTElement<N,P,PARAMSIZE>;
         fn mul(self, rhs: Self) -> Self::Output {  self.multiply(&rhs)  }
     }
 
-impl<const PARAMSIZE:usize,const N:usize> Div for GTElement<PARAMSIZE,N> {
-    type Output =  GTElement<PARAMSIZE,N>;
+impl<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Div for GTElement<N,P,PARAMSIZE> {
+    type Output =  GTElement<N,P,PARAMSIZE>;
     fn div(self, rhs: Self) -> Self::Output {   self * rhs.invert()    }                                        
     }
 
-impl<const PARAMSIZE:usize,const N: usize> Div <GTElement<PARAMSIZE,N>> for u8 {
-    type Output = GTElement<PARAMSIZE,N>;
-    fn div(self, rhs: GTElement<PARAMSIZE,N>) -> Self::Output {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Div <GTElement<N,P,PARAMSIZE>> for u8 {
+    type Output = GTElement<N,P,PARAMSIZE>;
+    fn div(self, rhs: GTElement<N,P,PARAMSIZE>) -> Self::Output {
         match  self { 0 => rhs.zero(),
                         1 =>{ rhs.invert()}
                         _ =>{ self /rhs}
@@ -408,113 +524,343 @@ impl<const PARAMSIZE:usize,const N: usize> Div <GTElement<PARAMSIZE,N>> for u8 {
                 }
     }
 
-impl <const N:usize, const PARAMSIZE:usize> GTField<N,PARAMSIZE> {
-    pub fn one(&self) -> GTElement<N,PARAMSIZE> {
-        match  self  {   GTField::Fp12_1(x) => x.one().into(),
-                         GTField::Fp24_1(x) => x.one().into(),
-                         GTField::Fp48_1(x) => x.one().into(),
-                         GTField::Fp24_2(x) => x.one().into(),
-                         GTField::Fp48_2(x) => x.one().into(),
-                         GTField::Fp48_3(x) => x.one().into(),
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTExtField<N,P,PARAMSIZE> {
+    pub fn one(&self) -> GTElement<N,P,PARAMSIZE> {
+        match  self  {   GTExtField::Fp12_1(x) => x.one().into(),
+                         GTExtField::Fp24_1(x) => x.one().into(),
+                         GTExtField::Fp48_1(x) => x.one().into(),
+                         GTExtField::Fp24_2(x) => x.one().into(),
+                         GTExtField::Fp48_2(x) => x.one().into(),
+                         GTExtField::Fp48_3(x) => x.one().into(),
                      }
         }
-    pub fn zero(&self) -> GTElement<N,PARAMSIZE> {
-        match  self  {   GTField::Fp12_1(x) => x.zero().into(),
-                            GTField::Fp24_1(x) => x.zero().into(),
-                            GTField::Fp48_1(x) => x.zero().into(),
-                            GTField::Fp24_2(x) => x.zero().into(),
-                            GTField::Fp48_2(x) => x.zero().into(),
-                            GTField::Fp48_3(x) => x.zero().into(),
+    pub fn zero(&self) -> GTElement<N,P,PARAMSIZE> {
+        match  self  {   GTExtField::Fp12_1(x) => x.zero().into(),
+                            GTExtField::Fp24_1(x) => x.zero().into(),
+                            GTExtField::Fp48_1(x) => x.zero().into(),
+                            GTExtField::Fp24_2(x) => x.zero().into(),
+                            GTExtField::Fp48_2(x) => x.zero().into(),
+                            GTExtField::Fp48_3(x) => x.zero().into(),
                         }
         }
-    pub fn random_element(&self) -> GTElement<N,PARAMSIZE> {
-        match  self  {   GTField::Fp12_1(x) => x.random_element().into(),
-                         GTField::Fp24_1(x) => x.random_element().into(),
-                         GTField::Fp48_1(x) => x.random_element().into(),
-                         GTField::Fp24_2(x) => x.random_element().into(),
-                         GTField::Fp48_2(x) => x.random_element().into(),
-                         GTField::Fp48_3(x) => x.random_element().into(),
+    pub fn random_element(&self) -> GTElement<N,P,PARAMSIZE> {
+        match  self  {   GTExtField::Fp12_1(x) => x.random_element().into(),
+                         GTExtField::Fp24_1(x) => x.random_element().into(),
+                         GTExtField::Fp48_1(x) => x.random_element().into(),
+                         GTExtField::Fp24_2(x) => x.random_element().into(),
+                         GTExtField::Fp48_2(x) => x.random_element().into(),
+                         GTExtField::Fp48_3(x) => x.random_element().into(),
                         }
         }
-    pub fn field_interface(&self) -> PrimeField<N>{
-        match  self  {  GTField::Fp12_1(x) => x.field_interface(),
-                        GTField::Fp24_1(x) => x.field_interface(),
-                        GTField::Fp48_1(x) => x.field_interface(),
-                        GTField::Fp24_2(x) => x.field_interface(),
-                        GTField::Fp48_2(x) => x.field_interface(),
-                        GTField::Fp48_3(x) => x.field_interface(),
+    pub fn field_interface(&self) -> PrimeField<N,P>{
+        match  self  {  GTExtField::Fp12_1(x) => x.field_interface(),
+                        GTExtField::Fp24_1(x) => x.field_interface(),
+                        GTExtField::Fp48_1(x) => x.field_interface(),
+                        GTExtField::Fp24_2(x) => x.field_interface(),
+                        GTExtField::Fp48_2(x) => x.field_interface(),
+                        GTExtField::Fp48_3(x) => x.field_interface(),
                     }
     }   
 
-    pub fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>>{
-        match  self  {  GTField::Fp12_1(x) => x.extconsts_interface(),
-                        GTField::Fp24_1(x) => x.extconsts_interface(),
-                        GTField::Fp48_1(x) => x.extconsts_interface(),
-                        GTField::Fp24_2(x) => x.extconsts_interface(),
-                        GTField::Fp48_2(x) => x.extconsts_interface(),
-                        GTField::Fp48_3(x) => x.extconsts_interface(),
+    pub fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>{
+        match  self  {  GTExtField::Fp12_1(x) => x.extconsts_interface(),
+                        GTExtField::Fp24_1(x) => x.extconsts_interface(),
+                        GTExtField::Fp48_1(x) => x.extconsts_interface(),
+                        GTExtField::Fp24_2(x) => x.extconsts_interface(),
+                        GTExtField::Fp48_2(x) => x.extconsts_interface(),
+                        GTExtField::Fp48_3(x) => x.extconsts_interface(),
                     }
     }
 
-    pub fn from_big_integers(&self, source :Vec<BigInt>) -> GTElement<N,PARAMSIZE>{
-        match  self  {  GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_big_integers(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_big_integers(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_big_integers(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_big_integers(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_big_integers(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_big_integers(source)),
+    pub fn from_big_integers(&self, source :Vec<BigInt>) -> GTElement<N,P,PARAMSIZE>{
+        match  self  {  GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.from_big_integers(source)),
+                        GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.from_big_integers(source)),
+                        GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.from_big_integers(source)),
+                        GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.from_big_integers(source)),
+                        GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.from_big_integers(source)),
+                        GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.from_big_integers(source)),
                     }
     }
 
-    pub fn from_hex_strings(&self, source :&[&str]) -> GTElement<N, PARAMSIZE>{
-        match  self  {    GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_hex_strings(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_hex_strings(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_hex_strings(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_hex_strings(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_hex_strings(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_hex_strings(source)),
+    pub fn from_hex_strings(&self, source :&[&str]) -> GTElement<N,P, PARAMSIZE>{
+        match  self  {    GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.from_hex_strings(source)),
+                        GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.from_hex_strings(source)),
+                        GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.from_hex_strings(source)),
+                        GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.from_hex_strings(source)),
+                        GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.from_hex_strings(source)),
+                        GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.from_hex_strings(source)),
                     }
     }
         
-    pub fn from_strings(&self, source :&[&str]) -> GTElement<N,PARAMSIZE>{
-        match  self  {  GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_strings(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_strings(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_strings(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_strings(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_strings(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_strings(source)),
+    pub fn from_strings(&self, source :&[&str]) -> GTElement<N,P,PARAMSIZE>{
+        match  self  {  GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.from_strings(source)),
+                        GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.from_strings(source)),
+                        GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.from_strings(source)),
+                        GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.from_strings(source)),
+                        GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.from_strings(source)),
+                        GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.from_strings(source)),
                     }
     }
 
-    pub fn from_field_elements(&self, source :&[FieldElement<N>]) -> GTElement<N,PARAMSIZE>{
-        match  self  {  GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_field_elements(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_field_elements(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_field_elements(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_field_elements(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_field_elements(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_field_elements(source)),
+    pub fn from_field_elements(&self, source :&[FieldElement<N,P>]) -> GTElement<N,P,PARAMSIZE>{
+        match  self  {  GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.from_field_elements(source)),
+                        GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.from_field_elements(source)),
+                        GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.from_field_elements(source)),
+                        GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.from_field_elements(source)),
+                        GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.from_field_elements(source)),
+                        GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.from_field_elements(source)),
                     }
     }
 
-    pub fn from_byte_array(&self, source :&[u8]) -> GTElement<N,PARAMSIZE>{
-        match  self  {  GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_byte_array(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_byte_array(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_byte_array(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_byte_array(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_byte_array(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_byte_array(source)),
-                    }
+    pub fn from_byte_array(&self, source :&[u8]) -> GTElement<N,P,PARAMSIZE>{
+        match self.try_from_byte_array(source) { Ok(e) => e,
+                                                 Err(e) => panic!("{}",e)}
     }
 
-    pub fn from_base64(&self, source :&str) -> GTElement<N, PARAMSIZE>{
-        match  self  {  GTField::Fp12_1(x) => GTElement::Fp12_1(x.from_base64(source)),
-                        GTField::Fp24_1(x) => GTElement::Fp24_1(x.from_base64(source)),
-                        GTField::Fp48_1(x) => GTElement::Fp48_1(x.from_base64(source)),
-                        GTField::Fp24_2(x) => GTElement::Fp24_2(x.from_base64(source)),
-                        GTField::Fp48_2(x) => GTElement::Fp48_2(x.from_base64(source)),
-                        GTField::Fp48_3(x) => GTElement::Fp48_3(x.from_base64(source)),
-                    }
+    pub fn from_base64(&self, source :&str) -> GTElement<N,P, PARAMSIZE>{
+        match self.try_from_base64(source) { Ok(e) => e,
+                                             Err(e) => panic!("{}",e)}
+    }
+
+    pub fn try_from_byte_array(&self, source :&[u8]) -> Result<GTElement<N,P,PARAMSIZE>, PairingError>{
+        self.try_from_byte_array_with(source, DecodingMode::Subgroup)
+    }
+
+    pub fn try_from_byte_array_with(&self, source :&[u8], mode :DecodingMode) -> Result<GTElement<N,P,PARAMSIZE>, PairingError>{
+        let result = match  self  {  GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.try_from_byte_array(source)?),
+                                     GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.try_from_byte_array(source)?),
+                                     GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.try_from_byte_array(source)?),
+                                     GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.try_from_byte_array(source)?),
+                                     GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.try_from_byte_array(source)?),
+                                     GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.try_from_byte_array(source)?),
+                                 };
+        validate(result, mode)
+    }
+
+    pub fn try_from_base64(&self, source :&str) -> Result<GTElement<N,P, PARAMSIZE>, PairingError>{
+        self.try_from_base64_with(source, DecodingMode::Subgroup)
+    }
+
+    pub fn try_from_base64_with(&self, source :&str, mode :DecodingMode) -> Result<GTElement<N,P, PARAMSIZE>, PairingError>{
+        let result = match  self  {  GTExtField::Fp12_1(x) => GTElement::Fp12_1(x.try_from_base64(source)?),
+                                     GTExtField::Fp24_1(x) => GTElement::Fp24_1(x.try_from_base64(source)?),
+                                     GTExtField::Fp48_1(x) => GTElement::Fp48_1(x.try_from_base64(source)?),
+                                     GTExtField::Fp24_2(x) => GTElement::Fp24_2(x.try_from_base64(source)?),
+                                     GTExtField::Fp48_2(x) => GTElement::Fp48_2(x.try_from_base64(source)?),
+                                     GTExtField::Fp48_3(x) => GTElement::Fp48_3(x.try_from_base64(source)?),
+                                 };
+        validate(result, mode)
     }
 }
 
+pub struct GTField <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E>
+            {   pub base_field : GTExtField<N,P,PARAMSIZE>,
+                element : PhantomData<E>
+            }
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E> fmt::Debug for GTField<N,P,PARAMSIZE,E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GTField").field("base_field", &self.base_field).finish()
+    }
+}
 
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> GTField<N,P,PARAMSIZE,E> {
+    // Typed view of a GT extension field : elements are produced directly in the representation E of the engine
+    pub const fn new(base_field :GTExtField<N,P,PARAMSIZE>) -> Self {
+        GTField { base_field, element: PhantomData }
+    }
+    pub fn one(&self) -> E {
+        E::from_gt_element(&self.base_field.one())
+    }
+    pub fn zero(&self) -> E {
+        E::from_gt_element(&self.base_field.zero())
+    }
+    pub fn random_element(&self) -> E {
+        E::from_gt_element(&self.base_field.random_element())
+    }
+    pub fn field_interface(&self) -> PrimeField<N,P>{
+        self.base_field.field_interface()
+    }
+    pub fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>{
+        self.base_field.extconsts_interface()
+    }
+    pub fn from_big_integers(&self, source :Vec<BigInt>) -> E{
+        E::from_gt_element(&self.base_field.from_big_integers(source))
+    }
+    pub fn from_hex_strings(&self, source :&[&str]) -> E{
+        E::from_gt_element(&self.base_field.from_hex_strings(source))
+    }
+    pub fn from_strings(&self, source :&[&str]) -> E{
+        E::from_gt_element(&self.base_field.from_strings(source))
+    }
+    pub fn from_field_elements(&self, source :&[FieldElement<N,P>]) -> E{
+        E::from_gt_element(&self.base_field.from_field_elements(source))
+    }
+    pub fn from_byte_array(&self, source :&[u8]) -> E{
+        match self.try_from_byte_array(source) { Ok(e) => e,
+                                                 Err(e) => panic!("{}",e)}
+    }
+    pub fn from_base64(&self, source :&str) -> E{
+        match self.try_from_base64(source) { Ok(e) => e,
+                                             Err(e) => panic!("{}",e)}
+    }
+    pub fn try_from_byte_array(&self, source :&[u8]) -> Result<E, PairingError>{
+        self.try_from_byte_array_with(source, DecodingMode::Subgroup)
+    }
+    pub fn try_from_byte_array_with(&self, source :&[u8], mode :DecodingMode) -> Result<E, PairingError>{
+        // Membership checks are run on the typed element, so they use the monomorphic arithmetic of E
+        let result = E::from_gt_element(&self.base_field.try_from_byte_array_with(source, DecodingMode::Unchecked)?);
+        validate(result, mode)
+    }
+    pub fn try_from_base64(&self, source :&str) -> Result<E, PairingError>{
+        self.try_from_base64_with(source, DecodingMode::Subgroup)
+    }
+    pub fn try_from_base64_with(&self, source :&str, mode :DecodingMode) -> Result<E, PairingError>{
+        let result = E::from_gt_element(&self.base_field.try_from_base64_with(source, DecodingMode::Unchecked)?);
+        validate(result, mode)
+    }
+}
+
+fn validate<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>>(element :E, mode :DecodingMode) -> Result<E, PairingError>{
+    // The pairing values live in the order-r sub-group of the cyclotomic sub-group : OnCurve only checks the latter
+    match mode {    DecodingMode::Unchecked => Ok(element),
+                    DecodingMode::OnCurve   => if element.is_cyclotomic() {Ok(element)} else {Err(PairingError::NotCyclotomic)},
+                    DecodingMode::Subgroup  => if !element.is_cyclotomic() {Err(PairingError::NotCyclotomic)}
+                                               else if !element.is_torsion() {Err(PairingError::NotInSubgroup)}
+                                               else {Ok(element)},
+               }
+}
+
+// Pairing values : elements of the engine's GT representation E that are known to lie in the cyclotomic sub-group (outputs of
+// the final exponentiation, or elements validated once by try_from_element). Their powers, squares and inverses always use the
+// cyclotomic algorithms, without the membership test of GTElement::pow. The read-only interface of E is reached through Deref
+#[derive(Clone, Copy)]
+pub struct Gt <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E>(E, PhantomData<P>);
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> Gt<N,P,PARAMSIZE,E> {
+    pub fn from_miller_loop(f :&E, strategy :HardPartStrategy) -> Self {
+        // The final exponentiation lands in the cyclotomic sub-group whatever f is
+        Gt(f.final_exponentiation_with(strategy), PhantomData)
+    }
+    pub fn try_from_element(element :&E) -> Result<Self, PairingError> {
+        if element.is_cyclotomic() {Ok(Gt(*element, PhantomData))} else {Err(PairingError::NotCyclotomic)}
+    }
+    pub fn try_from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Result<Self, PairingError> {
+        Self::try_from_element(&E::from_gt_element(element))
+    }
+    pub fn from_element(element :&E) -> Self {
+        match Self::try_from_element(element) { Ok(e) => e,
+                                                Err(e) => panic!("{}",e)}
+    }
+    pub fn element(&self) -> E {
+        self.0
+    }
+    pub fn one(&self) -> Self {
+        Gt(self.0.one(), PhantomData)
+    }
+    pub fn multiply(&self, rhs :&Self) -> Self {
+        Gt(self.0.multiply(&rhs.0), PhantomData)
+    }
+    pub fn square(&self) -> Self {
+        Gt(self.0.unisqr(), PhantomData)
+    }
+    pub fn inverse(&self) -> Self {
+        // x^(p^(k/2)+1) = 1 in the cyclotomic sub-group, so the inverse is the conjugate
+        Gt(self.0.conjugate(), PhantomData)
+    }
+    pub fn pow(&self, e :&dyn Exponent<N>) -> Self {
+        Gt(self.0.cyclotomic_pow(e), PhantomData)
+    }
+    pub fn frobinus(&self, order :u8) -> Self {
+        Gt(self.0.frobinus(order), PhantomData)
+    }
+    pub fn pow_gls<const R:usize, S: FieldConfig<R>>(&self, scalar :&FieldElement<R,S>, decomposer :&'static LatticeDecomposer<R>) -> Self {
+        // Constant-time exponentiation by a scalar of Fr, with the Frobenius map as the endomorphism : x^p = x^u on GT since p = u (mod r).
+        // The scalar is decomposed with the lattice of gls_multiply on G2 (engine.g2.consts.gls_decomposer, or engine.pow_gls that passes it),
+        // as k = Σ k_i*u^i (mod r) in dimension φ(k) = 4, 8 or 16, so that x^k = Π (x^(p^i))^k_i with sub-scalars of about log(r)/φ(k) bits. The sub-scalars are
+        // processed by groups of four as in gls_multiply (tables of 8 products, sign-aligned recodings with odd leaders), the inverses being conjugates.
+        // The result is x^k for the elements of order r (the pairing values), where the Frobenius acts as the power by u
+        let subscalars = decomposer.decompose(&scalar.to_canonical_limbs());
+        let count = decomposer.bound_bits + 1;
+        let mut powers = Vec::with_capacity(decomposer.dimension);
+        let mut current = *self;
+        for (_, negative) in &subscalars {  let mut x = current;
+                                            x.conditional_negate(*negative);
+                                            powers.push(x);
+                                            current = current.frobinus(1);
+                                         }
+        let mut tables = Vec::with_capacity(decomposer.dimension >> 2);
+        let mut codes = Vec::with_capacity(decomposer.dimension >> 2);
+        let mut evens = Vec::with_capacity(decomposer.dimension >> 2);
+        for group in 0..decomposer.dimension >> 2 { let q = &powers[4 * group..4 * group + 4];
+                                                    let mut lookup = [q[0];8];
+                                                    lookup[1] = q[1].multiply(&lookup[0]);
+                                                    lookup[2] = q[2].multiply(&lookup[0]);
+                                                    lookup[3] = q[2].multiply(&lookup[1]);
+                                                    lookup[4] = q[3].multiply(&lookup[0]);
+                                                    lookup[5] = q[3].multiply(&lookup[1]);
+                                                    lookup[6] = q[3].multiply(&lookup[2]);
+                                                    lookup[7] = q[3].multiply(&lookup[3]);
+                                                    let mut ks :Vec<[u64;R]> = subscalars[4 * group..4 * group + 4].iter().map(|(k, _)| *k).collect();
+                                                    evens.push(Choice::from(((ks[0][0] & 1) ^ 1) as u8));
+                                                    ks[0][0] |= 1;
+                                                    codes.push(recod_sign_aligned(&ks, count));
+                                                    tables.push(lookup);
+                                                  }
+        let mut result = signed_digit_lookup(&tables[0], codes[0][count - 1]);
+        for (table, code) in tables.iter().zip(&codes).skip(1) { result = result.multiply(&signed_digit_lookup(table, code[count - 1])) };
+        for i in (0..count - 1).rev() { result = result.square();
+                                        for (table, code) in tables.iter().zip(&codes) { result = result.multiply(&signed_digit_lookup(table, code[i])) };
+                                      }
+        // Remove the leaders contributions added to make them odd (always computed)
+        for (leader, even) in powers.iter().step_by(4).zip(&evens) {   let corrected = result.multiply(&leader.inverse());
+                                                                        result.conditional_assign(&corrected, *even);
+                                                                   }
+        result
+    }
+    pub fn equal(&self, rhs :&Self) -> bool {
+        self.0.equal(&rhs.0)
+    }
+    pub fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E> Deref for Gt<N,P,PARAMSIZE,E> {
+    type Target = E;
+    fn deref(&self) -> &E {
+        &self.0
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> PartialEq for Gt<N,P,PARAMSIZE,E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.equal(&other.0)
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> ConstantTimeEq for Gt<N,P,PARAMSIZE,E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> ConditionallySelectable for Gt<N,P,PARAMSIZE,E> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Gt(E::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> ConditionallyNegatable for Gt<N,P,PARAMSIZE,E> {
+    fn conditional_negate(&mut self, choice: Choice) {
+        // The group "negation" of GT (used by the signed recodings) is the inverse
+        let inverse = self.inverse();
+        self.conditional_assign(&inverse, choice)
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize, E: GTExtElement<N,P,PARAMSIZE>> fmt::Display for Gt<N,P,PARAMSIZE,E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -3,7 +3,9 @@
 // During May 2024.
 
 pub mod curve_arithmetics;
+pub mod fixed_base;
 pub mod g1;
 pub mod g2;
 pub mod gt;
+pub mod msm;
 mod g2_primitives;
\ No newline at end of file
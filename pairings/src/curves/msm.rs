@@ -0,0 +1,150 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use crate::curves::curve_arithmetics::EcPoint;
+use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{ConditionallySelectable, ConstantTimeEq};
+use std::fmt::Display;
+use std::thread;
+
+pub const STRAUS_THRESHOLD : usize = 32;     // Below this number of (point,scalar) pairs, Straus is faster than Pippenger
+const STRAUS_WINDOW : usize = 4;             // wNAF window used by the interleaved (Straus) method
+
+fn infinit<T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display>(sample :&EcPoint<T>) -> EcPoint<T>
+{
+    EcPoint { x: sample.x.one(), y: sample.x.one(), z: sample.x.zero() }
+}
+
+fn window_of(limbs :&[u64], offset :usize, c :usize) -> usize
+{
+    // Extract the c-bits window starting at bit "offset" from a little-endian limbs representation
+    let idx = offset / 64;
+    let shift = offset % 64;
+    if idx >= limbs.len() {return 0}
+    let mut w = limbs[idx] >> shift;
+    if (shift + c > 64) && (idx + 1 < limbs.len()) { w |= limbs[idx + 1] << (64 - shift) }
+    (w & ((1u64 << c) - 1)) as usize
+}
+
+fn bits_of(limbs :&[u64]) -> usize
+{
+    match limbs.iter().rposition(|l| *l != 0) { Some(i) => 64 * i + 64 - limbs[i].leading_zeros() as usize,
+                                                None => 0 }
+}
+
+fn wnaf(scalar :&[u64], w :usize) -> Vec<i8>
+{
+    // Width-w Non-Adjacent Form: odd digits in ]-2^(w-1), 2^(w-1)[, least significant digit first.
+    // The scalar is handled as little-endian limbs, with an extra limb for the carries of the negative digits
+    let modulo = 1i64 << w;
+    let mut k = scalar.to_vec();
+    k.push(0);
+    let mut naf = Vec::with_capacity(bits_of(scalar) + 1);
+    while k.iter().any(|l| *l != 0) {   if k[0] & 1 == 1 {  let m = (k[0] & (modulo as u64 - 1)) as i64;
+                                                            let digit = if m >= (modulo >> 1) {m - modulo} else {m};
+                                                            // k - digit only changes the low w bits (k - m), or carries into the upper limbs (k + |digit|)
+                                                            if digit > 0 {k[0] -= digit as u64}
+                                                            else {  let mut carry = (-digit) as u64;
+                                                                    for l in k.iter_mut() { let (sum, overflow) = l.overflowing_add(carry);
+                                                                                            *l = sum;
+                                                                                            if !overflow {break}
+                                                                                            carry = 1;
+                                                                                          }
+                                                                 }
+                                                            naf.push(digit as i8);
+                                                         }
+                                        else {naf.push(0)}
+                                        for i in 0..k.len() { k[i] = (k[i] >> 1) | if i + 1 < k.len() {k[i + 1] << 63} else {0} }
+                                    }
+    naf
+}
+
+fn straus<T, const L:usize>(points :&[EcPoint<T>], scalars :&[[u64;L]], b3 :&T) -> EcPoint<T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display
+{
+    //  Interleaved w-NAF multi-exponentiation (Straus-Shamir trick): all the scalars share the same doublings
+    //  Guide to Elliptic Curve Cryptography (Hankerson, Menezes, Vanstone), Algorithm 3.51
+    let mut result = infinit(&points[0]);
+    let nafs : Vec<Vec<i8>> = scalars.iter().map(|s| wnaf(s, STRAUS_WINDOW)).collect();
+    let tables : Vec<Vec<EcPoint<T>>> = points.iter().map(|p| {  let p2 = p.double_jacobian();
+                                                                let mut table = vec![*p; 1 << (STRAUS_WINDOW - 2)];
+                                                                for i in 1..table.len() { table[i] = table[i - 1].add_complete(&p2, b3)}
+                                                                table
+                                                              }).collect();
+    let len = nafs.iter().map(|n| n.len()).max().unwrap_or(0);
+    for i in (0..len).rev() {   result = result.double_jacobian();
+                                for (naf, table) in nafs.iter().zip(tables.iter()) {
+                                    if i < naf.len() && naf[i] != 0 {   let p = table[(naf[i].unsigned_abs() >> 1) as usize];
+                                                                        result = result.add_complete(& if naf[i] > 0 {p} else {p.negate()}, b3);
+                                                                    }
+                                }
+                            }
+    result
+}
+
+fn add_normalized<T>(acc :&EcPoint<T>, p :&EcPoint<T>, b3 :&T) -> EcPoint<T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display
+{
+    //  Variable-time mixed addition of a normalized point (the inputs of the MSM are public) : the rare exceptions 
+    //  (P+P and P+(-P), detected by h = 0 in mixed_jacobian) are delegated to the complete formulas
+    if p.is_infinit() {return *acc}
+    if acc.is_infinit() {return *p}
+    let sum = acc.mixed_jacobian(p);
+    if sum.z.is_zero() {acc.add_complete(p, b3)} else {sum}
+}
+
+fn pippenger<T, const L:usize>(points :&[EcPoint<T>], scalars :&[[u64;L]], b3 :&T) -> EcPoint<T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display
+{
+    //  Bucket method for multi-scalar multiplication (Pippenger), with the running-sum trick for bucket aggregation
+    //  https://eprint.iacr.org/2012/549.pdf (Section 4)
+    let inf = infinit(&points[0]);
+    let c = ((points.len() as f64).ln() as usize + 2).clamp(2, 16);
+    let numbits = scalars.iter().map(|s| bits_of(s)).max().unwrap_or(0);
+    let mut result = inf;
+    for w in (0..numbits.div_ceil(c)).rev() {
+        for _ in 0..c { result = result.double_jacobian() }
+        let mut buckets = vec![inf; (1 << c) - 1];
+        for (p, l) in points.iter().zip(scalars.iter()) { let idx = window_of(l, w * c, c);
+                                                        if idx != 0 { buckets[idx - 1] = add_normalized(&buckets[idx - 1], p, b3) }
+                                                      }
+        let mut running = inf;
+        let mut sum = inf;
+        for b in buckets.iter().rev() { running = running.add_complete(b, b3);
+                                        sum = sum.add_complete(&running, b3);
+                                      }
+        result = result.add_complete(&sum, b3);
+    }
+    result
+}
+
+pub fn multi_scalar_multiply<T, const L:usize>(points :&[EcPoint<T>], scalars :&[[u64;L]], b3 :&T, threads :usize) -> EcPoint<T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display + Send + Sync
+{
+    //  Compute sum(scalars[i]*points[i]) (scalars as little-endian limbs), using Straus for small inputs and Pippenger for large ones.
+    //  The points (public) are first normalized with a single variable-time inversion, so that the buckets are filled with mixed additions.
+    //  Both lists must be non-empty and have the same size (checked by the callers)
+    let mut normalized = points.to_vec();
+    EcPoint::batch_normalize_vartime(&mut normalized);
+    multi_scalar_multiply_normalized(&normalized, scalars, b3, threads)
+}
+
+fn multi_scalar_multiply_normalized<T, const L:usize>(points :&[EcPoint<T>], scalars :&[[u64;L]], b3 :&T, threads :usize) -> EcPoint<T>
+    where T :ArithmeticOperations + ConstantTimeEq + ConditionallySelectable + Copy + Display + Send + Sync
+{
+    //  When threads > 1, the input is split in chunks evaluated concurrently, and the partial results are summed
+    if threads > 1 && points.len() >= 2 * STRAUS_THRESHOLD {
+        let chunk = points.len().div_ceil(threads);
+        let partials : Vec<EcPoint<T>> = thread::scope(|s| {
+                    let handles : Vec<_> = points.chunks(chunk).zip(scalars.chunks(chunk))
+                                                 .map(|(p, k)| s.spawn(move || multi_scalar_multiply_normalized(p, k, b3, 1)))
+                                                 .collect();
+                    handles.into_iter().map(|h| h.join().unwrap()).collect()
+                });
+        partials.iter().skip(1).fold(partials[0], |acc, p| acc.add_complete(p, b3))
+    }
+    else {  if points.len() < STRAUS_THRESHOLD {straus(points, scalars, b3)}
+            else {pippenger(points, scalars, b3)}
+         }
+}
@@ -0,0 +1,55 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairingError {
+    BadLength { expected: usize, found: usize },   // Size of the input does not match the expected encoding size
+    BadFlagBits,                                   // Compression/Infinity/Sign bits are in an invalid combination
+    NotOnCurve,                                    // Decoded coordinates do not satisfy the curve equation
+    NotInSubgroup,                                 // Point (or GT element) is not in the prime-order sub-group
+    NotCyclotomic,                                 // GT element is not in the cyclotomic sub-group of the extension field
+    NonCanonicalFieldElement,                      // Encoded integer is not reduced modulo the field characteristic
+    InvalidInfinity,                               // Infinity flag is set but the payload is not all zeros
+    InvalidBase64,                                 // Input string is not a valid base64 encoding
+    InvalidHex,                                    // Input string is not a valid hexadecimal encoding
+    InvalidString,                                 // Input string is not a valid decimal/hexadecimal integer
+    LengthMismatch { left: usize, right: usize },  // Two lists that must be paired have different sizes
+    InvalidOutputLength,                           // Requested output size is out of the supported range
+    PreparedLinesMismatch,                         // Lines of a prepared G2 point do not match its point
+}
+
+impl fmt::Display for PairingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairingError::BadLength { expected, found } => write!(f, "Invalid input length: expected {} bytes, found {} ...", expected, found),
+            PairingError::BadFlagBits               => write!(f, "Invalid compressed point format: bad flag bits ..."),
+            PairingError::NotOnCurve                => write!(f, "Invalid point: not in the curve ..."),
+            PairingError::NotInSubgroup             => write!(f, "Invalid element: not in the prime-order sub-group ..."),
+            PairingError::NotCyclotomic             => write!(f, "Invalid GT element: not in the cyclotomic sub-group ..."),
+            PairingError::NonCanonicalFieldElement  => write!(f, "Non-canonical field element: value is not reduced modulo p ..."),
+            PairingError::InvalidInfinity           => write!(f, "Invalid compression of an infinity point ..."),
+            PairingError::InvalidBase64             => write!(f, "Failed to decode base64 string"),
+            PairingError::InvalidHex                => write!(f, "Failed to decode hexadecimal string"),
+            PairingError::InvalidString             => write!(f, "Failed to parse the string as an integer ..."),
+            PairingError::LengthMismatch { left, right } => write!(f, "Length mismatch between inputs ({} vs {}) ...", left, right),
+            PairingError::InvalidOutputLength       => write!(f, "Requested output length is out of the supported range ..."),
+            PairingError::PreparedLinesMismatch     => write!(f, "Invalid prepared point: the lines do not match the point ..."),
+        }
+    }
+}
+
+impl std::error::Error for PairingError {}
+
+//  Level of validation applied when de-serializing group elements. Unchecked only verifies the encoding itself (size, flags,
+//  canonical coordinates), OnCurve adds the curve equation (for GT : membership to the cyclotomic sub-group),
+//  and Subgroup adds the prime-order sub-group membership, which blocks small-subgroup and invalid-curve attacks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DecodingMode {
+    Unchecked,
+    OnCurve,
+    #[default]
+    Subgroup,
+}
@@ -5,29 +5,32 @@
 use base64::engine::general_purpose;
 use base64::Engine;
 use num_bigint::{BigInt, ToBigInt};
+use crate::errors::PairingError;
 use crate::tools::hashs::{ i2osp_pf, os2ip};
 use crate::{fields::arithmetic, tools::exponent::Exponent};
-use crate::tools::arithmetic_interface::ArithmeticOperations;
-use super::super::fields::prime_fields::{FieldElement,PrimeField};
+use crate::tools::arithmetic_interface::{batch_invert_with, ArithmeticOperations};
+use crate::extensions::towers::TowerOperations;
+use crate::tools::constant_time::{Choice, ConditionallySelectable, ConstantTimeEq};
+use super::super::fields::prime_fields::{FieldConfig, FieldElement,PrimeField};
 
-#[derive(Clone,Debug)]
-pub struct ExFieldConsts<const PARAMSIZE:usize,const N:usize> 
-                {   pub frobinus_consts:[FieldElement<N>;PARAMSIZE],
+#[derive(Clone,Copy,Debug)]
+pub struct ExFieldConsts<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> 
+                {   pub frobinus_consts:[FieldElement<N,P>;PARAMSIZE],
                     pub u:i128,      
                     pub fb_id:i8,
-                    pub base_qnr :FieldElement<N>                
+                    pub base_qnr :FieldElement<N,P>                
                 }
 
-pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
-    {   type ElementType : ExtElement<PARAMSIZE,ORDER,N>;
+pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize, P: FieldConfig<N>>
+    {   type ElementType : ExtElement<PARAMSIZE,ORDER,N,P>;
         type BaseFieldType ; 
 
         // Interface to the basefield and the constants of the implemented struct     
-        fn field_interface(&self) -> PrimeField<N>;   
-        fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>>;                       
+        fn field_interface(&self) -> PrimeField<N,P>;   
+        fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>;                       
 
         // Constructor of a new implemented extension from a base field  
-        fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Self;
+        fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Self;
 
         // Generate a random element from the extention field
         fn random_element(&self) -> Self::ElementType {
@@ -50,7 +53,7 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
             let zero = FieldElement {   mont_limbs: [0; N],
                                                          fieldparams: field.parametres,
                                                      };        
-            let mut result: [FieldElement<N>; ORDER] = [zero; ORDER];        
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];        
             for i in 0..source.len() {result[i] = field.from_bigint(&source[i]);}        
             Self::ElementType::new(&result, consts)
         }
@@ -63,7 +66,7 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
             let zero = FieldElement {   mont_limbs: [0; N],
                                                         fieldparams: field.parametres,
                                                     };        
-            let mut result: [FieldElement<N>; ORDER] = [zero; ORDER];        
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];        
             for i in 0..source.len() {result[i] = field.from_hex_str(&source[i]);}        
             Self::ElementType::new(&result,consts)
         }
@@ -76,21 +79,21 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
             let zero = FieldElement {   mont_limbs: [0; N],
                                                          fieldparams: field.parametres,
                                                      };        
-            let mut result: [FieldElement<N>; ORDER] = [zero; ORDER];        
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];        
             for i in 0..source.len() {result[i] = field.from_str(&source[i]);}        
             Self::ElementType::new(&result,consts)
         }
 
         
         //  Generate an element in the extention field from a list of baseField's elements (Fp)
-        fn from_field_elements(&self, source :&[FieldElement<N>]) -> Self::ElementType{
+        fn from_field_elements(&self, source :&[FieldElement<N,P>]) -> Self::ElementType{
             if source.len() != ORDER {panic!("Size of input does not correspond to the field's extension ...");}        
             let field = self.field_interface();
             let consts =self.extconsts_interface();
             let zero = FieldElement {   mont_limbs: [0; N],
                                                          fieldparams: field.parametres,
                                                      };        
-            let mut result: [FieldElement<N>; ORDER] = [zero; ORDER];        
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];        
             for i in 0..source.len() {result[i] = source[i];}        
             Self::ElementType::new(&result,consts)
         }
@@ -104,7 +107,7 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
             let zero = FieldElement {   mont_limbs: [0; N],
                                                          fieldparams: field.parametres,
                                                      };        
-            let mut result: [FieldElement<N>; ORDER] = [zero; ORDER];   
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];   
             for i in 0..ORDER {result[i] =  field.from_bigint(&os2ip(&source[i*sizeinbytes..(i+1)*sizeinbytes]).to_bigint().unwrap());}        
             Self::ElementType::new(&result,consts)
         }
@@ -119,6 +122,25 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
             self.from_byte_array(&decoded_bytes)
         }
 
+        //  Same as from_byte_array, but returns an error on wrong size or non-canonical coefficients instead of panicking
+        fn try_from_byte_array(&self, source :&[u8]) -> Result<Self::ElementType, PairingError>{
+            let field = self.field_interface();
+            let sizeinbytes = field.size_in_bytes(); 
+            if sizeinbytes*ORDER != source.len() {return Err(PairingError::BadLength { expected: sizeinbytes*ORDER, found: source.len() });}        
+            let consts =self.extconsts_interface();
+            let zero = FieldElement {   mont_limbs: [0; N],
+                                                         fieldparams: field.parametres,
+                                                     };        
+            let mut result: [FieldElement<N,P>; ORDER] = [zero; ORDER];   
+            for i in 0..ORDER {result[i] =  field.try_from_canonical_biguint(&os2ip(&source[i*sizeinbytes..(i+1)*sizeinbytes]))?;}        
+            Ok(Self::ElementType::new(&result,consts))
+        }
+
+        fn try_from_base64(&self, source :&str) -> Result<Self::ElementType, PairingError>{
+            let decoded_bytes = general_purpose::STANDARD.decode(source).map_err(|_| PairingError::InvalidBase64)?;
+            self.try_from_byte_array(&decoded_bytes)
+        }
+
         //  Generate Zero element of the extention field (identity element with respect to Addition)
         fn zero(&self) -> Self::ElementType{
             let field = self.field_interface();
@@ -142,18 +164,18 @@ pub trait  ExtField<const PARAMSIZE:usize,const ORDER :usize, const N:usize>
         }        
     }
 
-pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{    
-    fn content_interface(&self) -> &[FieldElement<N>;ORDER];
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>>;
+pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize, P: FieldConfig<N>>{    
+    fn content_interface(&self) -> &[FieldElement<N,P>;ORDER];
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>;
     fn multiply(&self, rhs:&Self)-> Self;
     fn sqr(&self)-> Self;
-    fn new(content :&[FieldElement<N>; ORDER], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Self;
+    fn new(content :&[FieldElement<N,P>; ORDER], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Self;
     
     fn addto (&self, b : &Self) -> Self where Self: Sized      
             {   let _a = self.content_interface();
                 let _b = b.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;                
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;                
                 for i in 0..ORDER {  result[i] = _a[i].addto(&_b[i])}
                 Self::new( &result, self.constants_interface())
             }
@@ -161,21 +183,21 @@ pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{
             {   let _a = self.content_interface();
                 let _b = b.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;                
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;                
                 for i in 0..ORDER { result[i] = _a[i].substract(&_b[i])}
                 Self::new( &result,self.constants_interface())
             }
     fn negate (&self) -> Self where Self: Sized      
             {   let _a = &self.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;                
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;                
                 for i in 0..ORDER { result[i] = _a[i].negate()}
                 Self::new( &result,self.constants_interface())
             }
     fn double (&self) -> Self where Self: Sized      
             {   let _a = &self.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;                
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;                
                 for i in 0..ORDER { result[i] = _a[i].double()}
                 Self::new( &result,self.constants_interface())
             }            
@@ -186,6 +208,25 @@ pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{
                 for i in 0..ORDER { eq = eq & _a[i].equal(&_b[i])}
                 eq
             }
+    //  Constant-time counterparts of equal/select/negate, on which the ConstantTimeEq, ConditionallySelectable 
+    //  and ConditionallyNegatable implementations of the extension elements rely 
+    fn ct_equal(&self, other: &Self) -> Choice {
+                let _a = self.content_interface();
+                let _b = other.content_interface();
+                let mut eq = Choice::from(1u8);
+                for i in 0..ORDER { eq = eq & _a[i].ct_eq(&_b[i])}
+                eq
+            }
+    fn ct_select(&self, other: &Self, choice :Choice) -> Self where Self: Sized
+            {   let _a = self.content_interface();
+                let _b = other.content_interface();
+                let mut result: [FieldElement<N,P>; ORDER] = *_a;
+                for i in 0..ORDER { result[i] = FieldElement::conditional_select(&_a[i], &_b[i], choice)}
+                Self::new( &result, self.constants_interface())
+            }
+    fn ct_negate(&self, choice :Choice) -> Self where Self: Sized
+            {   self.ct_select(&self.negate(), choice)
+            }
     fn mulbyu8(&self,  rhs: u8)-> Self where Self: Sized 
             {   let _a = self.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
@@ -198,16 +239,16 @@ pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{
                                 5 =>{let double =self.addto(&self);
                                         let fourth =double.addto(&double);
                                         fourth.addto(&self) },
-                                _ =>{  let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ; 
+                                _ =>{  let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ; 
                                         for i in 0..ORDER{  result[i] = rhs * _a[i]};
                                         Self::new(&result,self.constants_interface())
                                     }            
                             }        
             }
-    fn mulby_fp_element (&self, b : &FieldElement<N>) -> Self where Self: Sized      
+    fn mulby_fp_element (&self, b : &FieldElement<N,P>) -> Self where Self: Sized      
             {   let _a = self.content_interface();
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;                
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;                
                 for i in 0..ORDER {  result[i] = _a[i].multiply(&b)}
                 Self::new( &result, self.constants_interface())
             }
@@ -217,7 +258,7 @@ pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{
                 let out_params=  _a[0].fieldparams;
                 let one = FieldElement{mont_limbs:out_params.one,fieldparams:_a[0].fieldparams};
                 let zero = FieldElement{mont_limbs:[0;N],fieldparams:_a[0].fieldparams};
-                let mut result: [FieldElement<N>; ORDER] =[zero;ORDER] ;
+                let mut result: [FieldElement<N,P>; ORDER] =[zero;ORDER] ;
                 result [0] = one;   
                 let mut result = Self::new(&result,self.constants_interface());                            
                 if let Some(array) = e.to_u64_array() {   let limbnum= e.get_len();
@@ -294,6 +335,10 @@ pub trait ExtElement<const PARAMSIZE:usize,const ORDER :usize, const N:usize>{
                 result[0] = FieldElement { mont_limbs: content[0].fieldparams.one,fieldparams: content[0].fieldparams};
                 Self::new(&result, self.constants_interface())
             }         
+    fn batch_invert(elements :&mut [Self]) where Self: Sized + Copy + TowerOperations
+            {   // Inversion of all the elements with a single inversion in the extension (Montgomery's trick, zeros left unchanged)
+                batch_invert_with(elements, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert())
+            }
     fn sign(&self) -> i8
     {
             // sgn0 "sign" of x: returns -1 if x is lexically larger than  -x and, else returns 1
@@ -13,23 +13,24 @@ use crate::{extensions::{ext_fields::{ExtElement, ExtField},
             towering1::fp8::{Fp8Element as Fp8Element_1, Fp8Field as Fp8Field_1}, 
             towering2::fp8::{Fp8Element as Fp8Element_2, Fp8Field as Fp8Field_2}, 
             towering3::fp8::{Fp8Element as Fp8Element_3, Fp8Field as Fp8Field_3}}, 
-            fields::prime_fields::{FieldElement,  PrimeField}, 
-            tools::{arithmetic_interface::ArithmeticOperations, hashs::{hash_string_to_field, os2ip}}};
+            errors::PairingError,
+            fields::prime_fields::{FieldConfig, FieldElement,  PrimeField}, 
+            tools::{arithmetic_interface::ArithmeticOperations, constant_time::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption}, hashs::{hash_bytes_to_field, os2ip, Expander}}};
 
 
 #[derive(Clone,Copy,Debug)]
-pub enum ExtFieldG2Element <const N:usize, const PARAMSIZE:usize>
-            {   Fp2_1(Fp2Element_1<N>),
-                Fp4_1(Fp4Element_1<PARAMSIZE,N>),
-                Fp8_1(Fp8Element_1<PARAMSIZE,N>),
-                Fp2_2(Fp2Element_2<PARAMSIZE,N>),
-                Fp4_2(Fp4Element_2<PARAMSIZE,N>),
-                Fp8_2(Fp8Element_2<PARAMSIZE,N>),                
-                Fp4_3(Fp4Element_3<PARAMSIZE,N>),
-                Fp8_3(Fp8Element_3<PARAMSIZE,N>),                
+pub enum ExtFieldG2Element <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize>
+            {   Fp2_1(Fp2Element_1<N,P>),
+                Fp4_1(Fp4Element_1<PARAMSIZE,N,P>),
+                Fp8_1(Fp8Element_1<PARAMSIZE,N,P>),
+                Fp2_2(Fp2Element_2<PARAMSIZE,N,P>),
+                Fp4_2(Fp4Element_2<PARAMSIZE,N,P>),
+                Fp8_2(Fp8Element_2<PARAMSIZE,N,P>),                
+                Fp4_3(Fp4Element_3<PARAMSIZE,N,P>),
+                Fp8_3(Fp8Element_3<PARAMSIZE,N,P>),                
             }
 
-impl <const N:usize, const PARAMSIZE:usize> ArithmeticOperations for ExtFieldG2Element<N,PARAMSIZE>{
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ArithmeticOperations for ExtFieldG2Element<N,P,PARAMSIZE>{
     fn addto(&self, other: &Self) -> Self {
         match (self, other) {   (ExtFieldG2Element::Fp2_1(x), ExtFieldG2Element::Fp2_1(y)) => x.addto(y).into(),
                                 (ExtFieldG2Element::Fp4_1(x), ExtFieldG2Element::Fp4_1(y)) => x.addto(y).into(),
@@ -101,6 +102,18 @@ impl <const N:usize, const PARAMSIZE:usize> ArithmeticOperations for ExtFieldG2E
                             ExtFieldG2Element::Fp8_3(x)=> x.invert().into(),
                     }  
         }
+    fn invert_vartime(&self) -> Self 
+        {
+            match self {    ExtFieldG2Element::Fp2_1(x) => x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp4_1(x)=> x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp8_1(x)=> x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp2_2(x) => x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp4_2(x)=> x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp8_2(x)=> x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp4_3(x)=> x.invert_vartime().into(),
+                            ExtFieldG2Element::Fp8_3(x)=> x.invert_vartime().into(),
+                    }  
+        }
     fn negate(&self) -> Self 
         {
             match self {    ExtFieldG2Element::Fp2_1(x) => x.negate().into(),
@@ -194,7 +207,37 @@ impl <const N:usize, const PARAMSIZE:usize> ArithmeticOperations for ExtFieldG2E
     }
 }
 
-impl  <const N:usize, const PARAMSIZE:usize> ExtFieldG2Element<N,PARAMSIZE>
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ConstantTimeEq for ExtFieldG2Element<N,P,PARAMSIZE>{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        match (self, other) {   (ExtFieldG2Element::Fp2_1(x), ExtFieldG2Element::Fp2_1(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp4_1(x), ExtFieldG2Element::Fp4_1(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp8_1(x), ExtFieldG2Element::Fp8_1(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp2_2(x), ExtFieldG2Element::Fp2_2(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp4_2(x), ExtFieldG2Element::Fp4_2(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp8_2(x), ExtFieldG2Element::Fp8_2(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp4_3(x), ExtFieldG2Element::Fp4_3(y)) => x.ct_eq(y),
+                                (ExtFieldG2Element::Fp8_3(x), ExtFieldG2Element::Fp8_3(y)) => x.ct_eq(y),
+                                _ => unimplemented!("Comparison not implemented for different types"),
+                            }
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ConditionallySelectable for ExtFieldG2Element<N,P,PARAMSIZE>{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        match (a, b) {   (ExtFieldG2Element::Fp2_1(x), ExtFieldG2Element::Fp2_1(y)) => ExtFieldG2Element::Fp2_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp4_1(x), ExtFieldG2Element::Fp4_1(y)) => ExtFieldG2Element::Fp4_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp8_1(x), ExtFieldG2Element::Fp8_1(y)) => ExtFieldG2Element::Fp8_1(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp2_2(x), ExtFieldG2Element::Fp2_2(y)) => ExtFieldG2Element::Fp2_2(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp4_2(x), ExtFieldG2Element::Fp4_2(y)) => ExtFieldG2Element::Fp4_2(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp8_2(x), ExtFieldG2Element::Fp8_2(y)) => ExtFieldG2Element::Fp8_2(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp4_3(x), ExtFieldG2Element::Fp4_3(y)) => ExtFieldG2Element::Fp4_3(ConditionallySelectable::conditional_select(x, y, choice)),
+                         (ExtFieldG2Element::Fp8_3(x), ExtFieldG2Element::Fp8_3(y)) => ExtFieldG2Element::Fp8_3(ConditionallySelectable::conditional_select(x, y, choice)),
+                         _ => unimplemented!("Selection not implemented for different types"),
+                    }
+    }
+}
+
+impl  <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> ExtFieldG2Element<N,P,PARAMSIZE>
 {
     pub fn sqrt(&self) -> Option<Self> {
         match self {    ExtFieldG2Element::Fp2_1(x) => if x.sqrt().is_none() {None} else {Some(ExtFieldG2Element::Fp2_1(x.sqrt().unwrap()))},
@@ -263,7 +306,7 @@ impl  <const N:usize, const PARAMSIZE:usize> ExtFieldG2Element<N,PARAMSIZE>
                         ExtFieldG2Element::Fp8_3(x)=> x.to_i2osp_bytearray(),
                     }       
     }
-    pub fn mulby_fp_element(&self, b:&FieldElement<N>) -> Self 
+    pub fn mulby_fp_element(&self, b:&FieldElement<N,P>) -> Self 
     {
         match self {    ExtFieldG2Element::Fp2_1(x) => x.mulby_fp_element(&b).into(),
                         ExtFieldG2Element::Fp4_1(x)=> x.mulby_fp_element(&b).into(),
@@ -275,7 +318,7 @@ impl  <const N:usize, const PARAMSIZE:usize> ExtFieldG2Element<N,PARAMSIZE>
                         ExtFieldG2Element::Fp8_3(x)=> x.mulby_fp_element(&b).into(),
                     }       
     }
-    pub fn content(&self) -> &[FieldElement<N>]
+    pub fn content(&self) -> &[FieldElement<N,P>]
     {
         match self {    ExtFieldG2Element::Fp2_1(x) => &x.content,
                         ExtFieldG2Element::Fp4_1(x)=> &x.content,
@@ -291,69 +334,117 @@ impl  <const N:usize, const PARAMSIZE:usize> ExtFieldG2Element<N,PARAMSIZE>
 
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp2Element_1<N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp2: Fp2Element_1<N>) -> Self {
+pub trait G2ExtElement<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> : ArithmeticOperations + ConstantTimeEq + ConditionallySelectable 
+                                                              + fmt::Debug + fmt::Display + Send + Sync + 'static
+{
+    // Common interface of the G2 coordinates representations : the run-time dispatched ExtFieldG2Element enum (sized for the 
+    // largest Fp8 variant), and the TowerElement wrappers of extensions/towers.rs that hold only the extension of one family
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self;
+    fn to_g2_element(&self) -> ExtFieldG2Element<N,P,PARAMSIZE>;
+    fn sqrt(&self) -> Option<Self>;
+    fn is_qr(&self) -> bool;
+    fn sign(&self) -> i8;
+    fn conjugate(&self) -> Self;
+    fn frobinus(&self) -> Self;
+    fn to_i2osp_bytearray(&self) -> Vec<u8>;
+    fn mulby_fp_element(&self, b:&FieldElement<N,P>) -> Self;
+    fn content(&self) -> &[FieldElement<N,P>];
+
+    fn sqrt_ct(&self) -> CtOption<Self> {
+        // Square root with a Choice flag (zero is returned when there is no root) : the extension square roots themselves
+        // are not branch-free, so callers should only rely on the flag, through selections
+        let root = self.sqrt();
+        CtOption::new(root.unwrap_or(self.zero()), Choice::from(root.is_some()))
+    }
+    fn sgn0(&self) -> u8 {
+        // sgn0 for extension fields, over the coefficients of the element in the base field
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-the-sgn0-function
+        let mut sign = 0u8;
+        let mut zero = 1u8;
+        for c in self.content() {   sign |= zero & c.sgn0();
+                                    zero &= c.is_zero() as u8;
+                                }
+        sign
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2ExtElement<N,P,PARAMSIZE> for ExtFieldG2Element<N,P,PARAMSIZE>
+{
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self { *element }
+    fn to_g2_element(&self) -> ExtFieldG2Element<N,P,PARAMSIZE> { *self }
+    fn sqrt(&self) -> Option<Self> { ExtFieldG2Element::sqrt(self) }
+    fn is_qr(&self) -> bool { ExtFieldG2Element::is_qr(self) }
+    fn sign(&self) -> i8 { ExtFieldG2Element::sign(self) }
+    fn conjugate(&self) -> Self { ExtFieldG2Element::conjugate(self) }
+    fn frobinus(&self) -> Self { ExtFieldG2Element::frobinus(self) }
+    fn to_i2osp_bytearray(&self) -> Vec<u8> { ExtFieldG2Element::to_i2osp_bytearray(self) }
+    fn mulby_fp_element(&self, b:&FieldElement<N,P>) -> Self { ExtFieldG2Element::mulby_fp_element(self, b) }
+    fn content(&self) -> &[FieldElement<N,P>] { ExtFieldG2Element::content(self) }
+}
+
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp2Element_1<N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp2: Fp2Element_1<N,P>) -> Self {
         ExtFieldG2Element::Fp2_1(fp2)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp2Element_2<PARAMSIZE,N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp2: Fp2Element_2<PARAMSIZE,N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp2Element_2<PARAMSIZE,N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp2: Fp2Element_2<PARAMSIZE,N,P>) -> Self {
         ExtFieldG2Element::Fp2_2(fp2)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp4Element_1<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp4: Fp4Element_1<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp4Element_1<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp4: Fp4Element_1<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp4_1(fp4)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp4Element_2<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp4: Fp4Element_2<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp4Element_2<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp4: Fp4Element_2<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp4_2(fp4)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp4Element_3<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp4: Fp4Element_3<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp4Element_3<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp4: Fp4Element_3<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp4_3(fp4)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp8Element_1<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp8: Fp8Element_1<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp8Element_1<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp8: Fp8Element_1<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp8_1(fp8)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp8Element_2<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp8: Fp8Element_2<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp8Element_2<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp8: Fp8Element_2<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp8_2(fp8)
     }
 }
 
-impl<const N: usize, const PARAMSIZE: usize> From<Fp8Element_3<PARAMSIZE, N>> for ExtFieldG2Element<N, PARAMSIZE> {
-    fn from(fp8: Fp8Element_3<PARAMSIZE, N>) -> Self {
+impl<const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> From<Fp8Element_3<PARAMSIZE, N,P>> for ExtFieldG2Element<N,P, PARAMSIZE> {
+    fn from(fp8: Fp8Element_3<PARAMSIZE, N,P>) -> Self {
         ExtFieldG2Element::Fp8_3(fp8)
     }
 }
 
 #[derive(Debug)]
-pub enum ExtG2Field <const N:usize, const PARAMSIZE:usize>
-            {   Fp2_1(&'static Fp2Field_1<N>),
-                Fp4_1(&'static Fp4Field_1<PARAMSIZE,N>),
-                Fp8_1(&'static Fp8Field_1<PARAMSIZE,N>),
-                Fp2_2(&'static Fp2Field_2<N>),
-                Fp4_2(&'static Fp4Field_2<PARAMSIZE,N>),
-                Fp8_2(&'static Fp8Field_2<PARAMSIZE,N>),
-                Fp4_3(&'static Fp4Field_3<PARAMSIZE,N>),
-                Fp8_3(&'static Fp8Field_3<PARAMSIZE,N>)
+pub enum ExtG2Field <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize>
+            {   Fp2_1(&'static Fp2Field_1<N,P>),
+                Fp4_1(&'static Fp4Field_1<PARAMSIZE,N,P>),
+                Fp8_1(&'static Fp8Field_1<PARAMSIZE,N,P>),
+                Fp2_2(&'static Fp2Field_2<N,P>),
+                Fp4_2(&'static Fp4Field_2<PARAMSIZE,N,P>),
+                Fp8_2(&'static Fp8Field_2<PARAMSIZE,N,P>),
+                Fp4_3(&'static Fp4Field_3<PARAMSIZE,N,P>),
+                Fp8_3(&'static Fp8Field_3<PARAMSIZE,N,P>)
             }
 
-impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE> 
+impl <const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtG2Field<N,P,PARAMSIZE> 
 {
-    pub fn new(&self) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn new(&self) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.random_element().into(),
                         ExtG2Field::Fp4_1(x)=> x.random_element().into(),
@@ -366,7 +457,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn random_element(&self) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn random_element(&self) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.random_element().into(),
                         ExtG2Field::Fp4_1(x)=> x.random_element().into(),
@@ -379,7 +470,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn one(&self) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn one(&self) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.one().clone().into(),
                         ExtG2Field::Fp4_1(x)=> x.one().clone().into(),
@@ -392,7 +483,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn zero(&self) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn zero(&self) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.zero().clone().into(),
                         ExtG2Field::Fp4_1(x)=> x.zero().clone().into(),
@@ -405,7 +496,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn from_hex_strings(&self, source :&[&str]) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn from_hex_strings(&self, source :&[&str]) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.from_hex_strings(source).into(),
                         ExtG2Field::Fp4_1(x)=> x.from_hex_strings(source).into(),
@@ -418,7 +509,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn from_decimal_strings(&self, source :&[&str]) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn from_decimal_strings(&self, source :&[&str]) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.from_strings(source).into(),
                         ExtG2Field::Fp4_1(x)=> x.from_strings(source).into(),
@@ -431,7 +522,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn from_basefield_elements(&self, source :&[FieldElement<N>]) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn from_basefield_elements(&self, source :&[FieldElement<N,P>]) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   
         match self {    ExtG2Field::Fp2_1(x) => x.from_field_elements(source).into(),
                         ExtG2Field::Fp4_1(x)=> x.from_field_elements(source).into(),
@@ -444,7 +535,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                     }       
     }
 
-    pub fn from_i2osp_bytearray(&self, source :&[u8]) -> ExtFieldG2Element<N,PARAMSIZE>
+    pub fn from_i2osp_bytearray(&self, source :&[u8]) -> ExtFieldG2Element<N,P,PARAMSIZE>
     {   let field =self.basefield();
         
         match self {    ExtG2Field::Fp2_1(x) => 
@@ -506,19 +597,33 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                                     
                     }       
     }
-    pub fn basefield(&self) ->PrimeField<N>
+    pub fn try_from_i2osp_bytearray(&self, source :&[u8]) -> Result<ExtFieldG2Element<N,P,PARAMSIZE>, PairingError>
+    {   // Same as from_i2osp_bytearray, but checks the size of the input and that every coefficient is canonical (lower than p)
+        let field =self.basefield();
+        let order = match self {    ExtG2Field::Fp2_1(_) | ExtG2Field::Fp2_2(_) => 2,
+                                    ExtG2Field::Fp4_1(_) | ExtG2Field::Fp4_2(_) | ExtG2Field::Fp4_3(_) => 4,
+                                    ExtG2Field::Fp8_1(_) | ExtG2Field::Fp8_2(_) | ExtG2Field::Fp8_3(_) => 8
+                                };
+        let sizeinbytes = field.size_in_bytes();
+        if source.len() != sizeinbytes * order {return Err(PairingError::BadLength { expected: sizeinbytes * order, found: source.len() });}
+        let mut coefs = Vec::with_capacity(order);
+        for i in 0..order { coefs.push(field.try_from_canonical_biguint(&os2ip(&source[i*sizeinbytes..(i+1)*sizeinbytes]))?);}
+        Ok(self.from_basefield_elements(&coefs))
+    }
+
+    pub fn basefield(&self) ->PrimeField<N,P>
     {
         match self {    ExtG2Field::Fp2_1(x) => x.field_interface(),
                         ExtG2Field::Fp4_1(x)=> x.field_interface(),
                         ExtG2Field::Fp8_1(x)=> x.field_interface(),
-                        ExtG2Field::Fp2_2(x) =><Fp2Field_2<N> as ExtField<PARAMSIZE, 2, N>>::field_interface(x),
+                        ExtG2Field::Fp2_2(x) =><Fp2Field_2<N,P> as ExtField<PARAMSIZE, 2, N,P>>::field_interface(x),
                         ExtG2Field::Fp4_2(x)=> x.field_interface(),
                         ExtG2Field::Fp8_2(x)=> x.field_interface(),
                         ExtG2Field::Fp4_3(x)=> x.field_interface(),
                         ExtG2Field::Fp8_3(x)=> x.field_interface(),
         }       
     }
-    pub fn hash_to_field(&self,id : &str, security_level:usize,count :usize) -> Vec<ExtFieldG2Element<N,PARAMSIZE>>
+    pub fn hash_to_field(&self,msg : &[u8], dst : &[u8], security_level:usize, expander :Expander, count :usize) -> Vec<ExtFieldG2Element<N,P,PARAMSIZE>>
     {   
         let extorder;
         match self {    ExtG2Field::Fp2_1(_) => extorder = 2,
@@ -530,8 +635,8 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
                         ExtG2Field::Fp4_3(_)=> extorder = 4,
                         ExtG2Field::Fp8_3(_)=> extorder = 8,
                    }  
-        let hashvec = hash_string_to_field(id, &self.basefield(), count, security_level, extorder);
-        let mut result = Vec::<ExtFieldG2Element<N,PARAMSIZE>>::new();
+        let hashvec = hash_bytes_to_field(msg, count, &self.basefield(), dst, security_level, extorder, expander);
+        let mut result = Vec::<ExtFieldG2Element<N,P,PARAMSIZE>>::new();
         let mut i: usize =0;
         while i< count { result.push(self.from_basefield_elements(&hashvec[i*extorder..(i+1)*extorder]));
                          i = i + 1;
@@ -540,7 +645,7 @@ impl <const PARAMSIZE:usize,const N: usize> ExtG2Field<N,PARAMSIZE>
     }
 }
 
-impl<'a, const N: usize, const PARAMSIZE: usize> fmt::Display for ExtFieldG2Element<N,PARAMSIZE> {
+impl<'a, const N: usize, P: FieldConfig<N>, const PARAMSIZE: usize> fmt::Display for ExtFieldG2Element<N,P,PARAMSIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:}", &self.to_dec_string())
     }
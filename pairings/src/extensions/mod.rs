@@ -6,4 +6,5 @@ pub mod ext_fields;
 pub mod g2_extfields;
 pub mod towering1;
 pub mod towering2;
-pub mod towering3;
\ No newline at end of file
+pub mod towering3;
+pub mod towers;
\ No newline at end of file
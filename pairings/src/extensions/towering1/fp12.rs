@@ -4,52 +4,54 @@
 
 use std::{fmt, usize};
 use std::ops::Add;
-use crate::tools::exponent::Exponent;
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use crate::tools::exponent::{bls_hard_exponent, has_long_zero_runs, Exponent, HardPartStrategy, COMPRESSED_SQUARING_RUN_FP12};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
-use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::arithmetic_interface::{batch_invert_with, ArithmeticOperations};
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::fp2::Fp2Element;
 
 pub mod fp6 {
     use super::super::fp2::Fp2Element;
-    use super::super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+    use super::super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
     use crate::tools::arithmetic_interface::ArithmeticOperations;
+    use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
     use super::super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 
     #[derive(Clone, Copy)]
-    pub struct Fp6Element<const PARAMSIZE:usize,const N:usize>{
-                                        pub content :[FieldElement<N>;6],
-                                        pub constants :Option<&'static ExFieldConsts<PARAMSIZE,N>>
+    pub struct Fp6Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                        pub content :[FieldElement<N,P>;6],
+                                        pub constants :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>
                                         }
     #[derive(Clone)]
-    pub struct Fp6Field<const PARAMSIZE:usize,const N:usize> {
-                                        base_field:PrimeField<N>,
-                                        constants :Option<&'static ExFieldConsts<PARAMSIZE,N>>
+    pub struct Fp6Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                        base_field:PrimeField<N,P>,
+                                        constants :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>
                                         }
     
-    impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,6,N> for Fp6Field<PARAMSIZE,N> {    
-        type  ElementType   = Fp6Element<PARAMSIZE,N>;    
-        type  BaseFieldType = PrimeField<N>;
+    impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,6,N,P> for Fp6Field<PARAMSIZE,N,P> {    
+        type  ElementType   = Fp6Element<PARAMSIZE,N,P>;    
+        type  BaseFieldType = PrimeField<N,P>;
     
-        fn field_interface(&self)->PrimeField<N> 
+        fn field_interface(&self)->PrimeField<N,P> 
             { self.base_field.clone() }
     
-        fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp6Field<PARAMSIZE,N>
+        fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp6Field<PARAMSIZE,N,P>
             { Fp6Field {base_field : (*base_field).clone(), constants: consts}}   
         
-        fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+        fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
             self.constants
         }
         }       
     
-    impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,6,N> for Fp6Element<PARAMSIZE,N>{
-        fn new(content :&[FieldElement<N>; 6], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp6Element<PARAMSIZE,N>
+    impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,6,N,P> for Fp6Element<PARAMSIZE,N,P>{
+        fn new(content :&[FieldElement<N,P>; 6], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp6Element<PARAMSIZE,N,P>
             {   Fp6Element{content :content.clone(), constants :consts}    }
     
-        fn content_interface(&self) -> &[FieldElement<N>;6]
+        fn content_interface(&self) -> &[FieldElement<N,P>;6]
             {   &self.content   }
         
-        fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+        fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
             self.constants
         }    
     
@@ -87,7 +89,7 @@ pub mod fp6 {
 
      }
     
-    impl <const PARAMSIZE:usize,const N:usize> Fp6Element <PARAMSIZE,N>{
+    impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp6Element <PARAMSIZE,N,P>{
         pub fn invert(&self) -> Self {
             let a = Fp2Element{content :[self.content[0],self.content[1]]};
             let b = Fp2Element{content :[self.content[2],self.content[3]]};
@@ -108,7 +110,7 @@ pub mod fp6 {
                                 self.content[0],self.content[1],self.content[2],self.content[3]], constants : None}
             }
 
-        pub fn sparse_multiply(&self, rhs :&[&[FieldElement<N>];3], mode :u8) -> Self {  
+        pub fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], mode :u8) -> Self {  
             match mode { 0 => {
                                 // rhs is sparse in Fp6 :(y0+y1*u)+(y2+y3*u)*v , (y4=y5=0)
                                 let a0 = Fp2Element{content :[self.content[0],self.content[1]]};
@@ -151,21 +153,33 @@ pub mod fp6 {
         }
 
         
+    impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp6Element<PARAMSIZE,N,P> {
+            fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+        }
+    
+    impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp6Element<PARAMSIZE,N,P> {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+        }
+    
+    impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp6Element<PARAMSIZE,N,P> {
+            fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+        }
+    
     }
 #[derive(Clone, Copy)]
-pub struct Fp12Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;12],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp12Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;12],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp12Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp12Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-fn get_slice_fp6<const PARAMSIZE:usize,const N:usize>(element : &Fp12Element<PARAMSIZE,N>, i : usize) -> fp6::Fp6Element<PARAMSIZE,N>
+fn get_slice_fp6<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp12Element<PARAMSIZE,N,P>, i : usize) -> fp6::Fp6Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 6] = element.content[i*6..(i+1)*6].iter()
+        let _t: [FieldElement<N,P>; 6] = element.content[i*6..(i+1)*6].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -175,29 +189,35 @@ fn get_slice_fp6<const PARAMSIZE:usize,const N:usize>(element : &Fp12Element<PAR
     }
 
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,12,N> for Fp12Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp12Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp12Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp12Field<PARAMSIZE,N,P>
+        { Fp12Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,12,N,P> for Fp12Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp12Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp12Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp12Field<PARAMSIZE,N,P>
         { Fp12Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,12,N> for Fp12Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 12], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp12Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,12,N,P> for Fp12Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 12], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp12Element<PARAMSIZE,N,P>
         {   Fp12Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;12]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;12]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     
@@ -230,7 +250,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,12,N> for Fp12El
     }
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp12Element <PARAMSIZE,N,P>{
 
     pub fn conjugate(&self) -> Self {
         Self { content : [self.content[0],self.content[1],self.content[2],self.content[3],self.content[4],self.content[5],
@@ -325,13 +345,76 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
                constants : self.constants }
     }
     
+    pub fn compressed_unisqr(&self) -> Self {
+        // Karabina's squaring in the cyclotomic sub-group, on the compressed form (g2,g3,g4,g5) of f = g0 + g1*w^3 + g4*w^2 + g5*w^5 + g3*w^4 + g2*w
+        // (the Fp2 pairs (0,1),(8,9),(6,7),(4,5),(2,3),(10,11) of the content) : "Squaring in cyclotomic subgroups" https://eprint.iacr.org/2010/542
+        // g0 and g1 are not computed (left to zero), decompress_all recovers them
+        let g2 = Fp2Element{content :[self.content[6],self.content[7]]};
+        let g3 = Fp2Element{content :[self.content[4],self.content[5]]};
+        let g4 = Fp2Element{content :[self.content[2],self.content[3]]};
+        let g5 = Fp2Element{content :[self.content[10],self.content[11]]};
+        let t2 = g2.sqr();
+        let t3 = g3.sqr();
+        let t4 = g4.sqr();
+        let t5 = g5.sqr();
+        let b23 = g2.addto(&g3).sqr().substract(&t2).substract(&t3);
+        let b45 = g4.addto(&g5).sqr().substract(&t4).substract(&t5);
+        let h2 = b45.mul_by_u_p_1().mulbyu8(3u8).addto(&g2.addto(&g2));
+        let h3 = t4.addto(&t5.mul_by_u_p_1()).mulbyu8(3u8).substract(&g3.addto(&g3));
+        let h4 = t2.addto(&t3.mul_by_u_p_1()).mulbyu8(3u8).substract(&g4.addto(&g4));
+        let h5 = b23.mulbyu8(3u8).addto(&g5.addto(&g5));
+        let zero = self.content[0].zero();
+        Self { content : [zero, zero, h4.content[0], h4.content[1], h3.content[0], h3.content[1],
+                          h2.content[0], h2.content[1], zero, zero, h5.content[0], h5.content[1]],
+               constants : self.constants }
+    }
+
+    pub fn decompress_all(elements :&mut [Self]) {
+        // Recovers g1 = (ξ*g5^2 + 3*g4^2 - 2*g3)/(4*g2) (or 2*g4*g5/g3 when g2 = 0) and g0 = (2*g1^2 + g2*g5 - 3*g3*g4)*ξ + 1
+        // for all the elements, with a single inversion (Montgomery's trick) : Karabina, section 3.2
+        let parts = |e :&Self, i :usize| Fp2Element{content :[e.content[i],e.content[i + 1]]};
+        let mut numerators = Vec::with_capacity(elements.len());
+        let mut denominators = Vec::with_capacity(elements.len());
+        for e in elements.iter() {  let (g2, g3, g4, g5) = (parts(e, 6), parts(e, 4), parts(e, 2), parts(e, 10));
+                                    if g2.is_zero() { numerators.push(g4.multiply(&g5).double());
+                                                      denominators.push(g3); }
+                                    else { numerators.push(g5.sqr().mul_by_u_p_1().addto(&g4.sqr().mulbyu8(3u8)).substract(&g3.double()));
+                                           denominators.push(g2.double().double()); }
+                                 }
+        batch_invert_with(&mut denominators, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert());
+        for (e, (n, d)) in elements.iter_mut().zip(numerators.iter().zip(denominators.iter())) 
+                                {   let (g2, g3, g4, g5) = (parts(e, 6), parts(e, 4), parts(e, 2), parts(e, 10));
+                                    let g1 = n.multiply(d);
+                                    let g0 = g1.sqr().double().addto(&g2.multiply(&g5)).substract(&g3.multiply(&g4).mulbyu8(3u8)).mul_by_u_p_1();
+                                    e.content[0] = g0.content[0].addto(&g0.content[0].one());
+                                    e.content[1] = g0.content[1];
+                                    e.content[8] = g1.content[0];
+                                    e.content[9] = g1.content[1];
+                                }
+    }
+
+    pub fn compressed_power(&self, naf :&[i8]) -> Self {
+        // f^e for a NAF e (most significant digit first) : the squarings f^(2^i) are compressed and run right-to-left, the ones
+        // matching the non-zero digits are decompressed together and multiplied (Aranha et al. https://eprint.iacr.org/2010/526, section 5.2)
+        let mut powers = Vec::new();
+        let mut negatives = Vec::new();
+        let mut c = *self;
+        for (i, d) in naf.iter().rev().enumerate() {  if i > 0 { c = c.compressed_unisqr() }
+                                                      if *d != 0 { powers.push(c);
+                                                                   negatives.push(*d == -1); }
+                                                   }
+        let start = if naf.last().is_some_and(|d| *d != 0) {1} else {0};
+        Self::decompress_all(&mut powers[start..]);
+        powers.iter().zip(negatives).fold(self.one(), |r, (x, n)| if n {r.multiply(&x.conjugate())} else {r.multiply(x)})
+    }
+
     pub fn cyclotomic_power(&self, e:& dyn Exponent<N>, negative:bool, naf_repre:&Option<Vec<i8>>) -> Self {
         // Efficient powering inside cyclotomic sub-group (x^(p^6+1)=1) -- Scott Approache           
         let one = FieldElement{ mont_limbs:self.content[0].fieldparams.one,
                                                  fieldparams:self.content[0].fieldparams};
         let zero = FieldElement{ mont_limbs:[0;N],
                                                   fieldparams:self.content[0].fieldparams};        
-        let mut result: [FieldElement<N>; 12] =[zero;12] ;
+        let mut result: [FieldElement<N,P>; 12] =[zero;12] ;
         result [0] = one; 
         let mut result = Self::new(&result,self.constants_interface());
         if naf_repre.is_none() { if let Some(array) = e.to_u64_array() 
@@ -343,6 +426,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
                                                                             }                                                        
                                         }                                                                      
                             }
+        else if has_long_zero_runs(naf_repre.as_ref().unwrap(), COMPRESSED_SQUARING_RUN_FP12) { result = self.compressed_power(naf_repre.as_ref().unwrap()) }
         else {  let selfconj=self.conjugate();
                 for i in (*naf_repre).clone().unwrap() { result = result.unisqr();
                                                              if i == 1 {result = result.multiply(&self)};
@@ -352,7 +436,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
              if negative {result.conjugate()} else {result}
     }
 
-    pub fn final_exponentiation(&self, use_naf:bool) ->Self {
+    pub fn final_exponentiation(&self, use_naf:bool, strategy :HardPartStrategy) ->Self {
         // It really compute 3*(f^(p^12-1)/r) (the power of a pairings is a pairings)
         // Daiki Hayashida and Kenichiro Hayasaka and Tadanori Teruya : https://eprint.iacr.org/2020/875.pdf
         let u   = self.constants.u.abs().try_into().unwrap();        
@@ -369,6 +453,24 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
         let mut t = s.frobinus(2);
         s = t.multiply(&s);       
         
+            // Reference hard part : f^((p^4-p^2+1)/r) by a plain square-and-multiply on the exponent rebuilt from u (slow, no factor 3)
+        if strategy == HardPartStrategy::Naive {
+            let e = bls_hard_exponent(self.constants.u, 12);
+            return (0..e.bits()).rev().fold(s.one(), |a, i| if e.bit(i) {a.unisqr().multiply(&s)} else {a.unisqr()})
+        }
+            // Ghammam, Fouotsa decomposition (https://eprint.iacr.org/2016/130, for BLS24), written for BLS12 : 3*(p^4-p^2+1)/r = sum of λi*p^i for i = 0..3, 
+            // with λ3 = (u-1)^2, λi = λ(i+1)*u, except λ1 = λ2*u - λ3 and λ0 = λ1*u + 3. Every f^λi is a single power by u 
+            // of the previous one, and the sum is evaluated by Horner's rule in p (one Frobenius map per coefficient)
+        if strategy == HardPartStrategy::GhammamFouotsa {
+            let top = s.cyclotomic_power(&um1, um1_sign, &naf_um1_repr).cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
+            let (mut l, mut a) = (top, top);
+            for i in (0..3).rev() { l = l.cyclotomic_power(&u, u_sign, &naf_u_repr);
+                                    if i == 1 {l = l.multiply(&top.conjugate())}
+                                    if i == 0 {l = l.multiply(&s).multiply(&s.unisqr())}
+                                    a = a.frobinus(1).multiply(&l);
+                                  }
+            return a
+        }
             // Hard part of the Exponentiation  :  f^(3*(p^4-p^2+1)/r) = f^((u-1)^2*(u+p)*(u^2+p^2-1)+3)         
         let mut a = s.cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
         a = a.cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
@@ -382,7 +484,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
         a
     }
    
-    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N>];3]) -> Self {     
+    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N,P>];3]) -> Self {     
     //   Multiplication with a sparse Fp12 according to M - type twiste   
         let a0 = get_slice_fp6(&self, 0);
         let b0 = get_slice_fp6(&self, 1);        
@@ -400,10 +502,22 @@ impl <const PARAMSIZE:usize,const N:usize> Fp12Element <PARAMSIZE,N>{
 }
 
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp12Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp12Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp12Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp12Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp12Element<PARAMSIZE,N,P> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp12Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp12Element<PARAMSIZE,N,P> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }   
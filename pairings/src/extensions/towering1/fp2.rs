@@ -3,43 +3,50 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Clone, Copy,Debug)]
-pub struct Fp2Element<const N:usize>{
-                                    pub content :[FieldElement<N>;2]
+pub struct Fp2Element<const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;2]
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp2Field<const N:usize> {
-                                    base_field:PrimeField<N>,
+pub struct Fp2Field<const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
                                     }
 
-impl<const N: usize> ExtField<0,2,N> for Fp2Field<N> {     
-    type  ElementType   = Fp2Element<N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const N: usize, P: FieldConfig<N>> Fp2Field<N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>) -> Fp2Field<N,P>
+        { Fp2Field {base_field} }
+}
+
+impl<const N: usize, P: FieldConfig<N>> ExtField<0,2,N,P> for Fp2Field<N,P> {     
+    type  ElementType   = Fp2Element<N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<0,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<0,N,P>> {
         None
     }
 
-    fn new(base_field :&Self::BaseFieldType, _consts :Option<&ExFieldConsts<0,N>>)->  Fp2Field<N>
+    fn new(base_field :&Self::BaseFieldType, _consts :Option<&ExFieldConsts<0,N,P>>)->  Fp2Field<N,P>
         { Fp2Field {base_field : (*base_field).clone()}}   
     }
 
 
-impl <const N:usize> ExtElement<0,2,N> for Fp2Element<N>{
-    fn new(content :&[FieldElement<N>; 2],_consts :Option<&ExFieldConsts<0,N>>) -> Fp2Element<N>
+impl <const N:usize, P: FieldConfig<N>> ExtElement<0,2,N,P> for Fp2Element<N,P>{
+    fn new(content :&[FieldElement<N,P>; 2],_consts :Option<&ExFieldConsts<0,N,P>>) -> Fp2Element<N,P>
         {   Fp2Element{content :content.clone()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;2]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;2]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<0,N>>{
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<0,N,P>>{
         None
     }
 
@@ -61,7 +68,7 @@ impl <const N:usize> ExtElement<0,2,N> for Fp2Element<N>{
 
 }
 
-impl <const N:usize> Fp2Element<N>{
+impl <const N:usize, P: FieldConfig<N>> Fp2Element<N,P>{
     
     pub fn mul_by_u_p_1(&self) -> Self {
         Self {content : [self.content[0].substract(&self.content[1]),self.content[0].addto(&self.content[1])]}
@@ -75,14 +82,23 @@ impl <const N:usize> Fp2Element<N>{
         // In Fp2, the frobinus is equal to the conjugate
         self.conjugate()
     }   
-    pub fn invert(&self) -> Self{
-        let  t = (self.content[0].sqr()).addto(&self.content[1].sqr()).invert();
+    pub fn invert(&self) -> Self {
+        self.invert_with(false)
+    }
+
+    pub fn invert_vartime(&self) -> Self {
+        self.invert_with(true)
+    }
+
+    fn invert_with(&self, vartime :bool) -> Self {
+        let n = (self.content[0].sqr()).addto(&self.content[1].sqr());
+        let t = if vartime {n.invert_vartime()} else {n.invert()};
         Self {content :[self.content[0].multiply(&t),(self.content[1].multiply(&t)).negate()]}
     }
     
     pub fn is_qr(&self) -> bool{
-        let rootdelta = (self.content[0].sqr()).addto(&self.content[1].sqr()).sqrt(); 
-        rootdelta.is_some() 
+        // a is a square in Fp2 iff its norm is a square in Fp (constant-time Legendre test, no square root needed)
+        (self.content[0].sqr()).addto(&self.content[1].sqr()).is_square().into()
     }
 
     pub fn sqrt(&self) -> Option<Self>{
@@ -117,11 +133,23 @@ impl <const N:usize> Fp2Element<N>{
         }
     }
     
-impl<const N: usize> fmt::Display for Fp2Element<N> {
+impl<const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp2Element<N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp2Element<N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp2Element<N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const N: usize, P: FieldConfig<N>> fmt::Display for Fp2Element<N,P> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:}", &self.to_a_string()) }
     }
 
-impl<const N: usize> PartialEq for Fp2Element<N> {
+impl<const N: usize, P: FieldConfig<N>> PartialEq for Fp2Element<N,P> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }
 
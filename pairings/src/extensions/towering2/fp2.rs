@@ -3,44 +3,51 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::super::extensions::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Clone, Copy,Debug)]
-pub struct Fp2Element<const PARAMSIZE:usize, const N:usize>{
-                                    pub content :[FieldElement<N>;2],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp2Element<const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;2],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp2Field<const N:usize> {
-                                    base_field:PrimeField<N>,
+pub struct Fp2Field<const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
                                     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,2,N> for Fp2Field<N> {     
-    type  ElementType   = Fp2Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const N: usize, P: FieldConfig<N>> Fp2Field<N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>) -> Fp2Field<N,P>
+        { Fp2Field {base_field} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,2,N,P> for Fp2Field<N,P> {     
+    type  ElementType   = Fp2Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         None
     }
 
-    fn new(base_field :&Self::BaseFieldType,_consts :Option<&ExFieldConsts<PARAMSIZE,N>>)->  Fp2Field<N>
+    fn new(base_field :&Self::BaseFieldType,_consts :Option<&ExFieldConsts<PARAMSIZE,N,P>>)->  Fp2Field<N,P>
         { Fp2Field {base_field : (*base_field).clone()}}   
     }
 
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,2,N> for Fp2Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 2],consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp2Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,2,N,P> for Fp2Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 2],consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp2Element<PARAMSIZE,N,P>
         {   Fp2Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;2]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;2]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>>{
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>>{
         Some(self.constants)
     }
 
@@ -63,7 +70,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,2,N> for Fp2Elem
 
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp2Element<PARAMSIZE, N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp2Element<PARAMSIZE, N,P>{
     
     pub fn mul_by_u(&self) -> Self {
         Self {content : [self.content[1].multiply(&self.constants.base_qnr),self.content[0]],
@@ -79,15 +86,24 @@ impl <const PARAMSIZE:usize,const N:usize> Fp2Element<PARAMSIZE, N>{
         // In Fp2, the frobinus is equal to the conjugate
         self.conjugate()
     }   
-    pub fn invert(&self) -> Self{
-        let  t = (self.content[0].sqr()).substract(&self.content[1].sqr().multiply(&self.constants.base_qnr)).invert();
+    pub fn invert(&self) -> Self {
+        self.invert_with(false)
+    }
+
+    pub fn invert_vartime(&self) -> Self {
+        self.invert_with(true)
+    }
+
+    fn invert_with(&self, vartime :bool) -> Self {
+        let n = (self.content[0].sqr()).substract(&self.content[1].sqr().multiply(&self.constants.base_qnr));
+        let t = if vartime {n.invert_vartime()} else {n.invert()};
         Self {content :[self.content[0].multiply(&t),(self.content[1].multiply(&t)).negate()],
               constants :self.constants  }
     }
     
     pub fn is_qr(&self) -> bool{
-        let rootdelta = (self.content[0].sqr()).substract(&self.content[1].sqr().multiply(&self.constants.base_qnr)).sqrt(); 
-        rootdelta.is_some() 
+        // a is a square in Fp2 iff its norm is a square in Fp (constant-time Legendre test, no square root needed)
+        (self.content[0].sqr()).substract(&self.content[1].sqr().multiply(&self.constants.base_qnr)).is_square().into()
     }
 
     pub fn sqrt(&self) -> Option<Self>{
@@ -124,11 +140,23 @@ impl <const PARAMSIZE:usize,const N:usize> Fp2Element<PARAMSIZE, N>{
         }
     }
     
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp2Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp2Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp2Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp2Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp2Element<PARAMSIZE,N,P> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{:}", &self.to_a_string()) }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp2Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp2Element<PARAMSIZE,N,P> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }
 
@@ -3,29 +3,30 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use crate::tools::exponent::Exponent;
+use crate::tools::exponent::{bls_hard_exponent, has_long_zero_runs, Exponent, HardPartStrategy, COMPRESSED_SQUARING_RUN};
 
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::super::extensions::ext_fields::{ExtElement, ExtField,ExFieldConsts};
-use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::arithmetic_interface::{batch_invert_with, ArithmeticOperations};
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::fp8::Fp8Element;
 use super::fp4::Fp4Element;
 
 
 #[derive(Clone, Copy)]
-pub struct Fp24Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;24],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp24Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;24],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp24Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp24Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PARAMSIZE,N>, i : usize) -> Fp8Element<PARAMSIZE,N>
+fn get_slice_fp8<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp24Element<PARAMSIZE,N,P>, i : usize) -> Fp8Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 8] = element.content[i*8..(i+1)*8].iter()
+        let _t: [FieldElement<N,P>; 8] = element.content[i*8..(i+1)*8].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -34,9 +35,9 @@ fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PAR
         Fp8Element{content :_t, constants :element.constants}        
     }
 
-fn get_slice_fp4<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PARAMSIZE,N>, i : usize) -> Fp4Element<PARAMSIZE,N>
+fn get_slice_fp4<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp24Element<PARAMSIZE,N,P>, i : usize) -> Fp4Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 4] = element.content[i*4..(i+1)*4].iter()
+        let _t: [FieldElement<N,P>; 4] = element.content[i*4..(i+1)*4].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -45,29 +46,35 @@ fn get_slice_fp4<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PAR
         Fp4Element{content :_t, constants :element.constants}        
     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,24,N> for Fp24Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp24Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp24Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp24Field<PARAMSIZE,N,P>
+        { Fp24Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,24,N,P> for Fp24Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp24Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp24Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp24Field<PARAMSIZE,N,P>
         { Fp24Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,24,N> for Fp24Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 24], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp24Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,24,N,P> for Fp24Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 24], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp24Element<PARAMSIZE,N,P>
         {   Fp24Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;24]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;24]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     
@@ -111,7 +118,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,24,N> for Fp24El
     }
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp24Element <PARAMSIZE,N,P>{
 
     pub fn conjugate(&self) -> Self {
         Self {content : [self.content[0], self.content[1], self.content[2], self.content[3],
@@ -271,12 +278,75 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
        Self {  content : result, constants :self.constants} 
     }
     
+    pub fn compressed_unisqr(&self) -> Self {
+        // Karabina's squaring in the cyclotomic sub-group, on the compressed form (g2,g3,g4,g5) of f, where (g0,g1,g2,g3,g4,g5) are
+        // the Fp4 slices 0 to 5 of the content (as paired in unisqr) : "Squaring in cyclotomic subgroups" https://eprint.iacr.org/2010/542
+        // g0 and g1 are not computed (left to zero), decompress_all recovers them
+        let g2 = get_slice_fp4(self, 2);
+        let g3 = get_slice_fp4(self, 3);
+        let g4 = get_slice_fp4(self, 4);
+        let g5 = get_slice_fp4(self, 5);
+        let t2 = g2.sqr();
+        let t3 = g3.sqr();
+        let t4 = g4.sqr();
+        let t5 = g5.sqr();
+        let b23 = g2.addto(&g3).sqr().substract(&t2).substract(&t3);
+        let b45 = g4.addto(&g5).sqr().substract(&t4).substract(&t5);
+        let h2 = b45.mulby_v().mulbyu8(3u8).addto(&g2.addto(&g2));
+        let h3 = t4.addto(&t5.mulby_v()).mulbyu8(3u8).substract(&g3.addto(&g3));
+        let h4 = t2.addto(&t3.mulby_v()).mulbyu8(3u8).substract(&g4.addto(&g4));
+        let h5 = b23.mulbyu8(3u8).addto(&g5.addto(&g5));
+        let mut result = [self.content[0].zero(); 24];
+        result[8..12].copy_from_slice(&h2.content);
+        result[12..16].copy_from_slice(&h3.content);
+        result[16..20].copy_from_slice(&h4.content);
+        result[20..24].copy_from_slice(&h5.content);
+        Self { content : result, constants : self.constants }
+    }
+
+    pub fn decompress_all(elements :&mut [Self]) {
+        // Recovers g1 = (ξ*g5^2 + 3*g4^2 - 2*g3)/(4*g2) (or 2*g4*g5/g3 when g2 = 0) and g0 = (2*g1^2 + g2*g5 - 3*g3*g4)*ξ + 1
+        // for all the elements, with a single inversion (Montgomery's trick) : Karabina, section 3.2
+        let mut numerators = Vec::with_capacity(elements.len());
+        let mut denominators = Vec::with_capacity(elements.len());
+        for e in elements.iter() {  let (g2, g3, g4, g5) = (get_slice_fp4(e, 2), get_slice_fp4(e, 3), get_slice_fp4(e, 4), get_slice_fp4(e, 5));
+                                    if g2.is_zero() { numerators.push(g4.multiply(&g5).double());
+                                                      denominators.push(g3); }
+                                    else { numerators.push(g5.sqr().mulby_v().addto(&g4.sqr().mulbyu8(3u8)).substract(&g3.double()));
+                                           denominators.push(g2.double().double()); }
+                                 }
+        batch_invert_with(&mut denominators, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert());
+        for (e, (n, d)) in elements.iter_mut().zip(numerators.iter().zip(denominators.iter())) 
+                                {   let (g2, g3, g4, g5) = (get_slice_fp4(e, 2), get_slice_fp4(e, 3), get_slice_fp4(e, 4), get_slice_fp4(e, 5));
+                                    let g1 = n.multiply(d);
+                                    let mut g0 = g1.sqr().double().addto(&g2.multiply(&g5)).substract(&g3.multiply(&g4).mulbyu8(3u8)).mulby_v();
+                                    g0.content[0] = g0.content[0].addto(&g0.content[0].one());
+                                    e.content[0..4].copy_from_slice(&g0.content);
+                                    e.content[4..8].copy_from_slice(&g1.content);
+                                }
+    }
+
+    pub fn compressed_power(&self, naf :&[i8]) -> Self {
+        // f^e for a NAF e (most significant digit first) : the squarings f^(2^i) are compressed and run right-to-left, the ones
+        // matching the non-zero digits are decompressed together and multiplied (Aranha et al. https://eprint.iacr.org/2010/526, section 5.2)
+        let mut powers = Vec::new();
+        let mut negatives = Vec::new();
+        let mut c = *self;
+        for (i, d) in naf.iter().rev().enumerate() {  if i > 0 { c = c.compressed_unisqr() }
+                                                      if *d != 0 { powers.push(c);
+                                                                   negatives.push(*d == -1); }
+                                                   }
+        let start = if naf.last().is_some_and(|d| *d != 0) {1} else {0};
+        Self::decompress_all(&mut powers[start..]);
+        powers.iter().zip(negatives).fold(self.one(), |r, (x, n)| if n {r.multiply(&x.conjugate())} else {r.multiply(x)})
+    }
+
     pub fn cyclotomic_power(&self, e:& dyn Exponent<N>, negative :bool,naf_repre:&Option<Vec<i8>>) -> Self {
         let one = FieldElement{mont_limbs:self.content[0].fieldparams.one,
                                                 fieldparams:self.content[0].fieldparams};
         let zero = FieldElement{mont_limbs:[0;N],
                                                  fieldparams:self.content[0].fieldparams};        
-        let mut result: [FieldElement<N>; 24] =[zero;24] ;
+        let mut result: [FieldElement<N,P>; 24] =[zero;24] ;
         result [0] = one; 
         let mut result = Self::new(&result,self.constants_interface());
         if naf_repre.is_none() { if let Some(array) = e.to_u64_array() 
@@ -288,6 +358,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
                                                                             }                                                        
                                         }                                                                      
                             }
+        else if has_long_zero_runs(naf_repre.as_ref().unwrap(), COMPRESSED_SQUARING_RUN) { result = self.compressed_power(naf_repre.as_ref().unwrap()) }
         else {  let selfconj=self.conjugate();
                 for i in (*naf_repre).clone().unwrap() { result = result.unisqr();
                                                              if i == 1 {result = result.multiply(&self)};
@@ -299,7 +370,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
 
     }
 
-    pub fn final_exponentiation(&self, use_naf:bool) ->Self {
+    pub fn final_exponentiation(&self, use_naf:bool, strategy :HardPartStrategy) ->Self {
         // It actually computes 3 * (f^(p^24-1)/r) (the power of a pairings is a pairings)        
         // Daiki Hayashida, Kenichiro Hayasaka, Tadanori Teruya : Link: https://eprint.iacr.org/2020/875.pdf
         let u = self.constants.u.abs().try_into().unwrap();
@@ -315,6 +386,24 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
         let mut s = self.conjugate().multiply(&self.invert());                         
         let mut t = s.frobinus(4);
         s = t.multiply(&s);        
+                // Reference hard part : f^((p^8-p^4+1)/r) by a plain square-and-multiply on the exponent rebuilt from u (slow, no factor 3)
+        if strategy == HardPartStrategy::Naive {
+            let e = bls_hard_exponent(self.constants.u, 24);
+            return (0..e.bits()).rev().fold(s.one(), |a, i| if e.bit(i) {a.unisqr().multiply(&s)} else {a.unisqr()})
+        }
+                // Ghammam, Fouotsa : https://eprint.iacr.org/2016/130 : 3*(p^8-p^4+1)/r = sum of λi*p^i for i = 0..7, 
+                // with λ7 = (u-1)^2, λi = λ(i+1)*u, except λ3 = λ4*u - λ7 and λ0 = λ1*u + 3. Every f^λi is a single power by u 
+                // of the previous one, and the sum is evaluated by Horner's rule in p (one Frobenius map per coefficient)
+        if strategy == HardPartStrategy::GhammamFouotsa {
+            let top = s.cyclotomic_power(&um1, um1_sign, &naf_um1_repr).cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
+            let (mut l, mut a) = (top, top);
+            for i in (0..7).rev() { l = l.cyclotomic_power(&u, u_sign, &naf_u_repr);
+                                    if i == 3 {l = l.multiply(&top.conjugate())}
+                                    if i == 0 {l = l.multiply(&s).multiply(&s.unisqr())}
+                                    a = a.frobinus(1).multiply(&l);
+                                  }
+            return a
+        }
                 // Hard part of the Exponentiation  : f^(3*(p^8-p^4+1)/r) = f^((u-1)^2*(u+p)*(u^2+p^2)*(u^4+p^4-1)+3) 
         let mut a = s.cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
         a = a.cyclotomic_power(&um1, um1_sign,&naf_um1_repr);                
@@ -346,7 +435,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
                      constants : self.constants}
     }
 
-    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N>];3]) -> Self {   
+    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N,P>];3]) -> Self {   
             //  sparse multiplication of an Fp24 element with a sparse element 
             //  used during pairings on BLS24 () , rhs on 12 Fp element 
             let a0 = get_slice_fp8(&self, 0);        
@@ -369,7 +458,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
             Self {  content : result, constants :self.constants}   
     }
                 
-    pub fn sparse_multiply_for48(&self, rhs:&[&[FieldElement<N>];3], mode :u8) -> Self {         
+    pub fn sparse_multiply_for48(&self, rhs:&[&[FieldElement<N,P>];3], mode :u8) -> Self {         
         match  mode { 1 => { //  Sparse multiplication of an Fp24 element with a sparse element on two consecutive Fp8 only
                              //  Used during pairings on BLS48
                             let a0 = get_slice_fp8(&self, 0);        
@@ -412,10 +501,22 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
     }
 }
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp24Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp24Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp24Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp24Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp24Element<PARAMSIZE,N,P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
 }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp24Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp24Element<PARAMSIZE,N,P> {
     fn eq(&self, other: &Self) -> bool {    self.equal(other) }
 }
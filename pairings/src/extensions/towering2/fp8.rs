@@ -3,46 +3,53 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::super::extensions::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use super::fp2::Fp2Element;
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::fp4::Fp4Element;
 
 #[derive(Clone, Copy,Debug)]
-pub struct Fp8Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;8],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp8Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;8],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp8Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp8Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,8,N> for Fp8Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp8Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp8Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp8Field<PARAMSIZE,N,P>
+        { Fp8Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,8,N,P> for Fp8Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp8Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp8Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp8Field<PARAMSIZE,N,P>
         { Fp8Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,8,N> for Fp8Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 8], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp8Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,8,N,P> for Fp8Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 8], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp8Element<PARAMSIZE,N,P>
         {   Fp8Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;8]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;8]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
 
@@ -81,7 +88,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,8,N> for Fp8Elem
     }
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp8Element <PARAMSIZE,N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp8Element <PARAMSIZE,N,P>{
     
     pub fn conjugate(&self) -> Self {
         Self {content : [self.content[0] , self.content[1], self.content[2], self.content[3],
@@ -123,13 +130,22 @@ impl <const PARAMSIZE:usize,const N:usize> Fp8Element <PARAMSIZE,N>{
     }    
 
     pub fn invert(&self) -> Self {
+        self.invert_with(false)
+    }
+
+    pub fn invert_vartime(&self) -> Self {
+        self.invert_with(true)
+    }
+
+    fn invert_with(&self, vartime :bool) -> Self {
         let a= Fp4Element{   content : [self.content[0],self.content[1],self.content[2],self.content[3]], 
                                                constants:self.constants};
         let b= Fp4Element{   content : [self.content[4],self.content[5],self.content[6],self.content[7]], 
                                                constants:self.constants};
         let t0 = a.sqr();
         let t1 = b.sqr(); 
-        let t = t0.substract(&t1.mulby_v()).invert();                                               
+        let n = t0.substract(&t1.mulby_v());
+        let t = if vartime {n.invert_vartime()} else {n.invert()};                                               
         let t0 = a.multiply(&t);
         let t1 = b.multiply(&t).negate();                                       
         Self {  content : [ t0.content[0], t0.content[1],t0.content[2], t0.content[3],
@@ -200,7 +216,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp8Element <PARAMSIZE,N>{
 
         }       
     
-    pub fn sparse_multiply(&self, rhs:&[FieldElement<N>]) -> Self {                
+    pub fn sparse_multiply(&self, rhs:&[FieldElement<N,P>]) -> Self {                
                 let a0 = Fp2Element{ content : [ self.content[0],self.content[1]], constants:self.constants} ;
                 let a1 = Fp2Element{ content : [ self.content[2],self.content[3]], constants:self.constants} ;
                 let a2 = Fp2Element{ content : [ self.content[4],self.content[5]], constants:self.constants} ;
@@ -223,11 +239,23 @@ impl <const PARAMSIZE:usize,const N:usize> Fp8Element <PARAMSIZE,N>{
     
 
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp8Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp8Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp8Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp8Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp8Element<PARAMSIZE,N,P> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp8Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp8Element<PARAMSIZE,N,P> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }
     
\ No newline at end of file
@@ -3,25 +3,26 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::fp8::Fp8Element;
 
 #[derive(Clone, Copy)]
-pub struct Fp24Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;24],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp24Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;24],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp24Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp24Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PARAMSIZE,N>, i : usize) -> Fp8Element<PARAMSIZE,N>
+fn get_slice_fp8<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp24Element<PARAMSIZE,N,P>, i : usize) -> Fp8Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 8] = element.content[i*8..(i+1)*8].iter()
+        let _t: [FieldElement<N,P>; 8] = element.content[i*8..(i+1)*8].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -30,29 +31,35 @@ fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp24Element<PAR
         Fp8Element{content :_t, constants :element.constants}        
     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,24,N> for Fp24Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp24Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp24Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp24Field<PARAMSIZE,N,P>
+        { Fp24Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,24,N,P> for Fp24Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp24Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp24Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp24Field<PARAMSIZE,N,P>
         { Fp24Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,24,N> for Fp24Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 24], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp24Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,24,N,P> for Fp24Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 24], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp24Element<PARAMSIZE,N,P>
         {   Fp24Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;24]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;24]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     
@@ -96,7 +103,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,24,N> for Fp24El
     }
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{  
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp24Element <PARAMSIZE,N,P>{  
     pub fn invert(&self) -> Self {
         let a = get_slice_fp8(&self, 0);
         let b = get_slice_fp8(&self, 1);
@@ -127,7 +134,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
         Fp24Element {content : result,
                      constants : self.constants}
     }
-    pub fn sparse_multiply_for48(&self, rhs:&[&[FieldElement<N>];3], mode :u8) -> Self {         
+    pub fn sparse_multiply_for48(&self, rhs:&[&[FieldElement<N,P>];3], mode :u8) -> Self {         
         match  mode { 3|0 |1 => { //  Sparse multiplication of an Fp24 element with a sparse element on two consecutive Fp8 only
                              //  Used during pairings on BLS48 (with tow possible configurations of positions M/D-Types)
                             let a0 = get_slice_fp8(&self, 0);        
@@ -178,10 +185,22 @@ impl <const PARAMSIZE:usize,const N:usize> Fp24Element <PARAMSIZE,N>{
 
 
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp24Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp24Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp24Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp24Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp24Element<PARAMSIZE,N,P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
 }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp24Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp24Element<PARAMSIZE,N,P> {
     fn eq(&self, other: &Self) -> bool {    self.equal(other) }
 }
@@ -3,45 +3,52 @@
 // During May 2024.
 
 use std::{fmt, usize};
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use super::super::towering1::fp2::Fp2Element;
 
 #[derive(Clone, Copy,Debug)]
-pub struct Fp4Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;4],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp4Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;4],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp4Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp4Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,4,N> for Fp4Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp4Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp4Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp4Field<PARAMSIZE,N,P>
+        { Fp4Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,4,N,P> for Fp4Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp4Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) ->  Fp4Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) ->  Fp4Field<PARAMSIZE,N,P>
         { Fp4Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,4,N> for Fp4Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 4], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp4Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,4,N,P> for Fp4Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 4], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp4Element<PARAMSIZE,N,P>
         {   Fp4Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;4]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;4]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
 
@@ -71,7 +78,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,4,N> for Fp4Elem
 
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp4Element <PARAMSIZE,N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp4Element <PARAMSIZE,N,P>{
 
     pub fn conjugate(&self) -> Self {
         Self {content : [self.content[0] , self.content[1], self.content[2].negate(), self.content[3].negate()],
@@ -86,10 +93,19 @@ impl <const PARAMSIZE:usize,const N:usize> Fp4Element <PARAMSIZE,N>{
                 constants : self.constants}
     }   
  
-    pub fn invert(&self) -> Self{
+    pub fn invert(&self) -> Self {
+        self.invert_with(false)
+    }
+
+    pub fn invert_vartime(&self) -> Self {
+        self.invert_with(true)
+    }
+
+    fn invert_with(&self, vartime :bool) -> Self {
         let a = Fp2Element{content :[self.content[0],self.content[1]] };
         let b = Fp2Element{content :[self.content[2],self.content[3]] };
-        let t = a.sqr().substract(&b.sqr().mul_by_u_p_1().negate()).invert();
+        let n = a.sqr().substract(&b.sqr().mul_by_u_p_1().negate());
+        let t = if vartime {n.invert_vartime()} else {n.invert()};
         let a = a.multiply(&t);
         let b = b.multiply(&t).negate();
         Self { content : [a.content[0],a.content[1],b.content[0],b.content[1]],
@@ -109,7 +125,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp4Element <PARAMSIZE,N>{
                                               constants : self.constants};
         let a = Fp2Element{content :[self.content[0],self.content[1]] };
         let b = Fp2Element{content :[self.content[2],self.content[3]] };
-        let rootdelta: Option<Fp2Element<N>> = a.sqr().substract(&b.sqr().mul_by_u_p_1().negate()).sqrt();
+        let rootdelta: Option<Fp2Element<N,P>> = a.sqr().substract(&b.sqr().mul_by_u_p_1().negate()).sqrt();
         if rootdelta.is_some() {
             let rootdelta = rootdelta.unwrap();
             let mut t = Fp2Element{content :[ rootdelta.content[0].addto(&self.content[0]).multiply(&inv2),
@@ -153,11 +169,23 @@ impl <const PARAMSIZE:usize,const N:usize> Fp4Element <PARAMSIZE,N>{
         }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp4Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp4Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp4Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp4Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp4Element<PARAMSIZE,N,P> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
     }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp4Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp4Element<PARAMSIZE,N,P> {
         fn eq(&self, other: &Self) -> bool {    self.equal(other) }
     }
     
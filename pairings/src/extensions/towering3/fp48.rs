@@ -4,29 +4,30 @@
 
 use std::{fmt, usize};
 use std::ops::Sub;
-use crate::tools::exponent::Exponent;
+use crate::tools::exponent::{bls_hard_exponent, has_long_zero_runs, Exponent, HardPartStrategy, COMPRESSED_SQUARING_RUN};
 
-use super::super::super::fields::prime_fields::{FieldElement, PrimeField};
-use crate::tools::arithmetic_interface::ArithmeticOperations;
+use super::super::super::fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
+use crate::tools::arithmetic_interface::{batch_invert_with, ArithmeticOperations};
+use crate::tools::constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use super::super::ext_fields::{ExtElement, ExtField,ExFieldConsts};
 use super::fp24::Fp24Element;
 use super::fp8::Fp8Element;
 
 
 #[derive(Clone, Copy)]
-pub struct Fp48Element<const PARAMSIZE:usize,const N:usize>{
-                                    pub content :[FieldElement<N>;48],
-                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp48Element<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>{
+                                    pub content :[FieldElement<N,P>;48],
+                                    pub constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 #[derive(Clone,Debug)]
-pub struct Fp48Field<const PARAMSIZE:usize,const N:usize> {
-                                    base_field:PrimeField<N>,
-                                    constants :&'static ExFieldConsts<PARAMSIZE,N>
+pub struct Fp48Field<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> {
+                                    base_field:PrimeField<N,P>,
+                                    constants :&'static ExFieldConsts<PARAMSIZE,N,P>
                                     }
 
-fn get_slice_fp24<const PARAMSIZE:usize,const N:usize>(element : &Fp48Element<PARAMSIZE,N>, i : usize) -> Fp24Element<PARAMSIZE,N>
+fn get_slice_fp24<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp48Element<PARAMSIZE,N,P>, i : usize) -> Fp24Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 24] = element.content[i*24..(i+1)*24].iter()
+        let _t: [FieldElement<N,P>; 24] = element.content[i*24..(i+1)*24].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -35,9 +36,9 @@ fn get_slice_fp24<const PARAMSIZE:usize,const N:usize>(element : &Fp48Element<PA
              Fp24Element{content :_t, constants :element.constants}        
     }
 
-fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp48Element<PARAMSIZE,N>, i : usize) -> Fp8Element<PARAMSIZE,N>
+fn get_slice_fp8<const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>>(element : &Fp48Element<PARAMSIZE,N,P>, i : usize) -> Fp8Element<PARAMSIZE,N,P>
     {       
-        let _t: [FieldElement<N>; 8] = element.content[i*8..(i+1)*8].iter()
+        let _t: [FieldElement<N,P>; 8] = element.content[i*8..(i+1)*8].iter()
         .map(|&element1| FieldElement {fieldparams: element1.fieldparams,
                                                        mont_limbs: element1.mont_limbs,
                                                       })
@@ -46,29 +47,35 @@ fn get_slice_fp8<const PARAMSIZE:usize,const N:usize>(element : &Fp48Element<PAR
              Fp8Element{content :_t, constants :element.constants}        
     }
 
-impl<const PARAMSIZE:usize,const N: usize> ExtField<PARAMSIZE,48,N> for Fp48Field<PARAMSIZE,N> {    
-    type  ElementType   = Fp48Element<PARAMSIZE,N>;    
-    type  BaseFieldType = PrimeField<N>;
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> Fp48Field<PARAMSIZE,N,P> {
+    // Constant constructor, for the fields that are statically allocated by the preloaded configurations
+    pub const fn const_new(base_field :PrimeField<N,P>, constants :&'static ExFieldConsts<PARAMSIZE,N,P>) -> Fp48Field<PARAMSIZE,N,P>
+        { Fp48Field {base_field, constants} }
+}
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ExtField<PARAMSIZE,48,N,P> for Fp48Field<PARAMSIZE,N,P> {    
+    type  ElementType   = Fp48Element<PARAMSIZE,N,P>;    
+    type  BaseFieldType = PrimeField<N,P>;
 
-    fn field_interface(&self)->PrimeField<N> 
+    fn field_interface(&self)->PrimeField<N,P> 
         { self.base_field.clone() }
 
-    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>)->  Fp48Field<PARAMSIZE,N>
+    fn new(base_field :&Self::BaseFieldType, consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>)->  Fp48Field<PARAMSIZE,N,P>
         { Fp48Field {base_field : (*base_field).clone(), constants: consts.unwrap()}}   
     
-    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn extconsts_interface(&self) ->Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     }
 
-impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,48,N> for Fp48Element<PARAMSIZE,N>{
-    fn new(content :&[FieldElement<N>; 48], consts :Option<&'static ExFieldConsts<PARAMSIZE,N>>) -> Fp48Element<PARAMSIZE,N>
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> ExtElement<PARAMSIZE,48,N,P> for Fp48Element<PARAMSIZE,N,P>{
+    fn new(content :&[FieldElement<N,P>; 48], consts :Option<&'static ExFieldConsts<PARAMSIZE,N,P>>) -> Fp48Element<PARAMSIZE,N,P>
         {   Fp48Element{content :content.clone(), constants :consts.unwrap()}    }
 
-    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N>;48]
+    fn content_interface(&self) -> &[super::super::super::fields::prime_fields::FieldElement<N,P>;48]
         {   &self.content   }
     
-    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N>> {
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {
         Some(self.constants)
     }
     
@@ -80,7 +87,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,48,N> for Fp48El
         let t2 = a.addto(&b).sqr();              
         let x0 = t1.mulby_z().negate().addto(&t0);
         let x1 = t2.substract(&t0).substract(&t1);
-        let mut result:[FieldElement<N>;48] = [self.content[0];48];
+        let mut result:[FieldElement<N,P>;48] = [self.content[0];48];
         result[..24].copy_from_slice(&x0.content);
         result[24..].copy_from_slice(&x1.content);
         Self {  content : result,
@@ -97,7 +104,7 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,48,N> for Fp48El
         let t3 = a0.addto(&b0).multiply(&a1.addto(&b1));
         let x0 = t1.mulby_z().negate().addto(&t0);
         let x1 = t3.substract(&t0).substract(&t1);
-        let mut result:[FieldElement<N>;48] = [self.content[0];48] ;
+        let mut result:[FieldElement<N,P>;48] = [self.content[0];48] ;
         result[..24].copy_from_slice(&x0.content);
         result[24..].copy_from_slice(&x1.content);
         Self {  content : result,
@@ -105,10 +112,10 @@ impl <const PARAMSIZE:usize,const N:usize> ExtElement<PARAMSIZE,48,N> for Fp48El
     }
 }
 
-impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
+impl <const PARAMSIZE:usize,const N:usize, P: FieldConfig<N>> Fp48Element <PARAMSIZE,N,P>{
 
     pub fn conjugate(&self) -> Self {
-        let mut result:[FieldElement<N>;48] = [self.content[0];48];
+        let mut result:[FieldElement<N,P>;48] = [self.content[0];48];
         result[..24].copy_from_slice(&self.content[0..24]);
         let b = get_slice_fp24(&self, 1).negate();
         result[24..].copy_from_slice(&b.content);
@@ -329,7 +336,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         let t = a.sqr().substract(&b.sqr().mulby_z().negate()).invert();
         let x0 = a.multiply(&t);
         let x1 = b.negate().multiply(&t);
-        let mut result:[FieldElement<N>;48]=[self.content[0];48];
+        let mut result:[FieldElement<N,P>;48]=[self.content[0];48];
         result[..24].copy_from_slice(&x0.content);
         result[24..].copy_from_slice(&x1.content);
         Self {  content : result,
@@ -360,7 +367,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         let a1 = b2pb5_2.substract(&b22).substract(&b52).mulbyu8(3u8).mulby_w().negate().addto(&x3.addto(&x3));
         let a3 = b0pb3_2.substract(&b02).substract(&b32).mulbyu8(3u8).addto(&x4.addto(&x4));
         let a5 = b4pb1_2.substract(&b42).substract(&b12).mulbyu8(3u8).addto(&x5.addto(&x5)); 
-        let mut result:[FieldElement<N>;48] = [self.content[0];48];
+        let mut result:[FieldElement<N,P>;48] = [self.content[0];48];
         result[..8].copy_from_slice(&a0.content);
         result[8..16].copy_from_slice(&a2.content);
         result[16..24].copy_from_slice(&a4.content);
@@ -371,12 +378,76 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
                 constants : self.constants}                                                                                                                                                    
     }
     
+    pub fn compressed_unisqr(&self) -> Self {
+        // Karabina's squaring in the cyclotomic sub-group, on the compressed form (g2,g3,g4,g5) of f, where (g0,g1,g2,g3,g4,g5) are
+        // the Fp8 slices 0,4,3,2,1,5 of the content, slices 4 and 1 being negated (x0,-x4,x3,x2,-x1,x5 with the signs of unisqr) :
+        // "Squaring in cyclotomic subgroups" https://eprint.iacr.org/2010/542
+        // g0 and g1 are not computed (left to zero), decompress_all recovers them
+        let g2 = get_slice_fp8(self, 3);
+        let g3 = get_slice_fp8(self, 2);
+        let g4 = get_slice_fp8(self, 1).negate();
+        let g5 = get_slice_fp8(self, 5);
+        let t2 = g2.sqr();
+        let t3 = g3.sqr();
+        let t4 = g4.sqr();
+        let t5 = g5.sqr();
+        let b23 = g2.addto(&g3).sqr().substract(&t2).substract(&t3);
+        let b45 = g4.addto(&g5).sqr().substract(&t4).substract(&t5);
+        let h2 = b45.mulby_w().mulbyu8(3u8).addto(&g2.addto(&g2));
+        let h3 = t4.addto(&t5.mulby_w()).mulbyu8(3u8).substract(&g3.addto(&g3));
+        let h4 = t2.addto(&t3.mulby_w()).mulbyu8(3u8).substract(&g4.addto(&g4));
+        let h5 = b23.mulbyu8(3u8).addto(&g5.addto(&g5));
+        let mut result = [self.content[0].zero(); 48];
+        result[24..32].copy_from_slice(&h2.content);
+        result[16..24].copy_from_slice(&h3.content);
+        result[8..16].copy_from_slice(&h4.negate().content);
+        result[40..48].copy_from_slice(&h5.content);
+        Self { content : result, constants : self.constants }
+    }
+
+    pub fn decompress_all(elements :&mut [Self]) {
+        // Recovers g1 = (ξ*g5^2 + 3*g4^2 - 2*g3)/(4*g2) (or 2*g4*g5/g3 when g2 = 0) and g0 = (2*g1^2 + g2*g5 - 3*g3*g4)*ξ + 1
+        // for all the elements, with a single inversion (Montgomery's trick) : Karabina, section 3.2
+        let mut numerators = Vec::with_capacity(elements.len());
+        let mut denominators = Vec::with_capacity(elements.len());
+        for e in elements.iter() {  let (g2, g3, g4, g5) = (get_slice_fp8(e, 3), get_slice_fp8(e, 2), get_slice_fp8(e, 1).negate(), get_slice_fp8(e, 5));
+                                    if g2.is_zero() { numerators.push(g4.multiply(&g5).double());
+                                                      denominators.push(g3); }
+                                    else { numerators.push(g5.sqr().mulby_w().addto(&g4.sqr().mulbyu8(3u8)).substract(&g3.double()));
+                                           denominators.push(g2.double().double()); }
+                                 }
+        batch_invert_with(&mut denominators, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert());
+        for (e, (n, d)) in elements.iter_mut().zip(numerators.iter().zip(denominators.iter())) 
+                                {   let (g2, g3, g4, g5) = (get_slice_fp8(e, 3), get_slice_fp8(e, 2), get_slice_fp8(e, 1).negate(), get_slice_fp8(e, 5));
+                                    let g1 = n.multiply(d);
+                                    let mut g0 = g1.sqr().double().addto(&g2.multiply(&g5)).substract(&g3.multiply(&g4).mulbyu8(3u8)).mulby_w();
+                                    g0.content[0] = g0.content[0].addto(&g0.content[0].one());
+                                    e.content[0..8].copy_from_slice(&g0.content);
+                                    e.content[32..40].copy_from_slice(&g1.negate().content);
+                                }
+    }
+
+    pub fn compressed_power(&self, naf :&[i8]) -> Self {
+        // f^e for a NAF e (most significant digit first) : the squarings f^(2^i) are compressed and run right-to-left, the ones
+        // matching the non-zero digits are decompressed together and multiplied (Aranha et al. https://eprint.iacr.org/2010/526, section 5.2)
+        let mut powers = Vec::new();
+        let mut negatives = Vec::new();
+        let mut c = *self;
+        for (i, d) in naf.iter().rev().enumerate() {  if i > 0 { c = c.compressed_unisqr() }
+                                                      if *d != 0 { powers.push(c);
+                                                                   negatives.push(*d == -1); }
+                                                   }
+        let start = if naf.last().is_some_and(|d| *d != 0) {1} else {0};
+        Self::decompress_all(&mut powers[start..]);
+        powers.iter().zip(negatives).fold(self.one(), |r, (x, n)| if n {r.multiply(&x.conjugate())} else {r.multiply(x)})
+    }
+
     pub fn cyclotomic_power(&self, e:& dyn Exponent<N>, negative :bool, naf_repre:&Option<Vec<i8>>) -> Self {
         let one = FieldElement{ mont_limbs:self.content[0].fieldparams.one,
                                                  fieldparams:self.content[0].fieldparams};
         let zero = FieldElement{ mont_limbs:[0;N],
                                                   fieldparams:self.content[0].fieldparams};        
-        let mut result: [FieldElement<N>; 48] =[zero;48] ;
+        let mut result: [FieldElement<N,P>; 48] =[zero;48] ;
         result [0] = one; 
         let mut result = Self::new(&result,self.constants_interface());
         if naf_repre.is_none() { if let Some(array) = e.to_u64_array() 
@@ -388,6 +459,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
                                                                             }                                                        
                                         }                                                                      
                             }
+        else if has_long_zero_runs(naf_repre.as_ref().unwrap(), COMPRESSED_SQUARING_RUN) { result = self.compressed_power(naf_repre.as_ref().unwrap()) }
         else {  let selfconj=self.conjugate();
                 for i in (*naf_repre).clone().unwrap() { result = result.unisqr();
                                                              if i == 1 {result = result.multiply(&self)};
@@ -397,7 +469,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         if !negative { result} else { result.conjugate()}
     }
 
-    pub fn final_exponentiation(&self, use_naf:bool) ->Self {      
+    pub fn final_exponentiation(&self, use_naf:bool, strategy :HardPartStrategy) ->Self {      
         // It realy compute (f^(3*(p^48-1)/r)) (the power of a pairings is a pairings)
         // Daiki Hayashida and Kenichiro Hayasaka and Tadanori Teruya https://eprint.iacr.org/2020/875.pdf
         let u  = self.constants.u.abs().try_into().unwrap();        
@@ -414,6 +486,24 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         let mut t = s.frobinus(8);
         s = t.multiply(&s);
         
+            // Reference hard part : f^((p^16-p^8+1)/r) by a plain square-and-multiply on the exponent rebuilt from u (slow, no factor 3)
+        if strategy == HardPartStrategy::Naive {
+            let e = bls_hard_exponent(self.constants.u, 48);
+            return (0..e.bits()).rev().fold(s.one(), |a, i| if e.bit(i) {a.unisqr().multiply(&s)} else {a.unisqr()})
+        }
+            // Ghammam, Fouotsa decomposition (https://eprint.iacr.org/2016/130, for BLS24), extended to BLS48 : 3*(p^16-p^8+1)/r = sum of λi*p^i for i = 0..15, 
+            // with λ15 = (u-1)^2, λi = λ(i+1)*u, except λ7 = λ8*u - λ15 and λ0 = λ1*u + 3. Every f^λi is a single power by u 
+            // of the previous one, and the sum is evaluated by Horner's rule in p (one Frobenius map per coefficient)
+        if strategy == HardPartStrategy::GhammamFouotsa {
+            let top = s.cyclotomic_power(&um1, um1_sign, &naf_um1_repr).cyclotomic_power(&um1, um1_sign, &naf_um1_repr);
+            let (mut l, mut a) = (top, top);
+            for i in (0..15).rev() { l = l.cyclotomic_power(&u, u_sign, &naf_u_repr);
+                                     if i == 7 {l = l.multiply(&top.conjugate())}
+                                     if i == 0 {l = l.multiply(&s).multiply(&s.unisqr())}
+                                     a = a.frobinus(1).multiply(&l);
+                                  }
+            return a
+        }
             // Hard part of the Exponentiation  :f^(3*(p^16-p^8+1)/r) = f^((u-1)^2*(u+p)*(u^2+p^2)*(u^4+p^4)*(u^8+p^8-1)+3) 
         let mut a = s.cyclotomic_power(&um1, um1_sign,&naf_um1_repr);               
         a = a.cyclotomic_power(&um1, um1_sign,&naf_um1_repr);
@@ -443,12 +533,12 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         a
     }
 
-    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N>];3],twist_type:char) -> Self {       
+    pub fn sparse_multiply(&self, rhs:&[&[FieldElement<N,P>];3],twist_type:char) -> Self {       
 
         if twist_type =='D' { //  D-type (with negation), sparse multiplication of an Fp24 element with a sparse element 
                                 let a0 = get_slice_fp24(&self, 0);
                                 let b0 = get_slice_fp24(&self, 1);
-                                let y: [&[FieldElement<N>]; 3] = [&[self.content[0].zero();8],
+                                let y: [&[FieldElement<N,P>]; 3] = [&[self.content[0].zero();8],
                                                                     &[rhs[1][1].negate(),rhs[1][0],rhs[1][3].negate(),rhs[1][2],rhs[1][5].negate(),rhs[1][4],
                                                                     rhs[1][7].negate(),rhs[1][6]],                                               
                                                                     &[  rhs[0][0].addto(&rhs[2][1].negate()),rhs[0][1].addto(&rhs[2][0]),
@@ -461,7 +551,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
                                 let t3 = a0.addto(&b0).sparse_multiply_for48(&y, 1);
                                 let x0 = t1.mulby_z().negate().addto(&t0);
                                 let x1 = t3.substract(&t0).substract(&t1);
-                                let mut result:[FieldElement<N>;48] = [self.content[0];48] ;
+                                let mut result:[FieldElement<N,P>;48] = [self.content[0];48] ;
                                 result[..24].copy_from_slice(&x0.content);
                                 result[24..].copy_from_slice(&x1.content);
                                 Self {  content : result,
@@ -470,7 +560,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
         else {//  M-type sparse multiplication of an Fp24 element with a sparse element  
                 let a0 = get_slice_fp24(&self, 0);
                 let b0 = get_slice_fp24(&self, 1);
-                let y: [&[FieldElement<N>]; 3] = [&[self.content[0].zero();8],                                                                                              
+                let y: [&[FieldElement<N,P>]; 3] = [&[self.content[0].zero();8],                                                                                              
                                                   &[  rhs[1][0].negate().addto(&rhs[2][0]),rhs[1][1].negate().addto(&rhs[2][1]),
                                                         rhs[1][2].negate().addto(&rhs[2][2]),rhs[1][3].negate().addto(&rhs[2][3]),
                                                         rhs[1][4].negate().addto(&rhs[2][4]),rhs[1][5].negate().addto(&rhs[2][5]),
@@ -482,7 +572,7 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
                 let t3 = a0.addto(&b0).sparse_multiply_for48(&y,1);
                 let x0 = t1.mulby_z().negate().addto(&t0);
                 let x1 = t3.substract(&t0).substract(&t1);
-                let mut result:[FieldElement<N>;48] = [self.content[0];48] ;
+                let mut result:[FieldElement<N,P>;48] = [self.content[0];48] ;
                 result[..24].copy_from_slice(&x0.content);
                 result[24..].copy_from_slice(&x1.content);
                 Self {  content : result,
@@ -493,11 +583,23 @@ impl <const PARAMSIZE:usize,const N:usize> Fp48Element <PARAMSIZE,N>{
     
 }
 
-impl<const PARAMSIZE:usize,const N: usize> fmt::Display for Fp48Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConstantTimeEq for Fp48Element<PARAMSIZE,N,P> {
+        fn ct_eq(&self, other: &Self) -> Choice {    self.ct_equal(other) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallySelectable for Fp48Element<PARAMSIZE,N,P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {    a.ct_select(b, choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> ConditionallyNegatable for Fp48Element<PARAMSIZE,N,P> {
+        fn conditional_negate(&mut self, choice: Choice) {    *self = self.ct_negate(choice) }
+    }
+
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> fmt::Display for Fp48Element<PARAMSIZE,N,P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {  write!(f, "{:}", &self.to_a_string()) }
 }
 
-impl<const PARAMSIZE:usize,const N: usize> PartialEq for Fp48Element<PARAMSIZE,N> {
+impl<const PARAMSIZE:usize,const N: usize, P: FieldConfig<N>> PartialEq for Fp48Element<PARAMSIZE,N,P> {
     fn eq(&self, other: &Self) -> bool {    self.equal(other) }
 }
 
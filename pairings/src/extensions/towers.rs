@@ -0,0 +1,455 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use std::{fmt, marker::PhantomData, ops::{Add, Div, Mul, Neg, Sub}};
+use crate::{curves::gt::{GTElement, GTExtElement},
+            extensions::{ext_fields::{ExFieldConsts, ExtElement}, g2_extfields::{ExtFieldG2Element, G2ExtElement},
+                        towering1::{fp2::Fp2Element as Fp2Element_1, fp4::Fp4Element as Fp4Element_1, fp8::Fp8Element as Fp8Element_1,
+                                    fp12::Fp12Element as Fp12Element_1, fp24::Fp24Element as Fp24Element_1, fp48::Fp48Element as Fp48Element_1},
+                        towering2::{fp4::Fp4Element as Fp4Element_2, fp8::Fp8Element as Fp8Element_2,
+                                    fp24::Fp24Element as Fp24Element_2, fp48::Fp48Element as Fp48Element_2},
+                        towering3::{fp8::Fp8Element as Fp8Element_3, fp48::Fp48Element as Fp48Element_3}},
+            fields::prime_fields::{FieldConfig, FieldElement},
+            tools::{arithmetic_interface::ArithmeticOperations, constant_time::{Choice, ConditionallySelectable, ConstantTimeEq}, exponent::{Exponent, HardPartStrategy}}};
+
+// Monomorphic representation of the G2 coordinates and of the GT values : a TowerElement holds exactly one extension
+// of the tower (Fp2 for BLS12 G2, Fp12 for BLS12 GT, ...), so it has the size of that extension and its arithmetic is
+// resolved at compile time, while the ExtFieldG2Element and GTElement enums are sized for the largest (Fp8 / Fp48) variant
+// and dispatch every operation with a match. PARAMSIZE and ORDER are the parameters of the wrapped extension element,
+// and P the marker of its base field.
+#[derive(Clone, Copy, Debug)]
+pub struct TowerElement<E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>>(pub E, PhantomData<P>);
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> TowerElement<E,PARAMSIZE,ORDER,N,P> {
+    pub const fn new(element :E) -> Self {   TowerElement(element, PhantomData)   }
+}
+
+// Operations of the tower elements that are not part of the ExtElement interface (inherent methods of each extension)
+pub trait TowerOperations : Copy {
+    fn invert(&self) -> Self;
+    fn invert_vartime(&self) -> Self {  self.invert()  }
+    fn conjugate(&self) -> Self;
+}
+
+pub trait G2TowerOperations<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> : TowerOperations + Into<ExtFieldG2Element<N,P,PARAMSIZE>> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self;
+    fn frobinus(&self) -> Self;
+    fn sqrt(&self) -> Option<Self>;
+    fn is_qr(&self) -> bool;
+}
+
+pub trait GTTowerOperations<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> : TowerOperations + Into<GTElement<N,P,PARAMSIZE>> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self;
+    fn unisqr(&self) -> Self;
+    fn frobinus(&self, order :u8) -> Self;
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], twist_type :char) -> Self;
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self;
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self;
+}
+
+// Towers of the supported families : the G2 coordinates field and the GT field of the pairing engines
+pub trait PairingTower<const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> : fmt::Debug + 'static {
+    type G2 : G2ExtElement<N,P,PARAMSIZE>;
+    type GT : GTExtElement<N,P,PARAMSIZE>;
+}
+
+pub type G2Fp2_1<const N:usize, P> = TowerElement<Fp2Element_1<N,P>,0,2,N,P>;
+pub type G2Fp4_1<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp4Element_1<PARAMSIZE,N,P>,PARAMSIZE,4,N,P>;
+pub type G2Fp4_2<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp4Element_2<PARAMSIZE,N,P>,PARAMSIZE,4,N,P>;
+pub type G2Fp8_1<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp8Element_1<PARAMSIZE,N,P>,PARAMSIZE,8,N,P>;
+pub type G2Fp8_2<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp8Element_2<PARAMSIZE,N,P>,PARAMSIZE,8,N,P>;
+pub type G2Fp8_3<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp8Element_3<PARAMSIZE,N,P>,PARAMSIZE,8,N,P>;
+pub type GTFp12_1<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp12Element_1<PARAMSIZE,N,P>,PARAMSIZE,12,N,P>;
+pub type GTFp24_1<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp24Element_1<PARAMSIZE,N,P>,PARAMSIZE,24,N,P>;
+pub type GTFp24_2<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp24Element_2<PARAMSIZE,N,P>,PARAMSIZE,24,N,P>;
+pub type GTFp48_1<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp48Element_1<PARAMSIZE,N,P>,PARAMSIZE,48,N,P>;
+pub type GTFp48_2<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp48Element_2<PARAMSIZE,N,P>,PARAMSIZE,48,N,P>;
+pub type GTFp48_3<const N:usize, P, const PARAMSIZE:usize> = TowerElement<Fp48Element_3<PARAMSIZE,N,P>,PARAMSIZE,48,N,P>;
+
+// BLS12 curves : G2 over Fp2, GT in Fp12 (towering1)
+#[derive(Debug)]
+pub struct Bls12Tower;
+// BLS24-477, BLS24-479, BLS24-509 and BLS24-559 : G2 over Fp4, GT in Fp24 (towering1)
+#[derive(Debug)]
+pub struct Bls24Tower1;
+// BLS24-315 and BLS24-509-SNARK : G2 over Fp4, GT in Fp24 (towering2)
+#[derive(Debug)]
+pub struct Bls24Tower2;
+// BLS48-575 and BLS48-573 : G2 over Fp8, GT in Fp48 (towering1)
+#[derive(Debug)]
+pub struct Bls48Tower1;
+// BLS48-571 and BLS48-287 : G2 over Fp8, GT in Fp48 (towering2)
+#[derive(Debug)]
+pub struct Bls48Tower2;
+// BLS48-581 and BLS48-277 : G2 over Fp8, GT in Fp48 (towering3)
+#[derive(Debug)]
+pub struct Bls48Tower3;
+// Run-time dispatched representation (enums over all the towers), valid for every curve
+#[derive(Debug)]
+pub struct DynamicTower;
+
+// Fp2 does not depend on the tower parameters, so the family size (4 for all the BLS12 curves) is fixed here
+// to keep the G2 representation of the BLS12 engines unambiguous
+impl <const N:usize, P: FieldConfig<N>> PairingTower<N,P,4> for Bls12Tower {
+    type G2 = G2Fp2_1<N,P>;
+    type GT = GTFp12_1<N,P,4>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for Bls24Tower1 {
+    type G2 = G2Fp4_1<N,P,PARAMSIZE>;
+    type GT = GTFp24_1<N,P,PARAMSIZE>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for Bls24Tower2 {
+    type G2 = G2Fp4_2<N,P,PARAMSIZE>;
+    type GT = GTFp24_2<N,P,PARAMSIZE>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for Bls48Tower1 {
+    type G2 = G2Fp8_1<N,P,PARAMSIZE>;
+    type GT = GTFp48_1<N,P,PARAMSIZE>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for Bls48Tower2 {
+    type G2 = G2Fp8_2<N,P,PARAMSIZE>;
+    type GT = GTFp48_2<N,P,PARAMSIZE>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for Bls48Tower3 {
+    type G2 = G2Fp8_3<N,P,PARAMSIZE>;
+    type GT = GTFp48_3<N,P,PARAMSIZE>;
+}
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> PairingTower<N,P,PARAMSIZE> for DynamicTower {
+    type G2 = ExtFieldG2Element<N,P,PARAMSIZE>;
+    type GT = GTElement<N,P,PARAMSIZE>;
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> ArithmeticOperations for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    fn addto(&self, other: &Self) -> Self {   TowerElement::new(self.0.addto(&other.0))    }
+    fn double(&self) -> Self {   TowerElement::new(self.0.double())    }
+    fn substract(&self, other: &Self) -> Self {   TowerElement::new(self.0.substract(&other.0))    }
+    fn multiply(&self, other: &Self) -> Self {   TowerElement::new(self.0.multiply(&other.0))    }
+    fn sqr(&self) -> Self {   TowerElement::new(self.0.sqr())    }
+    fn invert(&self) -> Self {   TowerElement::new(self.0.invert())    }
+    fn invert_vartime(&self) -> Self {   TowerElement::new(self.0.invert_vartime())    }
+    fn negate(&self) -> Self {   TowerElement::new(self.0.negate())    }
+    fn equal(&self, rhs :&Self) -> bool {   self.0.equal(&rhs.0)    }
+    fn is_zero(&self) -> bool {   self.0.is_zero()    }
+    fn is_one(&self) -> bool {   self.0.is_one()    }
+    fn to_dec_string(&self) -> String {   self.0.to_a_string()    }
+    fn to_hex_string(&self) -> String {   self.0.to_hex_string()    }
+    fn one(&self) -> Self {   TowerElement::new(self.0.one())    }
+    fn zero(&self) -> Self {   TowerElement::new(self.0.zero())    }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> ConstantTimeEq for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P>
+{
+    fn ct_eq(&self, other: &Self) -> Choice {   self.0.ct_equal(&other.0)   }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> ConditionallySelectable for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + Copy
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {   TowerElement::new(a.0.ct_select(&b.0, choice))   }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>, const FAMILYPARAMSIZE:usize> G2ExtElement<N,P,FAMILYPARAMSIZE> for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + G2TowerOperations<N,P,FAMILYPARAMSIZE> + fmt::Debug + Send + Sync + 'static
+{
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,FAMILYPARAMSIZE>) -> Self {   TowerElement::new(E::from_g2_element(element))   }
+    fn to_g2_element(&self) -> ExtFieldG2Element<N,P,FAMILYPARAMSIZE> {   self.0.into()   }
+    fn sqrt(&self) -> Option<Self> {   self.0.sqrt().map(TowerElement::new)   }
+    fn is_qr(&self) -> bool {   self.0.is_qr()   }
+    fn sign(&self) -> i8 {   self.0.sign()   }
+    fn conjugate(&self) -> Self {   TowerElement::new(self.0.conjugate())   }
+    fn frobinus(&self) -> Self {   TowerElement::new(self.0.frobinus())   }
+    fn to_i2osp_bytearray(&self) -> Vec<u8> {   self.0.to_i2osp_bytearray()   }
+    fn mulby_fp_element(&self, b:&FieldElement<N,P>) -> Self {   TowerElement::new(self.0.mulby_fp_element(b))   }
+    fn content(&self) -> &[FieldElement<N,P>] {   self.0.content_interface()   }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> GTExtElement<N,P,PARAMSIZE> for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + GTTowerOperations<N,P,PARAMSIZE> + Send + Sync + 'static
+{
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {   TowerElement::new(E::from_gt_element(element))   }
+    fn to_gt_element(&self) -> GTElement<N,P,PARAMSIZE> {   self.0.into()   }
+    fn constants_interface(&self) -> Option<&'static ExFieldConsts<PARAMSIZE,N,P>> {   self.0.constants_interface()   }
+    fn content(&self) -> &[FieldElement<N,P>] {   self.0.content_interface()   }
+    fn unisqr(&self) -> Self {   TowerElement::new(self.0.unisqr())   }
+    fn conjugate(&self) -> Self {   TowerElement::new(self.0.conjugate())   }
+    fn frobinus(&self, order :u8) -> Self {   TowerElement::new(self.0.frobinus(order))   }
+    fn pow(&self,e : & dyn Exponent<N>) -> Self {
+        // Same as GTElement::pow : the cyclotomic exponentiation is only valid for unitary elements
+        if !self.0.conjugate().multiply(&self.0).is_one() {TowerElement::new(self.0.pow(e))}
+        else {TowerElement::new(self.0.cyclotomic_power(e, false, &None))}
+        }
+    fn cyclotomic_pow(&self,e : & dyn Exponent<N>) -> Self {   TowerElement::new(self.0.cyclotomic_power(e, false, &Some(e.to_naf())))   }
+    fn sparse_multiply(&self,rhs : &[&[FieldElement<N,P>];3],twist_type:char) -> Self {   TowerElement::new(self.0.sparse_multiply(rhs, twist_type))   }
+    fn final_exponentiation_with(&self, strategy :HardPartStrategy) -> Self {   TowerElement::new(self.0.final_exponentiation(true, strategy))   }
+    fn to_byte_array(&self) -> Vec<u8> {   self.0.to_i2osp_bytearray()   }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> fmt::Display for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:}", &self.0.to_a_string())
+    }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> PartialEq for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P>
+{
+    fn eq(&self, other: &Self) -> bool {    self.0.equal(&other.0) }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Add for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn add(self, rhs: Self) -> Self::Output {   self.addto(&rhs) }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Sub for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn sub(self, rhs: Self) -> Self::Output {   self.substract(&rhs) }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Neg for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn neg(self) -> Self::Output {   self.negate() }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Mul for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn mul(self, rhs: Self) -> Self::Output {   self.multiply(&rhs) }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Mul<u8> for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn mul(self, rhs: u8) -> Self::Output {   TowerElement::new(self.0.mulbyu8(rhs)) }
+}
+
+impl <E, const PARAMSIZE:usize, const ORDER:usize, const N:usize, P: FieldConfig<N>> Div for TowerElement<E,PARAMSIZE,ORDER,N,P>
+            where E : ExtElement<PARAMSIZE,ORDER,N,P> + TowerOperations
+{
+    type Output = TowerElement<E,PARAMSIZE,ORDER,N,P>;
+    fn div(self, rhs: Self) -> Self::Output {   self.multiply(&rhs.invert()) }
+}
+
+impl <const N:usize, P: FieldConfig<N>> TowerOperations for Fp2Element_1<N,P> {
+    fn invert(&self) -> Self {  Fp2Element_1::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp2Element_1::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp2Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp4Element_1<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp4Element_1::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp4Element_1::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp4Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp4Element_2<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp4Element_2::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp4Element_2::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp4Element_2::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp8Element_1<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp8Element_1::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp8Element_1::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp8Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp8Element_2<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp8Element_2::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp8Element_2::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp8Element_2::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp8Element_3<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp8Element_3::invert(self)  }
+    fn invert_vartime(&self) -> Self {  Fp8Element_3::invert_vartime(self)  }
+    fn conjugate(&self) -> Self {  Fp8Element_3::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp12Element_1<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp12Element_1::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp12Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp24Element_1<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp24Element_1::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp24Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp24Element_2<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp24Element_2::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp24Element_2::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp48Element_1<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp48Element_1::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp48Element_1::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp48Element_2<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp48Element_2::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp48Element_2::conjugate(self)  }
+}
+
+impl <const PARAMSIZE:usize, const N:usize, P: FieldConfig<N>> TowerOperations for Fp48Element_3<PARAMSIZE,N,P> {
+    fn invert(&self) -> Self {  Fp48Element_3::invert(self)  }
+    fn conjugate(&self) -> Self {  Fp48Element_3::conjugate(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>> G2TowerOperations<N,P,4> for Fp2Element_1<N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,4>) -> Self {
+        match element { ExtFieldG2Element::Fp2_1(x) => *x,
+                        _ => panic!("Fp2 (towering1) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp2Element_1::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp2Element_1::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp2Element_1::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2TowerOperations<N,P,PARAMSIZE> for Fp4Element_1<PARAMSIZE,N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self {
+        match element { ExtFieldG2Element::Fp4_1(x) => *x,
+                        _ => panic!("Fp4 (towering1) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp4Element_1::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp4Element_1::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp4Element_1::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2TowerOperations<N,P,PARAMSIZE> for Fp4Element_2<PARAMSIZE,N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self {
+        match element { ExtFieldG2Element::Fp4_2(x) => *x,
+                        _ => panic!("Fp4 (towering2) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp4Element_2::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp4Element_2::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp4Element_2::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2TowerOperations<N,P,PARAMSIZE> for Fp8Element_1<PARAMSIZE,N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self {
+        match element { ExtFieldG2Element::Fp8_1(x) => *x,
+                        _ => panic!("Fp8 (towering1) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp8Element_1::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp8Element_1::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp8Element_1::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2TowerOperations<N,P,PARAMSIZE> for Fp8Element_2<PARAMSIZE,N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self {
+        match element { ExtFieldG2Element::Fp8_2(x) => *x,
+                        _ => panic!("Fp8 (towering2) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp8Element_2::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp8Element_2::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp8Element_2::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> G2TowerOperations<N,P,PARAMSIZE> for Fp8Element_3<PARAMSIZE,N,P> {
+    fn from_g2_element(element :&ExtFieldG2Element<N,P,PARAMSIZE>) -> Self {
+        match element { ExtFieldG2Element::Fp8_3(x) => *x,
+                        _ => panic!("Fp8 (towering3) element expected ..."),
+                      }
+        }
+    fn frobinus(&self) -> Self {  Fp8Element_3::frobinus(self)  }
+    fn sqrt(&self) -> Option<Self> {  Fp8Element_3::sqrt(self)  }
+    fn is_qr(&self) -> bool {  Fp8Element_3::is_qr(self)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp12Element_1<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp12_1(x) => *x,
+                        _ => panic!("Fp12 (towering1) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp12Element_1::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp12Element_1::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], _twist_type :char) -> Self {  Fp12Element_1::sparse_multiply(self, rhs)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp12Element_1::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp12Element_1::cyclotomic_power(self, e, negative, naf_repre)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp24Element_1<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp24_1(x) => *x,
+                        _ => panic!("Fp24 (towering1) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp24Element_1::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp24Element_1::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], twist_type :char) -> Self {  Fp24Element_1::sparse_multiply(self, rhs, twist_type)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp24Element_1::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp24Element_1::cyclotomic_power(self, e, negative, naf_repre)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp24Element_2<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp24_2(x) => *x,
+                        _ => panic!("Fp24 (towering2) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp24Element_2::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp24Element_2::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], _twist_type :char) -> Self {  Fp24Element_2::sparse_multiply(self, rhs)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp24Element_2::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp24Element_2::cyclotomic_power(self, e, negative, naf_repre)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp48Element_1<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp48_1(x) => *x,
+                        _ => panic!("Fp48 (towering1) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp48Element_1::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp48Element_1::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], _twist_type :char) -> Self {  Fp48Element_1::sparse_multiply(self, rhs)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp48Element_1::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp48Element_1::cyclotomic_power(self, e, negative, naf_repre)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp48Element_2<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp48_2(x) => *x,
+                        _ => panic!("Fp48 (towering2) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp48Element_2::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp48Element_2::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], _twist_type :char) -> Self {  Fp48Element_2::sparse_multiply(self, rhs)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp48Element_2::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp48Element_2::cyclotomic_power(self, e, negative, naf_repre)  }
+}
+
+impl <const N:usize, P: FieldConfig<N>, const PARAMSIZE:usize> GTTowerOperations<N,P,PARAMSIZE> for Fp48Element_3<PARAMSIZE,N,P> {
+    fn from_gt_element(element :&GTElement<N,P,PARAMSIZE>) -> Self {
+        match element { GTElement::Fp48_3(x) => *x,
+                        _ => panic!("Fp48 (towering3) element expected ..."),
+                      }
+        }
+    fn unisqr(&self) -> Self {  Fp48Element_3::unisqr(self)  }
+    fn frobinus(&self, order :u8) -> Self {  Fp48Element_3::frobinus(self, order)  }
+    fn sparse_multiply(&self, rhs :&[&[FieldElement<N,P>];3], twist_type :char) -> Self {  Fp48Element_3::sparse_multiply(self, rhs, twist_type)  }
+    fn final_exponentiation(&self, use_naf :bool, strategy :HardPartStrategy) -> Self {  Fp48Element_3::final_exponentiation(self, use_naf, strategy)  }
+    fn cyclotomic_power(&self, e :&dyn Exponent<N>, negative :bool, naf_repre :&Option<Vec<i8>>) -> Self {  Fp48Element_3::cyclotomic_power(self, e, negative, naf_repre)  }
+}
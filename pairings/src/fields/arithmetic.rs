@@ -270,11 +270,18 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
+use crate::tools::constant_time::{Choice, CtOption};
+
 pub fn equal <const N: usize> (a : &[u64; N], b : &[u64; N]) -> bool
         // Constant-time equality comparison of two [u64; N]
-    {   let mut res=0;
-        for (i,j) in a.iter().zip(b){res += (i==j) as u8} ;
-        res == N as u8
+    {   ct_eq(a, b).into()
+    }
+
+pub fn ct_eq <const N: usize> (a : &[u64; N], b : &[u64; N]) -> Choice
+        // Constant-time equality comparison of two [u64; N], returned as a Choice (the differences are accumulated without early exit)
+    {   let mut diff = 0u64;
+        for (i,j) in a.iter().zip(b){diff |= i ^ j} ;
+        !Choice::from_nonzero(diff)
     }
 
 pub fn add <const N: usize> (a : &[u64; N], b : &[u64; N], _params :&super::prime_fields::FieldParams<N>) -> [u64; N] 
@@ -433,9 +440,38 @@ pub fn sqr <const N: usize> (a : &[u64; N],_params :&super::prime_fields::FieldP
             }
     } 
 
+#[inline(always)]
+fn cswap <const N: usize> (a :&mut [u64; N], b :&mut [u64; N], choice :u64)
+        // Constant-time conditional swap of a and b (swapped when choice = 1, untouched when choice = 0)
+    {   let mask = 0u64.wrapping_sub(choice);
+        for i in 0..N { let t = (a[i] ^ b[i]) & mask;
+                        a[i] ^= t;
+                        b[i] ^= t }
+    }
+
+#[inline(always)]
+pub fn select <const N: usize> (a : &[u64; N], b : &[u64; N], choice :Choice) -> [u64; N]
+        // Constant-time selection (a if choice is 0, b otherwise)
+    {   let mask = choice.mask();
+        let mut result = [0u64; N];
+        for i in 0..N {result[i] = a[i] ^ ((a[i] ^ b[i]) & mask)}
+        result
+    }
+
 pub fn invert <const N: usize> (a : &[u64; N],_params :&super::prime_fields::FieldParams<N>) -> [u64; N]
+        // Constant-time modular inversion using Fermat's little theorem (a^-1 = a^(p-2) mod p)
+        // The exponent is public, so the sequence of squarings and multiplications does not depend on a (invert(0) = 0)
+    {   let mut e = _params.modulo;
+        let mut borrow :bool;
+        (e[0], borrow) = e[0].overflowing_sub(2);
+        for limb in e.iter_mut().skip(1) { (*limb, borrow) = limb.overflowing_sub(borrow as u64)}
+        pow(a, &e, false, _params)
+    }
+
+pub fn invert_vartime <const N: usize> (a : &[u64; N],_params :&super::prime_fields::FieldParams<N>) -> [u64; N]
         // Modular ivnertion of a modulo p. Implementation from "Efficient Software-Implementation of    
         // Finite Fields with Applications to Cryptography" - Algorithm 16. https://link.springer.com/article/10.1007/s10440-006-9046-1        
+        // Not constant-time (loop count and branches depend on a) : to be used on public values only
     {   #[inline(always)]
         fn cmp <const N: usize> (a : &[u64; N], b : &[u64; N])-> i8 
                 // Comparing tow integers based on [u64; N] representation. Proposed constant-time implementation
@@ -505,13 +541,16 @@ pub fn invert <const N: usize> (a : &[u64; N],_params :&super::prime_fields::Fie
 
 pub fn pow <const N: usize> (a : &[u64; N], e :&[u64], useladder:bool,_params :&super::prime_fields::FieldParams<N>)-> [u64; N]
         // Implements montgomery Ladder (secure but relatively slow with respect to square and multiply)
+        // The ladder is branch-free: registers are conditionally swapped according to the exponent bit (https://eprint.iacr.org/2017/293.pdf, Algorithm 5)
+        // Square and multiply only branches on the exponent, so it is constant-time with respect to a when e is public
     {   if useladder {  let mut r0 = _params.one;     
                         let mut r1 = a.clone();                            
                         for i in e.as_ref().iter().rev() { 
-                                for j in (0..64).rev()  {   if (i >> j) & 1 == 0 {  r1 = mul(&r0, &r1, _params);
-                                                                                         r0 = sqr(&r0, _params); }                
-                                                            else {  r0 = mul(&r0, &r1,_params);
-                                                                    r1 = sqr(&r1,_params)}
+                                for j in (0..64).rev()  {   let bit = (i >> j) & 1;
+                                                            cswap(&mut r0, &mut r1, bit);
+                                                            r1 = mul(&r0, &r1, _params);
+                                                            r0 = sqr(&r0, _params);
+                                                            cswap(&mut r0, &mut r1, bit);
                                                         }
                                                         }
                         r0
@@ -526,39 +565,38 @@ pub fn pow <const N: usize> (a : &[u64; N], e :&[u64], useladder:bool,_params :&
             }
     }
 
+pub fn is_square <const N: usize> (a : &[u64; N],_params :&super::prime_fields::FieldParams<N>) -> Choice
+        // Constant-time quadratic residuosity test using Euler's criterion: a^((p-1)/2) is 1 for non-zero squares, and 0 for a = 0
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-is_square
+    {   let legendre = pow(a, &_params.sig_theshold, false, _params);
+        ct_eq(&legendre, &_params.one) | ct_eq(&legendre, &_params.zero)
+    }
+
 pub fn sqrt<const N: usize> (a:&[u64; N],_params :&super::prime_fields::FieldParams<N>) -> Option<[u64; N]>
-    {   if _params.sqrtid == -1 { // p mod 4 ==3
-                                    let result = pow(a,&_params.modplus1div4,false,_params); 
-                                    if equal(&mul(&sqr(&result,_params),&invert(a,_params),_params),&_params.one) { Some(result) }
-                                    else { None }
-                                }
-        else {      // Tonelli-Shanks Algorithm (p mod 4 ==1)
-                    //  Adapted from  section 6 of 'Square roots from 1; 24, 51, 10 to Dan Shanks'  by Ezra Brown:
-	                // https://www.maa.org/sites/default/files/pdf/upload_library/22/Polya/07468342.di020786.02p0470a.pdf 
-                    let w = pow(a,&_params.tonelli_params[1],false,_params);                    
-                    let mut y = mul(&a,&w,_params);
-                    let mut b = mul(&y,&w,_params);
-                    let mut g = mul(&_params.tonelli_params[0],&_params.rsquare,_params); // Convert to Montgomery repr
-                    let mut r = _params.sqrtid;
-                    let mut t = b.clone();
-                    for _ in 0..r-1 { t = sqr(&t, _params)}; 
-                    if equal(&t, &[0;N]) {return Some([0;N])}
-                    if !equal(&t, &_params.one) {return None}       
-                    loop  { let mut t= b.clone();
-                            let mut m= 0;
-                            while !equal(&t, &_params.one) {  t = sqr(&t, _params);
-                                                                   m = m + 1;
-                                                                }
-                            if m==0 { return Some(y)}
-                            let mut ge = r - m - 1;
-                            t = g.clone();                            
-                            while ge > 0 { t = sqr(&t,_params);
-                                           ge = ge - 1}
-                            g = sqr(&t,_params);
-                            y = mul(&y, &t, _params);
-                            b = mul(&b, &g, _params);   
-                            r = m;
-                        }
-                }
+    {   sqrt_ct(a, _params).into()
     }
 
+pub fn sqrt_ct<const N: usize> (a:&[u64; N],_params :&super::prime_fields::FieldParams<N>) -> CtOption<[u64; N]>
+        // Constant-time square root: the same operations are executed for any input, and the result is checked at the end
+    {   let result = if _params.sqrtid == -1 { // p mod 4 ==3 :  a^((p+1)/4)
+                                                pow(a,&_params.modplus1div4,false,_params)
+                                             }
+                     else { // Constant-time Tonelli-Shanks Algorithm (p mod 4 ==1), with a fixed number of iterations
+                            // https://www.rfc-editor.org/rfc/rfc9380.html#name-constant-time-tonelli-shank
+                            // p - 1 = 2^c1 * c2 with c1 = sqrtid, c3 = (c2 - 1)/2 and c5 = g^c2 for a non-square g (tonelli_params)
+                            let mut z = pow(a,&_params.tonelli_params[1],false,_params);
+                            let mut t = mul(&sqr(&z, _params), a, _params);
+                            z = mul(&z, a, _params);
+                            let mut b = t;
+                            let mut c = mul(&_params.tonelli_params[0],&_params.rsquare,_params); // Convert to Montgomery repr
+                            for i in (2..=_params.sqrtid).rev() {   for _ in 1..i-1 {b = sqr(&b, _params)}
+                                                                    let e = ct_eq(&b, &_params.one);
+                                                                    z = select(&mul(&z, &c, _params), &z, e);
+                                                                    c = sqr(&c, _params);
+                                                                    t = select(&mul(&t, &c, _params), &t, e);
+                                                                    b = t;
+                                                                }
+                            z
+                          };
+        CtOption::new(result, ct_eq(&sqr(&result, _params), a))
+    }
@@ -4,13 +4,16 @@
 
 use std::{str::FromStr, usize};
 use std::fmt;
-use std::ops::{Add,Sub,Mul,Div,Neg};
+use std::marker::PhantomData;
+use std::ops::{Add,Sub,Mul,Div,Neg,Deref};
 use base64::engine::general_purpose;
 use base64::Engine;
 use num_bigint::{BigInt, BigUint, ToBigInt, ToBigUint};
 use num_traits::{ Euclid, Num, One, ToPrimitive, Zero};
 use rand::RngCore;
+use crate::errors::PairingError;
 use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::tools::constant_time::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use crate::tools::hashs::*; 
 use super::super::tools::exponent::Exponent;
 use super::arithmetic;
@@ -41,87 +44,172 @@ pub sig_theshold : [u64;N],
 pub tonelli_params :[[u64;N];2], // Parameters for sqrt tonelli-shanks algorithm  if p mod 4 = 1 :s,(s-1)/2,g
 }
 
+// Parameters of a prime field bound at the type level : every field is identified by a zero-sized marker type, 
+// whose constant parameters are computed at compile time (see parameters::builders::build_field_params)
+pub trait FieldConfig<const N:usize> : Copy + fmt::Debug + Send + Sync + 'static {
+    const PARAMS : FieldParams<'static, N>;
+}
+
+// Zero-sized handle to the parameters of the field P, dereferencing to the (promoted) constant P::PARAMS
+pub struct ConfigParams<const N:usize, P>(PhantomData<P>);
+
+impl <const N:usize, P: FieldConfig<N>> ConfigParams<N,P> {
+    pub const fn new() -> Self { ConfigParams(PhantomData) }
+}
+
+impl <const N:usize, P: FieldConfig<N>> Default for ConfigParams<N,P> {
+    fn default() -> Self { Self::new() }
+}
+
+impl <const N:usize, P: FieldConfig<N>> Deref for ConfigParams<N,P> {
+    type Target = FieldParams<'static, N>;
+    fn deref(&self) -> &FieldParams<'static, N> { &P::PARAMS }
+}
+
+impl <const N:usize, P> Clone for ConfigParams<N,P> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl <const N:usize, P> Copy for ConfigParams<N,P> {}
+
+impl <const N:usize, P> fmt::Debug for ConfigParams<N,P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ConfigParams<{}>", std::any::type_name::<P>())
+    }
+}
+
 #[derive(Clone, Copy,Debug)]
-pub struct FieldElement <const N:usize> {
-pub fieldparams:&'static FieldParams<'static,N>,
+pub struct FieldElement <const N:usize, P: FieldConfig<N>> {
+pub fieldparams:ConfigParams<N,P>,
 pub mont_limbs:[u64;N]
 
 }
 
 #[derive(Clone,Debug)]
-pub struct PrimeField<const N:usize>{
-pub parametres :&'static FieldParams<'static , N>,
-pub modulo_as_bigint: BigUint
+pub struct PrimeField<const N:usize, P: FieldConfig<N>>{
+pub parametres :ConfigParams<N,P>
 }
 
 
-impl <'a, const N:usize> PrimeField<N> {             
-    pub fn new(input_params :&'static FieldParams<N>)-> PrimeField<N>
-        {   let bigint_modulo : BigUint =  BigUint::from_str_radix(&input_params.modulo_as_strhex[2..], 16).unwrap(); 
-            PrimeField {parametres : input_params, modulo_as_bigint: bigint_modulo}
+impl <const N:usize, P: FieldConfig<N>> Default for PrimeField<N,P> {
+    fn default() -> Self { Self::new() }
+}
+
+impl <'a, const N:usize, P: FieldConfig<N>> PrimeField<N,P> {             
+    pub const fn new()-> PrimeField<N,P>
+        {   PrimeField {parametres : ConfigParams::new()}
         }   
 
-    pub fn random_element(&self) -> FieldElement<N> 
+    pub fn modulo_as_bigint(&self) -> BigUint
+        {   let mut as_big : BigUint = BigUint::zero();
+            for i in 0..N {as_big += self.parametres.modulo[i].to_biguint().unwrap() << (i * 64)};
+            as_big
+        }
+
+    pub fn random_element(&self) -> FieldElement<N,P> 
         {   let mut rng = OsRng;
             let mut random_bytes = vec![0u8; N * 8]; 
             rng.fill_bytes(&mut random_bytes);
             let randomelement  = BigUint::from_bytes_be(&random_bytes); 
-            Self::from_bigint(&self,&((randomelement % self.modulo_as_bigint.clone()).to_bigint()).unwrap())
+            Self::from_bigint(&self,&((randomelement % self.modulo_as_bigint()).to_bigint()).unwrap())
         }
 
-    pub fn from_bigint(&self, input : &BigInt)-> FieldElement<N> 
+    pub fn random_nonzero_element(&self) -> FieldElement<N,P> 
+        {   // Rejection of the (negligible) zero draws, used for randomizers/masks that have to be invertible
+            loop { let r = self.random_element();
+                   if !r.is_zero() {return r}
+                 }
+        }
+
+    pub fn from_bigint(&self, input : &BigInt)-> FieldElement<N,P> 
             // Load the Fp element from a BigInt signed value, and convert to montgomery form     
         {   let mut limbs     : [u64;N] = [0;N];              
-            let reduced_big   : BigUint = input.rem_euclid(&self.modulo_as_bigint.to_bigint().unwrap()).to_biguint().unwrap();
+            let reduced_big   : BigUint = input.rem_euclid(&self.modulo_as_bigint().to_bigint().unwrap()).to_biguint().unwrap();
             let mask           = (BigUint::one() << 64) - BigUint::one();
             for i in 0..N { limbs[i] = (((&reduced_big & (&mask << (i * 64))) >> (i * 64)) as BigUint).to_u64().unwrap()};
-            FieldElement{   fieldparams : &self.parametres,  
+            FieldElement{   fieldparams : self.parametres,  
                             mont_limbs  : super::arithmetic::mul(&limbs, &self.parametres.rsquare, &self.parametres)
                             }
         }
     
-    pub fn from_u64(&self, input : u64)-> FieldElement<N> 
+    pub fn from_u64(&self, input : u64)-> FieldElement<N,P> 
         // Load the Fp element from a u64 unsigned value, and convert to montgomery form     
     {   let mut limbs     : [u64;N] = [0;N];              
         limbs[0] = input;
-        FieldElement{   fieldparams : &self.parametres,  
+        FieldElement{   fieldparams : self.parametres,  
                         mont_limbs  : super::arithmetic::mul(&limbs, &self.parametres.rsquare, &self.parametres)
                         }
     }
 
-    pub fn from_str(&self,input : &str)-> FieldElement<N> 
+    pub fn from_str(&self,input : &str)-> FieldElement<N,P> 
             // Load the Fp element from a string, and convert to montgomery form
+        {   match self.try_from_str(input) { Ok(x) => x,
+                                             Err(e) => panic!("{}",e)}        
+        }
+
+    pub fn try_from_str(&self,input : &str)-> Result<FieldElement<N,P>, PairingError> 
+            // Load the Fp element from a string (decimal format), returns an error on malformed strings
         {   let mut strin = input;
             let mut negative = false; 
-            if &strin[0..1]=="-" { negative =true;
-                                   strin = &strin[1..];
-                                 }
-            if negative {Self::from_bigint(&self,&BigInt::from_str(strin).unwrap()).negate()}
-            else {Self::from_bigint(&self,&BigInt::from_str(strin).unwrap())}        
+            if strin.starts_with("-") { negative =true;
+                                        strin = &strin[1..];
+                                      }
+            let value = BigInt::from_str(strin).map_err(|_| PairingError::InvalidString)?;
+            if negative {Ok(Self::from_bigint(&self,&value).negate())}
+            else {Ok(Self::from_bigint(&self,&value))}        
         }
 
-    pub fn from_hex_str(&self, input : &str) -> FieldElement<N> 
+    pub fn from_hex_str(&self, input : &str) -> FieldElement<N,P> 
             // Load the Fp element from a hex-string, and convert to montgomery form     
+        {   match self.try_from_hex_str(input) { Ok(x) => x,
+                                                 Err(e) => panic!("{}",e)}
+        }
+
+    pub fn try_from_hex_str(&self, input : &str) -> Result<FieldElement<N,P>, PairingError> 
+            // Load the Fp element from a hex-string, returns an error on malformed strings
         {   let mut strin = input;
             let mut negative = false; 
-            if &strin[0..1]=="-" { negative =true;
-                                   strin = &strin[1..];
-                                 }
-            if &strin[0..2]=="0x" { strin = &strin[2..];}
-            if negative { Self::from_bigint(&self,&BigInt::from_str_radix(&strin,16).unwrap()).negate()}
-            else {Self::from_bigint(&self,&BigInt::from_str_radix(&strin,16).unwrap())}
+            if strin.starts_with("-") { negative =true;
+                                        strin = &strin[1..];
+                                      }
+            if strin.starts_with("0x") { strin = &strin[2..];}
+            let value = BigInt::from_str_radix(&strin,16).map_err(|_| PairingError::InvalidString)?;
+            if negative { Ok(Self::from_bigint(&self,&value).negate())}
+            else {Ok(Self::from_bigint(&self,&value))}
         }
 
-    pub fn from_byte_array(&self, source :&[u8], repre:Endianness) -> FieldElement<N>{
+    pub fn from_byte_array(&self, source :&[u8], repre:Endianness) -> FieldElement<N,P>{
             let mut _source = source.to_vec();
             if repre == Endianness::Big {(_source).reverse();}
-            let numbits = self.parametres.num_of_bits;                
-            let sizeinbytes = (numbits >> 3) + if (numbits % 8) ==0 {0} else {1}; 
+            let sizeinbytes = self.size_in_bytes(); 
             if sizeinbytes != _source.len() {panic!("Size of input does not correspond to the field's extension ...");}                   
             Self::from_bigint(&self,&os2ip(&_source).to_bigint().unwrap())
         }                   
     
-    pub fn from_base64(&self, source :&str) -> FieldElement<N>
+    pub fn try_from_byte_array(&self, source :&[u8], repre:Endianness) -> Result<FieldElement<N,P>, PairingError>
+        {   
+            // Same as from_byte_array, but rejects inputs of wrong size and non-reduced (non-canonical) values
+            let mut _source = source.to_vec();
+            if repre == Endianness::Big {(_source).reverse();}
+            let sizeinbytes = self.size_in_bytes(); 
+            if sizeinbytes != _source.len() {return Err(PairingError::BadLength { expected: sizeinbytes, found: _source.len() });}
+            self.try_from_canonical_biguint(&os2ip(&_source))
+        }                   
+
+    pub fn try_from_canonical_biguint(&self, input :&BigUint) -> Result<FieldElement<N,P>, PairingError>
+        {   
+            // Load the Fp element from an unsigned integer that have to be strictly lower than the modulo
+            if *input >= self.modulo_as_bigint() {Err(PairingError::NonCanonicalFieldElement)}
+            else {Ok(Self::from_bigint(&self,&input.to_bigint().unwrap()))}
+        }
+
+    pub fn size_in_bytes(&self) -> usize
+        {   
+            let numbits = self.parametres.num_of_bits;                
+            (numbits >> 3) + if (numbits % 8) ==0 {0} else {1}
+        }
+
+    pub fn from_base64(&self, source :&str) -> FieldElement<N,P>
         {
             let decoded_bytes = match general_purpose::STANDARD.decode(source) {
                 Ok(bytes) => bytes,
@@ -131,39 +219,54 @@ impl <'a, const N:usize> PrimeField<N> {
             };
             self.from_byte_array(&decoded_bytes, Endianness::Little)
         }
+
+    pub fn try_from_base64(&self, source :&str) -> Result<FieldElement<N,P>, PairingError>
+        {
+            let decoded_bytes = general_purpose::STANDARD.decode(source).map_err(|_| PairingError::InvalidBase64)?;
+            self.try_from_byte_array(&decoded_bytes, Endianness::Little)
+        }
     
-    pub fn zero(&self) ->FieldElement<N>  {
-            FieldElement{   fieldparams: &self.parametres, 
+    pub fn zero(&self) ->FieldElement<N,P>  {
+            FieldElement{   fieldparams : self.parametres, 
                             mont_limbs: [0u64; N],
                         }
                     }
-    pub fn one(&self) ->FieldElement<N>  {
-                        FieldElement{   fieldparams: &self.parametres, 
+    pub fn one(&self) ->FieldElement<N,P>  {
+                        FieldElement{   fieldparams : self.parametres, 
                                         mont_limbs: self.parametres.one,
                                     }
                                 }
-    pub fn hash_to_field(&self,id : &str, security_level:usize,count :usize) -> Vec<FieldElement<N>>
+    pub fn hash_to_field(&self,msg : &[u8], dst : &[u8], security_level:usize, expander :Expander, count :usize) -> Vec<FieldElement<N,P>>
         {   
-            hash_string_to_field(id, self, count, security_level, 1)
+            hash_bytes_to_field(msg, count, self, dst, security_level, 1, expander)
         }
     } 
 
 
-impl  <'a, const N:usize> FieldElement<N>  {
-    pub fn pow(&self, e :& dyn Exponent<N>, useladder:bool) -> FieldElement<N>
+impl  <'a, const N:usize, P: FieldConfig<N>> FieldElement<N,P>  {
+    pub fn pow(&self, e :& dyn Exponent<N>, useladder:bool) -> FieldElement<N,P>
     {   
         let array = e.to_u64_array().unwrap();
-        FieldElement{   mont_limbs : arithmetic::pow(&self.mont_limbs, &array, useladder, self.fieldparams),
-                        fieldparams : &self.fieldparams} 
+        FieldElement{   mont_limbs : arithmetic::pow(&self.mont_limbs, &array, useladder, &self.fieldparams),
+                        fieldparams : self.fieldparams} 
     }
-    pub fn sqrt(&self) -> Option<FieldElement<N>> 
-    {   let result =super::arithmetic::sqrt(&self.mont_limbs, &self.fieldparams);
-        if result.is_none() { None }
-        else {  Some(FieldElement{   fieldparams : &self.fieldparams, 
-                                     mont_limbs  : result.unwrap()}) 
-            }
+    pub fn sqrt(&self) -> Option<FieldElement<N,P>> 
+    {   self.sqrt_ct().into()
     }
-    
+    pub fn sqrt_ct(&self) -> CtOption<FieldElement<N,P>> 
+    {   // Constant-time square root : the flag is set only when the returned value squares back to self
+        super::arithmetic::sqrt_ct(&self.mont_limbs, &self.fieldparams).map(|limbs| FieldElement{   fieldparams : self.fieldparams, 
+                                                                                                    mont_limbs  : limbs})
+    }
+    pub fn invert_ct(&self) -> CtOption<FieldElement<N,P>> 
+    {   // Constant-time inversion, the flag is unset for zero (whose "inverse" is returned as zero)
+        CtOption::new(self.invert(), !self.ct_eq(&self.zero()))
+    }
+    pub fn is_square(&self) -> Choice
+    {   // Constant-time Legendre symbol test (zero is considered as a square, as in RFC 9380)
+        super::arithmetic::is_square(&self.mont_limbs, &self.fieldparams)
+    }
+
     pub fn to_big_uint(&self) -> BigUint
     {   let mut as_big : BigUint = BigUint::zero();
         let mut one :[u64;N] = [0;N];
@@ -173,10 +276,24 @@ impl  <'a, const N:usize> FieldElement<N>  {
         as_big
     } 
 
+    pub fn to_canonical_limbs(&self) -> [u64;N]
+    {   // Canonical (non-Montgomery) little-endian limbs of the element
+        let mut one :[u64;N] = [0;N];
+        one[0] = 1;
+        arithmetic::mul(&self.mont_limbs, &one, &self.fieldparams)
+    }
+
+    pub fn sgn0(&self) -> u8
+    {   // Parity of the canonical representative (https://www.rfc-editor.org/rfc/rfc9380.html#name-the-sgn0-function)
+        let mut one :[u64;N] = [0;N];
+        one[0] = 1;
+        (arithmetic::mul(&self.mont_limbs, &one, &self.fieldparams)[0] & 1) as u8
+    }
+
     pub fn sign(&self) -> i8
     {   let mut one :[u64;N] = [0;N];
         one[0] = 1;
-        let val = arithmetic::mul(&self.mont_limbs, &one, self.fieldparams);        
+        let val = arithmetic::mul(&self.mont_limbs, &one, &self.fieldparams);        
         let mut i =N-1;
         while (val[i] == self.fieldparams.sig_theshold[i]) & (i>0) { i=i-1}
         let sig = if val[i] > self.fieldparams.sig_theshold[i] {-1} else {1};        
@@ -197,7 +314,7 @@ impl  <'a, const N:usize> FieldElement<N>  {
     }
 }
 
-impl <const N:usize> ArithmeticOperations for FieldElement<N> {    
+impl <const N:usize, P: FieldConfig<N>> ArithmeticOperations for FieldElement<N,P> {    
     fn addto(&self, other: &Self) -> Self {
         Self {  fieldparams:self.fieldparams, 
             mont_limbs: super::arithmetic::add(&self.mont_limbs, &other.mont_limbs, &self.fieldparams)}
@@ -230,26 +347,32 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
     }    
     
     fn sqr(&self) -> Self {
-        FieldElement {  fieldparams : &self.fieldparams,  
+        FieldElement {  fieldparams : self.fieldparams,  
             mont_limbs : super::arithmetic::sqr(&self.mont_limbs, &self.fieldparams)} 
     }
 
     fn invert(&self) -> Self {
-        FieldElement{   fieldparams : &self.fieldparams,  
+        FieldElement{   fieldparams : self.fieldparams,  
             mont_limbs : super::arithmetic::invert(&self.mont_limbs,&self.fieldparams)}        
     }
 
+    fn invert_vartime(&self) -> Self {
+        // Faster (binary extended GCD) inversion, not constant-time : for public values only
+        FieldElement{   fieldparams : self.fieldparams,
+                        mont_limbs : super::arithmetic::invert_vartime(&self.mont_limbs,&self.fieldparams)}
+    }
+
     fn negate(&self) -> Self {
         Self {  fieldparams:self.fieldparams, 
             mont_limbs: super::arithmetic::neg(&self.mont_limbs, &self.fieldparams )}
     }
     fn one(&self) -> Self {
-        FieldElement{   fieldparams: &self.fieldparams, 
+        FieldElement{   fieldparams : self.fieldparams, 
                         mont_limbs: self.fieldparams.one.clone(),
                     }
     }   
     fn zero(&self) -> Self {
-        FieldElement{   fieldparams: &self.fieldparams, 
+        FieldElement{   fieldparams : self.fieldparams, 
                         mont_limbs: self.fieldparams.zero.clone(),
                     }
     }   
@@ -263,15 +386,28 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
     
 }
 
-    impl  <'a, const N:usize> Add for FieldElement<N> {
-        type Output =  FieldElement<N>;
+impl <const N:usize, P: FieldConfig<N>> ConstantTimeEq for FieldElement<N,P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        super::arithmetic::ct_eq(&self.mont_limbs, &other.mont_limbs)
+    }
+}
+
+impl <const N:usize, P: FieldConfig<N>> ConditionallySelectable for FieldElement<N,P> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement{   fieldparams : a.fieldparams,
+                        mont_limbs  : super::arithmetic::select(&a.mont_limbs, &b.mont_limbs, choice)}
+    }
+}
+
+    impl  <'a, const N:usize, P: FieldConfig<N>> Add for FieldElement<N,P> {
+        type Output =  FieldElement<N,P>;
         fn add(self, rhs: Self) -> Self::Output {
             self.addto(&rhs)
         }
         }
     
-    impl <'a, const N:usize> Add<u64> for FieldElement<N> {
-        type Output = FieldElement<N>;
+    impl <'a, const N:usize, P: FieldConfig<N>> Add<u64> for FieldElement<N,P> {
+        type Output = FieldElement<N,P>;
         fn add(self, rhs: u64) -> Self::Output {
             let mut _rhs =[0;N];
             _rhs[0]=rhs;
@@ -280,15 +416,15 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
         }
     }
     
-    impl  <'a, const N:usize> Sub for FieldElement<N> {
-    type Output =  FieldElement<N>;
+    impl  <'a, const N:usize, P: FieldConfig<N>> Sub for FieldElement<N,P> {
+    type Output =  FieldElement<N,P>;
     fn sub(self, rhs: Self) -> Self::Output {
         self.substract(&rhs)
     }
     }
 
-    impl <'a, const N:usize> Sub<u64> for FieldElement<N> {
-        type Output = FieldElement<N>;
+    impl <'a, const N:usize, P: FieldConfig<N>> Sub<u64> for FieldElement<N,P> {
+        type Output = FieldElement<N,P>;
         fn sub(self, rhs: u64) -> Self::Output {
             let mut _rhs =[0;N];
             _rhs[0]=rhs;
@@ -297,16 +433,16 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
         }
     }
 
-    impl  <'a, const N:usize> Mul for FieldElement<N> {
-    type Output =  FieldElement<N>;
+    impl  <'a, const N:usize, P: FieldConfig<N>> Mul for FieldElement<N,P> {
+    type Output =  FieldElement<N,P>;
     fn mul(self, rhs: Self) -> Self::Output {
         self.multiply(&rhs)
     }
     }
     
     
-    impl <'a, const N:usize> Mul<u8> for FieldElement<N> {
-    type Output = FieldElement<N>;
+    impl <'a, const N:usize, P: FieldConfig<N>> Mul<u8> for FieldElement<N,P> {
+    type Output = FieldElement<N,P>;
     fn mul(self, rhs: u8) -> Self::Output {
         match  rhs { 0 => Self::Output {fieldparams:self.fieldparams, mont_limbs:self.fieldparams.zero},
                         1 => self,
@@ -328,9 +464,9 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
                 }
     }
     
-    impl<'a, const N: usize> Mul<FieldElement<N>> for u8 {
-    type Output = FieldElement<N>;
-    fn mul(self, rhs: FieldElement<N>) -> Self::Output {
+    impl<'a, const N: usize, P: FieldConfig<N>> Mul<FieldElement<N,P>> for u8 {
+    type Output = FieldElement<N,P>;
+    fn mul(self, rhs: FieldElement<N,P>) -> Self::Output {
         match  self { 0 => Self::Output {fieldparams:rhs.fieldparams, mont_limbs:rhs.fieldparams.zero},
                         1 => rhs,
                         2 => rhs + rhs,
@@ -350,29 +486,29 @@ impl <const N:usize> ArithmeticOperations for FieldElement<N> {
                 }
     }
     
-    impl  <'a, const N:usize> Div for FieldElement<N> {
-    type Output =  FieldElement<N>;
+    impl  <'a, const N:usize, P: FieldConfig<N>> Div for FieldElement<N,P> {
+    type Output =  FieldElement<N,P>;
     fn div(self, rhs: Self) -> Self::Output {
         self.multiply(&rhs.invert())
     }
     }
     
-    impl<'a, const N: usize> PartialEq for FieldElement<N> {
+    impl<'a, const N: usize, P: FieldConfig<N>> PartialEq for FieldElement<N,P> {
     fn eq(&self, rhs: &Self) -> bool {
         self.equal(&rhs)
     }
     }
     
-    impl<'a, const N: usize> Eq for FieldElement<N> {}
+    impl<'a, const N: usize, P: FieldConfig<N>> Eq for FieldElement<N,P> {}
     
-    impl  <'a, const N:usize> Neg for FieldElement<N> {
-    type Output =  FieldElement<N>;
+    impl  <'a, const N:usize, P: FieldConfig<N>> Neg for FieldElement<N,P> {
+    type Output =  FieldElement<N,P>;
     fn neg(self) -> Self::Output {
         self.negate()
     }
     }   
     
-    impl<'a, const N: usize> fmt::Display for FieldElement<N> {
+    impl<'a, const N: usize, P: FieldConfig<N>> fmt::Display for FieldElement<N,P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:}", &self.to_dec_string())
     }
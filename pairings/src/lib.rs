@@ -8,17 +8,24 @@ pub mod tools;
 pub mod parameters;
 pub mod curves;
 pub mod engines;
+pub mod errors;
 pub mod tests;
 
 
-use curves::{curve_arithmetics::EcPoint, g1::{G1Element, G1Field}, g2::{G2Element, G2Field}, gt::{GTElement, GTField}};
+use std::{fmt, marker::PhantomData, thread};
+use curves::{curve_arithmetics::EcPoint, g1::{G1Affine, G1Element, G1Field}, g2::{G2Affine, G2Element, G2Field, G2Prepared}, gt::{GTExtElement, GTField, Gt}};
 use engines::{  bls12_381_engine, bls12_446_engine, bls12_461_engine, bls24_315_engine, bls24_477_engine, bls24_479_engine, bls24_509_engine, 
                 bls24_509_snark_engine, bls24_559_engine, bls48_277_engine, bls48_287_engine, bls48_571_engine, bls48_573_engine, bls48_575_engine, bls48_581_engine};
-use extensions::g2_extfields::ExtFieldG2Element;
-use fields::prime_fields::{FieldElement, PrimeField};
-use tools::{arithmetic_interface::ArithmeticOperations, exponent::Exponent};
-
-#[derive(Debug)]
+use base64::{engine::general_purpose, Engine};
+use errors::{DecodingMode, PairingError};
+use extensions::{g2_extfields::G2ExtElement, towers::{Bls12Tower, Bls24Tower1, Bls24Tower2, Bls48Tower1, Bls48Tower2, Bls48Tower3, PairingTower}};
+use fields::prime_fields::{FieldConfig, FieldElement, PrimeField};
+use parameters::preloadedconfs::{ bls12::{Bls12_381Fp, Bls12_381Fr, Bls12_446Fp, Bls12_446Fr, Bls12_461Fp, Bls12_461Fr}, 
+                                   bls24::{Bls24_315Fp, Bls24_315Fr, Bls24_477Fp, Bls24_477Fr, Bls24_479Fp, Bls24_479Fr, Bls24_509Fp, Bls24_509Fr, Bls24_509SnarkFp, Bls24_509SnarkFr, Bls24_559Fp, Bls24_559Fr}, 
+                                   bls48::{Bls48_277Fp, Bls48_277Fr, Bls48_287Fp, Bls48_287Fr, Bls48_571Fp, Bls48_571Fr, Bls48_573Fp, Bls48_573Fr, Bls48_575Fp, Bls48_575Fr, Bls48_581Fp, Bls48_581Fr}};
+use tools::{arithmetic_interface::ArithmeticOperations, exponent::{Exponent, HardPartStrategy}};
+
+#[derive(Debug, Clone, Copy)]
 pub enum CurvesNames {
     Bls12_381, 
     Bls12_446, 
@@ -85,24 +92,53 @@ impl CurvesNames {
 }
 
 
-#[derive(Debug)]
-pub struct Pairings <const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>
+pub const AFFINE_MILLER_THRESHOLD : usize = 32;         // From this number of pairs, the shared Miller loop runs in affine coordinates
+pub const PARALLEL_PAIRING_THRESHOLD : usize = 8;       // Below this number of pairs, the parallel multi-pairing runs the serial loop
+
+pub struct Pairings <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
          {  pub identifier :&'static str,
             pub curvename :CurvesNames,
-            pub g1 : &'static G1Field<R,N,MAX_COEFS_COUNT1>, 
-            pub g2:  &'static G2Field<PRAMASIZE,R,N,MAX_COEFS_COUNT2>,
-            pub gt : &'static GTField<N,PRAMASIZE>,
-            pub fr : &'static PrimeField<R>}
+            pub g1 : &'static G1Field<R,S,N,P,MAX_COEFS_COUNT1>, 
+            pub g2:  &'static G2Field<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>,
+            pub gt : &'static GTField<N,P,PRAMASIZE,T::GT>,
+            pub fr : &'static PrimeField<R,S>,
+            pub hard_part : HardPartStrategy}
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>> fmt::Debug 
+      for Pairings<R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T> {
+    // Written by hand, as derive would require Debug on the GT tower types selected by T
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pairings").field("identifier", &self.identifier)
+                                  .field("curvename", &self.curvename)
+                                  .field("g1", &self.g1)
+                                  .field("g2", &self.g2)
+                                  .field("gt", &self.gt)
+                                  .field("fr", &self.fr)
+                                  .field("hard_part", &self.hard_part).finish()
+    }
+}
 
 
 
 pub trait  PairingsEngine {
     type GT;
+    type MillerOutput;
     type G1;
     type G2;
-    fn miller_loop(&self, p:&Self::G1, q:&Self::G2) -> Self::GT;
+    type G2Prepared;
+    fn miller_loop(&self, p:&Self::G1, q:&Self::G2) -> Self::MillerOutput;
+    fn prepare_g2(&self, q:&Self::G2) -> Self::G2Prepared;
+    fn miller_loop_prepared(&self, p:&Self::G1, q:&Self::G2Prepared) -> Self::MillerOutput;
+    fn multi_paire_prepared(&self, p:&[Self::G1], q:&[Self::G2Prepared]) -> Self::GT;
     fn paire(&self, p:&Self::G1, q:&Self::G2) -> Self::GT;
     fn multi_paire(&self, p:&[Self::G1], q:&[Self::G2]) -> Self::GT; 
+    fn pairing_check(&self, pairs:&[(Self::G1, Self::G2)]) -> bool;
+    fn try_multi_paire(&self, p:&[Self::G1], q:&[Self::G2]) -> Result<Self::GT, PairingError>
+        {   // Same as multi_paire, but reports incompatible list sizes as an error instead of panicking
+            if p.len() != q.len() {Err(PairingError::LengthMismatch { left: p.len(), right: q.len() })}
+            else {Ok(self.multi_paire(p, q))}
+        }
+    fn try_multi_paire_prepared(&self, p:&[Self::G1], q:&[Self::G2Prepared]) -> Result<Self::GT, PairingError>;
  }  
  
  
@@ -110,105 +146,105 @@ pub struct BLS12{}
 pub struct BLS24{}
 pub struct BLS48{}
 pub trait Bls12Curves{ 
-    fn _381() ->&'static Pairings<4, 6, 16, 4,  4>;    
-    fn _446() ->&'static Pairings<5, 7, 3, 4,  7>;    
-    fn _461() ->&'static Pairings<5, 8, 10, 4,  4>;
+    fn _381() ->&'static Pairings<4, Bls12_381Fr, 6, Bls12_381Fp, 16, 4,  4, Bls12Tower>;    
+    fn _446() ->&'static Pairings<5, Bls12_446Fr, 7, Bls12_446Fp, 3, 4,  7, Bls12Tower>;    
+    fn _461() ->&'static Pairings<5, Bls12_461Fr, 8, Bls12_461Fp, 10, 4,  4, Bls12Tower>;
 }
 
 impl Bls12Curves for BLS12{
-    fn _381() ->&'static Pairings<4, 6, 16, 4,  4>
+    fn _381() ->&'static Pairings<4, Bls12_381Fr, 6, Bls12_381Fp, 16, 4,  4, Bls12Tower>
     {
         bls12_381_engine()
     }
 
-    fn _446() ->&'static Pairings<5, 7, 3, 4,  7>
+    fn _446() ->&'static Pairings<5, Bls12_446Fr, 7, Bls12_446Fp, 3, 4,  7, Bls12Tower>
     {
         bls12_446_engine()
     }
 
-    fn _461() ->&'static Pairings<5, 8, 10, 4,  4>
+    fn _461() ->&'static Pairings<5, Bls12_461Fr, 8, Bls12_461Fp, 10, 4,  4, Bls12Tower>
     {
         bls12_461_engine()
     }
 }
     
 pub trait BLS24Curves{
-    fn _315() ->&'static Pairings<4, 5, 3, 11,  7>;
-    fn _477() ->&'static Pairings<6, 8, 7, 11,  4>;
-    fn _479() ->&'static Pairings<7, 8, 4, 10,  10>;
-    fn _509_snark() ->&'static Pairings<7, 8, 3, 11,  7>;
-    fn _509() ->&'static Pairings<7, 8, 3, 10,  10>;
-    fn _559() ->&'static Pairings<8, 9, 4, 10,  4>;
+    fn _315() ->&'static Pairings<4, Bls24_315Fr, 5, Bls24_315Fp, 3, 11,  7, Bls24Tower2>;
+    fn _477() ->&'static Pairings<6, Bls24_477Fr, 8, Bls24_477Fp, 7, 11,  4, Bls24Tower1>;
+    fn _479() ->&'static Pairings<7, Bls24_479Fr, 8, Bls24_479Fp, 4, 10,  10, Bls24Tower1>;
+    fn _509_snark() ->&'static Pairings<7, Bls24_509SnarkFr, 8, Bls24_509SnarkFp, 3, 11,  7, Bls24Tower2>;
+    fn _509() ->&'static Pairings<7, Bls24_509Fr, 8, Bls24_509Fp, 3, 10,  10, Bls24Tower1>;
+    fn _559() ->&'static Pairings<8, Bls24_559Fr, 9, Bls24_559Fp, 4, 10,  4, Bls24Tower1>;
 }
 
 impl BLS24Curves for BLS24 {
-    fn _315() ->&'static Pairings<4, 5, 3, 11,  7>
+    fn _315() ->&'static Pairings<4, Bls24_315Fr, 5, Bls24_315Fp, 3, 11,  7, Bls24Tower2>
     {
         bls24_315_engine()
     }
     
-    fn _477() ->&'static Pairings<6, 8, 7, 11,  4>
+    fn _477() ->&'static Pairings<6, Bls24_477Fr, 8, Bls24_477Fp, 7, 11,  4, Bls24Tower1>
     {
         bls24_477_engine()
     }
 
-    fn _479() ->&'static Pairings<7, 8, 4, 10,  10>
+    fn _479() ->&'static Pairings<7, Bls24_479Fr, 8, Bls24_479Fp, 4, 10,  10, Bls24Tower1>
     {
         bls24_479_engine()
     }
 
-    fn _509_snark() ->&'static Pairings<7, 8, 3, 11,  7>
+    fn _509_snark() ->&'static Pairings<7, Bls24_509SnarkFr, 8, Bls24_509SnarkFp, 3, 11,  7, Bls24Tower2>
     {
         bls24_509_snark_engine()
     }
 
-    fn _509() ->&'static Pairings<7, 8, 3, 10,  10>
+    fn _509() ->&'static Pairings<7, Bls24_509Fr, 8, Bls24_509Fp, 3, 10,  10, Bls24Tower1>
     {
         bls24_509_engine()
     }
 
-    fn _559() ->&'static Pairings<8, 9, 4, 10,  4>
+    fn _559() ->&'static Pairings<8, Bls24_559Fr, 9, Bls24_559Fp, 4, 10,  4, Bls24Tower1>
     {
         bls24_559_engine()
     }
 }
 
 pub trait  BLS48Curves{
-    fn _575() ->&'static Pairings<9, 9, 4, 24,  7>;
-    fn _573() ->&'static Pairings<8, 9, 7, 24,  4>;
-    fn _571() ->&'static Pairings<8, 9, 3, 36,  4>;
-    fn _581() ->&'static Pairings<9, 10, 3, 24,  4>;
-    fn _287() ->&'static Pairings<4, 5, 4, 35,  19>;
-    fn _277() ->&'static Pairings<4, 5, 10, 24,  4>;
+    fn _575() ->&'static Pairings<9, Bls48_575Fr, 9, Bls48_575Fp, 4, 24,  7, Bls48Tower1>;
+    fn _573() ->&'static Pairings<8, Bls48_573Fr, 9, Bls48_573Fp, 7, 24,  4, Bls48Tower1>;
+    fn _571() ->&'static Pairings<8, Bls48_571Fr, 9, Bls48_571Fp, 3, 36,  4, Bls48Tower2>;
+    fn _581() ->&'static Pairings<9, Bls48_581Fr, 10, Bls48_581Fp, 3, 24,  4, Bls48Tower3>;
+    fn _287() ->&'static Pairings<4, Bls48_287Fr, 5, Bls48_287Fp, 4, 35,  19, Bls48Tower2>;
+    fn _277() ->&'static Pairings<4, Bls48_277Fr, 5, Bls48_277Fp, 10, 24,  4, Bls48Tower3>;
 }
 
 impl  BLS48Curves for BLS48 {
-    fn _575() ->&'static Pairings<9, 9, 4, 24,  7>
+    fn _575() ->&'static Pairings<9, Bls48_575Fr, 9, Bls48_575Fp, 4, 24,  7, Bls48Tower1>
     {
         bls48_575_engine()
     }   
 
-    fn _581() ->&'static Pairings<9, 10, 3, 24,  4>
+    fn _581() ->&'static Pairings<9, Bls48_581Fr, 10, Bls48_581Fp, 3, 24,  4, Bls48Tower3>
     {
         bls48_581_engine()
     }
 
-    fn _573() ->&'static Pairings<8, 9, 7, 24,  4>
+    fn _573() ->&'static Pairings<8, Bls48_573Fr, 9, Bls48_573Fp, 7, 24,  4, Bls48Tower1>
     {
         bls48_573_engine()
     }  
 
-    fn _571() ->&'static Pairings<8, 9, 3, 36,  4>
+    fn _571() ->&'static Pairings<8, Bls48_571Fr, 9, Bls48_571Fp, 3, 36,  4, Bls48Tower2>
     {
         bls48_571_engine()
     }   
 
-    fn _287() ->&'static Pairings<4, 5, 4, 35,  19>
+    fn _287() ->&'static Pairings<4, Bls48_287Fr, 5, Bls48_287Fp, 4, 35,  19, Bls48Tower2>
     {
         bls48_287_engine()
     }   
 
-    fn _277() ->&'static Pairings<4, 5, 10, 24,  4>
+    fn _277() ->&'static Pairings<4, Bls48_277Fr, 5, Bls48_277Fp, 10, 24,  4, Bls48Tower3>
     {
         bls48_277_engine()
     }   
@@ -216,11 +252,11 @@ impl  BLS48Curves for BLS48 {
 
 
 
-fn double_jacobian_for_miller<const N: usize, const PARAMSIZE :usize>
-                  (q : &mut EcPoint<ExtFieldG2Element<N,PARAMSIZE>>,
-                   px :&FieldElement<N>,py : &FieldElement<N> )-> [ExtFieldG2Element<N,PARAMSIZE>;3] 
+fn double_jacobian_for_miller<const N: usize, P: FieldConfig<N>, const PARAMSIZE :usize, F: G2ExtElement<N,P,PARAMSIZE>>
+                  (q : &mut EcPoint<F>)-> [F;3] 
 {        
          //    Personal implementation of Algorithm 26 from https://eprint.iacr.org/2010/354.pdf
+         //    The returned line coefficients are independent from P (see evaluate_line_at), so they can be precomputed for a fixed Q
          let mut t0 = q.x.sqr();         
          let mut t1 = q.y.sqr();
          let mut t2 = t1.sqr();
@@ -239,16 +275,16 @@ fn double_jacobian_for_miller<const N: usize, const PARAMSIZE :usize>
          t1  = t1.double().double();
          t6  = t6.substract(&t1);
          t0  = q.z.multiply(&z2).double();
-         [t0.mulby_fp_element(py),t3.mulby_fp_element(px),t6]
+         [t0,t3,t6]
  } 
 
-fn add_jacobian_for_miller<const N: usize, const PARAMSIZE :usize>
-                    ( q :&mut EcPoint<ExtFieldG2Element<N,PARAMSIZE>>,
-                      xq :&ExtFieldG2Element<N,PARAMSIZE>,
-                      yq :&ExtFieldG2Element<N,PARAMSIZE>,
-                      px :&FieldElement<N>, py: &FieldElement<N> )-> [ExtFieldG2Element<N,PARAMSIZE>;3]                     
+fn add_jacobian_for_miller<const N: usize, P: FieldConfig<N>, const PARAMSIZE :usize, F: G2ExtElement<N,P,PARAMSIZE>>
+                    ( q :&mut EcPoint<F>,
+                      xq :&F,
+                      yq :&F)-> [F;3]                     
 {        
          //    Personal implementation of Algorithm 27 from https://eprint.iacr.org/2010/354.pdf
+         //    The returned line coefficients are independent from P (see evaluate_line_at)
          let mut z2 = q.z.sqr();
          let y2     = yq.sqr();
          let mut t0 = z2.multiply(&xq);
@@ -272,33 +308,99 @@ fn add_jacobian_for_miller<const N: usize, const PARAMSIZE :usize>
          t9  = t9.double().substract(&t10);
          t10 = q.z.double();
          t1  = t6.double().negate();
-         [t10.mulby_fp_element(py),t1.mulby_fp_element(px),t9]
+         [t10,t1,t9]
+}
+
+fn double_affine_for_miller<const N: usize, P: FieldConfig<N>, const PARAMSIZE :usize, F: G2ExtElement<N,P,PARAMSIZE>>
+                  (q : &mut EcPoint<F>, inv_2y :&F)-> [F;3] 
+{
+         //    Affine doubling of Q (z = 1), given the inverse of 2y : λ = 3x²/2y, x' = λ² - 2x, y' = λ(x - x') - y
+         //    The tangent l(P) = yP - λ.xP + (λx - y) is the Jacobian one divided by 2y, so it has the same shape (see evaluate_line_at)
+         let x2 = q.x.sqr();
+         let lambda = x2.double().addto(&x2).multiply(inv_2y);
+         let c = lambda.multiply(&q.x).substract(&q.y);
+         let x = lambda.sqr().substract(&q.x.double());
+         q.y = lambda.multiply(&q.x.substract(&x)).substract(&q.y);
+         q.x = x;
+         [q.z, lambda.negate(), c]
 }
 
-impl <const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize> PairingsEngine 
-      for Pairings<R,N,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2> {
-        type GT = GTElement<N,PRAMASIZE>;
-        type G1 = G1Element<R,N,MAX_COEFS_COUNT1>;
-        type G2 = G2Element<PRAMASIZE,R,N,MAX_COEFS_COUNT2>;     
+fn add_affine_for_miller<const N: usize, P: FieldConfig<N>, const PARAMSIZE :usize, F: G2ExtElement<N,P,PARAMSIZE>>
+                    ( q :&mut EcPoint<F>,
+                      xq :&F,
+                      yq :&F,
+                      inv_dx :&F)-> [F;3]                     
+{
+         //    Affine addition of (xq,yq) to Q (z = 1), given the inverse of xq - x : λ = (yq - y)/(xq - x), x' = λ² - x - xq, y' = λ(x - x') - y
+         //    The chord l(P) = yP - λ.xP + (λx - y) is the Jacobian one divided by 4(xq - x)
+         let lambda = yq.substract(&q.y).multiply(inv_dx);
+         let c = lambda.multiply(&q.x).substract(&q.y);
+         let x = lambda.sqr().substract(&q.x).substract(xq);
+         q.y = lambda.multiply(&q.x.substract(&x)).substract(&q.y);
+         q.x = x;
+         [q.z, lambda.negate(), c]
+}
+
+fn evaluate_line_at<const N: usize, P: FieldConfig<N>, const PARAMSIZE :usize, F: G2ExtElement<N,P,PARAMSIZE>>
+                    ( l :&[F;3], px :&FieldElement<N,P>, py: &FieldElement<N,P> )-> [F;3]                     
+{
+         //    Evaluation of the line (computed on Q by the doubling/addition steps) at the point P
+         [l[0].mulby_fp_element(py),l[1].mulby_fp_element(px),l[2]]
+}
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>> Pairings<R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T> {
+      fn multi_miller_loop_prepared(&self, p_list:&[EcPoint<FieldElement<N,P>>], q_list:&[&G2Prepared<N,P,PRAMASIZE,T::G2>]) -> T::GT
+      {
+         //  Shared Miller loop over prepared points (P given in affine coordinates): same steps as miller_loop/multi_paire,
+         //  but the lines are read from the precomputed coefficients instead of being computed on Q
+         let mut f = self.gt.one();
+         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.unsigned_abs()))[1..];
+         let idx = if self.g2.consts.twist_type == 'D' {(0,2,1)} else {(2,1,0)};
+         let mut step = 0;
+         for ib in _loop
+               {  f = f.sqr();
+                  for (p, q) in p_list.iter().zip(q_list.iter())
+                        {  let l = evaluate_line_at(&q.lines[step], &p.x, &p.y);
+                           f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                        }
+                  step += 1;
+                  if *ib != 0 { for (p, q) in p_list.iter().zip(q_list.iter())
+                                      {  let l = evaluate_line_at(&q.lines[step], &p.x, &p.y);
+                                         f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                                      }
+                                step += 1;
+                              }
+               }
+         f
+      }
 
+      pub fn with_hard_part(&self, strategy :HardPartStrategy) -> Self
+      {
+         //  Same engine, with another decomposition for the hard part of the final exponentiation (HardPartStrategy::Naive gives
+         //  the pairing itself, the other strategies give its cube)
+         Pairings { identifier: self.identifier, curvename: self.curvename, g1: self.g1, g2: self.g2, gt: self.gt, fr: self.fr, hard_part: strategy }
+      }
 
-      fn miller_loop(&self, p:&Self::G1, q:&Self::G2) -> Self::GT 
+      pub fn miller_loop_affine(&self, p:&G1Affine<R,S,N,P,MAX_COEFS_COUNT1>, q:&G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>) -> T::GT
       {         
          let mut f = self.gt.one();
-         let _p = p.to_affine().point;
-         let mut _q: EcPoint<ExtFieldG2Element<N, PRAMASIZE>>  = q.to_affine().point;
+         //  A pair with an infinity point contributes with the neutral element of GT (the inputs are public)
+         if p.is_infinit() || q.is_infinit() {return f}
+         //  Both points are already normalized, so the loop starts without any inversion
+         let _p = p.point;
+         let mut _q: EcPoint<T::G2>  = q.point;
          let qx = _q.x;
          let qy = _q.y;
-         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.abs() as u128))[1..];
+         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.unsigned_abs()))[1..];
          let idx = if self.g2.consts.twist_type == 'D' {(0,2,1)} else {(2,1,0)};
          for i in _loop{ f = f.sqr();                                
-                              let l = double_jacobian_for_miller(&mut _q,&_p.x,&_p.y);                                                                                                                                          
+                              let l = evaluate_line_at(&double_jacobian_for_miller(&mut _q),&_p.x,&_p.y);                                                                                                                                          
                               f = f.sparse_multiply(&[ l[idx.0].content(),
                                                             l[idx.1].content(),
                                                             l[idx.2].content()],self.g2.consts.twist_type);                                                    
                               if *i != 0 { let l = 
-                                                if *i==1 {add_jacobian_for_miller(&mut _q, &qx, &qy, &_p.x, &_p.y)}
-                                                else {add_jacobian_for_miller(&mut _q, &qx, &qy.negate(), &_p.x,&_p.y)} ;                                                                                                                                                                                                         
+                                                if *i==1 {evaluate_line_at(&add_jacobian_for_miller(&mut _q, &qx, &qy), &_p.x, &_p.y)}
+                                                else {evaluate_line_at(&add_jacobian_for_miller(&mut _q, &qx, &qy.negate()), &_p.x,&_p.y)} ;                                                                                                                                                                                                         
                                                 f = f.sparse_multiply(&[  l[idx.0].content(),
                                                                               l[idx.1].content(),
                                                                               l[idx.2].content()],self.g2.consts.twist_type);
@@ -306,40 +408,298 @@ impl <const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:u
                                     
                             }
          f        
-         }      
+         }
+
+      pub fn paire_affine(&self, p:&G1Affine<R,S,N,P,MAX_COEFS_COUNT1>, q:&G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>) -> Gt<N,P,PRAMASIZE,T::GT>
+      {
+         Gt::from_miller_loop(&self.miller_loop_affine(p, q), self.hard_part)
+      }
+
+      pub fn multi_paire_affine(&self, p_list:&[G1Affine<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>]) -> Gt<N,P,PRAMASIZE,T::GT>
+      {
+         if p_list.len()!=q_list.len() {panic!("Incompatible number of elements from G1 and G2 ... ")}
+         else {Gt::from_miller_loop(&self.multi_miller_loop_affine(p_list, q_list), self.hard_part)}
+      }
+
+      pub fn multi_miller_loop_affine(&self, p_list:&[G1Affine<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>]) -> T::GT
+      {
+         //  Product of the Miller loops of all the pairs (of the same size) : from AFFINE_MILLER_THRESHOLD pairs, the inversions shared
+         //  by all the pairs make the affine coordinates cheaper than the Jacobian ones
+         if p_list.len() >= AFFINE_MILLER_THRESHOLD {self.multi_miller_loop_batch_affine(p_list, q_list)}
+         else {self.multi_miller_loop_jacobian(p_list, q_list)}
+      }
+
+      pub fn multi_miller_loop_batch_affine(&self, p_list:&[G1Affine<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>]) -> T::GT
+      {
+         //  Shared Miller loop keeping all the points T_i = [m]Q_i in affine coordinates : at every step, the slopes of the n tangents (or chords)
+         //  need the inverses of 2y(T_i) (or of x(Q_i) - x(T_i)), computed together with a single inversion in the G2 field (Montgomery's trick).
+         //  For Q_i in G2, T_i never meets ±Q_i (m stays in ]1,|u|[), so the denominators are non-zero, but points decoded without the sub-group
+         //  check may give 2y(T_i) = 0 or x(T_i) = x(Q_i) : the whole product is then computed by the Jacobian loop. Pairs with an infinity point are skipped
+         let mut f = self.gt.one();
+         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.unsigned_abs()))[1..];
+         let (_plist, qfix) : (Vec<_>, Vec<_>) = p_list.iter().zip(q_list.iter()).filter(|(p, q)| !p.is_infinit() && !q.is_infinit())
+                                                       .map(|(p, q)| (p.point, q.point)).unzip();
+         let mut _qlist = qfix.clone();
+         let mut inverses = Vec::with_capacity(_qlist.len());
+         let idx = if self.g2.consts.twist_type == 'D' {(0,2,1)} else {(2,1,0)};
+         for ib in _loop
+               {  f = f.sqr();
+                  inverses.clear();
+                  inverses.extend(_qlist.iter().map(|q| q.y.double()));
+                  if inverses.iter().any(|d| d.is_zero()) {return self.multi_miller_loop_jacobian(p_list, q_list)}
+                  T::G2::batch_invert_vartime(&mut inverses);
+                  for i in 0.._qlist.len() 
+                        {  let l = evaluate_line_at(&double_affine_for_miller(&mut _qlist[i], &inverses[i]),&_plist[i].x,&_plist[i].y);
+                           f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                        }
+                  if *ib != 0 { inverses.clear();
+                                inverses.extend(_qlist.iter().zip(qfix.iter()).map(|(q, q0)| q0.x.substract(&q.x)));
+                                if inverses.iter().any(|d| d.is_zero()) {return self.multi_miller_loop_jacobian(p_list, q_list)}
+                                T::G2::batch_invert_vartime(&mut inverses);
+                                for i in 0.._qlist.len() 
+                                 {  let _qy =if *ib==1 {qfix[i].y} else {qfix[i].y.negate()};
+                                    let l = evaluate_line_at(&add_affine_for_miller(&mut _qlist[i], &qfix[i].x, &_qy, &inverses[i]), &_plist[i].x, &_plist[i].y);
+                                    f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                                 } 
+                              }
+               }
+         f
+      }
+
+      pub fn multi_miller_loop_jacobian(&self, p_list:&[G1Affine<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Affine<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>]) -> T::GT
+      {
+         //  Shared Miller loop with the Jacobian steps (no inversion), sharing the squarings of the accumulator.
+         //  Pairs with an infinity point contribute with the neutral element of GT, so they are skipped
+         let mut f = self.gt.one();
+         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.unsigned_abs()))[1..];
+         let mut _plist = Vec::<EcPoint<FieldElement<N,P>>>::new();                     
+         let mut _qlist  = Vec::<EcPoint<T::G2>>::new();
+         for (p, q) in p_list.iter().zip(q_list.iter()).filter(|(p, q)| !p.is_infinit() && !q.is_infinit()) {_plist.push(p.point);
+                                                                                                              _qlist.push(q.point)};
+         let qfix =_qlist.clone();                    
+         let idx = if self.g2.consts.twist_type == 'D' {(0,2,1)} else {(2,1,0)};
+         for ib in _loop
+               {  f = f.sqr();                                
+                  for i in 0.._qlist.len() 
+                        {  let l = evaluate_line_at(&double_jacobian_for_miller(&mut _qlist[i]),&_plist[i].x,&_plist[i].y);
+                           f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                        }   
+                  if *ib != 0 { for i in 0.._qlist.len() 
+                                 {  let _qy =if *ib==1 {qfix[i].y} else {qfix[i].y.negate()};
+                                    let l = 
+                                    evaluate_line_at(&add_jacobian_for_miller(&mut _qlist[i], &qfix[i].x,&_qy), &_plist[i].x, &_plist[i].y);
+                                    f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
+                                 } 
+                              }
+                           }
+         f
+      }
+
+      pub fn multi_paire_parallel(&self, p_list:&[G1Element<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>], threads :usize) -> Gt<N,P,PRAMASIZE,T::GT>
+      {
+         match self.try_multi_paire_parallel(p_list, q_list, threads) { Ok(f) => f,
+                                                                        Err(e) => panic!("{}",e)}
+      }
+
+      pub fn try_multi_paire_parallel(&self, p_list:&[G1Element<R,S,N,P,MAX_COEFS_COUNT1>], q_list:&[G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>], threads :usize) -> Result<Gt<N,P,PRAMASIZE,T::GT>, PairingError>
+      {
+         //  Multi-pairing with the pairs split in chunks, whose Miller loops are evaluated concurrently (one std::thread per chunk).
+         //  The partial products are multiplied in the order of the chunks and followed by a single final exponentiation, so the
+         //  result does not depend on the scheduling. Small inputs (or threads <= 1) keep the serial shared loop
+         if p_list.len() != q_list.len() {return Err(PairingError::LengthMismatch { left: p_list.len(), right: q_list.len() })}
+         let (p_list, q_list) = (G1Element::batch_to_affine_vartime(p_list), G2Element::batch_to_affine_vartime(q_list));
+         if threads <= 1 || p_list.len() < PARALLEL_PAIRING_THRESHOLD {return Ok(self.multi_paire_affine(&p_list, &q_list))}
+         let chunk = p_list.len().div_ceil(threads);
+         let partials : Vec<T::GT> = thread::scope(|s| {
+                     let handles : Vec<_> = p_list.chunks(chunk).zip(q_list.chunks(chunk))
+                                                  .map(|(p, q)| s.spawn(move || self.multi_miller_loop_affine(p, q)))
+                                                  .collect();
+                     handles.into_iter().map(|h| h.join().unwrap()).collect()
+                 });
+         Ok(Gt::from_miller_loop(&partials.iter().skip(1).fold(partials[0], |acc, f| acc.multiply(f)), self.hard_part))
+      }
+
+      pub fn pow_gls(&self, g :&Gt<N,P,PRAMASIZE,T::GT>, scalar :&FieldElement<R,S>) -> Gt<N,P,PRAMASIZE,T::GT>
+      {
+         // Gt::pow_gls with the GLS decomposer of the engine's G2 constants
+         g.pow_gls(scalar, self.g2.consts.gls_decomposer)
+      }
+
+      pub fn prepared_from_bytearray(&self, inbytes :&[u8]) -> G2Prepared<N,P,PRAMASIZE,T::G2>
+      {
+         match self.try_prepared_from_bytearray(inbytes) { Ok(p) => p,
+                                                           Err(e) => panic!("{}",e)}
+      }
+
+      pub fn try_prepared_from_bytearray(&self, inbytes :&[u8]) -> Result<G2Prepared<N,P,PRAMASIZE,T::G2>, PairingError>
+      {
+         self.try_prepared_from_bytearray_with(inbytes, DecodingMode::default())
+      }
+
+      pub fn try_prepared_from_bytearray_with(&self, inbytes :&[u8], mode :DecodingMode) -> Result<G2Prepared<N,P,PRAMASIZE,T::G2>, PairingError>
+      {
+         //  De-serialization of a prepared point (as produced by G2Prepared::to_bytearray) : Q is checked according to the decoding mode,
+         //  and its lines are recomputed and compared to the serialized ones, so that a tampered cache is rejected. The Unchecked mode
+         //  only checks the encoding (size, flags and canonical coefficients) and keeps the serialized lines, for trusted caches only
+         let elementsize = self.g2.base_field.basefield().size_in_bytes() * self.g2.getorder();
+         let expected = 1 + (2 + 3 * self.g2.prepared_lines_count()) * elementsize;
+         if inbytes.is_empty() {return Err(PairingError::BadLength { expected, found: 0 })}
+         match inbytes[0] {  0x40 => { if inbytes.len() != 1 {return Err(PairingError::InvalidInfinity)}
+                                       Ok(G2Prepared { point: EcPoint { x: self.g2.one(), y: self.g2.one(), z: self.g2.zero() }, lines: Vec::new(), 
+                                                       infinity: true, field: PhantomData })
+                                     },
+                             0x00 => { if inbytes.len() != expected {return Err(PairingError::BadLength { expected, found: inbytes.len() })}
+                                       let mut elements = Vec::with_capacity((expected - 1) / elementsize);
+                                       for chunk in inbytes[1..].chunks(elementsize) { elements.push(T::G2::from_g2_element(&self.g2.base_field.try_from_i2osp_bytearray(chunk)?)) }
+                                       let q = G2Affine { point: EcPoint { x: elements[0], y: elements[1], z: self.g2.one() }, consts: self.g2.consts };
+                                       if mode != DecodingMode::Unchecked && !q.is_on_curve() {return Err(PairingError::NotOnCurve)}
+                                       if mode == DecodingMode::Subgroup && !q.is_torsion() {return Err(PairingError::NotInSubgroup)}
+                                       let lines : Vec<[T::G2;3]> = elements[2..].chunks(3).map(|l| [l[0], l[1], l[2]]).collect();
+                                       if mode == DecodingMode::Unchecked {return Ok(G2Prepared { point: q.point, lines, infinity: false, field: PhantomData })}
+                                       let prepared = self.prepare_g2(&q.to_projective());
+                                       if prepared.lines.iter().zip(&lines).all(|(a, b)| a.iter().zip(b).all(|(x, y)| x.equal(y))) {Ok(prepared)}
+                                       else {Err(PairingError::PreparedLinesMismatch)}
+                                     },
+                             _ => Err(PairingError::BadFlagBits)
+                          }
+      }
+
+      pub fn try_prepared_from_base64(&self, input :&str) -> Result<G2Prepared<N,P,PRAMASIZE,T::G2>, PairingError>
+      {
+         let decoded_bytes = general_purpose::STANDARD.decode(input).map_err(|_| PairingError::InvalidBase64)?;
+         self.try_prepared_from_bytearray(&decoded_bytes)
+      }
+
+      pub fn accumulator(&self) -> PairingAccumulator<'_,R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T>
+      {
+         PairingAccumulator { engine: self, p_list: Vec::new(), q_list: Vec::new(), prepared_p: Vec::new(), prepared_q: Vec::new() }
+      }
+}
+
+pub struct PairingAccumulator <'a, const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+         {  // Pairs of a product of pairings, collected one at a time and evaluated together by finalize()
+            engine   : &'a Pairings<R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T>,
+            p_list   : Vec<G1Element<R,S,N,P,MAX_COEFS_COUNT1>>,
+            q_list   : Vec<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>>,
+            prepared_p : Vec<G1Element<R,S,N,P,MAX_COEFS_COUNT1>>,
+            prepared_q : Vec<&'a G2Prepared<N,P,PRAMASIZE,T::G2>>}
+
+impl <'a, const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>> PairingAccumulator<'a,R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T> {
+      pub fn add_pair(&mut self, p:&G1Element<R,S,N,P,MAX_COEFS_COUNT1>, q:&G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>)
+      {
+         //  Pairs with an infinity point contribute with the neutral element of GT, so they are not kept
+         if !p.point.is_infinit() && !q.point.is_infinit() { self.p_list.push(*p);
+                                                             self.q_list.push(*q); }
+      }
+
+      pub fn add_prepared(&mut self, p:&G1Element<R,S,N,P,MAX_COEFS_COUNT1>, q:&'a G2Prepared<N,P,PRAMASIZE,T::G2>)
+      {
+         if !p.point.is_infinit() && !q.infinity { self.prepared_p.push(*p);
+                                                   self.prepared_q.push(q); }
+      }
+
+      pub fn len(&self) -> usize
+      {
+         self.p_list.len() + self.prepared_p.len()
+      }
+
+      pub fn is_empty(&self) -> bool
+      {
+         self.len() == 0
+      }
+
+      pub fn miller_loop(&self) -> T::GT
+      {
+         //  Product of the Miller loops of all the pairs : the G1 points of both kinds of pairs are normalized with a single inversion
+         //  (and the G2 ones with another), then the regular and the prepared pairs are evaluated by their shared loops
+         let points = G1Element::batch_to_affine_vartime(&[self.p_list.as_slice(), self.prepared_p.as_slice()].concat());
+         let (regular, prepared) = points.split_at(self.p_list.len());
+         let mut f = self.engine.multi_miller_loop_affine(regular, &G2Element::batch_to_affine_vartime(&self.q_list));
+         if !prepared.is_empty() { let p_list : Vec<_> = prepared.iter().map(|p| p.point).collect();
+                                   f = f.multiply(&self.engine.multi_miller_loop_prepared(&p_list, &self.prepared_q));
+                                 }
+         f
+      }
+
+      pub fn finalize(self) -> Gt<N,P,PRAMASIZE,T::GT>
+      {
+         //  The whole product costs a single final exponentiation
+         Gt::from_miller_loop(&self.miller_loop(), self.engine.hard_part)
+      }
+}
+
+impl <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>> PairingsEngine 
+      for Pairings<R,S,N,P,MAX_COEFS_COUNT1,PRAMASIZE,MAX_COEFS_COUNT2,T> {
+        type GT = Gt<N,P,PRAMASIZE,T::GT>;
+        type MillerOutput = T::GT;
+        type G1 = G1Element<R,S,N,P,MAX_COEFS_COUNT1>;
+        type G2 = G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>;     
+        type G2Prepared = G2Prepared<N,P,PRAMASIZE,T::G2>;
+
+
+      fn miller_loop(&self, p:&Self::G1, q:&Self::G2) -> Self::MillerOutput 
+      {
+         self.miller_loop_affine(&p.to_affine_vartime(), &q.to_affine_vartime())
+      }
+
+      fn prepare_g2(&self, q:&Self::G2) -> Self::G2Prepared
+      {
+         //  Precomputation of all the line coefficients of the Miller loop for a fixed Q (generator, long-lived public keys, ...)
+         //  Lines are stored in the order they are consumed by the loop: doubling step, then addition step for non-zero NAF digits
+         if q.point.is_infinit() {return G2Prepared { point: q.point, lines: Vec::new(), infinity: true, field: PhantomData }}
+         let _qa = q.to_affine_vartime().point;
+         let mut _q = _qa;
+         let qx = _q.x;
+         let qy = _q.y;
+         let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.unsigned_abs()))[1..];
+         let mut lines = Vec::with_capacity(self.g2.prepared_lines_count());
+         for i in _loop { lines.push(double_jacobian_for_miller(&mut _q));
+                          if *i != 0 { lines.push(if *i==1 {add_jacobian_for_miller(&mut _q, &qx, &qy)}
+                                                  else {add_jacobian_for_miller(&mut _q, &qx, &qy.negate())}) }
+                        }
+         G2Prepared { point: _qa, lines, infinity: false, field: PhantomData }
+      }
+
+      fn miller_loop_prepared(&self, p:&Self::G1, q:&Self::G2Prepared) -> Self::MillerOutput
+      {
+         if q.infinity || p.point.is_infinit() {return self.gt.one()}
+         self.multi_miller_loop_prepared(&[p.to_affine_vartime().point], &[q])
+      }
+
+      fn multi_paire_prepared(&self, p_list:&[Self::G1], q_list:&[Self::G2Prepared]) -> Self::GT
+      {
+         match self.try_multi_paire_prepared(p_list, q_list) { Ok(f) => f,
+                                                               Err(e) => panic!("{}",e)}
+      }
+
+      fn try_multi_paire_prepared(&self, p_list:&[Self::G1], q_list:&[Self::G2Prepared]) -> Result<Self::GT, PairingError>
+      {
+         if p_list.len() != q_list.len() {return Err(PairingError::LengthMismatch { left: p_list.len(), right: q_list.len() })}
+         // Pairs with an infinity point contribute with the neutral element of GT, so they are skipped
+         let (_plist, _qlist) : (Vec<_>, Vec<_>) = p_list.iter().zip(q_list.iter())
+                                                          .filter(|(p, q)| !q.infinity && !p.point.is_infinit())
+                                                          .map(|(p, q)| (*p, q)).unzip();
+         let _plist : Vec<_> = G1Element::batch_to_affine_vartime(&_plist).iter().map(|p| p.point).collect();
+         Ok(Gt::from_miller_loop(&self.multi_miller_loop_prepared(&_plist, &_qlist), self.hard_part))
+      }
 
       fn paire(&self, p:&Self::G1, q:&Self::G2) -> Self::GT 
       {
-         self.miller_loop(p, q).final_exponentiation()
+         Gt::from_miller_loop(&self.miller_loop(p, q), self.hard_part)
       }            
 
       fn multi_paire(&self, p_list:&[Self::G1], q_list:&[Self::G2]) -> Self::GT 
       {
-         if p_list.len()!=q_list.len() {panic!("Incompatible number of elements from G1 and G2 ... ")}
-         else {      let mut f = self.gt.one();
-                     let _loop = &<u128 as Exponent<N>>::to_naf(&(self.g2.consts.u.abs() as u128))[1..];
-                     let mut _plist = Vec::<EcPoint<FieldElement<N>>>::new();                     
-                     let mut _qlist  = Vec::<EcPoint<ExtFieldG2Element<N, PRAMASIZE>>>::new();
-                     for q in q_list {_qlist.push(q.to_affine().point)};
-                     for p in p_list {_plist.push(p.to_affine().point)};
-                     let qfix =_qlist.clone();                    
-                     let idx = if self.g2.consts.twist_type == 'D' {(0,2,1)} else {(2,1,0)};
-                     for ib in _loop
-                           {  f = f.sqr();                                
-                              for i in 0..q_list.len() 
-                                    {  let l = double_jacobian_for_miller(&mut _qlist[i],&_plist[i].x,&_plist[i].y);
-                                       f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
-                                    }   
-                              if *ib != 0 { for i in 0..q_list.len() 
-                                             {  let _qy =if *ib==1 {qfix[i].y} else {qfix[i].y.negate()};
-                                                let l = 
-                                                add_jacobian_for_miller(&mut _qlist[i], &qfix[i].x,&_qy, &_plist[i].x, &_plist[i].y);
-                                                f = f.sparse_multiply(&[ l[idx.0].content(),l[idx.1].content(),l[idx.2].content()],self.g2.consts.twist_type);
-                                             } 
-                                          }
-                                       }
-                     f.final_exponentiation()        
-              }
+         //  All the inputs are normalized with one inversion in Fp and one in the G2 extension
+         self.multi_paire_affine(&G1Element::batch_to_affine_vartime(p_list), &G2Element::batch_to_affine_vartime(q_list))
+      }
 
+      fn pairing_check(&self, pairs:&[(Self::G1, Self::G2)]) -> bool
+      {
+         //  Checks that Π e(Pi,Qi) = 1 (e.g. e(σ,-G2).e(H(m),pk) = 1 for BLS signatures) : one shared Miller loop and one final exponentiation,
+         //  whose result is only compared to the neutral element
+         let mut accumulator = self.accumulator();
+         for (p, q) in pairs { accumulator.add_pair(p, q) }
+         accumulator.finalize().is_one()
       }
  }
\ No newline at end of file
@@ -2,194 +2,504 @@
 // EEDIS-Laboratory. UDL-University. Algeria
 // During May 2024.
 
-use num_bigint::{BigInt, BigUint, ToBigInt};
-use num_traits::{Num, One, ToPrimitive, Zero};
+use once_cell::sync::OnceCell;
 use crate::curves::curve_arithmetics::EcPoint;
 use crate::curves::g1::{G1SwuIsogeniesConsts,G1Consts};
 use crate::curves::g2::{G2Consts, G2SwuIsogeniesConsts};
-use crate::extensions::g2_extfields::{ExtFieldG2Element, ExtG2Field};
-use crate::fields::prime_fields::{FieldElement,FieldParams};
-use crate::tools::arithmetic_interface::ArithmeticOperations;
+use crate::extensions::ext_fields::ExFieldConsts;
+use crate::extensions::towers::{TowerElement, G2Fp2_1, G2Fp4_1, G2Fp4_2, G2Fp8_1, G2Fp8_2, G2Fp8_3};
+use crate::extensions::towering1::{fp2::Fp2Element as Fp2Element_1, fp4::Fp4Element as Fp4Element_1, fp8::Fp8Element as Fp8Element_1};
+use crate::extensions::towering2::{fp4::Fp4Element as Fp4Element_2, fp8::Fp8Element as Fp8Element_2};
+use crate::extensions::towering3::fp8::Fp8Element as Fp8Element_3;
+use crate::fields::prime_fields::{ConfigParams, FieldConfig, FieldElement, FieldParams};
+use crate::tools::{decomposition::LatticeDecomposer, hashs::Expander};
 use super::paramlist::CurveParams;
-use crate::{extensions::ext_fields::ExFieldConsts, fields::prime_fields::PrimeField};
-
-pub fn build_field_params<'a ,const NUMLIMBS:usize>(inputfield : &str) -> FieldParams<NUMLIMBS>
-            {   
-                fn mod_inverse(a: &BigInt, m: &BigInt) -> Option<BigInt> {  // Extended Euclidean Algorithm for finding modular inverse
-                    let (mut old_r, mut r) = (a.clone(), m.clone());
-                    let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
-                    let (mut old_t, mut t) = (BigInt::zero(), BigInt::one());
-                
-                    while !r.is_zero() {
-                        let quotient = &old_r / &r;
-                        let temp_r = r.clone();
-                        r = &old_r - &quotient * &r;
-                        old_r = temp_r;
-                
-                        let temp_s = s.clone();
-                        s = &old_s - &quotient * &s;
-                        old_s = temp_s;
-                
-                        let temp_t = t.clone();
-                        t = &old_t - &quotient * &t;
-                        old_t = temp_t;
+
+// Compile-time derivation of the parameters of a prime field from its hexadecimal modulo. 
+// Only const fn are used (while loops on little-endian limbs), so that the parameters of every field are evaluated by the compiler
+
+const fn hex_to_limbs<const NUMLIMBS:usize>(input : &str) -> [u64;NUMLIMBS]
+{   let bytes = input.as_bytes();
+    let start = if bytes.len() > 1 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {2} else {0};
+    let mut result = [0u64;NUMLIMBS];
+    let mut i = bytes.len();
+    let mut position = 0;
+    while i > start {   i -= 1;
+                        let digit = match bytes[i] {  b'0'..=b'9' => bytes[i] - b'0',
+                                                      b'a'..=b'f' => bytes[i] - b'a' + 10,
+                                                      b'A'..=b'F' => bytes[i] - b'A' + 10,
+                                                      _ => panic!("Invalid hexadecimal string ...") };
+                        if position / 64 < NUMLIMBS { result[position / 64] |= (digit as u64) << (position % 64); }
+                        else if digit != 0 { panic!("The value does not fit in the given number of limbs ..."); }
+                        position += 4;
                     }
-                
-                    if old_r == BigInt::one() {
-                        let mut inverse = old_s % m.clone();
-                        if inverse < BigInt::zero() { inverse = inverse + m.clone();
-                                                     }
-                        Some(inverse)
-                    } else {
-                        None // No modular inverse exists
-                    }}
-                
-                let mut mod_on_limbs = [0;NUMLIMBS];
-                let bigint_modulo:BigInt = BigInt::from_str_radix(&inputfield[2..], 16).unwrap();                
-                let mask           = (BigInt::one() << 64) - BigInt::one();                
-                let mut num_bits      = bigint_modulo.bits();
-                num_bits = ((num_bits / 64) +  ((num_bits %64 !=0) as u64)) * 64;                    
-                let mut one = [0;NUMLIMBS];
-                let mut rsquare = [0;NUMLIMBS];
-                let mut modplus1div4 =[0;NUMLIMBS];
-                let mut inv2 = [0;NUMLIMBS];
-                let mut threshold = [0;NUMLIMBS];
-                let mut tonelli_s = [0;NUMLIMBS];
-                let mut tonelli_g = [0;NUMLIMBS];
-                let r  = (BigInt::one() << num_bits) % &bigint_modulo;
-                let r2 = (BigInt::one() << num_bits).pow(2) % &bigint_modulo;
-                let mp1d4 = (&bigint_modulo + BigInt::one()) >> 2;
-                let in2= (mod_inverse(&(BigInt::one()+BigInt::one()),&bigint_modulo).unwrap()* &r) % &bigint_modulo;
-                let qp = mod_inverse(&(&(BigInt::one() << num_bits)-&bigint_modulo),&(BigInt::one() << num_bits)).unwrap();
-                let th = (&bigint_modulo - BigUint::one().to_bigint().unwrap()) >> 1;
-                for i in 0..NUMLIMBS {   mod_on_limbs[i] = (((&bigint_modulo & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                one[i] = (((&r & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                rsquare[i] = (((&r2 & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                modplus1div4[i] = (((&mp1d4 & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                inv2[i] = (((&in2 & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                threshold[i] = (((&th & (&mask << (i * 64))) >> (i * 64)) as BigInt).to_u64().unwrap();
-                                                };          
-                let qprime = (&qp  & &mask).to_u128().unwrap();
-                let mut sqrtid: i16 = if (&bigint_modulo % 4u8).to_u8().unwrap() == 3 {-1} else {1};
-                if sqrtid ==1 { let mut s = (bigint_modulo.clone()-BigInt::one()).to_biguint().unwrap();
-                                sqrtid = 0;
-                                while s.bit(0) == false {s=s >>1; sqrtid = sqrtid +1}                                
-                                let mut n = BigUint::one()+BigUint::one();
-                                let big_modulo_uint =bigint_modulo.to_biguint().unwrap();
-                                let big_m1_div2 = (&big_modulo_uint-BigUint::one()) >> 1;
-                                while n.modpow(&big_m1_div2, &big_modulo_uint) != &big_modulo_uint-BigUint::one() {n = n + BigUint::one()};
-                                let g = n.modpow(&s, &big_modulo_uint);
-                                for i in 0..NUMLIMBS 
-                                    {tonelli_s[i] = (((&((&s-BigUint::one())>>1) & (&(mask.to_biguint().unwrap()) << (i * 64))) >> (i * 64)) ).to_u64().unwrap();
-                                     tonelli_g[i] = (((&g & (&(mask.to_biguint().unwrap()) << (i * 64))) >> (i * 64)) ).to_u64().unwrap();
+    result
+}
+
+const fn limbs_geq<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], b : &[u64;NUMLIMBS]) -> bool
+{   let mut i = NUMLIMBS;
+    while i > 0 { i -= 1;
+                  if a[i] != b[i] {return a[i] > b[i]}
+                }
+    true
+}
+
+const fn limbs_add<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], b : &[u64;NUMLIMBS]) -> ([u64;NUMLIMBS], bool)
+{   let mut result = [0u64;NUMLIMBS];
+    let mut carry = 0u128;
+    let mut i = 0;
+    while i < NUMLIMBS { let t = a[i] as u128 + b[i] as u128 + carry;
+                         result[i] = t as u64;
+                         carry = t >> 64;
+                         i += 1;
+                       }
+    (result, carry != 0)
+}
+
+const fn limbs_sub<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], b : &[u64;NUMLIMBS]) -> [u64;NUMLIMBS]
+{   let mut result = [0u64;NUMLIMBS];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < NUMLIMBS { let (t1, b1) = a[i].overflowing_sub(b[i]);
+                         let (t2, b2) = t1.overflowing_sub(borrow);
+                         result[i] = t2;
+                         borrow = (b1 | b2) as u64;
+                         i += 1;
+                       }
+    result
+}
+
+const fn limbs_shr<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], shift :usize, top :u64) -> [u64;NUMLIMBS]
+{   // Right shift by 0 < shift < 64 bits, top being shifted into the most significant limb
+    let mut result = [0u64;NUMLIMBS];
+    let mut i = 0;
+    while i < NUMLIMBS { let next = if i + 1 < NUMLIMBS {a[i+1]} else {top};
+                         result[i] = (a[i] >> shift) | (next << (64 - shift));
+                         i += 1;
+                       }
+    result
+}
+
+const fn limbs_bits<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS]) -> usize
+{   let mut i = NUMLIMBS;
+    while i > 0 { i -= 1;
+                  if a[i] != 0 {return 64 * i + 64 - a[i].leading_zeros() as usize}
+                }
+    0
+}
+
+const fn mod_double<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], modulo : &[u64;NUMLIMBS]) -> [u64;NUMLIMBS]
+{   let (result, carry) = limbs_add(a, a);
+    if carry || limbs_geq(&result, modulo) {limbs_sub(&result, modulo)} else {result}
+}
+
+const fn mont_mul<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], b : &[u64;NUMLIMBS], modulo : &[u64;NUMLIMBS], qprime :u64) -> [u64;NUMLIMBS]
+{   // Textbook CIOS Montgomery multiplication (https://www.microsoft.com/en-us/research/wp-content/uploads/1998/06/97Acar.pdf)
+    let mut result = [0u64;NUMLIMBS];
+    let mut tn = 0u64;
+    let mut i = 0;
+    while i < NUMLIMBS { let mut carry = 0u128;
+                         let mut j = 0;
+                         while j < NUMLIMBS { let t = result[j] as u128 + a[j] as u128 * b[i] as u128 + carry;
+                                              result[j] = t as u64;
+                                              carry = t >> 64;
+                                              j += 1;
+                                            }
+                         let t = tn as u128 + carry;
+                         tn = t as u64;
+                         let tnp1 = (t >> 64) as u64;
+                         let m = result[0].wrapping_mul(qprime);
+                         carry = (result[0] as u128 + m as u128 * modulo[0] as u128) >> 64;
+                         j = 1;
+                         while j < NUMLIMBS { let t = result[j] as u128 + m as u128 * modulo[j] as u128 + carry;
+                                              result[j-1] = t as u64;
+                                              carry = t >> 64;
+                                              j += 1;
+                                            }
+                         let t = tn as u128 + carry;
+                         result[NUMLIMBS-1] = t as u64;
+                         tn = tnp1 + (t >> 64) as u64;
+                         i += 1;
+                       }
+    if tn != 0 || limbs_geq(&result, modulo) {limbs_sub(&result, modulo)} else {result}
+}
+
+const fn mod_small<const NUMLIMBS:usize>(a : &[u64;NUMLIMBS], n :u64) -> u64
+{   let mut rem = 0u128;
+    let mut i = NUMLIMBS;
+    while i > 0 { i -= 1;
+                  rem = ((rem << 64) | a[i] as u128) % n as u128;
+                }
+    rem as u64
+}
+
+const fn jacobi(mut a :u64, mut n :u64) -> i8
+{   // Jacobi symbol (a/n) for an odd n (binary algorithm with quadratic reciprocity)
+    let mut result = 1;
+    a %= n;
+    while a != 0 {  while a & 1 == 0 { a /= 2;
+                                       if n % 8 == 3 || n % 8 == 5 {result = -result}
                                      }
-                                }
-                let optimize_sqr = mod_on_limbs[NUMLIMBS-1]< ((1<<62)-1);
-                let optimize_mul =  (mod_on_limbs[NUMLIMBS-1] & (1 <<63)) == 0;                
-                FieldParams{    numlimbs: NUMLIMBS,
-                                modulo:   mod_on_limbs,
-                                modulo_as_strhex:inputfield,
-                                one,
-                                rsquare,
-                                modplus1div4,
-                                inv2 ,
-                                qprime ,
-                                sqrtid ,
-                                zero:[0;NUMLIMBS],
-                                optimize_sqr,
-                                optimize_mul,
-                                sig_theshold : threshold,
-                                num_of_bits : bigint_modulo.bits() as usize,
-                                tonelli_params : [tonelli_g ,tonelli_s]
-                            }
-            }
+                    let t = a; a = n; n = t;
+                    if a % 4 == 3 && n % 4 == 3 {result = -result}
+                    a %= n;
+                 }
+    if n == 1 {result} else {0}
+}
+
+const fn smallest_non_residue<const NUMLIMBS:usize>(modulo : &[u64;NUMLIMBS]) -> u64
+{   // (n/p) = (p/n) for p = 1 mod 4, so the Legendre symbol is computed on the (small) reduction of p modulo n
+    let mut n = 2u64;
+    loop {  let symbol = if n & 1 == 0 { let p8 = modulo[0] % 8;
+                                         let two = if p8 == 1 || p8 == 7 {1} else {-1};
+                                         let mut odd = n; let mut s = 1;
+                                         while odd & 1 == 0 {odd /= 2; s *= two}
+                                         if odd == 1 {s} else {s * jacobi(mod_small(modulo, odd), odd)}
+                                       }
+                         else { jacobi(mod_small(modulo, n), n) };
+            if symbol == -1 {return n}
+            n += 1;
+         }
+}
+
+pub const fn build_field_params<const NUMLIMBS:usize>(inputfield : &'static str) -> FieldParams<'static, NUMLIMBS>
+{   // Montgomery radix is 2^(64*NUMLIMBS): the CIOS reduction runs over all the limbs, including padding ones
+    let modulo = hex_to_limbs::<NUMLIMBS>(inputfield);
+    let mut one = [0u64;NUMLIMBS];
+    one[0] = 1;
+    let mut i = 0;
+    while i < 64 * NUMLIMBS { one = mod_double(&one, &modulo); i += 1; }
+    let mut rsquare = one;
+    i = 0;
+    while i < 64 * NUMLIMBS { rsquare = mod_double(&rsquare, &modulo); i += 1; }
+    // qprime = -1/p mod 2^64 (Newton iterations on the lowest limb)
+    let mut inverse = 1u64;
+    i = 0;
+    while i < 6 { inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulo[0].wrapping_mul(inverse))); i += 1; }
+    let qprime = inverse.wrapping_neg() as u128;
+    let mut unit = [0u64;NUMLIMBS];
+    unit[0] = 1;
+    let modminus1 = limbs_sub(&modulo, &unit);
+    let (modplus1, carry) = limbs_add(&modulo, &unit);
+    let modplus1div4 = limbs_shr(&limbs_shr(&modplus1, 1, carry as u64), 1, 0);
+    // Montgomery form of 1/2 : (R + p)/2 when R mod p is odd
+    let inv2 = if one[0] & 1 == 0 {limbs_shr(&one, 1, 0)} 
+               else {let (sum, carry) = limbs_add(&one, &modulo); limbs_shr(&sum, 1, carry as u64)};
+    let sig_theshold = limbs_shr(&modminus1, 1, 0);
+    let mut tonelli_params = [[0u64;NUMLIMBS];2];
+    let sqrtid : i16 = if modulo[0] % 4 == 3 {-1} 
+                       else { // p - 1 = 2^sqrtid * s, g = n^s for the smallest non-residue n
+                              let mut s = modminus1;
+                              let mut c1 = 0;
+                              while s[0] & 1 == 0 {s = limbs_shr(&s, 1, 0); c1 += 1;}
+                              tonelli_params[1] = limbs_shr(&limbs_sub(&s, &unit), 1, 0);
+                              let mut n = [0u64;NUMLIMBS];
+                              n[0] = smallest_non_residue(&modulo);
+                              let base = mont_mul(&n, &rsquare, &modulo, qprime as u64);
+                              let mut g = one;
+                              let mut bit = limbs_bits(&s);
+                              while bit > 0 { bit -= 1;
+                                              g = mont_mul(&g, &g, &modulo, qprime as u64);
+                                              if (s[bit / 64] >> (bit % 64)) & 1 == 1 {g = mont_mul(&g, &base, &modulo, qprime as u64);}
+                                            }
+                              tonelli_params[0] = mont_mul(&g, &unit, &modulo, qprime as u64);
+                              c1
+                            };
+    FieldParams{    numlimbs: NUMLIMBS,
+                    modulo,
+                    modulo_as_strhex: inputfield,
+                    one,
+                    rsquare,
+                    modplus1div4,
+                    inv2,
+                    qprime,
+                    sqrtid,
+                    zero: [0;NUMLIMBS],
+                    optimize_sqr: modulo[NUMLIMBS-1] < ((1<<62)-1),
+                    optimize_mul: (modulo[NUMLIMBS-1] & (1 <<63)) == 0,
+                    sig_theshold,
+                    num_of_bits: limbs_bits(&modulo),
+                    tonelli_params
+                }
+}
+
+// Compile-time decoding of the curves constants : the hexadecimal values of the parameters list are converted to the Montgomery 
+// form of their field by a multiplication with R^2, so that every preloaded configuration is a plain static (no parsing at run-time)
+
+const fn field_element_from_limbs<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(limbs : &[u64;NUMLIMBS], negative :bool) -> FieldElement<NUMLIMBS,P>
+{   let mont_limbs = mont_mul(limbs, &P::PARAMS.rsquare, &P::PARAMS.modulo, P::PARAMS.qprime as u64);
+    let is_zero = limbs_bits(&mont_limbs) == 0;
+    FieldElement {  fieldparams : ConfigParams::new(),
+                    mont_limbs  : if negative && !is_zero {limbs_sub(&P::PARAMS.modulo, &mont_limbs)} else {mont_limbs}
+                 }
+}
+
+pub const fn field_element_from_hex<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(input : &str) -> FieldElement<NUMLIMBS,P>
+{   // Same as PrimeField::from_hex_str (signed hexadecimal strings), evaluated by the compiler
+    let bytes = input.as_bytes();
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    let (_, digits) = bytes.split_at(if negative {1} else {0});
+    let limbs = match std::str::from_utf8(digits) { Ok(digits) => hex_to_limbs::<NUMLIMBS>(digits),
+                                                    Err(_) => panic!("Invalid hexadecimal string ...")};
+    field_element_from_limbs(&limbs, negative)
+}
+
+const fn field_element_from_small<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(input : i128) -> FieldElement<NUMLIMBS,P>
+{   let mut limbs = [0u64;NUMLIMBS];
+    let magnitude = input.unsigned_abs();
+    limbs[0] = magnitude as u64;
+    if NUMLIMBS > 1 {limbs[1] = (magnitude >> 64) as u64;}
+    field_element_from_limbs(&limbs, input < 0)
+}
+
+const fn field_elements_from_hex<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[&str;COUNT]) -> [FieldElement<NUMLIMBS,P>;COUNT]
+{   let mut result = [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = field_element_from_hex(input[i]); i += 1; }
+    result
+}
+
+const fn field_negate<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(a : &FieldElement<NUMLIMBS,P>) -> FieldElement<NUMLIMBS,P>
+{   let is_zero = limbs_bits(&a.mont_limbs) == 0;
+    FieldElement { fieldparams : ConfigParams::new(), mont_limbs : if is_zero {a.mont_limbs} else {limbs_sub(&P::PARAMS.modulo, &a.mont_limbs)}}
+}
+
+const fn field_multiply<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(a : &FieldElement<NUMLIMBS,P>, b : &FieldElement<NUMLIMBS,P>) -> FieldElement<NUMLIMBS,P>
+{   FieldElement { fieldparams : ConfigParams::new(), mont_limbs : mont_mul(&a.mont_limbs, &b.mont_limbs, &P::PARAMS.modulo, P::PARAMS.qprime as u64)}
+}
+
+const fn field_invert<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>>(a : &FieldElement<NUMLIMBS,P>) -> FieldElement<NUMLIMBS,P>
+{   // Fermat's little theorem a^(p-2) (square and multiply on the public exponent)
+    let mut two = [0u64;NUMLIMBS];
+    two[0] = 2;
+    let exponent = limbs_sub(&P::PARAMS.modulo, &two);
+    let mut result = FieldElement { fieldparams : ConfigParams::new(), mont_limbs : P::PARAMS.one};
+    let mut bit = limbs_bits(&exponent);
+    while bit > 0 { bit -= 1;
+                    result = field_multiply(&result, &result);
+                    if (exponent[bit / 64] >> (bit % 64)) & 1 == 1 {result = field_multiply(&result, a);}
+                  }
+    result
+}
+
+// Reduced lattice bases of the GLV (G1) and GLS (G2) decompositions, as (sign, |b|) limbs in row-major order. They are evaluated
+// at compile time in the preloaded configurations, next to the Babai constants generated by LatticeDecomposer::babai_constants
+
+pub const fn glv_basis<const R:usize>(lambda : &str, r : &str) -> [(bool,[u64;R]);4]
+{   // Rows (λ,-1) and (1,λ+1) for an eigenvalue λ with λ^2 + λ + 1 = r (BLS curves, λ = u^(k/6)-1 up to the sign),
+    // λ being replaced by λ - r when it is not short
+    let lambda = hex_to_limbs::<R>(lambda);
+    let r = hex_to_limbs::<R>(r);
+    let mut one = [0u64;R];
+    one[0] = 1;
+    let negative = limbs_bits(&lambda) > (limbs_bits(&r) >> 1) + 1;
+    let short = if negative {limbs_sub(&r, &lambda)} else {lambda};
+    let next = if negative {limbs_sub(&short, &one)} else {limbs_add(&short, &one).0};
+    [(negative, short), (true, one), (false, one), (negative, next)]
+}
+
+pub const fn gls_basis<const R:usize, const COUNT:usize>(u : i128) -> [(bool,[u64;R]);COUNT]
+{   // m-dimensional basis (COUNT = m*m) for the eigenvalue u of ψ, with r = u^m - u^(m/2) + 1 (BLS12/24/48 with m = 4/8/16) :
+    // rows u*e_i - e_(i+1) for i < m-1, and the last row (1,0,..,-1 (at m/2),..,0,u)
+    let mut m = 1;
+    while m * m < COUNT { m += 1; }
+    let mut abs_u = [0u64;R];
+    abs_u[0] = u.unsigned_abs() as u64;
+    if R > 1 { abs_u[1] = (u.unsigned_abs() >> 64) as u64; }
+    let mut one = [0u64;R];
+    one[0] = 1;
+    let mut basis = [(false, [0u64;R]);COUNT];
+    let mut i = 0;
+    while i < m - 1 { basis[i * m + i] = (u < 0, abs_u);
+                      basis[i * m + i + 1] = (true, one);
+                      i += 1;
+                    }
+    basis[(m - 1) * m] = (false, one);
+    basis[(m - 1) * m + (m >> 1)] = (true, one);
+    basis[m * m - 1] = (u < 0, abs_u);
+    basis
+}
 
-pub fn build_frob_params<'a ,const NUMLIMBS:usize,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize>
-        (input : CurveParams<'a ,NUMFROBCONSTS, MAX_ISO_COEFS_G1 ,G2_ORDER, MAX_ISO_COEFS_G2>,field:&PrimeField<NUMLIMBS>) -> ExFieldConsts<NUMFROBCONSTS,NUMLIMBS>
+pub const fn build_frob_params<'a ,const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize>
+        (input : CurveParams<'a ,NUMFROBCONSTS, MAX_ISO_COEFS_G1 ,G2_ORDER, MAX_ISO_COEFS_G2>) -> ExFieldConsts<NUMFROBCONSTS,NUMLIMBS,P>
 {    
-    let mut result =[field.from_str("0");NUMFROBCONSTS];
-    for i in 0..NUMFROBCONSTS  { result[i] =field.from_hex_str(&input.frob_consts[i]); }
-    ExFieldConsts {frobinus_consts : result, u: input.u, base_qnr :field.from_str(&input.qnr.to_string()) , fb_id:input.frob_id}
-}
-
-pub fn build_g1_params<'a ,const NUMLIMBS:usize,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize,const R:usize>
-                        (input : CurveParams<'a ,NUMFROBCONSTS, MAX_ISO_COEFS_G1 ,G2_ORDER, MAX_ISO_COEFS_G2>,base_field:&PrimeField<NUMLIMBS>,
-                                                                                           r_field:&PrimeField<R>) -> G1Consts<R,NUMLIMBS,MAX_ISO_COEFS_G1>
-    {   let mut xnum : [FieldElement<NUMLIMBS>;MAX_ISO_COEFS_G1] = [base_field.one() ;MAX_ISO_COEFS_G1]; 
-        let mut xden : [FieldElement<NUMLIMBS>;MAX_ISO_COEFS_G1] = [base_field.one();MAX_ISO_COEFS_G1]; 
-        let mut ynum : [FieldElement<NUMLIMBS>;MAX_ISO_COEFS_G1] = [base_field.one();MAX_ISO_COEFS_G1]; 
-        let mut yden : [FieldElement<NUMLIMBS>;MAX_ISO_COEFS_G1] = [base_field.one();MAX_ISO_COEFS_G1]; 
-        for i in 0..MAX_ISO_COEFS_G1 { xnum[i] = base_field.from_hex_str(&input.xnum_g1[i]); 
-                                           xden[i] = base_field.from_hex_str(&input.xden_g1[i]); 
-                                           ynum[i] = base_field.from_hex_str(&input.ynum_g1[i]);
-                                           yden[i] = base_field.from_hex_str(&input.yden_g1[i]);
-                                         }
-        G1Consts {  b: base_field.from_hex_str(&input.b),
-                    a: base_field.from_hex_str(&input.a),
+    ExFieldConsts { frobinus_consts : field_elements_from_hex(&input.frob_consts), 
+                    u: input.u, 
+                    base_qnr : field_element_from_small(input.qnr as i128), 
+                    fb_id:input.frob_id}
+}
+
+pub const fn build_g1_params<'a ,const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (input : CurveParams<'a ,NUMFROBCONSTS, MAX_ISO_COEFS_G1 ,G2_ORDER, MAX_ISO_COEFS_G2>, glv_decomposer : &'static LatticeDecomposer<R>) -> G1Consts<R,S,NUMLIMBS,P,MAX_ISO_COEFS_G1>
+    {   let swu_z = field_element_from_hex::<NUMLIMBS,P>(input.swu_z_g1);
+        let swu_a = field_element_from_hex::<NUMLIMBS,P>(input.swu_a_g1);
+        let swu_b = field_element_from_hex::<NUMLIMBS,P>(input.swu_b_g1);
+        G1Consts {  b: field_element_from_hex(input.b),
+                    a: field_element_from_hex(input.a),
                     h1: input.h1,
-                    w: base_field.from_hex_str(&input.w),
-                    lambda: r_field.from_hex_str(&input.lambda),
+                    w: field_element_from_hex(input.w),
+                    lambda: field_element_from_hex(input.lambda),
                     security_level : input.security_level,
-                    base_field_numbits : base_field.modulo_as_bigint.bits() as usize,
-                    lambda_big: BigUint::from_str_radix(&input.lambda[2..], 16).unwrap(),                
-                    swu_consts : G1SwuIsogeniesConsts {     z :  base_field.from_hex_str(&input.swu_z_g1),
-                                                            swu_a : base_field.from_hex_str(&input.swu_a_g1),
-                                                            swu_b : base_field.from_hex_str(&input.swu_b_g1),
-                                                            xnum  ,
-                                                            xden  ,
-                                                            ynum  ,
-                                                            yden  ,
-                                                            inv_z : base_field.from_hex_str(&input.swu_z_g1).invert().negate(),
-                                                            b_div_a : base_field.from_hex_str(&input.swu_b_g1).multiply(&base_field.from_hex_str(&input.swu_a_g1).invert()).negate()
+                    expander : Expander::for_security_level(input.security_level),
+                    base_field_numbits : P::PARAMS.num_of_bits,
+                    glv_decomposer,
+                    generator_table : OnceCell::new(),
+                    swu_consts : G1SwuIsogeniesConsts {     z :  swu_z,
+                                                            swu_a,
+                                                            swu_b,
+                                                            xnum  : field_elements_from_hex(&input.xnum_g1),
+                                                            xden  : field_elements_from_hex(&input.xden_g1),
+                                                            ynum  : field_elements_from_hex(&input.ynum_g1),
+                                                            yden  : field_elements_from_hex(&input.yden_g1),
+                                                            inv_z : field_negate(&field_invert(&swu_z)),
+                                                            b_div_a : field_negate(&field_multiply(&swu_b, &field_invert(&swu_a)))
                                                         },                              
-                    default_generator :EcPoint {    x: base_field.from_hex_str(&input.g1_generator[0]),
-                                                    y: base_field.from_hex_str(&input.g1_generator[1]),
-                                                    z: base_field.from_hex_str(&"0x1")
+                    default_generator :EcPoint {    x: field_element_from_hex(input.g1_generator[0]),
+                                                    y: field_element_from_hex(input.g1_generator[1]),
+                                                    z: FieldElement { fieldparams : ConfigParams::new(), mont_limbs : P::PARAMS.one}
                                                 }
               }
 }
 
-pub fn build_g2_params<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize,const R:usize>
+// The G2 constants are stored in the tower representation F of the engine. Trait methods can not be evaluated at compile time, so
+// every representation has its own decoding function, the derived SWU constants (-1/Z, 1/(-1/Z - 1) and -B/A) being precomputed
+// in the parameters list since the extensions arithmetic is not const
+
+const fn build_g2_params<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const NUMFROBCONSTS :usize, const MAX_ISO_COEFS_G1:usize ,const G2_ORDER:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>, F: Copy + 'static>
                         (   input : CurveParams<'a ,NUMFROBCONSTS, MAX_ISO_COEFS_G1,G2_ORDER, MAX_ISO_COEFS_G2>,
-                            base_field:&ExtG2Field<NUMLIMBS,PRAMASIZE>,
-                            r_field:&PrimeField<R>,
-                            extfieldparams : &ExFieldConsts<PRAMASIZE, NUMLIMBS> ) -> G2Consts<PRAMASIZE,R,NUMLIMBS,MAX_ISO_COEFS_G2>
+                            extfieldparams : &ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R>,
+                            elements : [F;11],
+                            isogenies : [[F;MAX_ISO_COEFS_G2];4] ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,F>
 {       
-    let mut xnum : [ExtFieldG2Element<NUMLIMBS,PRAMASIZE>;MAX_ISO_COEFS_G2] = [base_field.one();MAX_ISO_COEFS_G2]; 
-    let mut xden : [ExtFieldG2Element<NUMLIMBS,PRAMASIZE>;MAX_ISO_COEFS_G2] = [base_field.one();MAX_ISO_COEFS_G2]; 
-    let mut ynum : [ExtFieldG2Element<NUMLIMBS,PRAMASIZE>;MAX_ISO_COEFS_G2] = [base_field.one();MAX_ISO_COEFS_G2]; 
-    let mut yden : [ExtFieldG2Element<NUMLIMBS,PRAMASIZE>;MAX_ISO_COEFS_G2] = [base_field.one();MAX_ISO_COEFS_G2]; 
-    for i in 0..MAX_ISO_COEFS_G2 { xnum[i] = base_field.from_hex_strings(&input.xnum_g2[i]); 
-                                          xden[i] = base_field.from_hex_strings(&input.xden_g2[i]); 
-                                          ynum[i] = base_field.from_hex_strings(&input.ynum_g2[i]);
-                                          yden[i] = base_field.from_hex_strings(&input.yden_g2[i]);
-                                        }       
-    G2Consts {  b: base_field.from_hex_strings(&input.btw),
-                a: base_field.from_hex_strings(&input.atw),                
-                lambda: r_field.from_hex_str(&input.lambda),
+    let [b, a, z, swu_a, swu_b, inv_z, j_inv_z, b_div_a, x, y, one] = elements;
+    let [xnum, xden, ynum, yden] = isogenies;
+    G2Consts {  b,
+                a,                
+                lambda: field_element_from_hex(input.lambda),
                 security_level : input.security_level,
-                extfieldparams : extfieldparams.clone(),
-                base_field_numbits : base_field.basefield().modulo_as_bigint.bits() as usize,
-                lambda_big: BigUint::from_str_radix(&input.lambda[2..], 16).unwrap(),    
+                expander : Expander::for_security_level(input.security_level),
+                extfieldparams : *extfieldparams,
+                base_field_numbits : P::PARAMS.num_of_bits,
+                gls_decomposer,
+                generator_table : OnceCell::new(),
                 u : input.u,                
-                swu_consts : G2SwuIsogeniesConsts {     z :  base_field.from_hex_strings(&input.swu_z_g2),
-                                                        swu_a : base_field.from_hex_strings(&input.swu_a_g2),
-                                                        swu_b : base_field.from_hex_strings(&input.swu_b_g2),
-                                                        xnum  ,
-                                                        xden  ,
-                                                        ynum  ,
-                                                        yden  ,
-                                                        inv_z : base_field.from_hex_strings(&input.swu_z_g2).invert().negate(),
-                                                        j_inv_z : base_field.from_hex_strings(&input.swu_z_g2).invert().negate().substract(&base_field.one()).invert(),
-                                                        b_div_a : base_field.from_hex_strings(&input.swu_b_g2).multiply(&base_field.from_hex_strings(&input.swu_a_g2).invert()).negate()
-                                                    },
+                swu_consts : G2SwuIsogeniesConsts { z, swu_a, swu_b, xnum, xden, ynum, yden, inv_z, j_inv_z, b_div_a },
                 twist_type: input.twist_type,
                 order: G2_ORDER,
-                default_generator :EcPoint {
-                    x: base_field.from_hex_strings(&input.g2_generator[0]),
-                    y: base_field.from_hex_strings(&input.g2_generator[1]),
-                    z: base_field.one(),
-                }
+                default_generator :EcPoint { x, y, z: one }
             }
-            
-}                                                                                           
+}
+
+const fn g2_fp2_1<const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;2];COUNT]) -> [G2Fp2_1<NUMLIMBS,P>;COUNT]
+{   let mut result = [TowerElement::new(Fp2Element_1 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};2]});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp2Element_1 {content : field_elements_from_hex(&input[i])}); i += 1; }
+    result
+}
+
+const fn g2_fp4_1<const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;4];COUNT], constants :&'static ExFieldConsts<PRAMASIZE,NUMLIMBS,P>) -> [G2Fp4_1<NUMLIMBS,P,PRAMASIZE>;COUNT]
+{   let mut result = [TowerElement::new(Fp4Element_1 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};4], constants});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp4Element_1 {content : field_elements_from_hex(&input[i]), constants}); i += 1; }
+    result
+}
+
+const fn g2_fp4_2<const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;4];COUNT], constants :&'static ExFieldConsts<PRAMASIZE,NUMLIMBS,P>) -> [G2Fp4_2<NUMLIMBS,P,PRAMASIZE>;COUNT]
+{   let mut result = [TowerElement::new(Fp4Element_2 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};4], constants});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp4Element_2 {content : field_elements_from_hex(&input[i]), constants}); i += 1; }
+    result
+}
+
+const fn g2_fp8_1<const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;8];COUNT], constants :&'static ExFieldConsts<PRAMASIZE,NUMLIMBS,P>) -> [G2Fp8_1<NUMLIMBS,P,PRAMASIZE>;COUNT]
+{   let mut result = [TowerElement::new(Fp8Element_1 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};8], constants});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp8Element_1 {content : field_elements_from_hex(&input[i]), constants}); i += 1; }
+    result
+}
+
+const fn g2_fp8_2<const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;8];COUNT], constants :&'static ExFieldConsts<PRAMASIZE,NUMLIMBS,P>) -> [G2Fp8_2<NUMLIMBS,P,PRAMASIZE>;COUNT]
+{   let mut result = [TowerElement::new(Fp8Element_2 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};8], constants});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp8Element_2 {content : field_elements_from_hex(&input[i]), constants}); i += 1; }
+    result
+}
+
+const fn g2_fp8_3<const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>, const COUNT:usize>(input : &[[&str;8];COUNT], constants :&'static ExFieldConsts<PRAMASIZE,NUMLIMBS,P>) -> [G2Fp8_3<NUMLIMBS,P,PRAMASIZE>;COUNT]
+{   let mut result = [TowerElement::new(Fp8Element_3 {content : [FieldElement { fieldparams : ConfigParams::new(), mont_limbs : [0;NUMLIMBS]};8], constants});COUNT];
+    let mut i = 0;
+    while i < COUNT { result[i] = TowerElement::new(Fp8Element_3 {content : field_elements_from_hex(&input[i]), constants}); i += 1; }
+    result
+}
+
+pub const fn build_g2_params_fp2_1<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,2, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp2_1<NUMLIMBS,P>>
+{   
+    let elements = g2_fp2_1(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0"]]);
+    let isogenies = [g2_fp2_1(&input.xnum_g2), g2_fp2_1(&input.xden_g2), g2_fp2_1(&input.ynum_g2), g2_fp2_1(&input.yden_g2)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
+
+pub const fn build_g2_params_fp4_1<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,4, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp4_1<NUMLIMBS,P,PRAMASIZE>>
+{   
+    let elements = g2_fp4_1(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0","0x0","0x0"]], extfieldparams);
+    let isogenies = [g2_fp4_1(&input.xnum_g2, extfieldparams), g2_fp4_1(&input.xden_g2, extfieldparams), 
+                     g2_fp4_1(&input.ynum_g2, extfieldparams), g2_fp4_1(&input.yden_g2, extfieldparams)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
+
+pub const fn build_g2_params_fp4_2<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,4, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp4_2<NUMLIMBS,P,PRAMASIZE>>
+{   
+    let elements = g2_fp4_2(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0","0x0","0x0"]], extfieldparams);
+    let isogenies = [g2_fp4_2(&input.xnum_g2, extfieldparams), g2_fp4_2(&input.xden_g2, extfieldparams), 
+                     g2_fp4_2(&input.ynum_g2, extfieldparams), g2_fp4_2(&input.yden_g2, extfieldparams)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
+
+pub const fn build_g2_params_fp8_1<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,8, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp8_1<NUMLIMBS,P,PRAMASIZE>>
+{   
+    let elements = g2_fp8_1(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0","0x0","0x0","0x0","0x0","0x0","0x0"]], extfieldparams);
+    let isogenies = [g2_fp8_1(&input.xnum_g2, extfieldparams), g2_fp8_1(&input.xden_g2, extfieldparams), 
+                     g2_fp8_1(&input.ynum_g2, extfieldparams), g2_fp8_1(&input.yden_g2, extfieldparams)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
+
+pub const fn build_g2_params_fp8_2<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,8, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp8_2<NUMLIMBS,P,PRAMASIZE>>
+{   
+    let elements = g2_fp8_2(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0","0x0","0x0","0x0","0x0","0x0","0x0"]], extfieldparams);
+    let isogenies = [g2_fp8_2(&input.xnum_g2, extfieldparams), g2_fp8_2(&input.xden_g2, extfieldparams), 
+                     g2_fp8_2(&input.ynum_g2, extfieldparams), g2_fp8_2(&input.yden_g2, extfieldparams)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
+
+pub const fn build_g2_params_fp8_3<'a ,const PRAMASIZE:usize, const NUMLIMBS:usize, P: FieldConfig<NUMLIMBS>,const MAX_ISO_COEFS_G1:usize,const MAX_ISO_COEFS_G2:usize,const R:usize, S: FieldConfig<R>>
+                        (   input : CurveParams<'a ,PRAMASIZE, MAX_ISO_COEFS_G1,8, MAX_ISO_COEFS_G2>,
+                            extfieldparams : &'static ExFieldConsts<PRAMASIZE, NUMLIMBS,P>,
+                            gls_decomposer : &'static LatticeDecomposer<R> ) -> G2Consts<PRAMASIZE,R,S,NUMLIMBS,P,MAX_ISO_COEFS_G2,G2Fp8_3<NUMLIMBS,P,PRAMASIZE>>
+{   
+    let elements = g2_fp8_3(&[input.btw, input.atw, input.swu_z_g2, input.swu_a_g2, input.swu_b_g2, input.swu_inv_z_g2, input.swu_j_inv_z_g2, 
+                              input.swu_b_div_a_g2, input.g2_generator[0], input.g2_generator[1], ["0x1","0x0","0x0","0x0","0x0","0x0","0x0","0x0"]], extfieldparams);
+    let isogenies = [g2_fp8_3(&input.xnum_g2, extfieldparams), g2_fp8_3(&input.xden_g2, extfieldparams), 
+                     g2_fp8_3(&input.ynum_g2, extfieldparams), g2_fp8_3(&input.yden_g2, extfieldparams)];
+    build_g2_params(input, extfieldparams, gls_decomposer, elements, isogenies)
+}
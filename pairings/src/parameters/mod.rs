@@ -5,3 +5,4 @@
 mod paramlist;
 mod builders;
 pub mod preloadedconfs;
+pub mod suites;
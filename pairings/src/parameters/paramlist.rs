@@ -29,6 +29,9 @@ pub struct CurveParams <'a ,const NUMFROBCONSTS :usize,const MAX_ISO_COEFS_G1 :u
     pub swu_z_g2: [&'a str;G2_ORDER],
     pub swu_a_g2: [&'a str;G2_ORDER],
     pub swu_b_g2: [&'a str;G2_ORDER],
+    pub swu_inv_z_g2: [&'a str;G2_ORDER],      // -1/Z, 1/(-1/Z - 1) and -B/A precomputed in the G2 extension
+    pub swu_j_inv_z_g2: [&'a str;G2_ORDER],
+    pub swu_b_div_a_g2: [&'a str;G2_ORDER],
     pub xnum_g2: [[&'a str;G2_ORDER];MAX_ISO_COEFS_G2],
     pub xden_g2: [[&'a str;G2_ORDER];MAX_ISO_COEFS_G2],
     pub ynum_g2: [[&'a str;G2_ORDER];MAX_ISO_COEFS_G2],
@@ -121,6 +124,12 @@ pub const BLS12_381_PARAMS : CurveParams<4,16,2,4> = CurveParams
                                         "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa"],
                             swu_a_g2 : ["0x0","0xf0"],
                             swu_b_g2 : ["0x3f4","0x3f4"],
+                            swu_inv_z_g2 : ["0x14cda7ee9466521508e2ec91cf6fbd791d2c3c6a5c6a7565ec270ee72bc0c4e9b2233332277666662e65999999995556",
+                                            "0xf9a3df2ef4cbd8fc6aa316d5b93ce1ad5e12d4fc54fd80c711d4b2d60d093af459a66659d98cccca2cc333333330000"],
+                            swu_j_inv_z_g2 : ["0xd0088f51cbff34d258dd3db21a5d66bb23ba5c279c2895fb39869507b587b120f55ffff58a9ffffdcff7fffffffd554",
+                                              "0xd0088f51cbff34d258dd3db21a5d66bb23ba5c279c2895fb39869507b587b120f55ffff58a9ffffdcff7fffffffd556"],
+                            swu_b_div_a_g2 : ["0x83c12791abdd5d2fe2f284f0cc6e5aa9b8c2d3f6f3f792302cf75e62bfc4df1d6834443da498888725d8cccccccb1c3",
+                                              "0x11c4ff711ec210c74cec7f673684c72cc8eb1e458445999c64615cbacab4a8324828bbbad70a777747a173333332f8e8"],
                             xnum_g2  : [["0x5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6",
                                         "0x5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6"],
                                         ["0x0",
@@ -220,6 +229,12 @@ pub const BLS12_461_PARAMS : CurveParams<4,10,2,4> = CurveParams
                             swu_z_g2 : ["0x9","0x1"],
                             swu_a_g2 : ["0x0","0xf0"],
                             swu_b_g2 : ["0x3f4","0x3f4"],
+                            swu_inv_z_g2 : ["0x429a03f7aca690a690a814899de511eb805e475372e2c94e4c8b106c34aec2af2e0cac2dc993c63b51f383831f48d8746c3a46c7ce0e91b0e9",
+                                            "0x2576a23b511db15db15e8b8d68d0da147835081ef09f913c0b0e393cdda24d8289e720d9c1631f815e18f9f9c198f9c17ce0c7d063e831f3832"],
+                            swu_j_inv_z_g2 : ["0x6c252c764880857dfca165c8a522d8a97ccbc775c226be006802c1f422d9de56bf5e150afb4620ee432b17720f50439705ea0879a9d5c17a821",
+                                              "0x3973bf9ed68446eaee35be1297ba831a0a4c41f68f2494f037417709b283be1e15a9fb2dd57d417e93aee4749822a3e83b245480a2398ec9152"],
+                            swu_b_div_a_g2 : ["0x6c16c11b0587ebd2967fb6aa01d9a0d0ddaa12b07f400e2b56e3d2a7135dc14a2b4491082d027dfaf03333d555705b057a4fa501111471c71c3",
+                                              "0xe93e933a4f4db9830eddb297475c5b0614391adb2d8a3985f164e1837aa1e477a0bc61627c12d9f513ccce2aaae4fa4f305b05beeef638e38e8"],
                             xnum_g2  : [[ "0x4bda12bda113084bebdbde80492870da6ea44302982cf37cbabad2b41f8e24d5bb8e6ec25e768551c8000071c72f684bb425ed0e38e5ed097d6", 
                                           "0x4bda12bda113084bebdbde80492870da6ea44302982cf37cbabad2b41f8e24d5bb8e6ec25e768551c8000071c72f684bb425ed0e38e5ed097d6"],
                                         [ "0x0",
@@ -302,6 +317,12 @@ pub const BLS12_446_PARAMS : CurveParams<4,3,2,7> = CurveParams
                                                       "0x27bb207fbf1ee679699f6fbc00cd4e8524b7d29d8bd76896dbd19ac8cd2611d5862c32677f3174cbd27e50014d64e9c7d1e0e557b9f817a4"],
                                           swu_b_g2 : ["0x22668726991cc065521b599214015679ced1d2c6c8be3bd26bb56d8afce604cf025c4587c4ecb6419a7f5179ab114d9a1985a0a14ea9f5ac",
                                                       "0x1a7859d48fa924cfcdf469b751a97fc631c3d1f0c144c25fa3c038c0bde111337ff2283c1d4e17acbc6ef3af1a62683217965f855c06c959"],
+                                          swu_inv_z_g2 : ["0x1e6f707d9462f29a9007e1a4b2d56b20004ad25bc5017f1907bad325dd638b01412736e1f11d66f72b77229462b9dae6188e001355585556",
+                                                          "0x1e6f707d9462f29a9007e1a4b2d56b20004ad25bc5017f1907bad325dd638b01412736e1f11d66f72b77229462b9dae6188e001355585555"],
+                                          swu_j_inv_z_g2 : ["0x3cdee0fb28c5e535200fc34965aad6400095a4b78a02fe320f75a64bbac71602824e6dc3e23acdee56ee4528c573b5cc311c0026aab0aaaa",
+                                                            "0x1"],
+                                          swu_b_div_a_g2 : ["0x1923e4b3b5815638875d588eb139d8d4c86e0cceac5997514817a57c7d2bd41442a0462fd4c0cbc2f48f85a9d62dbd61b532b35e35ae7e9d",
+                                                            "0x2bd805011d35b1472f5c509ea7b0f993537f9f2bd6b0a40ea41b2a52b72e9ce3b0b00ea7a6415a3f7e924dc0db834550ceb977abb13d4c43"],
                                           xnum_g2  : [["0x36e1d709999d72686899a03ab5aed82cac314bd1f895ff5b275ad17d41c2b3101942ba20a6841dd95f7a3c0a1e4365d2536157eac13eaddd",
                                                         "0xf1dd4dbfd83cb9df21e3dd10f3e2355636f38adeeb1fd58ee9b948e31ff93c8c40019e69fad8ef4e3fe92bc885b43c24e24f038b35a0930"],
                                                       ["0x1bdc69e1ee8642a187433cbe72853648da14ae55326ed91bea03473ef9259bd56ee1f41832498bfc8df71ec50972bfbe3dc8b83311456107",
@@ -424,6 +445,18 @@ pub const BLS24_315_PARAMS : CurveParams<11,3,4,7> = CurveParams
                                             swu_b_g2 : ["0x0","0x0","0x0",
                                                         "0x195508a43cf0850a5a6e24bf3b92fc0ff5f1ec14fbae031d6ea1e1339c298e032fd47ae61aaaafe"
                                                         ],
+                                            swu_inv_z_g2 : ["0x1804b7b2e2a68d99b7592273ecea3ab48b706a5c6c8e8b094432ddc6a1aec6354e56f5bbe39a6d8",
+                                                            "0x2ff656f9e2cef95eae864400ee8f5bc985bfe2b3ca7746eeba45c5fe55f1ad31344979cf716e69c",
+                                                            "0x49230934fc18935a088974701a28a3a75aaef45e9a0fdca2f96801ec57a8776054b5513c868cb26",
+                                                            "0x201305366fdcd35ac9e983df2e110a05c556847e9a81ee2439ae7612cb30f26d58b6e8c0135232d"],
+                                            swu_j_inv_z_g2 : ["0x3fbc7b770b8606de62e4ed35a123dae736522bbd11f3f0544a495be5ec70e11cc864a8c8f080525",
+                                                              "0x5f65ab17dcff6a74f0bcf7fb3c3b4d058b515e47af4c130fe5e7552b924d0083304ad906d94e79",
+                                                              "0x22672550e7bda67a1621ec40ad6bf94b7f29686acaf065ad2d5928621bdc9fed995f710103edab2",
+                                                              "0x35888a059ae74983d8e3e1bf0995fbbc2e43fe0fc199a9d7619cc46a2974f6f61c365299a958714"],
+                                            swu_b_div_a_g2 : ["0x0",
+                                                              "0x0",
+                                                              "0x1e996bbb27f4f683bcb57ec45f6c90356cf54d71915cfb17b4c750f3cb14d4b051499862f671309",
+                                                              "0x0"],
                                             xnum_g2  : [[ "0x0","0x0",
                                                           "0x2dc82db4e7938838584fbe950ab48d8056c3165ede5c9019b8ed2b5a06819af2453b36787e6126f","0x0"],
                                                         [ "0x14db8384d71c18702c2e4133d54a7c0a80c0db1e9519f0824f704b30ac2ff12c7c5c8411d2d744a",
@@ -560,6 +593,18 @@ pub const BLS24_477_PARAMS : CurveParams<11,7,4,4> = CurveParams
                                           "0x167278fac63bd5b007ebb8f693a2ab3dbd9f92cf437c399d928e94bfe9a04a009fda9e8cf9226901de62aea9dcea48bf1a0ebbf8860a5e7ad000150d",
                                           "0x0","0x0"],
                               swu_b_g2 : ["0x0","0x0","0x58","0x0"],
+                              swu_inv_z_g2 : ["0xed8aae4893a98778f33092b08237cdd7bffea11366dd8622fa2622aaa91ff2a1f860208ad4a6fe2147f9210832dbf6f1807fd469ee347a34457a877",
+                                              "0xc4ccc48e13d7276b95ce8083061774c13a0e49aaa08c3b752dab96436e8144e577059d877922ac94ea9bf2ca58ac0463b581a69c625f07a0eb79d25",
+                                              "0x46d4245e0ff4c8b4cbee3cd5b5d89f854015c8b69f454b69f24a8fb21b510db517169c28dd5e43bf76db854666d59494aa5e506ba26923766592010",
+                                              "0x10c95432d4c2b7312c3d9003fb84e7a09d4572b06699c688b34cf627037cf2714900eacbd259c38f90feb875978bd261ee8761810d1d8f87e5e071f1"],
+                              swu_j_inv_z_g2 : ["0x13287858f9d38b1ebc36564de6ec7cfb6959f26ad32324f9dbfca1c75155b3adf1964b8f50adda41522d9fd053f730506d46dcceed09f45467f474c",
+                                                "0x12c030f0408245faa2b2d3e3f4f14995e67b61b56d8ac2a43e95ff0bfb1c38a5f764387ed27cc2ca994c2f6333ad0347dd88d47aa0e43508297f5726",
+                                                "0x1183e97bab27fdcff0d15fd2d92c93969797c504b2d978d0e645daedffa9096d3bc0400d3de2ce1bb8409579ef71cfc1dc719976cdd285cc6810caaf",
+                                                "0x29248605d06a6c0f8809f5addb30c483c9ebdfbb638abced63d9fcb153a7b607530f914f97e5d5e19ccde1ae5093b74569feef367c18101d800843"],
+                              swu_b_div_a_g2 : ["0x0",
+                                                "0x0",
+                                                "0xa79afec7ea474c99d4bded97818b65003285593ec4b09d21131789dd36cef559fee8e41c998976745a584b5ab5c4414ea06e040c715e7d2e999a379",
+                                                "0xa79afec7ea474c99d4bded97818b65003285593ec4b09d21131789dd36cef559fee8e41c998976745a584b5ab5c4414ea06e040c715e7d2e999a379"],
                               xnum_g2  : [[ "0xb393c7d631dead803f5dc7b49d1559edecfc967a1be1ccec9474a5ff4d025004fed4f467c913480ef315754ee75245f8d075dfc43052f3d68000a97",
                                             "0xb393c7d631dead803f5dc7b49d1559edecfc967a1be1ccec9474a5ff4d025004fed4f467c913480ef315754ee75245f8d075dfc43052f3d68000a94",
                                             "0x0","0x0"],
@@ -659,6 +704,18 @@ pub const BLS24_479_PARAMS : CurveParams<10,4,4,10> = CurveParams
                                           "0x0","0x0"],
                               swu_b_g2 : ["0x0","0x0","0x1143b8887c8cceff3b69572a8379f1d79a6544fa28a785af8df0718d42152ab46c27b6e7d8a14481abc77a1757f9cfb93dbd12dba397bcf7f3f533c1",
                                           "0x1143b8887c8cceff3b69572a8379f1d79a6544fa28a785af8df0718d42152ab46c27b6e7d8a14481abc77a1757f9cfb93dbd12dba397bcf7f3f4e6f8"],
+                              swu_inv_z_g2 : ["0x3333252334f28bb86afd0b54ac4d1174d8639d89e66673f2641d44dc7a1788cf94af0cdb3e27eca6e7ce672c38e3fa8937707fd48c119ba64e7e6666",
+                                              "0x11110c6111a62e92ce54591c396f05d19d7689d8a22226a6215f16f428b282efdc3a599e6a0d4ee24d44cd0ebda15383127ad546d95b33e21a2a2222",
+                                              "0x3bbbab53bdc5a301d22737e2c904945da71ee2763777874574ccd0568e70ca4782cc39aa732e94180e70cdb397b4a44ac0adea77f8bf35975b937778",
+                                              "0x4cccb7b4cf6bd194a07b90ff02739a2f44956c4ed999adeb962be74ab7234d375f069348dd3be2fa5bb59ac25555f7cdd328bfbed21a697975bd999a"],
+                              swu_j_inv_z_g2 : ["0x2f42e73430dfe36f27ae809cedd0fc6bdb6fa51cd4ad5755e64266f2e6daa5abebb546f1c3388badc2485f3c8348987ea954273a59e8de7221123723",
+                                                "0x3092fbd9323b70dce8c8aeda2d4fae1985164d412feb0b8a10360640c2999c0d240888ea418856ab79750c8c6a7c63d7835d9a186aa11d83858b9c3a",
+                                                "0x4594462947f447b8fa6b92ae253ac8f41f80cf86e3c64eccaf6ffb1e7e890220a93ca87226850686ec3fe18addb9196523f4c7f976250e99cd21ed9f",
+                                                "0x28b27ffb2a16204a622b996ab05784078b2e5c670c78d25114804a6d9c1fd5c5d214fd174ba994b92e68fcacff459fc26724e8e4064fa31b2ab33db4"],
+                              swu_b_div_a_g2 : ["0x0",
+                                                "0x0",
+                                                "0x3fc2650a712ebd1c19d147aabf913aa32fb8c61829e8254ef3e0068867bce87f25c7340215824d5573171a1f5850ee29e141d1959fd5b732b7d8caa5",
+                                                "0x35404c2581be1b85a73f46013955b3bd94b8c1aa2eafd4639509dfc50251859118f7bf0b9321a29010abf21c1b59464835917aab9a6011e92fcc2235"],
                               xnum_g2  : [[ "0x0","0x0",
                                             "0x3d2be841032280ded19a6bf3b90c74acba7f5aa0dff71bead745c03a9823058229df3a0c47f677cb7adf452edc5b0c025c3039c4d12ec6d294dc868c",
                                             "0x3c46407e26d470c6c0e520f6d45e9d5e4140c20cbb2d16a9aebabdfeaeaa2fdff0baa5983701345a61e46967080ce515a7452e828abf9c5a31d8da9f"],
@@ -831,6 +888,18 @@ pub const BLS24_509_SNARK_PARAMS : CurveParams<11,3,4,7> = CurveParams
                                           swu_b_g2 : ["0x0","0x0","0x15db44669674fcfc2eedf68006d81fc2310a8d2aa0e78f6a5391bb3559b79c17522ccb9322bd40502e6f833c2ba40f43d4a65b21f54c6f1faf81af1a32a847c",
                                                       "0x17452a017cbdd682a502a1e13a9d671d27958eecd2c33c3a36c2ade221d9b956bea4f49b2b5ee7d7d72ad20065dcb2b8e9ca0015b5152c00000811dffffffdf8"
                                                       ],
+                                          swu_inv_z_g2 : ["0xa933f1ed4195e32dde9ed2186f5bbba824a6518434373e7d07ebd54e3f4936cef59b3dcd88f1fcb5b88a5d7aefe8dd37e18f6b2973b2a9b521ecd2f3f252b89",
+                                                          "0x13a38c4e2e8c4807a16a9a0789c47167a3cf10ae9cb1ae6b5fe97f4227e3348653d3fecb8515470fe63d89713d9fc63f77a52bb5fcc969f9055667853396486",
+                                                          "0x3b4bef06b9f823195126861ce62a7c5f9bf12d20a5754c832bfbc8491d020496a2828c28520cc23aff6870febfdca73b11f32b3d2c6a7360a8546178e596f48",
+                                                          "0x1cebd7c856f2c9c3b72def6a183da7dcc751cc9a0f1c4e1f64e167ce6272212e1153ae597fddc7eb048fa4d8afffcca0bdb1fb4d894399376edb42c10032040"],
+                                          swu_j_inv_z_g2 : ["0xd52493bc478703abc44897b475c668a7f78fc9152ea80987166c532f72b394b1ed5fde4c99b3361428fd28b6d5fc0ca66e9f111311d226285030171effec52f",
+                                                            "0x15462c498921a68a271dda28dbbaa2d3dc08368295ae974e9de43f38c3c3e33291a645e9bcb9170174b4dc3170ccf95df0a0f0c3d3013c0d99d32ae5c813393c",
+                                                            "0xd9171df4837d52da4f18bec8f6672362d9bfb52d14b171320adef5a3b08285c3f4195140672141afc1b6e9a755853fe5e277dd2cf2b756966149397eeb0e955",
+                                                            "0x111893c441bf2e5c699eb551ad8cf8b2f21c96a9e8ee292fd1d71093e3a41a5a73d157ca319f7cd679806fc869f2a3084f17f51344d635ade5e348fcafad170"],
+                                          swu_b_div_a_g2 : ["0x0",
+                                                            "0x0",
+                                                            "0xaea6651a01eea42788647d2bf404a6797043caeff93b02a6f635634a925d98f1a3cae94db9b7b3603a5ccf0f6db099b27caeed9da59385105a085ddf2893666",
+                                                            "0x1516aa3b9bb054888a54e129ee56b1718137c6e6aae10a8b5c15fcd0d0fcde80a05a64debd94fba51a67b88f7de848d4b7840bf6e23f0b92a8a7349e3924314d"],
                                           xnum_g2  : [[ "0x0","0x0",
                                                         "0x7e7ff9b0e5fb0176756652607f2d2ef9dec58973fffced61825d6a3d840bd8044491690033734607e02f98ad88da66162f27abe177916860fa59ea90beeeced",
                                                         "0x167d890391f7db4e00dbb21c9121993f603a4b39fb23c3f6da2a5b83ecee687452a2b35e87bd2b5281a3212a52f83759253dde40093b4fc0c91eb946b024df50"],
@@ -969,6 +1038,18 @@ pub const BLS24_509_PARAMS : CurveParams<10,3,4,10> = CurveParams
                                                         swu_b_g2 : ["0x0","0x0",
                                                                     "0x13e3eb835d7d5e9f279977a6be82b4ed2cc12b47b3271fb6c8e30e06e75130127c50d3bd455b52f847d62600acae988d9b72916350df099243658dc7aa032e77",
                                                                     "0x13e3eb835d7d5e9f279977a6be82b4ed2cc12b47b3271fb6c8e30e06e75130127c50d3bd455b52f847d62600acae988d9b72916350df099243658dc7aa02e1ae"],
+                                                        swu_inv_z_g2 : ["0x0",
+                                                                        "0x0",
+                                                                        "0xaaaab7fff9ce54dfe76f95a7ce0767b65c56424ae8c3f4619750081f008485db13742dfbe0c507867e5ae3038dd69e97731de83b746c980509e88c6dc5fe955",
+                                                                        "0xaaaab7fff9ce54dfe76f95a7ce0767b65c56424ae8c3f4619750081f008485db13742dfbe0c507867e5ae3038dd69e97731de83b746c980509e88c6dc5fe956"],
+                                                        swu_j_inv_z_g2 : ["0x155556ffff39ca9bfcedf2b4f9c0ecf6cb8ac8495d187e8c32ea0103e01090bb626e85bf7c18a0f0cfcb5c6071bad3d2ee63bd076e8d9300a13d118db8bfd2aa",
+                                                                          "0x1",
+                                                                          "0x0",
+                                                                          "0x155556ffff39ca9bfcedf2b4f9c0ecf6cb8ac8495d187e8c32ea0103e01090bb626e85bf7c18a0f0cfcb5c6071bad3d2ee63bd076e8d9300a13d118db8bfd2aa"],
+                                                        swu_b_div_a_g2 : ["0x0",
+                                                                          "0x0",
+                                                                          "0xd090c8803466fca03af9266f1f09231025f2a6c8ef6c1079d6728e95d419ed8858f82141b5a4a90d682ad32dea55ac3b9335f42559632ecba8ab81971e8897f",
+                                                                          "0x41ef39645b27655bb1ffb08623ac6c225454c931a2aefaea34a5bccf5c00946a72f40e50f251d7d89ed3e4607b55584629b1048a136d0151eac1326bfb21aad"],
                                                         xnum_g2  : [["0x0",
                                                                       "0x0",
                                                                       "0x73568d4223aacb0a0f4115dc59213bdad0d2c0ed849b47f3b35178d4dad803f263db3d04911d0067ed53f35429d3c26d933b9ec49580421f07ee885258b6929",
@@ -1185,6 +1266,18 @@ pub const BLS24_559_PARAMS : CurveParams<10,4,4,4> = CurveParams
                               swu_b_g2 : ["0x0","0x0",
                                           "0x557003c04ffe89db51ef86c56921091e1c72d1e92397d41e37c255cd44eff051c38fa813a695c5d574923beee353ada1100a0b5fb2111a277d389362d77fcb581fe8b50105d5",
                                           "0x16"],
+                              swu_inv_z_g2 : ["0x0",
+                                              "0x0",
+                                              "0x2ab801e027ff44eda8f7c362b490848f0e3968f491cbea0f1be12ae6a277f828e1c7d409d34ae2eaba491df771a9d6d0880505afd9088d13be9c49b16bbfe5ac0ff45a8082f5",
+                                              "0x2ab801e027ff44eda8f7c362b490848f0e3968f491cbea0f1be12ae6a277f828e1c7d409d34ae2eaba491df771a9d6d0880505afd9088d13be9c49b16bbfe5ac0ff45a8082f6"],
+                              swu_j_inv_z_g2 : ["0x557003c04ffe89db51ef86c56921091e1c72d1e92397d41e37c255cd44eff051c38fa813a695c5d574923beee353ada1100a0b5fb2111a277d389362d77fcb581fe8b50105ea",
+                                                "0x1",
+                                                "0x0",
+                                                "0x557003c04ffe89db51ef86c56921091e1c72d1e92397d41e37c255cd44eff051c38fa813a695c5d574923beee353ada1100a0b5fb2111a277d389362d77fcb581fe8b50105ea"],
+                              swu_b_div_a_g2 : ["0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x5b2226227775ffdf7a5b49d1d968604725b9dd88e6f8a70d4e5eb24676874f72b46cec8aca717be6dd77ef03242459e792e2141a730362dcc2279b132aca132368b1338295b"],
                               xnum_g2  : [[ "0x2ab801e027ff45addefe83e0aea8000d64d2e3f87399f541f5647a669db99198d9f8980cdd2cae21eb92541b95119a5071138aab3fbacdb5742ee5ce7b682e42bc367285ec44",
                                             "0x2ab801e027ff45addefe83e0aea8000d64d2e3f87399f541f5647a669db99198d9f8980cdd2cae21eb92541b95119a5071138aab3fbacdb5742ee5ce7b682e42bc367285ec44",
                                             "0x0","0x0"],
@@ -1302,6 +1395,30 @@ pub const BLS48_575_PARAMS : CurveParams<24,4,8,7> = CurveParams
                               swu_b_g2 : ["0x0","0x0","0x0","0x0",
                                           "0x388e62090bfed90180182594813dca7b6c2ee485aaa6bb4a7a852c2e75c0a57e14cdf703dfecd975c0282cb9d6ec954badb4728575c905eb301c5b164590da6561c5ceec8f337a5f",
                                           "0x0","0x0","0x0"],
+                              swu_inv_z_g2 : ["0x0",
+                                              "0x0",
+                                              "0x0",
+                                              "0x0",
+                                              "0x0",
+                                              "0x0",
+                                              "0x29311104c5f06b404f611521d5bce8f9090765e7dd5c49a7360cd8d9852217753a64979f69ca00186df02fa98e6a0e34a3263c9c10c031b37604a2a8e14147116c08ee618df58156",
+                                              "0x29311104c5f06b404f611521d5bce8f9090765e7dd5c49a7360cd8d9852217753a64979f69ca00186df02fa98e6a0e34a3263c9c10c031b37604a2a8e14147116c08ee618df58155"],
+                              swu_j_inv_z_g2 : ["0x526222098be0d6809ec22a43ab79d1f2120ecbcfbab8934e6c19b1b30a442eea74c92f3ed3940030dbe05f531cd41c69464c793821806366ec094551c2828e22d811dcc31beb02aa",
+                                                "0x1",
+                                                "0x0",
+                                                "0x1",
+                                                "0x0",
+                                                "0x1",
+                                                "0x0",
+                                                "0x1"],
+                              swu_b_div_a_g2 : ["0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x0",
+                                                "0x3474b721d3e7cf2079a95478e039713f9dac752fd48a8b9a83314c41fa874915b20f23b413b7d36e362b1c78cb1d8ae99e6e371723cf92f9c76c950b82bb72bcc806905d84aaf535"],
                               xnum_g2  : [["0x0","0x0","0x0","0x0","0x0","0x0",
                                             "0x3c75a7d12ddbff8b316e941c849f5975c992922d58673b4113491b49d4587f68c0f74a9958b0b84199cff81190ea73916107bb583f6e92fdc0a70109cf8e05606db76e88390b0def",
                                             "0x3c75a7d12ddbff8b316e941c849f5975c992922d58673b4113491b49d4587f68c0f74a9958b0b84199cff81190ea73916107bb583f6e92fdc0a70109cf8e05606db76e88390b0def"],
@@ -1469,6 +1586,30 @@ pub const BLS48_573_PARAMS : CurveParams<24,7,8,4> = CurveParams
                                                           "0x0","0x0","0x0"],
                                               swu_a_g2 : ["0x0","0x0","0x78","0x78","0x0","0x0","0x0","0x0"],
                                               swu_b_g2 : ["0x0","0x0","0x0","0x0","0x3f4","0x0","0x0","0x0"],
+                                              swu_inv_z_g2 : ["0xd71de83fbe3b554ff9038af395757b5dea2dcbb5762eb9bd7a8174f5a991d0935ecb0a14b1da42186e4d03d603de767ed44013c194f6cd4fbc5cec24cac10e8f75305d92d9633b3",
+                                                              "0xa783cbc91cac2dff65fc685d4b7a2e3a535aa5b9aab47a263ab2303a0a77a156037a9f929b04eea75cc7a8ca52d55d46b57e6f581821d189ed9a9f78b9d84997f59312a144db414",
+                                                              "0xbfae97484b3fb03bff666e1bcf8f6d05806b42dafa60d192866a2facfcb81fb81e256b4ebe3e09433a5a21d822ddec0c8df8605a7313db353ef173b0932b043cff3993434d4f8cf",
+                                                              "0x15eeaff81894344c6001368c1ff8bd2466eb3746185ce15ca7bcda2c830ae10794d72c0c13cd93c0a68a30761d46405978a5804ef560874627b66a7de2e76126a82f685f8bd617b",
+                                                              "0xbbd8489f07b9c7ec5b21876e2e6f96b3df5dfe7b49e47b06e53f438808fcaf20db921d8fc87b7c90464832b277a06f8e1170aaaf76126560874627b66a7de2ea20c2c77b258edf",
+                                                              "0x85948cce7d7696e3268a9a49ace75d94a28310c25839ec5baf3a455595b91fe725dab87bf7ab625b5d1f997e557cab7a17cc66a1a4fbc5cec24cac10e8c4f6cf44167e540d79ed3",
+                                                              "0xa93074f07fe72dc4582fe36731fa5e92381c967c91a880564367683e6d33151f5c17e22006073f13f39b6e0283716f6a185c35a4839d84995821d189ed9a9f7b3393e669d9f0b91",
+                                                              "0x23e36b92e530809f38d26303d19691326e5e3275a05882bc88ac8791a560a8e386f79d9a10494d93fd5cdd97244d0dbd8c8a5d194b53ef173b0932b043a313dbbbac4b1e1c0a926"],
+                                              swu_j_inv_z_g2 : ["0xe8ca32fcfc49f21c051aec2aca2f9c13c341b7422b31f4a94e5860bb878dec42632a7916ed6ffdfcd4534f045958b0f8527021e84778b7f640a04f5d3fad6217ea76c0806e94b3a",
+                                                                "0x2f5ab00595d6b8c09b05843c096c392c1f43489aa47c874c3d25a1467f948e7327c05ef20f9587bca1d954ec2233b563bf286126f4435b622117f5cb06887fb93c1893ef3b40d39",
+                                                                "0xdb2fabb6ef178ab915a62681bfdc782c61af713718ee190bef47c89990715f5e1d0996559c0344239845bd0bf9c428f1a03bb754ff7fd9f55a229e5ab8281c22f8f9d86eab5a1c1",
+                                                                "0xf6efb9ff32707a7faeee50fc01cad0f67f04b2afc022010636258d8ac69cf3d5b35000d3db0bb4b3f9acd50b14097982c8ac6a87e164a3d2fb736d8187f85636664ff079230e626",
+                                                                "0xae48ccbc0d4534f295a7bc9205b18609583de3dd92224f116aef56d73372d2033a15ff7aa6124e9ad4d66986a0c48c1ef380a2b93810a4a890dab84f6e73b21cd03db8b6d5e910",
+                                                                "0x7dae35612b7dfe271ef5a11bff83f191a20bdee461d7c45a13bfa98ea61645fee4488f60873e115794ecdbcdf1d5c30fb11b8ce0b63f853b1fd801754835a49e553af492939b4f8",
+                                                                "0x6522116c67e27271569589ed77bd0d23e1b346ce0810af87d5bac882c83fdf01e5d062e497c0ad48df6fa85bae77fbb247cfa52346edbc4aa9fb0df30a4369b3cc887d12a829fc0",
+                                                                "0xb977378b036ef2d6b96c75c39fa7f016f72f52ddaec98be4587ffc0316d08c1f9d15363627589d4fd6813f8de17b6b1f380684feab3ac4066e5d397fc801184499eefa039165907"],
+                                              swu_b_div_a_g2 : ["0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x578fa5b11f86dd071376d205b0ecd4096ca939809d0512daedda711b026271c5e05d292d6ecb112c6d79a622b8717e2d65021dc72c000000000000000000000147fa5d2c1ea5b0a"],
                                               xnum_g2  : [["0x0","0x0","0x0","0x0",
                                                             "0x44ace658b95ab764ec20f5097bb4ba61bea2d7c43eeae19ca679217e98755e40d820e9149321c731e76e7843637c26ba311ac205a00000000000000000000001013cd5a018097d6",
                                                             "0x0","0x0","0x0"],
@@ -1608,6 +1749,30 @@ pub const BLS48_571_PARAMS : CurveParams<36,3,8,4> = CurveParams
                                                                           "0x0","0x0","0x0","0x0","0x0"],
                                                               swu_b_g2 : ["0x0","0x0","0x0","0x0","0x0","0x0","0x0",
                                                                           "0x3d8eafa9d5c9ed06fdcf90ab0595ba134cf8cd988b8ee0fa797f4d3e0be46fe6d1ddefca39537fc8a37aa2924fa5c2b9d1744dc910a7be27004887dfe5f91540e07c30029de14d5"],
+                                                              swu_inv_z_g2 : ["0x4efc52dbdeea09c4aaf43d82a5dd0a1428d7e47b732d6fd410caafb05e91a7fa3b39439aae7d2bf1a2f75c17f6ba1290354edc417e52c3fb3e9def2c533a0fcbbb4df8350110d0",
+                                                                              "0xf42a2bf0ae2a1a4d74b89b1a31653492078118ed8de60215e0324fc4bb3617cf04f23c0ddb257e1ba123965b995b6cf9292b42a95ca459ef5a62dc880bfb83910a8cc3e510ebf2",
+                                                                              "0xe3a5963b663d4bd9608b5e8b09d796b8a4702d488b39f375b1db26291858d96cf0c6233589e175e43aebf6ee6f7e7d8843fb9ed22ccf648a2468c4cbacb4f7fd4f6ce2b9dc22d5",
+                                                                              "0x53c5a6f7ac7bbfc64de441f7c43be4e8f87585db43f3a86e1e384ea7a67d672532860acdba66b424995034790d0a17f260727bbb7c2f0545a9e4f3245d6169b22d2a1dd1862e000",
+                                                                              "0x506e532888685bd53984ff9bf95307e3fb1c92b37d02e44ee1a58354633837093696b8fa68ccb9767be906d7ea63cdea16296dcab8e8997419bd6ad99b903c024213f582627c02e",
+                                                                              "0x76072913fd484ed709309e1ad4ceadb8e4744001797e4c947e52e37d53e269f953a17e5428c0e24608ef3202b6c44883c7625f83ef616519040455d24bb2426ebb6ac69b20bda07",
+                                                                              "0x4d5577168aebf51f75bc844121e87a4b388966848c82a190d8f52b8734aebb56d2ce7451d98ff7ec18be98f3728a6104eb307f125ff0ab711f9e866649b301d68efdfb4a48964d7",
+                                                                              "0x362707e538e3fc1c8dc8aae6c60901216924902cf8c29e7e97ec4cc58306c8275c509173b8f7c3a9fc3371f0108450fc4c74e922eccffbc77269b973be2ba3c5c6ece0bda5c7b1e"],
+                                                              swu_j_inv_z_g2 : ["0x28e7ce119aaeaed9ef0376cc5202adf68726b20b2d727febefebb30f0ccd18e5b667c25208d3c9a4c9cae4f4bace3f0d2f8483690ddf525d572ee0874c4327e1b7ee7553e7c8ac4",
+                                                                                "0x411565133115a6ac399fee0b2c35ecee51b58f88ee5e23c818142f2a7f97bb1e570e7aa99a7ee48f4135225b44bba19f08182e8d789ec9df7fa3ae2eabd8f7924e7ae918166b5fe",
+                                                                                "0x386b5695b4bb9a53bd05d6026a032db04bcc1f062cf42cd9e94e1ec73b34b3785308adf612b64c69dba7fc8f515dee536a3e0c467f70bb87f5b264ec5dfe2516dfeb915788a1a78",
+                                                                                "0x5103b1e57e4dc2b380dd73b679be8767055259f73d587b759960638036d707926bc6536761504e03c06c9d5c97daed823652e4242c5b9a4eb854e4baca34ea0a3d82ea94c6c04cc",
+                                                                                "0x148d645084394acd0326eaa26e4a318c04b2ba497a6ad08240c4b9db42bef1b9fb5507509a74cd7b1f4a980bf91f51999cd9060a44dc0a7c2a588bce1febc0a802427aac54fe77a",
+                                                                                "0x2d4956650984835bef6795283a883ed7b88b69b64ff75808737a41736bfe811f3c1a511fd2b3c6a4d32d59fb7e76da520f6e4225b2800baf205f9c543b02d75c4cdd75bc98466d7",
+                                                                                "0x6d0289ae3e64f3790c5dabd570aaa8ba86fe936f8be0b6be78ab12ca48fbb2ef8ef9b416edf96c76d00b4600caf409dec859188081734d6c03247684b472a13c08fd7baf599a20f",
+                                                                                "0x66dc72da4c0069611b67c4686cbbd24cd89d04b347c7a3178ca6819c0a131de908ca4aba9a52ec1056da1dcd7954ca131553e289163895ba527bd6911964efff3197a5600a1277a"],
+                                                              swu_b_div_a_g2 : ["0x0",
+                                                                                "0x0",
+                                                                                "0x0",
+                                                                                "0x0",
+                                                                                "0x0",
+                                                                                "0x5527840ddc9bcba3b060f49e473259c76d2ce44d226f29ad4e4bade448648eecfa9bf0eca46758234860eb36ef6cad3008fd4dd558b0528971316d64ada9ea2dab1ec793b4f5ff4",
+                                                                                "0x0",
+                                                                                "0x0"],
                                                               xnum_g2  : [["0x0","0x0","0x0","0x0","0x0","0x0","0x0",
                                                                             "0x5fc18308301da9991909fd7bcfcc76c8b09f78b4675008a212543f44127fca837f593c1e2048ffa9e1db35c726901276624323aa8baf99920070d35c494a92d6b2884aaebcb3c19"],
                                                                           ["0x0","0x0",
@@ -1734,6 +1899,30 @@ pub const BLS48_581_PARAMS : CurveParams<24,3,8,4> = CurveParams
                                               swu_b_g2 : ["0x0","0x0","0x0","0x0","0x0","0x0",
                                                           "0x9407b9ff9a3b7989c12718ea38095002b7427c6891098dd9df36078f9cbaa225245721d7b7041566ce6981ca7a39b6d7b41b3d2a898b877052bc7efb90d2524561f6e0aa732b2c817",
                                                           "0x9407b9ff9a3b7989c12718ea38095002b7427c6891098dd9df36078f9cbaa225245721d7b7041566ce6981ca7a39b6d7b41b3d2a898b877052bc7efb90d2524561f6e0aa732b2c914"],
+                                              swu_inv_z_g2 : ["0xe00140553cf637ef126d3c8b7a544e74b95f6fe456fcc697b7767f5c2ef04ff27f9f143a6f6acfb95f10229cff8dfe7effa39515854d88bf8b7bad0fe6147ca3f8bf5df12062e83f8",
+                                                              "0x631f6ee5cf9fe87a05cacb954dc2488ea8f86a752370fedfcb454f62a1265af0112c1ccdd6da6440ac22a39e00131042d3038a063675cf9794f29988c1e0f2fe7375184e6c3755289",
+                                                              "0xbf26b635fdba3cf10822df89c034bdd5b64ee2c5144cd4100f2f6a195de3f26ac95a6c8fc6427a5225e8ac17d4c1e59c13b51ba2c9061b73ffc61d645009a31f110af72aca6fb4ee4",
+                                                              "0x789ae623f3a602612f059d545e5095fe4ce964b770c4d2af57eb196dd376e826815cb7d8b619c7f051e52eb9acf6ad401e730b950d8d7235d6249038818576b2c035253687a270f87",
+                                                              "0x102b9e94654f8946ba9e6a7bd310492f1fa4a662b1393a5451fd84f3a86f7e1a1de13c0505b765d840cfafdd01f31189a3d9b36d17cdf78160e39b6747b4be51a1a7887020b9b325d7",
+                                                              "0x83827cd94cf6282015854c5155be43eaf06e6aaa6076b8b492aa7a5481dee85276cfc7b5688a5a06b1b9113024e13421e64550d42b228f81b9e96564c5d77f1af9635c9d6121f3ad4",
+                                                              "0x42d4084c11ddf188ff6996052ffd6b08dbc9d7cf87f9908e9246c8b0d8d66e5c4c0059cd2e4cf9e1834a39d858b0d3a074c94ed68f86ab0ce1dca516fddff0fe6922c1ea805f7f893",
+                                                              "0xe29dba4eadc774d564c039f84dacc1af79f293e50999c94de55d885d0ca33ce22701c46e12ad2d034a5efb32843713dcd1c4e40362fba40790debb99dbc84aab2de7f03da9578cbb8"],
+                                              swu_j_inv_z_g2 : ["0x72e3e8839005cf97ecd84de4d6caf099ffb9762bc89c33e0d9539589063279ca9cd3861b721e88279d601965890dae492d8100912c0a5b3e91c83ba9f19f0a2074a7c7b4370bbab97",
+                                                                "0x262955525578dc75e0dd73bf5856f12b0e0ced703c0de955e0da01fef6ae20a2de41e2d82cbdaf23af754065fe21f8058e11f465b939f7396c4338d69ad5fb5540abefd5e9b1b864d",
+                                                                "0xc50afb02010c1268143ab24141de9b1aa340dea0d9627f4fe44c4b0d01ff93fedf615f178bb149118086ecfefaa646501102b5a40bf531f40e6cbeb7dd7b4f42ab5e00bd561557863",
+                                                                "0xc09115c454c360c66aa17c3876686e07e49fb7c4fae79f9d6d51cb24160a55ba3fd3b9dbde7466bf04cb549de3990e53175cae2b08afd5a66f142c881d429558e1e6208be53f36683",
+                                                                "0xc30f0d113578b48a846218770ccbbda4bcb2332169650c604465d0de2827facef8f08ae719b8544410646ca508be3572d6390e2807582229cf153f2a87cc98a79fdaffd9c13c00609",
+                                                                "0xa3b9c8617f7bd91ee1319e397c908221864a231e5408ee7f038c517fb47346ee9c1106455d0e2402ae4341fd6761ad8802aed9d8f0729c0a73a93fdc463f83431d93de7fab61188e9",
+                                                                "0x38708f91c5b28bf6b4a03dea3b2ea9d92cd3950033d2d127f1388be27dd86c156291980a300d9413acbb4ce18fc0154b3fea9b4a03befd1d51c9e42c57afb5f98d3680465a159f3b5",
+                                                                "0x10c8d032c4ced52f729edfb833a95fe044ddbde906bf204e665a98463364f380b1afdbb6b72d2f78dc0b5f77e6638fdecae04aa9811d95e12035189e3afbfb3c735f1738a0ebb08286"],
+                                              swu_b_div_a_g2 : ["0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x0",
+                                                                "0x78e42d4142b0317125b5ef0170b9443a844237a5060135a96f3189b485707533e979bebcc53ca5f403ae460be338db20c90a89b0c024215192c40a2626e3db0762baaece1367d3eff",
+                                                                "0x78e42d4142b0317125b5ef0170b9443a844237a5060135a96f3189b485707533e979bebcc53ca5f403ae460be338db20c90a89b0c024215192c40a2626e3db0762baaece1367d3eff",
+                                                                "0x0",
+                                                                "0x0"],
                                               xnum_g2  : [["0x0","0x0","0x0","0x0","0x0","0x0",
                                                             "0x41ca8b8e0ba8a7cb724a43f651cb400135011ad924043f0b7fa63c3fd3e12b9ebad1645fdf9009830667c7e836528a26fab6c5da043e034e5da91bfe0779ebad0f1863d9fa4c13cb0",
                                                             "0xe644e87128ce4b481003edde1e4760043983ddf7fe0edca83ec5d2df659418ab8ddcdf4f8e78214a966b3bacbe20e3886d7fb47b0ed90b9247cfe1f91a2ab8ddb4d55d7aec0a4547b"
@@ -1893,6 +2082,30 @@ pub const BLS48_287_PARAMS : CurveParams<35,4,8,19> = CurveParams
                                                                           "0x3e323aa8606044bfc814820aa9a823c4e960e869b21576f8e4ed75dd95ab2988e2a83673", 
                                                                           "0x1148daa978e93f82562e4002baaa76b4d42293f978f6014be70e1291cf98f30e9f7405bb", 
                                                                           "0xada4458df9dfb375e845beff6bbd16583b88d62e3cbdb69ef14d6e267a90cb705e7932d"],
+                                                              swu_inv_z_g2 : ["0x36359551e80a33d31aabce7bc8038bceadab1df935c5f96f97bdcf7820f3d686b9dedf31",
+                                                                              "0x107b7d9cb0dbefc195c0a88ba05325cc170b98e55a54b314d9231cd58dbd867e7ecfcf75",
+                                                                              "0x32aaa4f4a1adfc4ad1e4c3f5f601d3cd8cfcc210653e3c7bffaa821c87b54557e8f4f0b9",
+                                                                              "0x47921325e00a291bff42f5f521d6f0249418dd9bc60887c76a7a4dcb3b6e240f7963f89c",
+                                                                              "0x190c7172d20b02d399f4ac903a20c68b4194f080eb01ecc5da7846449e659b939f1e877c",
+                                                                              "0x392b52ab1ca43642a2a630d9fcd93d3a4d57013deb08198794326f28d47fb045ee9e2159",
+                                                                              "0xda23a555cca6eb62612fb98192f41f6a28cd577196be7a195ed29ba4e3e36970b4cb75a",
+                                                                              "0x1d8e8c925ec28aebcef53d7e41ebcd1dc58d68f86e433095ad74ff06e7de9c757fdecf62"],
+                                                              swu_j_inv_z_g2 : ["0x3df9c50c7b97247ad4c3fb63a4425209d3ced065c5e25eddfdad737a07100af2c7076c29",
+                                                                                "0xca34fe2b75da601943a5f2fab73cc78a00f4cddc80c85b75a3dd1ef84332b3bb1d74da3",
+                                                                                "0x2e0d5bd0b97748c2c8e06e87605153874a3b2345d73663afe518b5468341f98effbf6dac",
+                                                                                "0x40046fe6ff7ede2069d0308b8a450201ed99bda49c20628dec6f5d266a0f8c74388f5270",
+                                                                                "0x26fbcc74e35ad7f9464c94a673c5f6955b2edd844a318b734ec6f7dc54fe7b387716c179",
+                                                                                "0xf604e8766e648653a0b831b65da99343a260572351e9aff84bf6a30369e7db2d7927f0c",
+                                                                                "0x32f93ce450d68774a5aa5613dd12febb0d4d64fbbf09afa9ad5425794611adbfc2140161",
+                                                                                "0x47654426c1ed60eefbc6801810b0150f9b5c50287890afb6d41c34f73bc2cef8dc19f111"],
+                                                              swu_b_div_a_g2 : ["0x0",
+                                                                                "0x0",
+                                                                                "0x0",
+                                                                                "0x0",
+                                                                                "0x260586ed88f3b47761a78a985c226cf22badc34a9c0c3ba7f0e0d37dcff4ae889f874c53",
+                                                                                "0x27b5cc545974748a1327bd151c6251b541b63b6934a96a733bb9d12f1ceadd6544409cbd",
+                                                                                "0x18d72715d3e35639274a995d4325bedb00d81535f8f0dfc65cf6aed502fe2061402f98b8",
+                                                                                "0x28a5af539242245612096420630b46299cbd0753e54922c255d6994904737ce0f2d70210"],
                                                               xnum_g2  : [["0x0","0x0","0x0","0x0",
                                                                             "0xd9db13db3c97dceeb9289ca62afe5f044880d77064457bbb416dff5749f821477c2c0bc",
                                                                             "0x4b2001356ec237ec4c24809ea1045acc2e4fc707c74f39d86ad0345fe65a8f85264cba6b",
@@ -2324,6 +2537,30 @@ pub const BLS48_277_PARAMS : CurveParams<24,10,8,4> = CurveParams
                                                       swu_z_g2 : ["0x7","0x0","0x0","0x0","0x1","0x0","0x0","0x0"],
                                                       swu_a_g2 : ["0x0","0x0","0x78","0x78","0x0","0x0","0x0","0x0"],
                                                       swu_b_g2 : ["0x0","0x0","0x0","0x0","0x3f4","0x0","0x0","0x0"],
+                                                      swu_inv_z_g2 : ["0x74370f2fe01b7c3d0b9731664baa0d73a864989c1fbce6e504279385ed63c26f8230c",
+                                                                      "0x1c38912398fe6068647dafe32ed9df45b633a9f8d70684a50f7644cb31fe25c0f7592",
+                                                                      "0x6ee382e0229961f6337a3ac3b894e8ae866119519e4e5c7941dd2b0db8de9ed7ec33f",
+                                                                      "0x124919a559720257c2b49c536f5ade63757745b541c6655563d468fa2e8c693b8a5b72",
+                                                                      "0x7b4ecd0f3d8d100ad4d07f03db1c692455f3a3e90b1d66207e0c8d3d6e1652fd9bfe3",
+                                                                      "0x593de32203f32d53746f0f7e24df709f60125755ebb6f1cabfef0d0c7d6dfb4efbb9c",
+                                                                      "0x1369d87b1f9f340d9b01fceea9065b400e14c279d7b9cf1191e17130f5a29597aa6662",
+                                                                      "0x90c047668bbcb9fc23f77e35589b24b879fe3fe6ec3c8f93c4acab84e7e3a3286f976"],
+                                                      swu_j_inv_z_g2 : ["0xd8e6bb41bc26faa1436f2084742e5a9f58727ef5244f0be41f543b1132fedee037c03",
+                                                                        "0x1dbf81e2cca6b717e429c18d0e4413688d26fec3d625dc056602f62c80d2c31f4d24e",
+                                                                        "0x39d24fbca1fed2de08c6ee2602c6556ca2bf1743e2476eecf6eaa616fa3404fa6b834",
+                                                                        "0xd5c5b74a441368e190d7ae4613d7a3a34e3e7863f09dbf73c453d4b2aa7cb6903e235",
+                                                                        "0x881d9a5c35a44817fc73e304fdb7b1946a9eb375edb7e6cf8fd560a681a0255e6f635",
+                                                                        "0x4de548a5dcffbcd315ebab5924563c071931a1d1ce5c28a55d1f953ec3e5a83951891",
+                                                                        "0x9c0027ccd8e94d1063c8e950cbe4b23ac155206c15f8da6e74e29345c8b9809b28eaf",
+                                                                        "0x102ed902e768597e10e70269cf2f0a6222c26f6c781df6696aa055b78d0b06b54475b7"],
+                                                      swu_b_div_a_g2 : ["0x0",
+                                                                        "0x0",
+                                                                        "0x0",
+                                                                        "0x0",
+                                                                        "0x0",
+                                                                        "0x0",
+                                                                        "0x0",
+                                                                        "0x6760bf84efcd43a25ba00f273f38024ee0ed932d074bcba7a61bac8e040000c728d33"],
                                                       xnum_g2  : [["0x0","0x0","0x0","0x0",
                                                                    "0x488bc0c914124a68f3f2915ee8fee22e5ef78fb3cf39ae5ab371bc75a000008bc2d56",
                                                                    "0x0","0x0","0x0"],
@@ -4,281 +4,163 @@
 
 use crate::curves::g2::*;
 use crate::curves::g1::{G1Consts, G1Field};
-use crate::curves::gt::GTField;
-use crate::extensions::ext_fields::{ExFieldConsts, ExtField};
+use crate::curves::gt::{GTExtField, GTField};
+use crate::extensions::towers::{G2Fp2_1, GTFp12_1};
+use crate::extensions::ext_fields::ExFieldConsts;
 use crate::extensions::towering1::fp2::Fp2Field;
 use crate::extensions::towering1::fp12::Fp12Field;
 use crate::extensions::g2_extfields::ExtG2Field;
 
-use crate::fields::prime_fields::{FieldParams, PrimeField};
+use crate::fields::prime_fields::{FieldConfig, FieldParams, PrimeField};
+use crate::tools::decomposition::LatticeDecomposer;
 use crate::parameters::builders::*;
 use crate::parameters::paramlist::{BLS12_381_PARAMS, BLS12_446_PARAMS, BLS12_461_PARAMS};
-use once_cell::sync::OnceCell;
 
 // Parameters, fields and constants for BLS12-381
 
-static BLS12_381_FIELD_PARAMS   : OnceCell<FieldParams<6>>          = OnceCell::new();    
-static BLS12_381_R_FIELD_PARAMS : OnceCell<FieldParams<4>>          = OnceCell::new();    
-static BLS12_381_FROB_CONSTS    : OnceCell<ExFieldConsts<4,6>>      = OnceCell::new();
-static BLS12_381_G1_CONSTS      : OnceCell<G1Consts<4,6,16>>        = OnceCell::new();
-static BLS12_381_G2_CONSTS      : OnceCell<G2Consts<4,4,6,4>>       = OnceCell::new();
-static FP_BLS12_381             : OnceCell<PrimeField<6>>           = OnceCell::new();
-static FP2_BLS12_381            : OnceCell<Fp2Field<6>>             = OnceCell::new();
-static EXFP2_BLS12_381          : OnceCell<ExtG2Field<6,4>>         = OnceCell::new();
-static FR_BLS12_381             : OnceCell<PrimeField<4>>           = OnceCell::new();
-static G1_BLS12_381             : OnceCell<G1Field<4,6,16>>         = OnceCell::new();
-static G2_BLS12_381             : OnceCell<G2Field<4,4,6,4>>        = OnceCell::new();
-static FP12_BLS12_381           : OnceCell<Fp12Field<4,6>>          = OnceCell::new();
-static GT_BLS12_381             : OnceCell<GTField<6,4>>            = OnceCell::new();
-
-pub fn g1_bls12_381() -> &'static G1Field<4,6,16>
-{   if G1_BLS12_381.get().is_none() 
-            { if BLS12_381_R_FIELD_PARAMS.get().is_none()
-                    {BLS12_381_R_FIELD_PARAMS.set(build_field_params(BLS12_381_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS12_381_FIELD_PARAMS.get().is_none() 
-                    {BLS12_381_FIELD_PARAMS.set(build_field_params(BLS12_381_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS12_381.get().is_none() 
-                    {FP_BLS12_381.set(PrimeField::new(&BLS12_381_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS12_381.get().is_none() 
-                    {FR_BLS12_381.set(PrimeField::new(&BLS12_381_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS12_381_G1_CONSTS.get().is_none() 
-                    {BLS12_381_G1_CONSTS.set(build_g1_params(BLS12_381_PARAMS,
-                                                        &FP_BLS12_381.get().unwrap(),
-                                                           &FR_BLS12_381.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS12_381.set(G1Field { consts : BLS12_381_G1_CONSTS.get().unwrap(),
-                                                base_field: FP_BLS12_381.get().unwrap(),
-                                                fr_field : FR_BLS12_381.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS12_381.get().unwrap()
+// Marker types of the base (Fp) and scalar (Fr) fields, whose parameters are evaluated at compile time
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_381Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_381Fr;
+impl FieldConfig<6> for Bls12_381Fp { const PARAMS : FieldParams<'static,6> = build_field_params(BLS12_381_PARAMS.modulo_as_strhex); }
+impl FieldConfig<4> for Bls12_381Fr { const PARAMS : FieldParams<'static,4> = build_field_params(BLS12_381_PARAMS.r_as_strhex); }
+
+static BLS12_381_FROB_CONSTS   : ExFieldConsts<4,6,Bls12_381Fp> = build_frob_params(BLS12_381_PARAMS);
+static BLS12_381_GLV           : LatticeDecomposer<4> = LatticeDecomposer::new(&glv_basis(BLS12_381_PARAMS.lambda, BLS12_381_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x63f6e522f6cfee30, 0x7c6becf1e01faadd, 0x1, 0x0]),
+                                                                                 (false, [0x2, 0x0, 0x0, 0x0])]);
+static BLS12_381_GLS           : LatticeDecomposer<4> = LatticeDecomposer::new(&gls_basis::<4,16>(BLS12_381_PARAMS.u),
+                                                                               &[(true, [0x92078a5e8573b29d, 0x33cfcc0d3e76ec28, 0x381204ca56cd56b5, 0x1]),
+                                                                                 (false, [0x63f6e522f6cfee2e, 0x7c6becf1e01faadd, 0x1, 0x0]),
+                                                                                 (true, [0xcfbe4f7bd0027db3, 0x1, 0x0, 0x0]),
+                                                                                 (false, [0x2, 0x0, 0x0, 0x0])]);
+static BLS12_381_G1_CONSTS     : G1Consts<4,Bls12_381Fr,6,Bls12_381Fp,16> = build_g1_params(BLS12_381_PARAMS, &BLS12_381_GLV);
+static BLS12_381_G2_CONSTS     : G2Consts<4,4,Bls12_381Fr,6,Bls12_381Fp,4,G2Fp2_1<6,Bls12_381Fp>> = build_g2_params_fp2_1(BLS12_381_PARAMS, &BLS12_381_FROB_CONSTS, &BLS12_381_GLS);
+static FP_BLS12_381            : PrimeField<6,Bls12_381Fp> = PrimeField::new();
+static FP2_BLS12_381           : Fp2Field<6,Bls12_381Fp> = Fp2Field::const_new(PrimeField::new());
+static EXFP2_BLS12_381         : ExtG2Field<6,Bls12_381Fp,4> = ExtG2Field::Fp2_1(&FP2_BLS12_381);
+static FR_BLS12_381            : PrimeField<4,Bls12_381Fr> = PrimeField::new();
+static G1_BLS12_381            : G1Field<4,Bls12_381Fr,6,Bls12_381Fp,16> = G1Field { consts : &BLS12_381_G1_CONSTS, base_field : &FP_BLS12_381, fr_field : &FR_BLS12_381 };
+static G2_BLS12_381            : G2Field<4,4,Bls12_381Fr,6,Bls12_381Fp,4,G2Fp2_1<6,Bls12_381Fp>> = G2Field { consts : &BLS12_381_G2_CONSTS, base_field : &EXFP2_BLS12_381, fr_field : &FR_BLS12_381 };
+static FP12_BLS12_381          : Fp12Field<4,6,Bls12_381Fp> = Fp12Field::const_new(PrimeField::new(), &BLS12_381_FROB_CONSTS);
+static GT_BLS12_381            : GTField<6,Bls12_381Fp,4,GTFp12_1<6,Bls12_381Fp,4>> = GTField::new(GTExtField::Fp12_1(&FP12_BLS12_381));
+
+pub const fn g1_bls12_381() -> &'static G1Field<4,Bls12_381Fr,6,Bls12_381Fp,16>
+{   &G1_BLS12_381
 }
 
-pub fn g2_bls12_381() -> &'static G2Field<4,4,6,4>
-{   if G2_BLS12_381.get().is_none() 
-                {   if G1_BLS12_381.get().is_none() 
-                            { let _ =g1_bls12_381();}
-                    if BLS12_381_FROB_CONSTS.get().is_none() 
-                            { BLS12_381_FROB_CONSTS.set(build_frob_params(BLS12_381_PARAMS, &FP_BLS12_381.get().unwrap())).unwrap()}; 
-                    if FP2_BLS12_381.get().is_none() 
-                            { FP2_BLS12_381.set(Fp2Field::new(&FP_BLS12_381.get().unwrap(),None )).unwrap();}                                                            
-                    if BLS12_381_G2_CONSTS.get().is_none() 
-                            {BLS12_381_G2_CONSTS.set(build_g2_params(BLS12_381_PARAMS,
-                                                                     &ExtG2Field::Fp2_1(FP2_BLS12_381.get().unwrap()),
-                                                                     &PrimeField::new(&BLS12_381_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS12_381_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP2_BLS12_381.get().is_none()  {EXFP2_BLS12_381.set(ExtG2Field::Fp2_1(&FP2_BLS12_381.get().unwrap())).unwrap();}                                     
-                    G2_BLS12_381.set(G2Field {  consts : BLS12_381_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP2_BLS12_381.get().unwrap() ,
-                                                        fr_field : FR_BLS12_381.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS12_381.get().unwrap()
+pub const fn g2_bls12_381() -> &'static G2Field<4,4,Bls12_381Fr,6,Bls12_381Fp,4,G2Fp2_1<6,Bls12_381Fp>>
+{   &G2_BLS12_381
 }
 
-pub fn fp12_bls12_381()-> &'static Fp12Field<4, 6>
-{   if FP12_BLS12_381.get().is_none() {  if BLS12_381_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS12_381_FIELD_PARAMS.set(build_field_params(BLS12_381_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS12_381.get().is_none() 
-                                                        {   FP_BLS12_381.set(PrimeField::new(&BLS12_381_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS12_381_FROB_CONSTS.get().is_none() 
-                                                        {   BLS12_381_FROB_CONSTS.set(build_frob_params(BLS12_381_PARAMS, &FP_BLS12_381.get().unwrap())).unwrap()
-                                                        };               
-                                         FP12_BLS12_381.set(Fp12Field::new(&FP_BLS12_381.get().unwrap(), Some(&BLS12_381_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP12_BLS12_381.get().unwrap()
+pub const fn fp12_bls12_381() -> &'static Fp12Field<4, 6,Bls12_381Fp>
+{   &FP12_BLS12_381
 }
 
-pub fn gt_bls12_381() -> &'static GTField<6,4>
-{  
-        if FP12_BLS12_381.get().is_none() { let _ = fp12_bls12_381(); };
-        GT_BLS12_381.set(GTField::Fp12_1(FP12_BLS12_381.get().unwrap())).unwrap();
-        GT_BLS12_381.get().unwrap()       
+pub const fn gt_bls12_381() -> &'static GTField<6,Bls12_381Fp,4,GTFp12_1<6,Bls12_381Fp,4>>
+{   &GT_BLS12_381
 }
 
-pub fn fr_bls12_381()-> &'static PrimeField<4>
-{   if FR_BLS12_381.get().is_none() 
-            { if BLS12_381_R_FIELD_PARAMS.get().is_none() 
-                    {BLS12_381_R_FIELD_PARAMS.set(build_field_params(BLS12_381_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS12_381.set(PrimeField::new(&BLS12_381_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS12_381.get().unwrap()
+pub const fn fr_bls12_381() -> &'static PrimeField<4,Bls12_381Fr>
+{   &FR_BLS12_381
 }
 
 // Parameters, fields and constants for BLS12-461
 
-static BLS12_461_FIELD_PARAMS   : OnceCell<FieldParams<8>>              = OnceCell::new();    
-static BLS12_461_R_FIELD_PARAMS : OnceCell<FieldParams<5>>              = OnceCell::new();    
-static BLS12_461_FROB_CONSTS    : OnceCell<ExFieldConsts<4,8>>          = OnceCell::new();
-static BLS12_461_G1_CONSTS      : OnceCell<G1Consts<5,8,10>>            = OnceCell::new();
-static BLS12_461_G2_CONSTS      : OnceCell<G2Consts<4,5,8,4>>           = OnceCell::new();
-static FP_BLS12_461             : OnceCell<PrimeField<8>>               = OnceCell::new();
-static FP2_BLS12_461            : OnceCell<Fp2Field<8>>                 = OnceCell::new();
-static EXFP2_BLS12_461          : OnceCell<ExtG2Field<8,4>>             = OnceCell::new();
-static FR_BLS12_461             : OnceCell<PrimeField<5>>               = OnceCell::new();
-static G1_BLS12_461             : OnceCell<G1Field<5,8,10>>             = OnceCell::new();
-static G2_BLS12_461             : OnceCell<G2Field<4,5,8,4>>            = OnceCell::new();
-static FP12_BLS12_461           : OnceCell<Fp12Field<4,8>>              = OnceCell::new();
-static GT_BLS12_461             : OnceCell<GTField<8,4>>                = OnceCell::new();
-
-
-
-pub fn fr_bls12_461()-> &'static PrimeField<5>
-{   if FR_BLS12_461.get().is_none() 
-            { if BLS12_461_R_FIELD_PARAMS.get().is_none() 
-                    {BLS12_461_R_FIELD_PARAMS.set(build_field_params(BLS12_461_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS12_461.set(PrimeField::new(&BLS12_461_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS12_461.get().unwrap()
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_461Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_461Fr;
+impl FieldConfig<8> for Bls12_461Fp { const PARAMS : FieldParams<'static,8> = build_field_params(BLS12_461_PARAMS.modulo_as_strhex); }
+impl FieldConfig<5> for Bls12_461Fr { const PARAMS : FieldParams<'static,5> = build_field_params(BLS12_461_PARAMS.r_as_strhex); }
+
+static BLS12_461_FROB_CONSTS   : ExFieldConsts<4,8,Bls12_461Fp> = build_frob_params(BLS12_461_PARAMS);
+static BLS12_461_GLV           : LatticeDecomposer<5> = LatticeDecomposer::new(&glv_basis(BLS12_461_PARAMS.lambda, BLS12_461_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x146028131e079a78, 0x80300030080c0c0, 0x4000001000, 0x0, 0x0]),
+                                                                                 (false, [0x1000, 0x0, 0x0, 0x0, 0x0])]);
+static BLS12_461_GLS           : LatticeDecomposer<5> = LatticeDecomposer::new(&gls_basis::<5,16>(BLS12_461_PARAMS.u),
+                                                                               &[(true, [0x144785e509d2011a, 0x10090c02842280e, 0x2000200408060083, 0x8000001000080, 0x0]),
+                                                                                 (false, [0x146028131e078a78, 0x80300030080c0c0, 0x4000001000, 0x0, 0x0]),
+                                                                                 (true, [0xc000603000300a0c, 0x2000000, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1000, 0x0, 0x0, 0x0, 0x0])]);
+static BLS12_461_G1_CONSTS     : G1Consts<5,Bls12_461Fr,8,Bls12_461Fp,10> = build_g1_params(BLS12_461_PARAMS, &BLS12_461_GLV);
+static BLS12_461_G2_CONSTS     : G2Consts<4,5,Bls12_461Fr,8,Bls12_461Fp,4,G2Fp2_1<8,Bls12_461Fp>> = build_g2_params_fp2_1(BLS12_461_PARAMS, &BLS12_461_FROB_CONSTS, &BLS12_461_GLS);
+static FP_BLS12_461            : PrimeField<8,Bls12_461Fp> = PrimeField::new();
+static FP2_BLS12_461           : Fp2Field<8,Bls12_461Fp> = Fp2Field::const_new(PrimeField::new());
+static EXFP2_BLS12_461         : ExtG2Field<8,Bls12_461Fp,4> = ExtG2Field::Fp2_1(&FP2_BLS12_461);
+static FR_BLS12_461            : PrimeField<5,Bls12_461Fr> = PrimeField::new();
+static G1_BLS12_461            : G1Field<5,Bls12_461Fr,8,Bls12_461Fp,10> = G1Field { consts : &BLS12_461_G1_CONSTS, base_field : &FP_BLS12_461, fr_field : &FR_BLS12_461 };
+static G2_BLS12_461            : G2Field<4,5,Bls12_461Fr,8,Bls12_461Fp,4,G2Fp2_1<8,Bls12_461Fp>> = G2Field { consts : &BLS12_461_G2_CONSTS, base_field : &EXFP2_BLS12_461, fr_field : &FR_BLS12_461 };
+static FP12_BLS12_461          : Fp12Field<4,8,Bls12_461Fp> = Fp12Field::const_new(PrimeField::new(), &BLS12_461_FROB_CONSTS);
+static GT_BLS12_461            : GTField<8,Bls12_461Fp,4,GTFp12_1<8,Bls12_461Fp,4>> = GTField::new(GTExtField::Fp12_1(&FP12_BLS12_461));
+
+
+
+pub const fn fr_bls12_461() -> &'static PrimeField<5,Bls12_461Fr>
+{   &FR_BLS12_461
 }
 
-pub fn g1_bls12_461() -> &'static G1Field<5,8,10>
-{   if G1_BLS12_461.get().is_none() 
-            { if BLS12_461_R_FIELD_PARAMS.get().is_none()
-                    {BLS12_461_R_FIELD_PARAMS.set(build_field_params(BLS12_461_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS12_461_FIELD_PARAMS.get().is_none() 
-                    {BLS12_461_FIELD_PARAMS.set(build_field_params(BLS12_461_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS12_461.get().is_none() 
-                    {FP_BLS12_461.set(PrimeField::new(&BLS12_461_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS12_461.get().is_none() 
-                    {FR_BLS12_461.set(PrimeField::new(&BLS12_461_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS12_461_G1_CONSTS.get().is_none() 
-                    {BLS12_461_G1_CONSTS.set(build_g1_params(BLS12_461_PARAMS,
-                                                        &FP_BLS12_461.get().unwrap(),
-                                                           &FR_BLS12_461.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS12_461.set(G1Field { consts : BLS12_461_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS12_461.get().unwrap(),
-                                                  fr_field : FR_BLS12_461.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS12_461.get().unwrap()
+pub const fn g1_bls12_461() -> &'static G1Field<5,Bls12_461Fr,8,Bls12_461Fp,10>
+{   &G1_BLS12_461
 }
 
-pub fn g2_bls12_461() -> &'static G2Field<4,5,8,4>
-{   if G2_BLS12_461.get().is_none() 
-                {   if G1_BLS12_461.get().is_none() 
-                            { let _ = g1_bls12_461();}
-                    if BLS12_461_FROB_CONSTS.get().is_none() 
-                            { BLS12_461_FROB_CONSTS.set(build_frob_params(BLS12_461_PARAMS, &FP_BLS12_461.get().unwrap())).unwrap()}; 
-                    if FP2_BLS12_461.get().is_none() 
-                            { FP2_BLS12_461.set(Fp2Field::new(&FP_BLS12_461.get().unwrap(),None )).unwrap();}                                                            
-                    if BLS12_461_G2_CONSTS.get().is_none() 
-                            {BLS12_461_G2_CONSTS.set(build_g2_params(BLS12_461_PARAMS,
-                                                                     &ExtG2Field::Fp2_1(FP2_BLS12_461.get().unwrap()),
-                                                                     &PrimeField::new(&BLS12_461_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS12_461_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP2_BLS12_461.get().is_none()  {EXFP2_BLS12_461.set(ExtG2Field::Fp2_1(&FP2_BLS12_461.get().unwrap())).unwrap();}                                     
-                    G2_BLS12_461.set(G2Field {  consts : BLS12_461_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP2_BLS12_461.get().unwrap() ,
-                                                fr_field : FR_BLS12_461.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS12_461.get().unwrap()
+pub const fn g2_bls12_461() -> &'static G2Field<4,5,Bls12_461Fr,8,Bls12_461Fp,4,G2Fp2_1<8,Bls12_461Fp>>
+{   &G2_BLS12_461
 }
 
-pub fn fp12_bls12_461()-> &'static Fp12Field<4, 8>
-{   if FP12_BLS12_461.get().is_none() {  if BLS12_461_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS12_461_FIELD_PARAMS.set(build_field_params(BLS12_461_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS12_461.get().is_none() 
-                                                        {   FP_BLS12_381.set(PrimeField::new(&BLS12_381_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS12_461_FROB_CONSTS.get().is_none() 
-                                                        {   BLS12_461_FROB_CONSTS.set(build_frob_params(BLS12_461_PARAMS, &FP_BLS12_461.get().unwrap())).unwrap()
-                                                        };               
-                                         FP12_BLS12_461.set(Fp12Field::new(&FP_BLS12_461.get().unwrap(), Some(&BLS12_461_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP12_BLS12_461.get().unwrap()
+pub const fn fp12_bls12_461() -> &'static Fp12Field<4, 8,Bls12_461Fp>
+{   &FP12_BLS12_461
 }
 
-pub fn gt_bls12_461() -> &'static GTField<8,4>
-{  
-        if FP12_BLS12_461.get().is_none() { let _ = fp12_bls12_461(); };
-        GT_BLS12_461.set(GTField::Fp12_1(FP12_BLS12_461.get().unwrap())).unwrap();
-        GT_BLS12_461.get().unwrap()       
+pub const fn gt_bls12_461() -> &'static GTField<8,Bls12_461Fp,4,GTFp12_1<8,Bls12_461Fp,4>>
+{   &GT_BLS12_461
 }
 
 
-static BLS12_446_FIELD_PARAMS   : OnceCell<FieldParams<7>>              = OnceCell::new();    
-static BLS12_446_R_FIELD_PARAMS : OnceCell<FieldParams<5>>              = OnceCell::new();    
-static BLS12_446_FROB_CONSTS    : OnceCell<ExFieldConsts<4,7>>          = OnceCell::new();
-static BLS12_446_G1_CONSTS      : OnceCell<G1Consts<5,7,3>>            = OnceCell::new();
-static BLS12_446_G2_CONSTS      : OnceCell<G2Consts<4,5,7,7>>           = OnceCell::new();
-static FP_BLS12_446             : OnceCell<PrimeField<7>>               = OnceCell::new();
-static FP2_BLS12_446            : OnceCell<Fp2Field<7>>                 = OnceCell::new();
-static EXFP2_BLS12_446          : OnceCell<ExtG2Field<7,4>>             = OnceCell::new();
-static FR_BLS12_446             : OnceCell<PrimeField<5>>               = OnceCell::new();
-static G1_BLS12_446             : OnceCell<G1Field<5,7,3>>             = OnceCell::new();
-static G2_BLS12_446             : OnceCell<G2Field<4,5,7,7>>            = OnceCell::new();
-static FP12_BLS12_446           : OnceCell<Fp12Field<4,7>>              = OnceCell::new();
-static GT_BLS12_446             : OnceCell<GTField<7,4>>                = OnceCell::new();
-
-pub fn fr_bls12_446()-> &'static PrimeField<5>
-{   if FR_BLS12_446.get().is_none() 
-            { if BLS12_446_R_FIELD_PARAMS.get().is_none() 
-                    {BLS12_446_R_FIELD_PARAMS.set(build_field_params(BLS12_446_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS12_446.set(PrimeField::new(&BLS12_446_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS12_446.get().unwrap()
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_446Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12_446Fr;
+impl FieldConfig<7> for Bls12_446Fp { const PARAMS : FieldParams<'static,7> = build_field_params(BLS12_446_PARAMS.modulo_as_strhex); }
+impl FieldConfig<5> for Bls12_446Fr { const PARAMS : FieldParams<'static,5> = build_field_params(BLS12_446_PARAMS.r_as_strhex); }
+
+static BLS12_446_FROB_CONSTS   : ExFieldConsts<4,7,Bls12_446Fp> = build_frob_params(BLS12_446_PARAMS);
+static BLS12_446_GLV           : LatticeDecomposer<5> = LatticeDecomposer::new(&glv_basis(BLS12_446_PARAMS.lambda, BLS12_446_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x97ce5c19d9fade2b, 0xc46c24743a8c394b, 0x71b3dbed0f4, 0x0, 0x0]),
+                                                                                 (false, [0x328047, 0x0, 0x0, 0x0, 0x0])]);
+static BLS12_446_GLS           : LatticeDecomposer<5> = LatticeDecomposer::new(&gls_basis::<5,16>(BLS12_446_PARAMS.u),
+                                                                               &[(true, [0xcd86fb55282cc3da, 0x2bc25ea5f055ebab, 0x26a3382bc08c3ea5, 0x2aa70e6aad99b2, 0x0]),
+                                                                                 (false, [0x97ce5c19d9c85de5, 0xc46c24743a8c394b, 0x71b3dbed0f4, 0x0, 0x0]),
+                                                                                 (true, [0x3c2549a660225341, 0x12f1b4d09, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x328047, 0x0, 0x0, 0x0, 0x0])]);
+static BLS12_446_G1_CONSTS     : G1Consts<5,Bls12_446Fr,7,Bls12_446Fp,3> = build_g1_params(BLS12_446_PARAMS, &BLS12_446_GLV);
+static BLS12_446_G2_CONSTS     : G2Consts<4,5,Bls12_446Fr,7,Bls12_446Fp,7,G2Fp2_1<7,Bls12_446Fp>> = build_g2_params_fp2_1(BLS12_446_PARAMS, &BLS12_446_FROB_CONSTS, &BLS12_446_GLS);
+static FP_BLS12_446            : PrimeField<7,Bls12_446Fp> = PrimeField::new();
+static FP2_BLS12_446           : Fp2Field<7,Bls12_446Fp> = Fp2Field::const_new(PrimeField::new());
+static EXFP2_BLS12_446         : ExtG2Field<7,Bls12_446Fp,4> = ExtG2Field::Fp2_1(&FP2_BLS12_446);
+static FR_BLS12_446            : PrimeField<5,Bls12_446Fr> = PrimeField::new();
+static G1_BLS12_446            : G1Field<5,Bls12_446Fr,7,Bls12_446Fp,3> = G1Field { consts : &BLS12_446_G1_CONSTS, base_field : &FP_BLS12_446, fr_field : &FR_BLS12_446 };
+static G2_BLS12_446            : G2Field<4,5,Bls12_446Fr,7,Bls12_446Fp,7,G2Fp2_1<7,Bls12_446Fp>> = G2Field { consts : &BLS12_446_G2_CONSTS, base_field : &EXFP2_BLS12_446, fr_field : &FR_BLS12_446 };
+static FP12_BLS12_446          : Fp12Field<4,7,Bls12_446Fp> = Fp12Field::const_new(PrimeField::new(), &BLS12_446_FROB_CONSTS);
+static GT_BLS12_446            : GTField<7,Bls12_446Fp,4,GTFp12_1<7,Bls12_446Fp,4>> = GTField::new(GTExtField::Fp12_1(&FP12_BLS12_446));
+
+pub const fn fr_bls12_446() -> &'static PrimeField<5,Bls12_446Fr>
+{   &FR_BLS12_446
 }
 
-pub fn g1_bls12_446() -> &'static G1Field<5,7,3>
-{   if G1_BLS12_446.get().is_none() 
-            { if BLS12_446_R_FIELD_PARAMS.get().is_none()
-                    {BLS12_446_R_FIELD_PARAMS.set(build_field_params(BLS12_446_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS12_446_FIELD_PARAMS.get().is_none() 
-                    {BLS12_446_FIELD_PARAMS.set(build_field_params(BLS12_446_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS12_446.get().is_none() 
-                    {FP_BLS12_446.set(PrimeField::new(&BLS12_446_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS12_446.get().is_none() 
-                    {FR_BLS12_446.set(PrimeField::new(&BLS12_446_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS12_446_G1_CONSTS.get().is_none() 
-                    {BLS12_446_G1_CONSTS.set(build_g1_params(BLS12_446_PARAMS,
-                                                        &FP_BLS12_446.get().unwrap(),
-                                                           &FR_BLS12_446.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS12_446.set(G1Field { consts : BLS12_446_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS12_446.get().unwrap(),
-                                                  fr_field : FR_BLS12_446.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS12_446.get().unwrap()
+pub const fn g1_bls12_446() -> &'static G1Field<5,Bls12_446Fr,7,Bls12_446Fp,3>
+{   &G1_BLS12_446
 }
 
-pub fn g2_bls12_446() -> &'static G2Field<4,5,7,7>
-{   if G2_BLS12_446.get().is_none() 
-                {   if G1_BLS12_446.get().is_none() 
-                            { let _ = g1_bls12_446();}
-                    if BLS12_446_FROB_CONSTS.get().is_none() 
-                            { BLS12_446_FROB_CONSTS.set(build_frob_params(BLS12_446_PARAMS, &FP_BLS12_446.get().unwrap())).unwrap()}; 
-                    if FP2_BLS12_446.get().is_none() 
-                            { FP2_BLS12_446.set(Fp2Field::new(&FP_BLS12_446.get().unwrap(),None )).unwrap();}                                                            
-                    if BLS12_446_G2_CONSTS.get().is_none() 
-                            {BLS12_446_G2_CONSTS.set(build_g2_params(BLS12_446_PARAMS,
-                                                                     &ExtG2Field::Fp2_1(FP2_BLS12_446.get().unwrap()),
-                                                                     &PrimeField::new(&BLS12_446_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS12_446_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP2_BLS12_446.get().is_none()  {EXFP2_BLS12_446.set(ExtG2Field::Fp2_1(&FP2_BLS12_446.get().unwrap())).unwrap();}                                     
-                    G2_BLS12_446.set(G2Field {  consts : BLS12_446_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP2_BLS12_446.get().unwrap() ,
-                                                fr_field : FR_BLS12_446.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS12_446.get().unwrap()
+pub const fn g2_bls12_446() -> &'static G2Field<4,5,Bls12_446Fr,7,Bls12_446Fp,7,G2Fp2_1<7,Bls12_446Fp>>
+{   &G2_BLS12_446
 }
 
-pub fn fp12_bls12_446()-> &'static Fp12Field<4, 7>
-{   if FP12_BLS12_446.get().is_none() {  if BLS12_446_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS12_446_FIELD_PARAMS.set(build_field_params(BLS12_446_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS12_446.get().is_none() 
-                                                        {   FP_BLS12_381.set(PrimeField::new(&BLS12_381_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS12_446_FROB_CONSTS.get().is_none() 
-                                                        {   BLS12_446_FROB_CONSTS.set(build_frob_params(BLS12_446_PARAMS, &FP_BLS12_446.get().unwrap())).unwrap()
-                                                        };               
-                                         FP12_BLS12_446.set(Fp12Field::new(&FP_BLS12_446.get().unwrap(), Some(&BLS12_446_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP12_BLS12_446.get().unwrap()
+pub const fn fp12_bls12_446() -> &'static Fp12Field<4, 7,Bls12_446Fp>
+{   &FP12_BLS12_446
 }
 
-pub fn gt_bls12_446() -> &'static GTField<7,4>
-{  
-        if FP12_BLS12_446.get().is_none() { let _ = fp12_bls12_446(); };
-        GT_BLS12_446.set(GTField::Fp12_1(FP12_BLS12_446.get().unwrap())).unwrap();
-        GT_BLS12_446.get().unwrap()       
+pub const fn gt_bls12_446() -> &'static GTField<7,Bls12_446Fp,4,GTFp12_1<7,Bls12_446Fp,4>>
+{   &GT_BLS12_446
 }
\ No newline at end of file
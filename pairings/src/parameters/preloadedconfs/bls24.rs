@@ -4,591 +4,344 @@
 
 use crate::curves::g1::G1Consts;
 use crate::curves::g2::*;
-use crate::curves::gt::GTField;
-use crate::extensions::ext_fields::{ExFieldConsts, ExtField};
-use crate::fields::prime_fields::{FieldParams, PrimeField};
+use crate::curves::gt::{GTExtField, GTField};
+use crate::extensions::towers::{G2Fp4_1, G2Fp4_2, GTFp24_1, GTFp24_2};
+use crate::extensions::ext_fields::ExFieldConsts;
+use crate::fields::prime_fields::{FieldConfig, FieldParams, PrimeField};
+use crate::tools::decomposition::LatticeDecomposer;
 use crate::{curves::g1::G1Field, extensions::towering1::fp24::Fp24Field as Fp24Field_1,extensions::towering2::fp24::Fp24Field as Fp24Field_2};
 use crate::extensions::towering1::fp4::Fp4Field as Fp4Field_1;
 use crate::extensions::g2_extfields::ExtG2Field;
 use crate::extensions::towering2::fp4::Fp4Field as Fp4Field_2;
 use crate::parameters::builders::*;
 use crate::parameters::paramlist::{BLS24_315_PARAMS, BLS24_479_PARAMS,BLS24_477_PARAMS, BLS24_509_PARAMS, BLS24_509_SNARK_PARAMS, BLS24_559_PARAMS};
-use once_cell::sync::OnceCell;
-
-static BLS24_477_FIELD_PARAMS   : OnceCell<FieldParams<8>> = OnceCell::new();    
-static BLS24_477_FROB_CONSTS    : OnceCell<ExFieldConsts<11,8>> = OnceCell::new();
-static BLS24_477_R_FIELD_PARAMS : OnceCell<FieldParams<6>> = OnceCell::new();    
-static FP_BLS24_477             : OnceCell<PrimeField<8>> = OnceCell::new();
-static FP4_BLS24_477            : OnceCell<Fp4Field_1<11,8>> = OnceCell::new();
-static EXFP4_BLS24_477          : OnceCell<ExtG2Field<8,11>> = OnceCell::new();
-static BLS24_477_G1_CONSTS      : OnceCell<G1Consts<6,8,7>> = OnceCell::new();
-static BLS24_477_G2_CONSTS      : OnceCell<G2Consts<11,6,8,4>> = OnceCell::new();
-static FR_BLS24_477             : OnceCell<PrimeField<6>> = OnceCell::new();
-static G1_BLS24_477             : OnceCell<G1Field<6,8,7>> = OnceCell::new();
-static G2_BLS24_477             : OnceCell<G2Field<11,6,8,4>> = OnceCell::new();
-static FP24_BLS24_477           : OnceCell<Fp24Field_1<11,8>> = OnceCell::new();
-static GT_BLS24_477             : OnceCell<GTField<8,11>> = OnceCell::new();
-
-pub fn fr_bls24_477()-> &'static PrimeField<6>
-{   if FR_BLS24_477.get().is_none() 
-            { if BLS24_477_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_477_R_FIELD_PARAMS.set(build_field_params(BLS24_477_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_477.set(PrimeField::new(&BLS24_477_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_477.get().unwrap()
-}
-pub fn fp4_bls24_477()-> &'static Fp4Field_1<11, 8>
-{   if FP4_BLS24_477.get().is_none() {  if BLS24_477_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_477_FIELD_PARAMS.set(build_field_params(BLS24_477_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_477.get().is_none() 
-                                            {   FP_BLS24_477.set(PrimeField::new(&BLS24_477_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_477_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_477_FROB_CONSTS.set(build_frob_params(BLS24_477_PARAMS, &FP_BLS24_477.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_477.set(Fp4Field_1::new( &FP_BLS24_477.get().unwrap(), Some(&BLS24_477_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_477.get().unwrap()
-}
-
-pub fn g1_bls24_477() -> &'static G1Field<6,8,7>
-{   if G1_BLS24_477.get().is_none() 
-            { if BLS24_477_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_477_R_FIELD_PARAMS.set(build_field_params(BLS24_477_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS24_477_FIELD_PARAMS.get().is_none() 
-                    {BLS24_477_FIELD_PARAMS.set(build_field_params(BLS24_477_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS24_477.get().is_none() 
-                    {FP_BLS24_477.set(PrimeField::new(&BLS24_477_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS24_477.get().is_none() 
-                    {FR_BLS24_477.set(PrimeField::new(&BLS24_477_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_477_G1_CONSTS.get().is_none() 
-                    {BLS24_477_G1_CONSTS.set(build_g1_params(BLS24_477_PARAMS,
-                                                        &FP_BLS24_477.get().unwrap(),
-                                                           &FR_BLS24_477.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_477.set(G1Field { consts : BLS24_477_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_477.get().unwrap(),
-                                                  fr_field : FR_BLS24_477.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS24_477.get().unwrap()
-}
-
-pub fn g2_bls24_477() -> &'static G2Field<11,6,8,4>
-{   if G2_BLS24_477.get().is_none() 
-                {   if G1_BLS24_477.get().is_none() 
-                            { let _ = g1_bls24_477();}
-                    if BLS24_477_FROB_CONSTS.get().is_none() 
-                            { BLS24_477_FROB_CONSTS.set(build_frob_params(BLS24_477_PARAMS, &FP_BLS24_477.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_477.get().is_none() 
-                            { let _ = fp4_bls24_477();}                                                            
-                    if BLS24_477_G2_CONSTS.get().is_none() 
-                            {BLS24_477_G2_CONSTS.set(build_g2_params(BLS24_477_PARAMS,
-                                                                     &ExtG2Field::Fp4_1(FP4_BLS24_477.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_477_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_477_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP4_BLS24_477.get().is_none()  {EXFP4_BLS24_477.set(ExtG2Field::Fp4_1(&FP4_BLS24_477.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_477.set(G2Field {  consts : BLS24_477_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_477.get().unwrap() ,
-                                                fr_field : FR_BLS24_477.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_477.get().unwrap()
-}
-
-pub fn fp24_bls24_477()-> &'static Fp24Field_1<11, 8>
-{   if FP24_BLS24_477.get().is_none() {  if BLS24_477_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_477_FIELD_PARAMS.set(build_field_params(BLS24_477_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_477.get().is_none() 
-                                            {   FP_BLS24_477.set(PrimeField::new(&BLS24_477_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_477_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_477_FROB_CONSTS.set(build_frob_params(BLS24_477_PARAMS, &FP_BLS24_477.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_477.set(Fp24Field_1::new(&FP_BLS24_477.get().unwrap(), Some(&BLS24_477_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_477.get().unwrap()
-}
-
-pub fn gt_bls24_477() -> &'static GTField<8,11>
-{  
-        if FP24_BLS24_477.get().is_none() { let _ = fp24_bls24_477(); };
-        GT_BLS24_477.set(GTField::Fp24_1(FP24_BLS24_477.get().unwrap())).unwrap();
-        GT_BLS24_477.get().unwrap()       
-}
-
-static BLS24_479_FIELD_PARAMS   : OnceCell<FieldParams<8>> = OnceCell::new();    
-static BLS24_479_FROB_CONSTS    : OnceCell<ExFieldConsts<10,8>> = OnceCell::new();
-static BLS24_479_R_FIELD_PARAMS : OnceCell<FieldParams<7>> = OnceCell::new();    
-static FP_BLS24_479             : OnceCell<PrimeField<8>> = OnceCell::new();
-static FP4_BLS24_479            : OnceCell<Fp4Field_1<10,8>> = OnceCell::new();
-static EXFP4_BLS24_479          : OnceCell<ExtG2Field<8,10>> = OnceCell::new();
-static BLS24_479_G1_CONSTS      : OnceCell<G1Consts<7,8,4>> = OnceCell::new();
-static BLS24_479_G2_CONSTS      : OnceCell<G2Consts<10,7,8,10>> = OnceCell::new();
-static FR_BLS24_479             : OnceCell<PrimeField<7>> = OnceCell::new();
-static G1_BLS24_479             : OnceCell<G1Field<7,8,4>> = OnceCell::new();
-static G2_BLS24_479             : OnceCell<G2Field<10,7,8,10>> = OnceCell::new();
-static FP24_BLS24_479           : OnceCell<Fp24Field_1<10,8>> = OnceCell::new();
-static GT_BLS24_479             : OnceCell<GTField<8,10>> = OnceCell::new();
-
-pub fn fr_bls24_479()-> &'static PrimeField<7>
-{   if FR_BLS24_479.get().is_none() 
-            { if BLS24_479_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_479_R_FIELD_PARAMS.set(build_field_params(BLS24_479_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_479.set(PrimeField::new(&BLS24_479_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_479.get().unwrap()
-}
-pub fn fp4_bls24_479()-> &'static Fp4Field_1<10, 8>
-{   if FP4_BLS24_479.get().is_none() {  if BLS24_479_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_479_FIELD_PARAMS.set(build_field_params(BLS24_479_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_479.get().is_none() 
-                                            {   FP_BLS24_479.set(PrimeField::new(&BLS24_479_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_479_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_479_FROB_CONSTS.set(build_frob_params(BLS24_479_PARAMS, &FP_BLS24_479.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_479.set(Fp4Field_1::new( &FP_BLS24_479.get().unwrap(), Some(&BLS24_479_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_479.get().unwrap()
-}
-
-pub fn g1_bls24_479() -> &'static G1Field<7,8,4>
-{   if G1_BLS24_479.get().is_none() 
-            { if BLS24_479_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_479_R_FIELD_PARAMS.set(build_field_params(BLS24_479_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS24_479_FIELD_PARAMS.get().is_none() 
-                    {BLS24_479_FIELD_PARAMS.set(build_field_params(BLS24_479_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS24_479.get().is_none() 
-                    {FP_BLS24_479.set(PrimeField::new(&BLS24_479_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS24_479.get().is_none() 
-                    {FR_BLS24_479.set(PrimeField::new(&BLS24_479_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_479_G1_CONSTS.get().is_none() 
-                    {BLS24_479_G1_CONSTS.set(build_g1_params(BLS24_479_PARAMS,
-                                                        &FP_BLS24_479.get().unwrap(),
-                                                           &FR_BLS24_479.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_479.set(G1Field { consts : BLS24_479_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_479.get().unwrap(),
-                                                  fr_field : FR_BLS24_479.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS24_479.get().unwrap()
-}
-
-pub fn g2_bls24_479() -> &'static G2Field<10,7,8,10>
-{   if G2_BLS24_479.get().is_none() 
-                {   if G1_BLS24_479.get().is_none() 
-                            { let _ = g1_bls24_479();}
-                    if BLS24_479_FROB_CONSTS.get().is_none() 
-                            { BLS24_479_FROB_CONSTS.set(build_frob_params(BLS24_479_PARAMS, &FP_BLS24_479.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_479.get().is_none() 
-                            { let _ = fp4_bls24_479();}                                                            
-                    if BLS24_479_G2_CONSTS.get().is_none() 
-                            {BLS24_479_G2_CONSTS.set(build_g2_params(BLS24_479_PARAMS,
-                                                                     &ExtG2Field::Fp4_1(FP4_BLS24_479.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_479_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_479_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP4_BLS24_479.get().is_none()  {EXFP4_BLS24_479.set(ExtG2Field::Fp4_1(&FP4_BLS24_479.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_479.set(G2Field {  consts : BLS24_479_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_479.get().unwrap() ,
-                                                fr_field : FR_BLS24_479.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_479.get().unwrap()
-}
-
-pub fn fp24_bls24_479()-> &'static Fp24Field_1<10, 8>
-{   if FP24_BLS24_479.get().is_none() {  if BLS24_479_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_479_FIELD_PARAMS.set(build_field_params(BLS24_479_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_479.get().is_none() 
-                                            {   FP_BLS24_479.set(PrimeField::new(&BLS24_479_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_479_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_479_FROB_CONSTS.set(build_frob_params(BLS24_479_PARAMS, &FP_BLS24_479.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_479.set(Fp24Field_1::new(&FP_BLS24_479.get().unwrap(), Some(&BLS24_479_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_479.get().unwrap()
-}
-
-pub fn gt_bls24_479() -> &'static GTField<8,10>
-{  
-        if FP24_BLS24_479.get().is_none() { let _ = fp24_bls24_479(); };
-        GT_BLS24_479.set(GTField::Fp24_1(FP24_BLS24_479.get().unwrap())).unwrap();
-        GT_BLS24_479.get().unwrap()       
-}
-
-static BLS24_559_FIELD_PARAMS       : OnceCell<FieldParams<9>> = OnceCell::new();    
-static BLS24_559_FROB_CONSTS        : OnceCell<ExFieldConsts<10,9>> = OnceCell::new();
-static BLS24_559_R_FIELD_PARAMS     : OnceCell<FieldParams<8>> = OnceCell::new();   
-static FP_BLS24_559                 : OnceCell<PrimeField<9>> = OnceCell::new(); 
-static FP4_BLS24_559                : OnceCell<Fp4Field_1<10,9>> = OnceCell::new();
-static EXFP4_BLS24_559              : OnceCell<ExtG2Field<9,10>> = OnceCell::new();
-static FP24_BLS24_559               : OnceCell<Fp24Field_1<10,9>> = OnceCell::new();
-static BLS24_559_G1_CONSTS          : OnceCell<G1Consts<8,9,4>> = OnceCell::new();
-static BLS24_559_G2_CONSTS          : OnceCell<G2Consts<10,8,9,4>> = OnceCell::new();
-static FR_BLS24_559                 : OnceCell<PrimeField<8>> = OnceCell::new();
-static G1_BLS24_559                 : OnceCell<G1Field<8,9,4>> = OnceCell::new();
-static G2_BLS24_559                 : OnceCell<G2Field<10,8,9,4>> = OnceCell::new();
-static GT_BLS24_559                 : OnceCell<GTField<9,10>> = OnceCell::new();
-
-
-pub fn fr_bls24_559()-> &'static PrimeField<8>
-{   if FR_BLS24_559.get().is_none() 
-            { if BLS24_559_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_559_R_FIELD_PARAMS.set(build_field_params(BLS24_559_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_559.set(PrimeField::new(&BLS24_559_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_559.get().unwrap()
-}
-
-pub fn fp4_bls24_559()-> &'static Fp4Field_1<10, 9>
-{   if FP4_BLS24_559.get().is_none() {  if BLS24_559_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_559_FIELD_PARAMS.set(build_field_params(BLS24_559_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_559.get().is_none() 
-                                            {   FP_BLS24_559.set(PrimeField::new(&BLS24_559_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_559_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_559_FROB_CONSTS.set(build_frob_params(BLS24_559_PARAMS, &FP_BLS24_559.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_559.set(Fp4Field_1::new(&FP_BLS24_559.get().unwrap(), Some(&BLS24_559_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_559.get().unwrap()
-}
-
-pub fn g1_bls24_559() -> &'static G1Field<8,9,4>
-{   if G1_BLS24_559.get().is_none() 
-            { if BLS24_559_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_559_R_FIELD_PARAMS.set(build_field_params(BLS24_559_PARAMS.r_as_strhex)).unwrap()}; 
-              if BLS24_559_FIELD_PARAMS.get().is_none() 
-                    {BLS24_559_FIELD_PARAMS.set(build_field_params(BLS24_559_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if FP_BLS24_559.get().is_none() 
-                    {FP_BLS24_559.set(PrimeField::new(&BLS24_559_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS24_559.get().is_none() 
-                    {FR_BLS24_559.set(PrimeField::new(&BLS24_559_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_559_G1_CONSTS.get().is_none() 
-                    {BLS24_559_G1_CONSTS.set(build_g1_params(BLS24_559_PARAMS,
-                                                        &FP_BLS24_559.get().unwrap(),
-                                                           &FR_BLS24_559.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_559.set(G1Field { consts : BLS24_559_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_559.get().unwrap(),
-                                                  fr_field : FR_BLS24_559.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS24_559.get().unwrap()
-}
-
-pub fn g2_bls24_559() -> &'static G2Field<10,8,9,4>
-{   if G2_BLS24_559.get().is_none() 
-                {   if G1_BLS24_559.get().is_none() 
-                            { let _ = g1_bls24_559();}
-                    if BLS24_559_FROB_CONSTS.get().is_none() 
-                            { BLS24_559_FROB_CONSTS.set(build_frob_params(BLS24_559_PARAMS, &FP_BLS24_559.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_559.get().is_none() 
-                            { let _ = fp4_bls24_559();}                                                            
-                    if BLS24_559_G2_CONSTS.get().is_none() 
-                            {BLS24_559_G2_CONSTS.set(build_g2_params(BLS24_559_PARAMS,
-                                                                     &ExtG2Field::Fp4_1(FP4_BLS24_559.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_559_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_559_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                   
-                    if EXFP4_BLS24_559.get().is_none()  {EXFP4_BLS24_559.set(ExtG2Field::Fp4_1(&FP4_BLS24_559.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_559.set(G2Field {  consts : BLS24_559_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_559.get().unwrap() ,
-                                                        fr_field : FR_BLS24_559.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_559.get().unwrap()
-}
-
-pub fn fp24_bls24_559()-> &'static Fp24Field_1<10, 9>
-{   if FP24_BLS24_559.get().is_none() {  if BLS24_559_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_559_FIELD_PARAMS.set(build_field_params(BLS24_559_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_559.get().is_none() 
-                                            {   FP_BLS24_559.set(PrimeField::new(&BLS24_559_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_559_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_559_FROB_CONSTS.set(build_frob_params(BLS24_559_PARAMS, &FP_BLS24_559.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_559.set(Fp24Field_1::new(&FP_BLS24_559.get().unwrap(), Some(&BLS24_559_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_559.get().unwrap()
-}
-
-pub fn gt_bls24_559() -> &'static GTField<9,10>
-{  
-        if FP24_BLS24_559.get().is_none() { let _ = fp24_bls24_559(); };
-        GT_BLS24_559.set(GTField::Fp24_1(FP24_BLS24_559.get().unwrap())).unwrap();
-        GT_BLS24_559.get().unwrap()       
-}
-
-static BLS24_315_FIELD_PARAMS       : OnceCell<FieldParams<5>> = OnceCell::new();    
-static BLS24_315_FROB_CONSTS        : OnceCell<ExFieldConsts<11,5>> = OnceCell::new();
-static BLS24_315_R_FIELD_PARAMS     : OnceCell<FieldParams<4>> = OnceCell::new();   
-static FP_BLS24_315                 : OnceCell<PrimeField<5>> = OnceCell::new(); 
-static FP4_BLS24_315                : OnceCell<Fp4Field_2<11,5>> = OnceCell::new();
-static EXFP4_BLS24_315              : OnceCell<ExtG2Field<5,11>> = OnceCell::new();
-static FP24_BLS24_315               : OnceCell<Fp24Field_2<11,5>> = OnceCell::new();
-static BLS24_315_G1_CONSTS          : OnceCell<G1Consts<4,5,3>> = OnceCell::new();
-static BLS24_315_G2_CONSTS          : OnceCell<G2Consts<11,4,5,7>> = OnceCell::new();
-static FR_BLS24_315                 : OnceCell<PrimeField<4>> = OnceCell::new();
-static G1_BLS24_315                 : OnceCell<G1Field<4,5,3>> = OnceCell::new();
-static G2_BLS24_315                 : OnceCell<G2Field<11,4,5,7>> = OnceCell::new();
-static GT_BLS24_315                 : OnceCell<GTField<5,11>> = OnceCell::new();
-
-pub fn fr_bls24_315()-> &'static PrimeField<4>
-{   if FR_BLS24_315.get().is_none() 
-            { if BLS24_315_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_315_R_FIELD_PARAMS.set(build_field_params(BLS24_315_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_315.set(PrimeField::new(&BLS24_315_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_315.get().unwrap()
-}
-pub fn fp4_bls24_315()-> &'static Fp4Field_2<11, 5>
-{   if FP4_BLS24_315.get().is_none() {  if BLS24_315_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_315_FIELD_PARAMS.set(build_field_params(BLS24_315_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_315.get().is_none() 
-                                            {   FP_BLS24_315.set(PrimeField::new(&BLS24_315_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_315_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_315_FROB_CONSTS.set(build_frob_params(BLS24_315_PARAMS, &FP_BLS24_315.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_315.set(Fp4Field_2::new( &FP_BLS24_315.get().unwrap(), Some(&BLS24_315_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_315.get().unwrap()
-}
-
-pub fn g1_bls24_315() -> &'static G1Field<4,5,3>
-{   if G1_BLS24_315.get().is_none() 
-            { 
-              if BLS24_315_FIELD_PARAMS.get().is_none() 
-                    {BLS24_315_FIELD_PARAMS.set(build_field_params(BLS24_315_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if BLS24_315_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_315_R_FIELD_PARAMS.set(build_field_params(BLS24_315_PARAMS.r_as_strhex)).unwrap()};                     
-              if FP_BLS24_315.get().is_none() 
-                    {FP_BLS24_315.set(PrimeField::new(&BLS24_315_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                               
-              if FR_BLS24_315.get().is_none() 
-                    {FR_BLS24_315.set(PrimeField::new(&BLS24_315_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_315_G1_CONSTS.get().is_none() 
-                    {BLS24_315_G1_CONSTS.set(build_g1_params(BLS24_315_PARAMS,
-                                                        &FP_BLS24_315.get().unwrap(),
-                                                           &FR_BLS24_315.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_315.set(G1Field { consts : BLS24_315_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_315.get().unwrap(),
-                                                  fr_field : FR_BLS24_315.get().unwrap()}).unwrap() ;                                                  
-            }
-    G1_BLS24_315.get().unwrap()
-}
-
-pub fn g2_bls24_315() -> &'static G2Field<11,4,5,7>
-{   if G2_BLS24_315.get().is_none() 
-                {   if G1_BLS24_315.get().is_none() 
-                            { let _ = g1_bls24_315();}
-                    if BLS24_315_FROB_CONSTS.get().is_none() 
-                            { BLS24_315_FROB_CONSTS.set(build_frob_params(BLS24_315_PARAMS, &FP_BLS24_315.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_315.get().is_none() 
-                            { let _ = fp4_bls24_315();}                                                                                           
-                    if BLS24_315_G2_CONSTS.get().is_none() 
-                            {BLS24_315_G2_CONSTS.set(build_g2_params(BLS24_315_PARAMS,
-                                                                     &ExtG2Field::Fp4_2(FP4_BLS24_315.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_315_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_315_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                                           
-                    if EXFP4_BLS24_315.get().is_none()  {EXFP4_BLS24_315.set(ExtG2Field::Fp4_2(&FP4_BLS24_315.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_315.set(G2Field {  consts : BLS24_315_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_315.get().unwrap() ,
-                                                fr_field : FR_BLS24_315.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_315.get().unwrap()
-}
-
-pub fn fp24_bls24_315()-> &'static Fp24Field_2<11, 5>
-{  if FP24_BLS24_315.get().is_none() {  if BLS24_315_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_315_FIELD_PARAMS.set(build_field_params(BLS24_315_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_315.get().is_none() 
-                                            {   FP_BLS24_315.set(PrimeField::new(&BLS24_315_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_315_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_315_FROB_CONSTS.set(build_frob_params(BLS24_315_PARAMS, &FP_BLS24_315.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_315.set(Fp24Field_2::new(&FP_BLS24_315.get().unwrap(), Some(&BLS24_315_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_315.get().unwrap()
-}
-
-pub fn gt_bls24_315() -> &'static GTField<5,11>
-{  
-        if FP24_BLS24_315.get().is_none() { let _ = fp24_bls24_315(); };
-        GT_BLS24_315.set(GTField::Fp24_2(FP24_BLS24_315.get().unwrap())).unwrap();
-        GT_BLS24_315.get().unwrap()       
-}
-
-static BLS24_509_SNARK_FIELD_PARAMS       : OnceCell<FieldParams<8>> = OnceCell::new();    
-static BLS24_509_SNARK_FROB_CONSTS        : OnceCell<ExFieldConsts<11,8>> = OnceCell::new();
-static BLS24_509_SNARK_R_FIELD_PARAMS     : OnceCell<FieldParams<7>> = OnceCell::new();   
-static FP_BLS24_509_SNARK                 : OnceCell<PrimeField<8>> = OnceCell::new(); 
-static FP4_BLS24_509_SNARK                : OnceCell<Fp4Field_2<11,8>> = OnceCell::new();
-static EXFP4_BLS24_509_SNARK              : OnceCell<ExtG2Field<8,11>> = OnceCell::new();
-static FP24_BLS24_509_SNARK               : OnceCell<Fp24Field_2<11,8>> = OnceCell::new();
-static BLS24_509_SNARK_G1_CONSTS          : OnceCell<G1Consts<7,8,3>> = OnceCell::new();
-static BLS24_509_SNARK_G2_CONSTS          : OnceCell<G2Consts<11,7,8,7>> = OnceCell::new();
-static FR_BLS24_509_SNARK                 : OnceCell<PrimeField<7>> = OnceCell::new();
-static G1_BLS24_509_SNARK                 : OnceCell<G1Field<7,8,3>> = OnceCell::new();
-static G2_BLS24_509_SNARK                 : OnceCell<G2Field<11,7,8,7>> = OnceCell::new();
-static GT_BLS24_509_SNARK                 : OnceCell<GTField<8,11>> = OnceCell::new();
-
-
-pub fn fr_bls24_509_snark()-> &'static PrimeField<7>
-{   if FR_BLS24_509_SNARK.get().is_none() 
-            { if BLS24_509_SNARK_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_509_SNARK_R_FIELD_PARAMS.set(build_field_params(BLS24_509_SNARK_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_509_SNARK.set(PrimeField::new(&BLS24_509_SNARK_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_509_SNARK.get().unwrap()
-}
-
-pub fn fp4_bls24_509_snark()-> &'static Fp4Field_2<11, 8>
-{   if FP4_BLS24_509_SNARK.get().is_none() {  if BLS24_509_SNARK_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_509_SNARK_FIELD_PARAMS.set(build_field_params(BLS24_509_SNARK_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_509_SNARK.get().is_none() 
-                                            {   FP_BLS24_509_SNARK.set(PrimeField::new(&BLS24_509_SNARK_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_509_SNARK_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_509_SNARK_FROB_CONSTS.set(build_frob_params(BLS24_509_SNARK_PARAMS, &FP_BLS24_509_SNARK.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_509_SNARK.set(Fp4Field_2::new(&FP_BLS24_509_SNARK.get().unwrap(), Some(&BLS24_509_SNARK_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_509_SNARK.get().unwrap()
-}
-
-pub fn g1_bls24_509_snark() -> &'static G1Field<7,8,3>
-{   if G1_BLS24_509_SNARK.get().is_none() 
-            { 
-              if BLS24_509_SNARK_FIELD_PARAMS.get().is_none() 
-                    {BLS24_509_SNARK_FIELD_PARAMS.set(build_field_params(BLS24_509_SNARK_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if BLS24_509_SNARK_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_509_SNARK_R_FIELD_PARAMS.set(build_field_params(BLS24_509_SNARK_PARAMS.r_as_strhex)).unwrap()};                     
-              if FP_BLS24_509_SNARK.get().is_none() 
-                    {FP_BLS24_509_SNARK.set(PrimeField::new(&BLS24_509_SNARK_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                               
-              if FR_BLS24_509_SNARK.get().is_none() 
-                    {FR_BLS24_509_SNARK.set(PrimeField::new(&BLS24_509_SNARK_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_509_SNARK_G1_CONSTS.get().is_none() 
-                    {BLS24_509_SNARK_G1_CONSTS.set(build_g1_params(BLS24_509_SNARK_PARAMS,
-                                                        &FP_BLS24_509_SNARK.get().unwrap(),
-                                                           &FR_BLS24_509_SNARK.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_509_SNARK.set(G1Field { consts : BLS24_509_SNARK_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_509_SNARK.get().unwrap(),
-                                                  fr_field : FR_BLS24_509_SNARK.get().unwrap()}).unwrap() ;                                                  
-            }
-    G1_BLS24_509_SNARK.get().unwrap()
-}
-
-pub fn g2_bls24_509_snark() -> &'static G2Field<11,7,8,7>
-{   if G2_BLS24_509_SNARK.get().is_none() 
-                {   if G1_BLS24_509_SNARK.get().is_none() 
-                            { let _ = g1_bls24_509_snark();}
-                    if BLS24_509_SNARK_FROB_CONSTS.get().is_none() 
-                            { BLS24_509_SNARK_FROB_CONSTS.set(build_frob_params(BLS24_509_SNARK_PARAMS, &FP_BLS24_509_SNARK.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_509_SNARK.get().is_none() 
-                            { let _ = fp4_bls24_509_snark();}                                                                                           
-                    if BLS24_509_SNARK_G2_CONSTS.get().is_none() 
-                            {BLS24_509_SNARK_G2_CONSTS.set(build_g2_params(BLS24_509_SNARK_PARAMS,
-                                                                     &ExtG2Field::Fp4_2(FP4_BLS24_509_SNARK.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_509_SNARK_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_509_SNARK_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                                           
-                    if EXFP4_BLS24_509_SNARK.get().is_none()  {EXFP4_BLS24_509_SNARK.set(ExtG2Field::Fp4_2(&FP4_BLS24_509_SNARK.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_509_SNARK.set(G2Field {  consts : BLS24_509_SNARK_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_509_SNARK.get().unwrap() ,
-                                                fr_field : FR_BLS24_509_SNARK.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_509_SNARK.get().unwrap()
-}
-
-pub fn fp24_bls24_509_snark()-> &'static Fp24Field_2<11, 8>
-{  if FP24_BLS24_509_SNARK.get().is_none() {  if BLS24_509_SNARK_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_509_SNARK_FIELD_PARAMS.set(build_field_params(BLS24_509_SNARK_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_509_SNARK.get().is_none() 
-                                            {   FP_BLS24_509_SNARK.set(PrimeField::new(&BLS24_509_SNARK_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_509_SNARK_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_509_SNARK_FROB_CONSTS.set(build_frob_params(BLS24_509_SNARK_PARAMS, &FP_BLS24_509_SNARK.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_509_SNARK.set(Fp24Field_2::new(&FP_BLS24_509_SNARK.get().unwrap(), Some(&BLS24_509_SNARK_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_509_SNARK.get().unwrap()
-}
-
-pub fn gt_bls24_509_snark() -> &'static GTField<8,11>
-{  
-        if FP24_BLS24_509_SNARK.get().is_none() { let _ = fp24_bls24_509_snark(); };
-        GT_BLS24_509_SNARK.set(GTField::Fp24_2(FP24_BLS24_509_SNARK.get().unwrap())).unwrap();
-        GT_BLS24_509_SNARK.get().unwrap()       
-}
-
-static BLS24_509_FIELD_PARAMS       : OnceCell<FieldParams<8>> = OnceCell::new();    
-static BLS24_509_FROB_CONSTS        : OnceCell<ExFieldConsts<10,8>> = OnceCell::new();
-static BLS24_509_R_FIELD_PARAMS     : OnceCell<FieldParams<7>> = OnceCell::new();   
-static FP_BLS24_509                 : OnceCell<PrimeField<8>> = OnceCell::new(); 
-static FP4_BLS24_509                : OnceCell<Fp4Field_1<10,8>> = OnceCell::new();
-static EXFP4_BLS24_509              : OnceCell<ExtG2Field<8,10>> = OnceCell::new();
-static FP24_BLS24_509               : OnceCell<Fp24Field_1<10,8>> = OnceCell::new();
-static BLS24_509_G1_CONSTS          : OnceCell<G1Consts<7,8,3>> = OnceCell::new();
-static BLS24_509_G2_CONSTS          : OnceCell<G2Consts<10,7,8,10>> = OnceCell::new();
-static FR_BLS24_509                 : OnceCell<PrimeField<7>> = OnceCell::new();
-static G1_BLS24_509                 : OnceCell<G1Field<7,8,3>> = OnceCell::new();
-static G2_BLS24_509                 : OnceCell<G2Field<10,7,8,10>> = OnceCell::new();
-static GT_BLS24_509                 : OnceCell<GTField<8,10>> = OnceCell::new();
-
-pub fn fr_bls24_509()-> &'static PrimeField<7>
-{   if FR_BLS24_509.get().is_none() 
-            { if BLS24_509_R_FIELD_PARAMS.get().is_none() 
-                    {BLS24_509_R_FIELD_PARAMS.set(build_field_params(BLS24_509_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS24_509.set(PrimeField::new(&BLS24_509_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS24_509.get().unwrap()
-}
-
-pub fn fp4_bls24_509()-> &'static Fp4Field_1<10, 8>
-{   if FP4_BLS24_509.get().is_none() {  if BLS24_509_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_509_FIELD_PARAMS.set(build_field_params(BLS24_509_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_509.get().is_none() 
-                                            {   FP_BLS24_509.set(PrimeField::new(&BLS24_509_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_509_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_509_FROB_CONSTS.set(build_frob_params(BLS24_509_PARAMS, &FP_BLS24_509.get().unwrap())).unwrap()};               
-                                         FP4_BLS24_509.set(Fp4Field_1::new(&FP_BLS24_509.get().unwrap(), Some(&BLS24_509_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP4_BLS24_509.get().unwrap()
-}
-
-pub fn g1_bls24_509() -> &'static G1Field<7,8,3>
-{   if G1_BLS24_509.get().is_none() 
-            { 
-              if BLS24_509_FIELD_PARAMS.get().is_none() 
-                    {BLS24_509_FIELD_PARAMS.set(build_field_params(BLS24_509_PARAMS.modulo_as_strhex)).unwrap()}; 
-              if BLS24_509_R_FIELD_PARAMS.get().is_none()
-                    {BLS24_509_R_FIELD_PARAMS.set(build_field_params(BLS24_509_PARAMS.r_as_strhex)).unwrap()};                     
-              if FP_BLS24_509.get().is_none() 
-                    {FP_BLS24_509.set(PrimeField::new(&BLS24_509_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                               
-              if FR_BLS24_509.get().is_none() 
-                    {FR_BLS24_509.set(PrimeField::new(&BLS24_509_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS24_509_G1_CONSTS.get().is_none() 
-                    {BLS24_509_G1_CONSTS.set(build_g1_params(BLS24_509_PARAMS,
-                                                        &FP_BLS24_509.get().unwrap(),
-                                                           &FR_BLS24_509.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS24_509.set(G1Field { consts : BLS24_509_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS24_509.get().unwrap(),
-                                                  fr_field : FR_BLS24_509.get().unwrap()}).unwrap() ;                                                  
-            }
-    G1_BLS24_509.get().unwrap()
-}
-
-pub fn g2_bls24_509() -> &'static G2Field<10,7,8,10>
-{   if G2_BLS24_509.get().is_none() 
-                {   if G1_BLS24_509.get().is_none() 
-                            { let _ = g1_bls24_509();}
-                    if BLS24_509_FROB_CONSTS.get().is_none() 
-                            { BLS24_509_FROB_CONSTS.set(build_frob_params(BLS24_509_PARAMS, &FP_BLS24_509.get().unwrap())).unwrap()}; 
-                    if FP4_BLS24_509.get().is_none() 
-                            { let _ = fp4_bls24_509();}                                                                                           
-                    if BLS24_509_G2_CONSTS.get().is_none() 
-                            {BLS24_509_G2_CONSTS.set(build_g2_params(BLS24_509_PARAMS,
-                                                                     &ExtG2Field::Fp4_1(FP4_BLS24_509.get().unwrap()),
-                                                                     &PrimeField::new(&BLS24_509_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS24_509_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                                           
-                    if EXFP4_BLS24_509.get().is_none()  {EXFP4_BLS24_509.set(ExtG2Field::Fp4_1(&FP4_BLS24_509.get().unwrap())).unwrap();}                                     
-                    G2_BLS24_509.set(G2Field {  consts : BLS24_509_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP4_BLS24_509.get().unwrap() ,
-                                                fr_field : FR_BLS24_509.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS24_509.get().unwrap()
-}
-
-pub fn fp24_bls24_509()-> &'static Fp24Field_1<10, 8>
-{  if FP24_BLS24_509.get().is_none() {  if BLS24_509_FIELD_PARAMS.get().is_none() 
-                                            {   BLS24_509_FIELD_PARAMS.set(build_field_params(BLS24_509_PARAMS.modulo_as_strhex)).unwrap()};
-                                         if FP_BLS24_509.get().is_none() 
-                                            {   FP_BLS24_509.set(PrimeField::new(&BLS24_509_FIELD_PARAMS.get().unwrap())).unwrap()};
-                                         if BLS24_509_FROB_CONSTS.get().is_none() 
-                                            {   BLS24_509_FROB_CONSTS.set(build_frob_params(BLS24_509_PARAMS, &FP_BLS24_509.get().unwrap())).unwrap()};               
-                                         FP24_BLS24_509.set(Fp24Field_1::new(&FP_BLS24_509.get().unwrap(), Some(&BLS24_509_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP24_BLS24_509.get().unwrap()
-}
-
-pub fn gt_bls24_509() -> &'static GTField<8,10>
-{  
-        if FP24_BLS24_509.get().is_none() { let _ = fp24_bls24_509(); };
-        GT_BLS24_509.set(GTField::Fp24_1(FP24_BLS24_509.get().unwrap())).unwrap();
-        GT_BLS24_509.get().unwrap()       
+
+// Marker types of the base (Fp) and scalar (Fr) fields, whose parameters are evaluated at compile time
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_477Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_477Fr;
+impl FieldConfig<8> for Bls24_477Fp { const PARAMS : FieldParams<'static,8> = build_field_params(BLS24_477_PARAMS.modulo_as_strhex); }
+impl FieldConfig<6> for Bls24_477Fr { const PARAMS : FieldParams<'static,6> = build_field_params(BLS24_477_PARAMS.r_as_strhex); }
+
+static BLS24_477_FROB_CONSTS   : ExFieldConsts<11,8,Bls24_477Fp> = build_frob_params(BLS24_477_PARAMS);
+static FP_BLS24_477            : PrimeField<8,Bls24_477Fp> = PrimeField::new();
+static FP4_BLS24_477           : Fp4Field_1<11,8,Bls24_477Fp> = Fp4Field_1::const_new(PrimeField::new(), &BLS24_477_FROB_CONSTS);
+static EXFP4_BLS24_477         : ExtG2Field<8,Bls24_477Fp,11> = ExtG2Field::Fp4_1(&FP4_BLS24_477);
+static BLS24_477_GLV           : LatticeDecomposer<6> = LatticeDecomposer::new(&glv_basis(BLS24_477_PARAMS.lambda, BLS24_477_PARAMS.r_as_strhex),
+                                                                               &[(false, [0xeb6dc222d01604a1, 0x9fcf057e30964e01, 0xb4bd6c0f7c86debd, 0x1, 0x0, 0x0]),
+                                                                                 (false, [0x3, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_477_GLS           : LatticeDecomposer<6> = LatticeDecomposer::new(&gls_basis::<6,64>(BLS24_477_PARAMS.u),
+                                                                               &[(true, [0x19169ce78d9a8d23, 0xea1b15957d868165, 0x9434eefbca845579, 0x11766bf8eff1fc4c, 0xf053f66438d482c8, 0x12492]),
+                                                                                 (false, [0x7fc7fe6024617609, 0xd03461384e7dacc1, 0xddd8c00c1ebb1a82, 0x8b77357b396da782, 0x14e5f8896, 0x0]),
+                                                                                 (true, [0x92e080ba38615689, 0x537946175f4e95f7, 0xa358843498564694, 0x17e24e42ed7c8, 0x0, 0x0]),
+                                                                                 (false, [0xeb6dc222d016049e, 0x9fcf057e30964e01, 0xb4bd6c0f7c86debd, 0x1, 0x0, 0x0]),
+                                                                                 (true, [0xe512b5bc0233df0f, 0xbd4a1df06988364d, 0x1f322, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xc48af0d9c0652458, 0x23a721e4c, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x28bf1972aec67, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x3, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_477_G1_CONSTS     : G1Consts<6,Bls24_477Fr,8,Bls24_477Fp,7> = build_g1_params(BLS24_477_PARAMS, &BLS24_477_GLV);
+static BLS24_477_G2_CONSTS     : G2Consts<11,6,Bls24_477Fr,8,Bls24_477Fp,4,G2Fp4_1<8,Bls24_477Fp,11>> = build_g2_params_fp4_1(BLS24_477_PARAMS, &BLS24_477_FROB_CONSTS, &BLS24_477_GLS);
+static FR_BLS24_477            : PrimeField<6,Bls24_477Fr> = PrimeField::new();
+static G1_BLS24_477            : G1Field<6,Bls24_477Fr,8,Bls24_477Fp,7> = G1Field { consts : &BLS24_477_G1_CONSTS, base_field : &FP_BLS24_477, fr_field : &FR_BLS24_477 };
+static G2_BLS24_477            : G2Field<11,6,Bls24_477Fr,8,Bls24_477Fp,4,G2Fp4_1<8,Bls24_477Fp,11>> = G2Field { consts : &BLS24_477_G2_CONSTS, base_field : &EXFP4_BLS24_477, fr_field : &FR_BLS24_477 };
+static FP24_BLS24_477          : Fp24Field_1<11,8,Bls24_477Fp> = Fp24Field_1::const_new(PrimeField::new(), &BLS24_477_FROB_CONSTS);
+static GT_BLS24_477            : GTField<8,Bls24_477Fp,11,GTFp24_1<8,Bls24_477Fp,11>> = GTField::new(GTExtField::Fp24_1(&FP24_BLS24_477));
+
+pub const fn fr_bls24_477() -> &'static PrimeField<6,Bls24_477Fr>
+{   &FR_BLS24_477
+}
+pub const fn fp4_bls24_477() -> &'static Fp4Field_1<11, 8,Bls24_477Fp>
+{   &FP4_BLS24_477
+}
+
+pub const fn g1_bls24_477() -> &'static G1Field<6,Bls24_477Fr,8,Bls24_477Fp,7>
+{   &G1_BLS24_477
+}
+
+pub const fn g2_bls24_477() -> &'static G2Field<11,6,Bls24_477Fr,8,Bls24_477Fp,4,G2Fp4_1<8,Bls24_477Fp,11>>
+{   &G2_BLS24_477
+}
+
+pub const fn fp24_bls24_477() -> &'static Fp24Field_1<11, 8,Bls24_477Fp>
+{   &FP24_BLS24_477
+}
+
+pub const fn gt_bls24_477() -> &'static GTField<8,Bls24_477Fp,11,GTFp24_1<8,Bls24_477Fp,11>>
+{   &GT_BLS24_477
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_479Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_479Fr;
+impl FieldConfig<8> for Bls24_479Fp { const PARAMS : FieldParams<'static,8> = build_field_params(BLS24_479_PARAMS.modulo_as_strhex); }
+impl FieldConfig<7> for Bls24_479Fr { const PARAMS : FieldParams<'static,7> = build_field_params(BLS24_479_PARAMS.r_as_strhex); }
+
+static BLS24_479_FROB_CONSTS   : ExFieldConsts<10,8,Bls24_479Fp> = build_frob_params(BLS24_479_PARAMS);
+static FP_BLS24_479            : PrimeField<8,Bls24_479Fp> = PrimeField::new();
+static FP4_BLS24_479           : Fp4Field_1<10,8,Bls24_479Fp> = Fp4Field_1::const_new(PrimeField::new(), &BLS24_479_FROB_CONSTS);
+static EXFP4_BLS24_479         : ExtG2Field<8,Bls24_479Fp,10> = ExtG2Field::Fp4_1(&FP4_BLS24_479);
+static BLS24_479_GLV           : LatticeDecomposer<7> = LatticeDecomposer::new(&glv_basis(BLS24_479_PARAMS.lambda, BLS24_479_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x7ea8ed0ab7d5d9fa, 0x5b0445a917aa816e, 0x9baa6a9d731d119b, 0x1c2001ea629a, 0x1, 0x0, 0x0]),
+                                                                                 (false, [0x384006ebc9a1, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_479_GLS           : LatticeDecomposer<7> = LatticeDecomposer::new(&gls_basis::<7,64>(BLS24_479_PARAMS.u),
+                                                                               &[(true, [0x8e713572cefb40ce, 0x3b0bb52fcb8ebd03, 0xb59634c697875ec3, 0xddf3e2f920e309b, 0x4af8d935d829f79f, 0x708003070414d8d, 0x10000]),
+                                                                                 (false, [0xbded6ce5200d7b8d, 0x99b3a45333b90a5b, 0x820a09e90b94e225, 0x8f104542ac977b9c, 0x9250c54445356e, 0x100000e10, 0x0]),
+                                                                                 (true, [0x5dd554ba2e371d2d, 0xf835d73c292a020, 0xa34613dec6e333c, 0xa18d3fc51d714f7a, 0x1000015180125, 0x0, 0x0]),
+                                                                                 (false, [0x7ea8b4cab0ea1059, 0x5b0445a917aa816d, 0x9baa6a9d731d119b, 0x1c2001ea629a, 0x1, 0x0, 0x0]),
+                                                                                 (true, [0x906c944dbbe0b0cd, 0x8e1b7da58f544100, 0x232802e093eeb392, 0x10000, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1e05220c5277d90b, 0x408358ae31b02a0, 0x100002a30, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x477085e14c6c2eb6, 0x1000031380561, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x384006ebc9a1, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_479_G1_CONSTS     : G1Consts<7,Bls24_479Fr,8,Bls24_479Fp,4> = build_g1_params(BLS24_479_PARAMS, &BLS24_479_GLV);
+static BLS24_479_G2_CONSTS     : G2Consts<10,7,Bls24_479Fr,8,Bls24_479Fp,10,G2Fp4_1<8,Bls24_479Fp,10>> = build_g2_params_fp4_1(BLS24_479_PARAMS, &BLS24_479_FROB_CONSTS, &BLS24_479_GLS);
+static FR_BLS24_479            : PrimeField<7,Bls24_479Fr> = PrimeField::new();
+static G1_BLS24_479            : G1Field<7,Bls24_479Fr,8,Bls24_479Fp,4> = G1Field { consts : &BLS24_479_G1_CONSTS, base_field : &FP_BLS24_479, fr_field : &FR_BLS24_479 };
+static G2_BLS24_479            : G2Field<10,7,Bls24_479Fr,8,Bls24_479Fp,10,G2Fp4_1<8,Bls24_479Fp,10>> = G2Field { consts : &BLS24_479_G2_CONSTS, base_field : &EXFP4_BLS24_479, fr_field : &FR_BLS24_479 };
+static FP24_BLS24_479          : Fp24Field_1<10,8,Bls24_479Fp> = Fp24Field_1::const_new(PrimeField::new(), &BLS24_479_FROB_CONSTS);
+static GT_BLS24_479            : GTField<8,Bls24_479Fp,10,GTFp24_1<8,Bls24_479Fp,10>> = GTField::new(GTExtField::Fp24_1(&FP24_BLS24_479));
+
+pub const fn fr_bls24_479() -> &'static PrimeField<7,Bls24_479Fr>
+{   &FR_BLS24_479
+}
+pub const fn fp4_bls24_479() -> &'static Fp4Field_1<10, 8,Bls24_479Fp>
+{   &FP4_BLS24_479
+}
+
+pub const fn g1_bls24_479() -> &'static G1Field<7,Bls24_479Fr,8,Bls24_479Fp,4>
+{   &G1_BLS24_479
+}
+
+pub const fn g2_bls24_479() -> &'static G2Field<10,7,Bls24_479Fr,8,Bls24_479Fp,10,G2Fp4_1<8,Bls24_479Fp,10>>
+{   &G2_BLS24_479
+}
+
+pub const fn fp24_bls24_479() -> &'static Fp24Field_1<10, 8,Bls24_479Fp>
+{   &FP24_BLS24_479
+}
+
+pub const fn gt_bls24_479() -> &'static GTField<8,Bls24_479Fp,10,GTFp24_1<8,Bls24_479Fp,10>>
+{   &GT_BLS24_479
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_559Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_559Fr;
+impl FieldConfig<9> for Bls24_559Fp { const PARAMS : FieldParams<'static,9> = build_field_params(BLS24_559_PARAMS.modulo_as_strhex); }
+impl FieldConfig<8> for Bls24_559Fr { const PARAMS : FieldParams<'static,8> = build_field_params(BLS24_559_PARAMS.r_as_strhex); }
+
+static BLS24_559_FROB_CONSTS   : ExFieldConsts<10,9,Bls24_559Fp> = build_frob_params(BLS24_559_PARAMS);
+static FP_BLS24_559            : PrimeField<9,Bls24_559Fp> = PrimeField::new();
+static FP4_BLS24_559           : Fp4Field_1<10,9,Bls24_559Fp> = Fp4Field_1::const_new(PrimeField::new(), &BLS24_559_FROB_CONSTS);
+static EXFP4_BLS24_559         : ExtG2Field<9,Bls24_559Fp,10> = ExtG2Field::Fp4_1(&FP4_BLS24_559);
+static FP24_BLS24_559          : Fp24Field_1<10,9,Bls24_559Fp> = Fp24Field_1::const_new(PrimeField::new(), &BLS24_559_FROB_CONSTS);
+static BLS24_559_GLV           : LatticeDecomposer<8> = LatticeDecomposer::new(&glv_basis(BLS24_559_PARAMS.lambda, BLS24_559_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x2676ec933cf588c9, 0x11f2ecf7751537cf, 0xdd82d7f8f7d1da51, 0xd8092ecc1b8e073d, 0xffe0027f, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xffc008ff10229a85, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_559_GLS           : LatticeDecomposer<8> = LatticeDecomposer::new(&gls_basis::<8,64>(BLS24_559_PARAMS.u),
+                                                                               &[(true, [0x1d6f21b3242bd124, 0x573b980dbe626595, 0x29306d7153616d09, 0x6aa29c8eb1b06d19, 0x64a03da40efc383, 0x53f1e39cb13cc901, 0xf8003ffe01cfe381, 0xff]),
+                                                                                 (false, [0x1637697c86c985b2, 0xf4282a1250d7ade0, 0xdcb92be5ea494922, 0x22ef91a693fea098, 0xb90c7fb5088140d2, 0xbff803cfa9055b, 0xfff0, 0x0]),
+                                                                                 (true, [0x1bf6e55f50f1497c, 0xec6770c6627cb91, 0x3aea87cadf9ec301, 0x9e058c93d72aed55, 0x7fec062f4d8d8f29, 0xffe801, 0x0, 0x0]),
+                                                                                 (false, [0x26b6e3942cd2ee45, 0x11f2ecf7751537ce, 0xdd82d7f8f7d1da51, 0xd8092ecc1b8e073d, 0xffe0027f, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x6e6eff048c36aeb3, 0x902992b0d89688a6, 0xd1e1d31340334b8, 0xffd803bfba, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xcd118d7681f47da9, 0x5d3650b0b9cf0d36, 0xffd0053f9012, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x97ca3aeddf757ac, 0xffc806ff58195c, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xffc008ff10229a85, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_559_G1_CONSTS     : G1Consts<8,Bls24_559Fr,9,Bls24_559Fp,4> = build_g1_params(BLS24_559_PARAMS, &BLS24_559_GLV);
+static BLS24_559_G2_CONSTS     : G2Consts<10,8,Bls24_559Fr,9,Bls24_559Fp,4,G2Fp4_1<9,Bls24_559Fp,10>> = build_g2_params_fp4_1(BLS24_559_PARAMS, &BLS24_559_FROB_CONSTS, &BLS24_559_GLS);
+static FR_BLS24_559            : PrimeField<8,Bls24_559Fr> = PrimeField::new();
+static G1_BLS24_559            : G1Field<8,Bls24_559Fr,9,Bls24_559Fp,4> = G1Field { consts : &BLS24_559_G1_CONSTS, base_field : &FP_BLS24_559, fr_field : &FR_BLS24_559 };
+static G2_BLS24_559            : G2Field<10,8,Bls24_559Fr,9,Bls24_559Fp,4,G2Fp4_1<9,Bls24_559Fp,10>> = G2Field { consts : &BLS24_559_G2_CONSTS, base_field : &EXFP4_BLS24_559, fr_field : &FR_BLS24_559 };
+static GT_BLS24_559            : GTField<9,Bls24_559Fp,10,GTFp24_1<9,Bls24_559Fp,10>> = GTField::new(GTExtField::Fp24_1(&FP24_BLS24_559));
+
+
+pub const fn fr_bls24_559() -> &'static PrimeField<8,Bls24_559Fr>
+{   &FR_BLS24_559
+}
+
+pub const fn fp4_bls24_559() -> &'static Fp4Field_1<10, 9,Bls24_559Fp>
+{   &FP4_BLS24_559
+}
+
+pub const fn g1_bls24_559() -> &'static G1Field<8,Bls24_559Fr,9,Bls24_559Fp,4>
+{   &G1_BLS24_559
+}
+
+pub const fn g2_bls24_559() -> &'static G2Field<10,8,Bls24_559Fr,9,Bls24_559Fp,4,G2Fp4_1<9,Bls24_559Fp,10>>
+{   &G2_BLS24_559
+}
+
+pub const fn fp24_bls24_559() -> &'static Fp24Field_1<10, 9,Bls24_559Fp>
+{   &FP24_BLS24_559
+}
+
+pub const fn gt_bls24_559() -> &'static GTField<9,Bls24_559Fp,10,GTFp24_1<9,Bls24_559Fp,10>>
+{   &GT_BLS24_559
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_315Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_315Fr;
+impl FieldConfig<5> for Bls24_315Fp { const PARAMS : FieldParams<'static,5> = build_field_params(BLS24_315_PARAMS.modulo_as_strhex); }
+impl FieldConfig<4> for Bls24_315Fr { const PARAMS : FieldParams<'static,4> = build_field_params(BLS24_315_PARAMS.r_as_strhex); }
+
+static BLS24_315_FROB_CONSTS   : ExFieldConsts<11,5,Bls24_315Fp> = build_frob_params(BLS24_315_PARAMS);
+static FP_BLS24_315            : PrimeField<5,Bls24_315Fp> = PrimeField::new();
+static FP4_BLS24_315           : Fp4Field_2<11,5,Bls24_315Fp> = Fp4Field_2::const_new(PrimeField::new(), &BLS24_315_FROB_CONSTS);
+static EXFP4_BLS24_315         : ExtG2Field<5,Bls24_315Fp,11> = ExtG2Field::Fp4_2(&FP4_BLS24_315);
+static FP24_BLS24_315          : Fp24Field_2<11,5,Bls24_315Fp> = Fp24Field_2::const_new(PrimeField::new(), &BLS24_315_FROB_CONSTS);
+static BLS24_315_GLV           : LatticeDecomposer<4> = LatticeDecomposer::new(&glv_basis(BLS24_315_PARAMS.lambda, BLS24_315_PARAMS.r_as_strhex),
+                                                                               &[(false, [0xc15551530756b093, 0x2c41307681d0b1c6, 0x3, 0x0]),
+                                                                                 (false, [0xa, 0x0, 0x0, 0x0])]);
+static BLS24_315_GLS           : LatticeDecomposer<4> = LatticeDecomposer::new(&gls_basis::<4,64>(BLS24_315_PARAMS.u),
+                                                                               &[(true, [0x626f2620b6170def, 0xb120a2a59590e7c1, 0x1eab55898b4743dd, 0x155aac007]),
+                                                                                 (false, [0x18ec8d6d5524e34c, 0xb9126e9adefbd5f6, 0xc80055769133dba1, 0x1]),
+                                                                                 (true, [0x449a52d286aad5b5, 0x9ee2fdbd39eb0b38, 0x26098981c, 0x0]),
+                                                                                 (false, [0xc15551530756b089, 0x2c41307681d0b1c6, 0x3, 0x0]),
+                                                                                 (true, [0x850da89d2fcfebe6, 0x43c109a1f, 0x0, 0x0]),
+                                                                                 (false, [0xa6d52d7cef14e2b1, 0x5, 0x0, 0x0]),
+                                                                                 (true, [0x78afefc70, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xa, 0x0, 0x0, 0x0])]);
+static BLS24_315_G1_CONSTS     : G1Consts<4,Bls24_315Fr,5,Bls24_315Fp,3> = build_g1_params(BLS24_315_PARAMS, &BLS24_315_GLV);
+static BLS24_315_G2_CONSTS     : G2Consts<11,4,Bls24_315Fr,5,Bls24_315Fp,7,G2Fp4_2<5,Bls24_315Fp,11>> = build_g2_params_fp4_2(BLS24_315_PARAMS, &BLS24_315_FROB_CONSTS, &BLS24_315_GLS);
+static FR_BLS24_315            : PrimeField<4,Bls24_315Fr> = PrimeField::new();
+static G1_BLS24_315            : G1Field<4,Bls24_315Fr,5,Bls24_315Fp,3> = G1Field { consts : &BLS24_315_G1_CONSTS, base_field : &FP_BLS24_315, fr_field : &FR_BLS24_315 };
+static G2_BLS24_315            : G2Field<11,4,Bls24_315Fr,5,Bls24_315Fp,7,G2Fp4_2<5,Bls24_315Fp,11>> = G2Field { consts : &BLS24_315_G2_CONSTS, base_field : &EXFP4_BLS24_315, fr_field : &FR_BLS24_315 };
+static GT_BLS24_315            : GTField<5,Bls24_315Fp,11,GTFp24_2<5,Bls24_315Fp,11>> = GTField::new(GTExtField::Fp24_2(&FP24_BLS24_315));
+
+pub const fn fr_bls24_315() -> &'static PrimeField<4,Bls24_315Fr>
+{   &FR_BLS24_315
+}
+pub const fn fp4_bls24_315() -> &'static Fp4Field_2<11, 5,Bls24_315Fp>
+{   &FP4_BLS24_315
+}
+
+pub const fn g1_bls24_315() -> &'static G1Field<4,Bls24_315Fr,5,Bls24_315Fp,3>
+{   &G1_BLS24_315
+}
+
+pub const fn g2_bls24_315() -> &'static G2Field<11,4,Bls24_315Fr,5,Bls24_315Fp,7,G2Fp4_2<5,Bls24_315Fp,11>>
+{   &G2_BLS24_315
+}
+
+pub const fn fp24_bls24_315() -> &'static Fp24Field_2<11, 5,Bls24_315Fp>
+{   &FP24_BLS24_315
+}
+
+pub const fn gt_bls24_315() -> &'static GTField<5,Bls24_315Fp,11,GTFp24_2<5,Bls24_315Fp,11>>
+{   &GT_BLS24_315
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_509SnarkFp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_509SnarkFr;
+impl FieldConfig<8> for Bls24_509SnarkFp { const PARAMS : FieldParams<'static,8> = build_field_params(BLS24_509_SNARK_PARAMS.modulo_as_strhex); }
+impl FieldConfig<7> for Bls24_509SnarkFr { const PARAMS : FieldParams<'static,7> = build_field_params(BLS24_509_SNARK_PARAMS.r_as_strhex); }
+
+static BLS24_509_SNARK_FROB_CONSTS: ExFieldConsts<11,8,Bls24_509SnarkFp> = build_frob_params(BLS24_509_SNARK_PARAMS);
+static FP_BLS24_509_SNARK      : PrimeField<8,Bls24_509SnarkFp> = PrimeField::new();
+static FP4_BLS24_509_SNARK     : Fp4Field_2<11,8,Bls24_509SnarkFp> = Fp4Field_2::const_new(PrimeField::new(), &BLS24_509_SNARK_FROB_CONSTS);
+static EXFP4_BLS24_509_SNARK   : ExtG2Field<8,Bls24_509SnarkFp,11> = ExtG2Field::Fp4_2(&FP4_BLS24_509_SNARK);
+static FP24_BLS24_509_SNARK    : Fp24Field_2<11,8,Bls24_509SnarkFp> = Fp24Field_2::const_new(PrimeField::new(), &BLS24_509_SNARK_FROB_CONSTS);
+static BLS24_509_SNARK_GLV     : LatticeDecomposer<7> = LatticeDecomposer::new(&glv_basis(BLS24_509_SNARK_PARAMS.lambda, BLS24_509_SNARK_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x83a3bf9c7fd3b3b6, 0x60857447c6595bc4, 0xb153e4cc32cb6a6b, 0xf74110e2e90cf, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xeece9f1f72, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_509_SNARK_GLS     : LatticeDecomposer<7> = LatticeDecomposer::new(&gls_basis::<7,64>(BLS24_509_SNARK_PARAMS.u),
+                                                                               &[(false, [0xb6c326196ff65eac, 0x82048a83eeeb799b, 0x119c37679ab49255, 0x2a583a37c64ce6e, 0x1ec487e49453128a, 0x1e602135c7de478c, 0x1fb9]),
+                                                                                 (false, [0xcdc8fcda6edb2b6a, 0x793d8e5d9f704244, 0xbb7a5d23fb76cd51, 0x3dc9fbb968040f88, 0x3295b2043c144046, 0x3ee5b38, 0x0]),
+                                                                                 (false, [0xb19c0202a1a25cbe, 0xbbf1ea16cab74628, 0xf8bd28552688202f, 0x6e97f31f029e936, 0x7cb4c32252, 0x0, 0x0]),
+                                                                                 (false, [0x83a3beadb1349444, 0x60857447c6595bc4, 0xb153e4cc32cb6a6b, 0xf74110e2e90cf, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x88aee68d6a8a3296, 0x1dc4b5855b94e7a2, 0xea3ac6726a73ddff, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1bf743c7aaefbdeb, 0x9cac3c77597bff15, 0x3cbf, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x57e28c0dad1f072f, 0x78721a6, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xeece9f1f72, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_509_SNARK_G1_CONSTS: G1Consts<7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,3> = build_g1_params(BLS24_509_SNARK_PARAMS, &BLS24_509_SNARK_GLV);
+static BLS24_509_SNARK_G2_CONSTS: G2Consts<11,7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,7,G2Fp4_2<8,Bls24_509SnarkFp,11>> = build_g2_params_fp4_2(BLS24_509_SNARK_PARAMS, &BLS24_509_SNARK_FROB_CONSTS, &BLS24_509_SNARK_GLS);
+static FR_BLS24_509_SNARK      : PrimeField<7,Bls24_509SnarkFr> = PrimeField::new();
+static G1_BLS24_509_SNARK      : G1Field<7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,3> = G1Field { consts : &BLS24_509_SNARK_G1_CONSTS, base_field : &FP_BLS24_509_SNARK, fr_field : &FR_BLS24_509_SNARK };
+static G2_BLS24_509_SNARK      : G2Field<11,7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,7,G2Fp4_2<8,Bls24_509SnarkFp,11>> = G2Field { consts : &BLS24_509_SNARK_G2_CONSTS, base_field : &EXFP4_BLS24_509_SNARK, fr_field : &FR_BLS24_509_SNARK };
+static GT_BLS24_509_SNARK      : GTField<8,Bls24_509SnarkFp,11,GTFp24_2<8,Bls24_509SnarkFp,11>> = GTField::new(GTExtField::Fp24_2(&FP24_BLS24_509_SNARK));
+
+
+pub const fn fr_bls24_509_snark() -> &'static PrimeField<7,Bls24_509SnarkFr>
+{   &FR_BLS24_509_SNARK
+}
+
+pub const fn fp4_bls24_509_snark() -> &'static Fp4Field_2<11, 8,Bls24_509SnarkFp>
+{   &FP4_BLS24_509_SNARK
+}
+
+pub const fn g1_bls24_509_snark() -> &'static G1Field<7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,3>
+{   &G1_BLS24_509_SNARK
+}
+
+pub const fn g2_bls24_509_snark() -> &'static G2Field<11,7,Bls24_509SnarkFr,8,Bls24_509SnarkFp,7,G2Fp4_2<8,Bls24_509SnarkFp,11>>
+{   &G2_BLS24_509_SNARK
+}
+
+pub const fn fp24_bls24_509_snark() -> &'static Fp24Field_2<11, 8,Bls24_509SnarkFp>
+{   &FP24_BLS24_509_SNARK
+}
+
+pub const fn gt_bls24_509_snark() -> &'static GTField<8,Bls24_509SnarkFp,11,GTFp24_2<8,Bls24_509SnarkFp,11>>
+{   &GT_BLS24_509_SNARK
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_509Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls24_509Fr;
+impl FieldConfig<8> for Bls24_509Fp { const PARAMS : FieldParams<'static,8> = build_field_params(BLS24_509_PARAMS.modulo_as_strhex); }
+impl FieldConfig<7> for Bls24_509Fr { const PARAMS : FieldParams<'static,7> = build_field_params(BLS24_509_PARAMS.r_as_strhex); }
+
+static BLS24_509_FROB_CONSTS   : ExFieldConsts<10,8,Bls24_509Fp> = build_frob_params(BLS24_509_PARAMS);
+static FP_BLS24_509            : PrimeField<8,Bls24_509Fp> = PrimeField::new();
+static FP4_BLS24_509           : Fp4Field_1<10,8,Bls24_509Fp> = Fp4Field_1::const_new(PrimeField::new(), &BLS24_509_FROB_CONSTS);
+static EXFP4_BLS24_509         : ExtG2Field<8,Bls24_509Fp,10> = ExtG2Field::Fp4_1(&FP4_BLS24_509);
+static FP24_BLS24_509          : Fp24Field_1<10,8,Bls24_509Fp> = Fp24Field_1::const_new(PrimeField::new(), &BLS24_509_FROB_CONSTS);
+static BLS24_509_GLV           : LatticeDecomposer<7> = LatticeDecomposer::new(&glv_basis(BLS24_509_PARAMS.lambda, BLS24_509_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x81f2131e3794756a, 0x17140a3c44a75442, 0xfd7650aef91abb7a, 0xfffff80004277, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xfffff00009, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_509_GLS           : LatticeDecomposer<7> = LatticeDecomposer::new(&gls_basis::<7,64>(BLS24_509_PARAMS.u),
+                                                                               &[(true, [0xc24be9603aeb733, 0x2a9c852817c5a702, 0x9044a817e52c22d5, 0x423a8be4d281889f, 0x2179cfbc2c9ffd47, 0xffc000207bff7f10, 0x1fff]),
+                                                                                 (false, [0xe6e0090b2d3f0e57, 0x87aeafe3aedbbbaf, 0xe2d15a98e93d7536, 0x27cd9560610e3020, 0x82effcf860cbe, 0x3fffff0, 0x0]),
+                                                                                 (true, [0x7fe5229761e1a33e, 0xdd20acebc3161e78, 0x16533d3e1692509f, 0x8bcff3d9833bb06d, 0x7ffffd0001, 0x0, 0x0]),
+                                                                                 (false, [0x81f2121e37a47561, 0x17140a3c44a75442, 0xfd7650aef91abb7a, 0xfffff80004277, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x95f6190c4053fb18, 0xdf2148d5c2cbfcc1, 0xffffec000a76bf85, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xe892bd1dadb1cd0e, 0xfd000194cfea92a5, 0x3fff, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x3b78fc6b70ffad, 0x7ffff90, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xfffff00009, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS24_509_G1_CONSTS     : G1Consts<7,Bls24_509Fr,8,Bls24_509Fp,3> = build_g1_params(BLS24_509_PARAMS, &BLS24_509_GLV);
+static BLS24_509_G2_CONSTS     : G2Consts<10,7,Bls24_509Fr,8,Bls24_509Fp,10,G2Fp4_1<8,Bls24_509Fp,10>> = build_g2_params_fp4_1(BLS24_509_PARAMS, &BLS24_509_FROB_CONSTS, &BLS24_509_GLS);
+static FR_BLS24_509            : PrimeField<7,Bls24_509Fr> = PrimeField::new();
+static G1_BLS24_509            : G1Field<7,Bls24_509Fr,8,Bls24_509Fp,3> = G1Field { consts : &BLS24_509_G1_CONSTS, base_field : &FP_BLS24_509, fr_field : &FR_BLS24_509 };
+static G2_BLS24_509            : G2Field<10,7,Bls24_509Fr,8,Bls24_509Fp,10,G2Fp4_1<8,Bls24_509Fp,10>> = G2Field { consts : &BLS24_509_G2_CONSTS, base_field : &EXFP4_BLS24_509, fr_field : &FR_BLS24_509 };
+static GT_BLS24_509            : GTField<8,Bls24_509Fp,10,GTFp24_1<8,Bls24_509Fp,10>> = GTField::new(GTExtField::Fp24_1(&FP24_BLS24_509));
+
+pub const fn fr_bls24_509() -> &'static PrimeField<7,Bls24_509Fr>
+{   &FR_BLS24_509
+}
+
+pub const fn fp4_bls24_509() -> &'static Fp4Field_1<10, 8,Bls24_509Fp>
+{   &FP4_BLS24_509
+}
+
+pub const fn g1_bls24_509() -> &'static G1Field<7,Bls24_509Fr,8,Bls24_509Fp,3>
+{   &G1_BLS24_509
+}
+
+pub const fn g2_bls24_509() -> &'static G2Field<10,7,Bls24_509Fr,8,Bls24_509Fp,10,G2Fp4_1<8,Bls24_509Fp,10>>
+{   &G2_BLS24_509
+}
+
+pub const fn fp24_bls24_509() -> &'static Fp24Field_1<10, 8,Bls24_509Fp>
+{   &FP24_BLS24_509
+}
+
+pub const fn gt_bls24_509() -> &'static GTField<8,Bls24_509Fp,10,GTFp24_1<8,Bls24_509Fp,10>>
+{   &GT_BLS24_509
 }
\ No newline at end of file
@@ -4,620 +4,389 @@
 
 use crate::curves::g1::{G1Consts, G1Field};
 use crate::curves::g2::{G2Consts, G2Field};
-use crate::curves::gt::GTField;
-use crate::extensions::ext_fields::{ExFieldConsts, ExtField};
+use crate::curves::gt::{GTExtField, GTField};
+use crate::extensions::towers::{G2Fp8_1, G2Fp8_2, G2Fp8_3, GTFp48_1, GTFp48_2, GTFp48_3};
+use crate::extensions::ext_fields::ExFieldConsts;
 use crate::extensions::g2_extfields::ExtG2Field;
 use crate::extensions::towering1::{fp48::Fp48Field as Fp48Field_1,fp8::Fp8Field as Fp8Field_1};
 use crate::extensions::towering2::{fp48::Fp48Field as Fp48Field_2,fp8::Fp8Field as Fp8Field_2};
 use crate::extensions::towering3::{fp48::Fp48Field as Fp48Field_3,fp8::Fp8Field as Fp8Field_3};
-use crate::fields::prime_fields::{FieldParams, PrimeField};
-use crate::parameters::builders::{build_field_params, build_frob_params, build_g1_params, build_g2_params};
+use crate::fields::prime_fields::{FieldConfig, FieldParams, PrimeField};
+use crate::tools::decomposition::LatticeDecomposer;
+use crate::parameters::builders::{build_field_params, build_frob_params, build_g1_params, build_g2_params_fp8_1, build_g2_params_fp8_2, build_g2_params_fp8_3, glv_basis, gls_basis};
 use crate::parameters::paramlist::{BLS48_277_PARAMS, BLS48_287_PARAMS, BLS48_571_PARAMS, BLS48_573_PARAMS, BLS48_575_PARAMS, BLS48_581_PARAMS};
-use once_cell::sync::OnceCell;
-
-static BLS48_575_FIELD_PARAMS       : OnceCell<FieldParams<9>>           = OnceCell::new();    
-static BLS48_575_FROB_CONSTS        : OnceCell<ExFieldConsts<24,9>>      = OnceCell::new();
-static BLS48_575_R_FIELD_PARAMS     : OnceCell<FieldParams<9>>           = OnceCell::new();    
-static FP_BLS48_575                 : OnceCell<PrimeField<9>>            = OnceCell::new();  
-static FP8_BLS48_575                : OnceCell<Fp8Field_1<24,9>>           = OnceCell::new();
-static EXFP8_BLS48_575              : OnceCell<ExtG2Field<9,24>>         = OnceCell::new();
-static BLS48_575_G1_CONSTS          : OnceCell<G1Consts<9,9,4>>          = OnceCell::new();
-static BLS48_575_G2_CONSTS          : OnceCell<G2Consts<24,9,9,7>>       = OnceCell::new();
-static FR_BLS48_575                 : OnceCell<PrimeField<9>>            = OnceCell::new();
-static G1_BLS48_575                 : OnceCell<G1Field<9,9,4>>           = OnceCell::new();
-static G2_BLS48_575                 : OnceCell<G2Field<24,9,9,7>>        = OnceCell::new();
-static FP48_BLS48_575               : OnceCell<Fp48Field_1<24,9>>          = OnceCell::new();
-static GT_BLS48_575                 : OnceCell<GTField<9,24>>           = OnceCell::new();
-
-pub fn fr_bls48_575()-> &'static PrimeField<9>
-{   if FR_BLS48_575.get().is_none() 
-            { if BLS48_575_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_575_R_FIELD_PARAMS.set(build_field_params(BLS48_575_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_575.set(PrimeField::new(&BLS48_575_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_575.get().unwrap()
-}
-pub fn fp8_bls48_575()-> &'static Fp8Field_1<24,9>
-{   if FP8_BLS48_575.get().is_none() {  if BLS48_575_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_575_FIELD_PARAMS.set(build_field_params(BLS48_575_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_575.get().is_none() 
-                                                        {   FP_BLS48_575.set(PrimeField::new(&BLS48_575_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_575_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_575_FROB_CONSTS.set(build_frob_params(BLS48_575_PARAMS, &FP_BLS48_575.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_575.set(Fp8Field_1::new(&FP_BLS48_575.get().unwrap(), Some(&BLS48_575_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_575.get().unwrap()
-}
-
-pub fn g1_bls48_575() -> &'static G1Field<9,9,4>
-{   if G1_BLS48_575.get().is_none() 
-            { if BLS48_575_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_575_R_FIELD_PARAMS.set(build_field_params(BLS48_575_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_575_FIELD_PARAMS.get().is_none() 
-                    {BLS48_575_FIELD_PARAMS.set(build_field_params(BLS48_575_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_575.get().is_none() 
-                    {FP_BLS48_575.set(PrimeField::new(&BLS48_575_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_575.get().is_none() 
-                    {FR_BLS48_575.set(PrimeField::new(&BLS48_575_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_575_G1_CONSTS.get().is_none() 
-                    {BLS48_575_G1_CONSTS.set(build_g1_params(BLS48_575_PARAMS,
-                                                             &FP_BLS48_575.get().unwrap(),
-                                                             &FR_BLS48_575.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_575.set(G1Field { consts : BLS48_575_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_575.get().unwrap(),
-                                                  fr_field : FR_BLS48_575.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_575.get().unwrap()
-}
-
-pub fn g2_bls48_575() -> &'static G2Field<24,9,9,7>
-{   if G2_BLS48_575.get().is_none() 
-                {   if G1_BLS48_575.get().is_none() 
-                            { let _ = g1_bls48_575();}
-                    if BLS48_575_FROB_CONSTS.get().is_none() 
-                            { BLS48_575_FROB_CONSTS.set(build_frob_params(BLS48_575_PARAMS, &FP_BLS48_575.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_575.get().is_none() 
-                                        { let _ = fp8_bls48_575();}        
-                    if BLS48_575_G2_CONSTS.get().is_none() 
-                            {BLS48_575_G2_CONSTS.set(build_g2_params(BLS48_575_PARAMS,
-                                                                     &ExtG2Field::Fp8_1(FP8_BLS48_575.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_575_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_575_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_575.get().is_none()  {EXFP8_BLS48_575.set(ExtG2Field::Fp8_1(&FP8_BLS48_575.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_575.set(G2Field {  consts : BLS48_575_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_575.get().unwrap() ,
-                                                fr_field : FR_BLS48_575.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_575.get().unwrap()
-}
-
-pub fn fp48_bls48_575()-> &'static Fp48Field_1<24, 9>
-{   if FP48_BLS48_575.get().is_none() {  if BLS48_575_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_575_FIELD_PARAMS.set(build_field_params(BLS48_575_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_575.get().is_none() 
-                                                        {   FP_BLS48_575.set(PrimeField::new(&BLS48_575_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_575_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_575_FROB_CONSTS.set(build_frob_params(BLS48_575_PARAMS, &FP_BLS48_575.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_575.set(Fp48Field_1::new(&FP_BLS48_575.get().unwrap(), Some(&BLS48_575_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_575.get().unwrap()
-}
-
-pub fn gt_bls48_575() -> &'static GTField<9,24>
-{  
-        if FP48_BLS48_575.get().is_none() { let _ = fp48_bls48_575(); };
-        GT_BLS48_575.set(GTField::Fp48_1(FP48_BLS48_575.get().unwrap())).unwrap();
-        GT_BLS48_575.get().unwrap()       
-}
-
-
-static BLS48_573_FIELD_PARAMS       : OnceCell<FieldParams<9>>           = OnceCell::new();    
-static BLS48_573_FROB_CONSTS        : OnceCell<ExFieldConsts<24,9>>      = OnceCell::new();
-static BLS48_573_R_FIELD_PARAMS     : OnceCell<FieldParams<8>>           = OnceCell::new();    
-static FP_BLS48_573                 : OnceCell<PrimeField<9>>            = OnceCell::new();  
-static FP8_BLS48_573                : OnceCell<Fp8Field_1<24,9>>           = OnceCell::new();
-static EXFP8_BLS48_573              : OnceCell<ExtG2Field<9,24>>         = OnceCell::new();
-static BLS48_573_G1_CONSTS          : OnceCell<G1Consts<8,9,7>>          = OnceCell::new();
-static BLS48_573_G2_CONSTS          : OnceCell<G2Consts<24,8,9,4>>       = OnceCell::new();
-static FR_BLS48_573                 : OnceCell<PrimeField<8>>            = OnceCell::new();
-static G2_BLS48_573                 : OnceCell<G2Field<24,8,9,4>>        = OnceCell::new();
-static G1_BLS48_573                 : OnceCell<G1Field<8,9,7>>           = OnceCell::new();
-static FP48_BLS48_573               : OnceCell<Fp48Field_1<24,9>>          = OnceCell::new();
-static GT_BLS48_573                 : OnceCell<GTField<9,24>>           = OnceCell::new();
-
-pub fn fr_bls48_573()-> &'static PrimeField<8>
-{   if FR_BLS48_573.get().is_none() 
-            { if BLS48_573_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_573_R_FIELD_PARAMS.set(build_field_params(BLS48_573_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_573.set(PrimeField::new(&BLS48_573_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_573.get().unwrap()
-}
-pub fn fp8_bls48_573()-> &'static Fp8Field_1<24,9>
-{   if FP8_BLS48_573.get().is_none() {  if BLS48_573_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_573_FIELD_PARAMS.set(build_field_params(BLS48_573_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_573.get().is_none() 
-                                                        {   FP_BLS48_573.set(PrimeField::new(&BLS48_573_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_573_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_573_FROB_CONSTS.set(build_frob_params(BLS48_573_PARAMS, &FP_BLS48_573.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_573.set(Fp8Field_1::new(&FP_BLS48_573.get().unwrap(), Some(&BLS48_573_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_573.get().unwrap()
-}
-
-pub fn g1_bls48_573() -> &'static G1Field<8,9,7>
-{   if G1_BLS48_573.get().is_none() 
-            { if BLS48_573_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_573_R_FIELD_PARAMS.set(build_field_params(BLS48_573_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_573_FIELD_PARAMS.get().is_none() 
-                    {BLS48_573_FIELD_PARAMS.set(build_field_params(BLS48_573_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_573.get().is_none() 
-                    {FP_BLS48_573.set(PrimeField::new(&BLS48_573_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_573.get().is_none() 
-                    {FR_BLS48_573.set(PrimeField::new(&BLS48_573_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_573_G1_CONSTS.get().is_none() 
-                    {BLS48_573_G1_CONSTS.set(build_g1_params(BLS48_573_PARAMS,
-                                                             &FP_BLS48_573.get().unwrap(),
-                                                             &FR_BLS48_573.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_573.set(G1Field { consts : BLS48_573_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_573.get().unwrap(),
-                                                  fr_field : FR_BLS48_573.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_573.get().unwrap()
-}
-
-pub fn g2_bls48_573() -> &'static G2Field<24,8,9,4>
-{   if G2_BLS48_573.get().is_none() 
-                {   if G1_BLS48_573.get().is_none() 
-                            { let _ = g1_bls48_573();}
-                    if BLS48_573_FROB_CONSTS.get().is_none() 
-                            { BLS48_573_FROB_CONSTS.set(build_frob_params(BLS48_573_PARAMS, &FP_BLS48_573.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_573.get().is_none() 
-                                        { let _ = fp8_bls48_573();}        
-                    if BLS48_573_G2_CONSTS.get().is_none() 
-                            {BLS48_573_G2_CONSTS.set(build_g2_params(BLS48_573_PARAMS,
-                                                                     &ExtG2Field::Fp8_1(FP8_BLS48_573.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_573_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_573_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_573.get().is_none()  {EXFP8_BLS48_573.set(ExtG2Field::Fp8_1(&FP8_BLS48_573.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_573.set(G2Field {  consts : BLS48_573_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_573.get().unwrap() ,
-                                                fr_field : FR_BLS48_573.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_573.get().unwrap()
-}
-
-pub fn fp48_bls48_573()-> &'static Fp48Field_1<24, 9>
-{   if FP48_BLS48_573.get().is_none() {  if BLS48_573_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_573_FIELD_PARAMS.set(build_field_params(BLS48_573_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_573.get().is_none() 
-                                                        {   FP_BLS48_573.set(PrimeField::new(&BLS48_573_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_573_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_573_FROB_CONSTS.set(build_frob_params(BLS48_573_PARAMS, &FP_BLS48_573.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_573.set(Fp48Field_1::new(&FP_BLS48_573.get().unwrap(), Some(&BLS48_573_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_573.get().unwrap()
-}
-
-pub fn gt_bls48_573() -> &'static GTField<9,24>
-{  
-        if FP48_BLS48_573.get().is_none() { let _ = fp48_bls48_573(); };
-        GT_BLS48_573.set(GTField::Fp48_1(FP48_BLS48_573.get().unwrap())).unwrap();
-        GT_BLS48_573.get().unwrap()       
-}
-
-static BLS48_581_FIELD_PARAMS       : OnceCell<FieldParams<10>>           = OnceCell::new();    
-static BLS48_581_FROB_CONSTS        : OnceCell<ExFieldConsts<24,10>>      = OnceCell::new();
-static BLS48_581_R_FIELD_PARAMS     : OnceCell<FieldParams<9>>           = OnceCell::new();    
-static FP_BLS48_581                 : OnceCell<PrimeField<10>>            = OnceCell::new();  
-static FP8_BLS48_581                : OnceCell<Fp8Field_3<24,10>>           = OnceCell::new();
-static EXFP8_BLS48_581              : OnceCell<ExtG2Field<10,24>>         = OnceCell::new();
-static BLS48_581_G1_CONSTS          : OnceCell<G1Consts<9,10,3>>          = OnceCell::new();
-static BLS48_581_G2_CONSTS          : OnceCell<G2Consts<24,9,10,4>>       = OnceCell::new();
-static FR_BLS48_581                 : OnceCell<PrimeField<9>>            = OnceCell::new();
-static G2_BLS48_581                 : OnceCell<G2Field<24,9,10,4>>        = OnceCell::new();
-static G1_BLS48_581                 : OnceCell<G1Field<9,10,3>>           = OnceCell::new();
-static FP48_BLS48_581               : OnceCell<Fp48Field_3<24,10>>          = OnceCell::new();
-static GT_BLS48_581                 : OnceCell<GTField<10,24>>           = OnceCell::new();
-
-pub fn fr_bls48_581()-> &'static PrimeField<9>
-{   if FR_BLS48_581.get().is_none() 
-            { if BLS48_581_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_581_R_FIELD_PARAMS.set(build_field_params(BLS48_581_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_581.set(PrimeField::new(&BLS48_581_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_581.get().unwrap()
-}
-pub fn fp8_bls48_581()-> &'static Fp8Field_3<24,10>
-{   if FP8_BLS48_581.get().is_none() {  if BLS48_581_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_581_FIELD_PARAMS.set(build_field_params(BLS48_581_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_581.get().is_none() 
-                                                        {   FP_BLS48_581.set(PrimeField::new(&BLS48_581_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_581_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_581_FROB_CONSTS.set(build_frob_params(BLS48_581_PARAMS, &FP_BLS48_581.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_581.set(Fp8Field_3::new(&FP_BLS48_581.get().unwrap(), Some(&BLS48_581_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_581.get().unwrap()
-}
-
-pub fn g1_bls48_581() -> &'static G1Field<9,10,3>
-{   if G1_BLS48_581.get().is_none() 
-            { if BLS48_581_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_581_R_FIELD_PARAMS.set(build_field_params(BLS48_581_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_581_FIELD_PARAMS.get().is_none() 
-                    {BLS48_581_FIELD_PARAMS.set(build_field_params(BLS48_581_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_581.get().is_none() 
-                    {FP_BLS48_581.set(PrimeField::new(&BLS48_581_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_581.get().is_none() 
-                    {FR_BLS48_581.set(PrimeField::new(&BLS48_581_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_581_G1_CONSTS.get().is_none() 
-                    {BLS48_581_G1_CONSTS.set(build_g1_params(BLS48_581_PARAMS,
-                                                             &FP_BLS48_581.get().unwrap(),
-                                                             &FR_BLS48_581.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_581.set(G1Field { consts : BLS48_581_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_581.get().unwrap(),
-                                                  fr_field : FR_BLS48_581.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_581.get().unwrap()
-}
-
-pub fn g2_bls48_581() -> &'static G2Field<24,9,10,4>
-{   if G2_BLS48_581.get().is_none() 
-                {   if G1_BLS48_581.get().is_none() 
-                            { let _ = g1_bls48_581();}
-                    if BLS48_581_FROB_CONSTS.get().is_none() 
-                            { BLS48_581_FROB_CONSTS.set(build_frob_params(BLS48_581_PARAMS, &FP_BLS48_581.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_581.get().is_none() 
-                                        { let _ = fp8_bls48_581();}        
-                    if BLS48_581_G2_CONSTS.get().is_none() 
-                            {BLS48_581_G2_CONSTS.set(build_g2_params(BLS48_581_PARAMS,
-                                                                     &ExtG2Field::Fp8_3(FP8_BLS48_581.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_581_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_581_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_581.get().is_none()  {EXFP8_BLS48_581.set(ExtG2Field::Fp8_3(&FP8_BLS48_581.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_581.set(G2Field {  consts : BLS48_581_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_581.get().unwrap() ,
-                                                fr_field : FR_BLS48_581.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_581.get().unwrap()
-}
-
-pub fn fp48_bls48_581()-> &'static Fp48Field_3<24, 10>
-{   if FP48_BLS48_581.get().is_none() {  if BLS48_581_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_581_FIELD_PARAMS.set(build_field_params(BLS48_581_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_581.get().is_none() 
-                                                        {   FP_BLS48_581.set(PrimeField::new(&BLS48_581_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_581_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_581_FROB_CONSTS.set(build_frob_params(BLS48_581_PARAMS, &FP_BLS48_581.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_581.set(Fp48Field_3::new(&FP_BLS48_581.get().unwrap(), Some(&BLS48_581_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_581.get().unwrap()
-}
-
-pub fn gt_bls48_581() -> &'static GTField<10,24>
-{  
-        if FP48_BLS48_581.get().is_none() { let _ = fp48_bls48_581(); };
-        GT_BLS48_581.set(GTField::Fp48_3(FP48_BLS48_581.get().unwrap())).unwrap();
-        GT_BLS48_581.get().unwrap()       
-}
-
-
-static BLS48_571_FIELD_PARAMS       : OnceCell<FieldParams<9>>           = OnceCell::new();    
-static BLS48_571_FROB_CONSTS        : OnceCell<ExFieldConsts<36,9>>      = OnceCell::new();
-static BLS48_571_R_FIELD_PARAMS     : OnceCell<FieldParams<8>>           = OnceCell::new();    
-static FP_BLS48_571                 : OnceCell<PrimeField<9>>            = OnceCell::new();  
-static FP8_BLS48_571                : OnceCell<Fp8Field_2<36,9>>           = OnceCell::new();
-static EXFP8_BLS48_571              : OnceCell<ExtG2Field<9,36>>         = OnceCell::new();
-static BLS48_571_G1_CONSTS          : OnceCell<G1Consts<8,9,3>>          = OnceCell::new();
-static BLS48_571_G2_CONSTS          : OnceCell<G2Consts<36,8,9,4>>       = OnceCell::new();
-static FR_BLS48_571                 : OnceCell<PrimeField<8>>            = OnceCell::new();
-static G2_BLS48_571                 : OnceCell<G2Field<36,8,9,4>>        = OnceCell::new();
-static G1_BLS48_571                 : OnceCell<G1Field<8,9,3>>           = OnceCell::new();
-static FP48_BLS48_571               : OnceCell<Fp48Field_2<36,9>>          = OnceCell::new();
-static GT_BLS48_571                 : OnceCell<GTField<9,36>>           = OnceCell::new();
-
-pub fn fr_bls48_571()-> &'static PrimeField<8>
-{   if FR_BLS48_571.get().is_none() 
-            { if BLS48_571_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_571_R_FIELD_PARAMS.set(build_field_params(BLS48_571_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_571.set(PrimeField::new(&BLS48_571_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_571.get().unwrap()
-}
-pub fn fp8_bls48_571()-> &'static Fp8Field_2<36,9>
-{   if FP8_BLS48_571.get().is_none() {  if BLS48_571_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_571_FIELD_PARAMS.set(build_field_params(BLS48_571_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_571.get().is_none() 
-                                                        {   FP_BLS48_571.set(PrimeField::new(&BLS48_571_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_571_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_571_FROB_CONSTS.set(build_frob_params(BLS48_571_PARAMS, &FP_BLS48_571.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_571.set(Fp8Field_2::new(&FP_BLS48_571.get().unwrap(), Some(&BLS48_571_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_571.get().unwrap()
-}
-
-pub fn g1_bls48_571() -> &'static G1Field<8,9,3>
-{   if G1_BLS48_571.get().is_none() 
-            { if BLS48_571_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_571_R_FIELD_PARAMS.set(build_field_params(BLS48_571_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_571_FIELD_PARAMS.get().is_none() 
-                    {BLS48_571_FIELD_PARAMS.set(build_field_params(BLS48_571_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_571.get().is_none() 
-                    {FP_BLS48_571.set(PrimeField::new(&BLS48_571_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_571.get().is_none() 
-                    {FR_BLS48_571.set(PrimeField::new(&BLS48_571_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_571_G1_CONSTS.get().is_none() 
-                    {BLS48_571_G1_CONSTS.set(build_g1_params(BLS48_571_PARAMS,
-                                                             &FP_BLS48_571.get().unwrap(),
-                                                             &FR_BLS48_571.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_571.set(G1Field { consts : BLS48_571_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_571.get().unwrap(),
-                                                  fr_field : FR_BLS48_571.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_571.get().unwrap()
-}
-
-pub fn g2_bls48_571() -> &'static G2Field<36,8,9,4>
-{   if G2_BLS48_571.get().is_none() 
-                {   if G1_BLS48_571.get().is_none() 
-                            { let _ = g1_bls48_571();}
-                    if BLS48_571_FROB_CONSTS.get().is_none() 
-                            { BLS48_571_FROB_CONSTS.set(build_frob_params(BLS48_571_PARAMS, &FP_BLS48_571.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_571.get().is_none() 
-                                        { let _ = fp8_bls48_571();}        
-                    if BLS48_571_G2_CONSTS.get().is_none() 
-                            {BLS48_571_G2_CONSTS.set(build_g2_params(BLS48_571_PARAMS,
-                                                                     &ExtG2Field::Fp8_2(FP8_BLS48_571.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_571_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_571_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_571.get().is_none()  {EXFP8_BLS48_571.set(ExtG2Field::Fp8_2(&FP8_BLS48_571.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_571.set(G2Field {  consts : BLS48_571_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_571.get().unwrap() ,
-                                                fr_field : FR_BLS48_571.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_571.get().unwrap()
-}
-
-pub fn fp48_bls48_571()-> &'static Fp48Field_2<36, 9>
-{   if FP48_BLS48_571.get().is_none() {  if BLS48_571_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_571_FIELD_PARAMS.set(build_field_params(BLS48_571_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_571.get().is_none() 
-                                                        {   FP_BLS48_571.set(PrimeField::new(&BLS48_571_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_571_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_571_FROB_CONSTS.set(build_frob_params(BLS48_571_PARAMS, &FP_BLS48_571.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_571.set(Fp48Field_2::new(&FP_BLS48_571.get().unwrap(), Some(&BLS48_571_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_571.get().unwrap()
-}
-
-pub fn gt_bls48_571() -> &'static GTField<9,36>
-{  
-        if FP48_BLS48_571.get().is_none() { let _ = fp48_bls48_571(); };
-        GT_BLS48_571.set(GTField::Fp48_2(FP48_BLS48_571.get().unwrap())).unwrap();
-        GT_BLS48_571.get().unwrap()       
-}
-
-static BLS48_287_FIELD_PARAMS       : OnceCell<FieldParams<5>>                      = OnceCell::new();    
-static BLS48_287_FROB_CONSTS        : OnceCell<ExFieldConsts<35,5>>      = OnceCell::new();
-static BLS48_287_R_FIELD_PARAMS     : OnceCell<FieldParams<4>>                      = OnceCell::new();    
-static FP_BLS48_287                 : OnceCell<PrimeField<5>>                       = OnceCell::new();  
-static FP8_BLS48_287                : OnceCell<Fp8Field_2<35,5>>          = OnceCell::new();
-static EXFP8_BLS48_287              : OnceCell<ExtG2Field<5,35>>          = OnceCell::new();
-static BLS48_287_G1_CONSTS          : OnceCell<G1Consts<4,5,4>>  = OnceCell::new();
-static BLS48_287_G2_CONSTS          : OnceCell<G2Consts<35,4,5,19>> = OnceCell::new();
-static FR_BLS48_287                 : OnceCell<PrimeField<4>>                                     = OnceCell::new();
-static G2_BLS48_287                 : OnceCell<G2Field<35,4,5,19>>  = OnceCell::new();
-static G1_BLS48_287                 : OnceCell<G1Field<4,5,4>> = OnceCell::new();
-static FP48_BLS48_287               : OnceCell<Fp48Field_2<35,5>>       = OnceCell::new();
-static GT_BLS48_287                 : OnceCell<GTField<5,35>>           = OnceCell::new();
-
-pub fn fr_bls48_287()-> &'static PrimeField<4>
-{   if FR_BLS48_287.get().is_none() 
-            { if BLS48_287_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_287_R_FIELD_PARAMS.set(build_field_params(BLS48_287_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_287.set(PrimeField::new(&BLS48_287_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_287.get().unwrap()
-}
-pub fn fp8_bls48_287()-> &'static Fp8Field_2<35,5>
-{   if FP8_BLS48_287.get().is_none() {  if BLS48_287_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_287_FIELD_PARAMS.set(build_field_params(BLS48_287_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_287.get().is_none() 
-                                                        {   FP_BLS48_287.set(PrimeField::new(&BLS48_287_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_287_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_287_FROB_CONSTS.set(build_frob_params(BLS48_287_PARAMS, &FP_BLS48_287.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_287.set(Fp8Field_2::new(&FP_BLS48_287.get().unwrap(), Some(&BLS48_287_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_287.get().unwrap()
-}
-
-pub fn g1_bls48_287() -> &'static G1Field<4,5,4>
-{   if G1_BLS48_287.get().is_none() 
-            { if BLS48_287_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_287_R_FIELD_PARAMS.set(build_field_params(BLS48_287_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_287_FIELD_PARAMS.get().is_none() 
-                    {BLS48_287_FIELD_PARAMS.set(build_field_params(BLS48_287_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_287.get().is_none() 
-                    {FP_BLS48_287.set(PrimeField::new(&BLS48_287_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_287.get().is_none() 
-                    {FR_BLS48_287.set(PrimeField::new(&BLS48_287_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_287_G1_CONSTS.get().is_none() 
-                    {BLS48_287_G1_CONSTS.set(build_g1_params(BLS48_287_PARAMS,
-                                                             &FP_BLS48_287.get().unwrap(),
-                                                             &FR_BLS48_287.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_287.set(G1Field { consts : BLS48_287_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_287.get().unwrap(),
-                                                  fr_field : FR_BLS48_287.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_287.get().unwrap()
-}
-
-pub fn g2_bls48_287() -> &'static G2Field<35,4,5,19>
-{   if G2_BLS48_287.get().is_none() 
-                {   if G1_BLS48_287.get().is_none() 
-                            { let _ = g1_bls48_287();}
-                    if BLS48_287_FROB_CONSTS.get().is_none() 
-                            { BLS48_287_FROB_CONSTS.set(build_frob_params(BLS48_287_PARAMS, &FP_BLS48_287.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_287.get().is_none() 
-                                        { let _ = fp8_bls48_287();}        
-                    if BLS48_287_G2_CONSTS.get().is_none() 
-                            {BLS48_287_G2_CONSTS.set(build_g2_params(BLS48_287_PARAMS,
-                                                                     &ExtG2Field::Fp8_2(FP8_BLS48_287.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_287_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_287_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_287.get().is_none()  {EXFP8_BLS48_287.set(ExtG2Field::Fp8_2(&FP8_BLS48_287.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_287.set(G2Field {  consts : BLS48_287_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_287.get().unwrap() ,
-                                                fr_field : FR_BLS48_287.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_287.get().unwrap()
-}
-
-pub fn fp48_bls48_287()-> &'static Fp48Field_2<35, 5>
-{   if FP48_BLS48_287.get().is_none() {  if BLS48_287_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_287_FIELD_PARAMS.set(build_field_params(BLS48_287_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_287.get().is_none() 
-                                                        {   FP_BLS48_287.set(PrimeField::new(&BLS48_287_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_287_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_287_FROB_CONSTS.set(build_frob_params(BLS48_287_PARAMS, &FP_BLS48_287.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_287.set(Fp48Field_2::new(&FP_BLS48_287.get().unwrap(), Some(&BLS48_287_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_287.get().unwrap()
-}
-
-pub fn gt_bls48_287() -> &'static GTField<5,35>
-{  
-        if FP48_BLS48_287.get().is_none() { let _ = fp48_bls48_287(); };
-        GT_BLS48_287.set(GTField::Fp48_2(FP48_BLS48_287.get().unwrap())).unwrap();
-        GT_BLS48_287.get().unwrap()       
-}
-
-static BLS48_277_FIELD_PARAMS       : OnceCell<FieldParams<5>>                      = OnceCell::new();    
-static BLS48_277_FROB_CONSTS        : OnceCell<ExFieldConsts<24,5>>      = OnceCell::new();
-static BLS48_277_R_FIELD_PARAMS     : OnceCell<FieldParams<4>>                      = OnceCell::new();    
-static FP_BLS48_277                 : OnceCell<PrimeField<5>>                       = OnceCell::new();  
-static FP8_BLS48_277                : OnceCell<Fp8Field_3<24,5>>          = OnceCell::new();
-static EXFP8_BLS48_277              : OnceCell<ExtG2Field<5,24>>          = OnceCell::new();
-static BLS48_277_G1_CONSTS          : OnceCell<G1Consts<4,5,10>>  = OnceCell::new();
-static BLS48_277_G2_CONSTS          : OnceCell<G2Consts<24,4,5,4>> = OnceCell::new();
-static FR_BLS48_277                 : OnceCell<PrimeField<4>>                                     = OnceCell::new();
-static G2_BLS48_277                 : OnceCell<G2Field<24,4,5,4>>  = OnceCell::new();
-static G1_BLS48_277                 : OnceCell<G1Field<4,5,10>> = OnceCell::new();
-static FP48_BLS48_277               : OnceCell<Fp48Field_3<24,5>>       = OnceCell::new();
-static GT_BLS48_277                 : OnceCell<GTField<5,24>>           = OnceCell::new();
-
-pub fn fr_bls48_277()-> &'static PrimeField<4>
-{   if FR_BLS48_277.get().is_none() 
-            { if BLS48_277_R_FIELD_PARAMS.get().is_none() 
-                    {BLS48_277_R_FIELD_PARAMS.set(build_field_params(BLS48_277_PARAMS.r_as_strhex)).unwrap()}; 
-              FR_BLS48_277.set(PrimeField::new(&BLS48_277_R_FIELD_PARAMS.get().unwrap())).unwrap();                                                                                                
-            }
-    FR_BLS48_277.get().unwrap()
-}
-pub fn fp8_bls48_277()-> &'static Fp8Field_3<24,5>
-{   if FP8_BLS48_277.get().is_none() {  if BLS48_277_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_277_FIELD_PARAMS.set(build_field_params(BLS48_277_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_277.get().is_none() 
-                                                        {   FP_BLS48_277.set(PrimeField::new(&BLS48_277_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_277_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_277_FROB_CONSTS.set(build_frob_params(BLS48_277_PARAMS, &FP_BLS48_277.get().unwrap())).unwrap()
-                                                        };               
-                                         FP8_BLS48_277.set(Fp8Field_3::new(&FP_BLS48_277.get().unwrap(), Some(&BLS48_277_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP8_BLS48_277.get().unwrap()
-}
-
-pub fn g1_bls48_277() -> &'static G1Field<4,5,10>
-{   if G1_BLS48_277.get().is_none() 
-            { if BLS48_277_R_FIELD_PARAMS.get().is_none()
-                    {BLS48_277_R_FIELD_PARAMS.set(build_field_params(BLS48_277_PARAMS.r_as_strhex)).unwrap();}; 
-              if BLS48_277_FIELD_PARAMS.get().is_none() 
-                    {BLS48_277_FIELD_PARAMS.set(build_field_params(BLS48_277_PARAMS.modulo_as_strhex)).unwrap();}; 
-              if FP_BLS48_277.get().is_none() 
-                    {FP_BLS48_277.set(PrimeField::new(&BLS48_277_FIELD_PARAMS.get().unwrap())).unwrap();}                                                            
-              if FR_BLS48_277.get().is_none() 
-                    {FR_BLS48_277.set(PrimeField::new(&BLS48_277_R_FIELD_PARAMS.get().unwrap())).unwrap();}                                                                                                                           
-              if BLS48_277_G1_CONSTS.get().is_none() 
-                    {BLS48_277_G1_CONSTS.set(build_g1_params(BLS48_277_PARAMS,
-                                                             &FP_BLS48_277.get().unwrap(),
-                                                             &FR_BLS48_277.get().unwrap())).unwrap();}                                                                                                                                   
-              G1_BLS48_277.set(G1Field { consts : BLS48_277_G1_CONSTS.get().unwrap(),
-                                                  base_field: FP_BLS48_277.get().unwrap(),
-                                                  fr_field : FR_BLS48_277.get().unwrap()}).unwrap() ;
-            }
-    G1_BLS48_277.get().unwrap()
-}
-
-pub fn g2_bls48_277() -> &'static G2Field<24,4,5,4>
-{   if G2_BLS48_277.get().is_none() 
-                {   if G1_BLS48_277.get().is_none() 
-                            { let _ = g1_bls48_277();}
-                    if BLS48_277_FROB_CONSTS.get().is_none() 
-                            { BLS48_277_FROB_CONSTS.set(build_frob_params(BLS48_277_PARAMS, &FP_BLS48_277.get().unwrap())).unwrap()};                                         
-                    if FP8_BLS48_277.get().is_none() 
-                                        { let _ = fp8_bls48_277();}        
-                    if BLS48_277_G2_CONSTS.get().is_none() 
-                            {BLS48_277_G2_CONSTS.set(build_g2_params(BLS48_277_PARAMS,
-                                                                     &ExtG2Field::Fp8_3(FP8_BLS48_277.get().unwrap()),
-                                                                     &PrimeField::new(&BLS48_277_R_FIELD_PARAMS.get().unwrap()),
-                                                                     BLS48_277_FROB_CONSTS.get().unwrap())).unwrap();}                                                                                                                                                                                                          
-                    if EXFP8_BLS48_277.get().is_none()  {EXFP8_BLS48_277.set(ExtG2Field::Fp8_3(&FP8_BLS48_277.get().unwrap())).unwrap();}                                     
-                    G2_BLS48_277.set(G2Field {  consts : BLS48_277_G2_CONSTS.get().unwrap(),
-                                                base_field : &EXFP8_BLS48_277.get().unwrap() ,
-                                                fr_field : FR_BLS48_277.get().unwrap()}).unwrap() ;
-                }            
-    G2_BLS48_277.get().unwrap()
-}
-
-pub fn fp48_bls48_277()-> &'static Fp48Field_3<24, 5>
-{   if FP48_BLS48_277.get().is_none() {  if BLS48_277_FIELD_PARAMS.get().is_none() 
-                                                        {   BLS48_277_FIELD_PARAMS.set(build_field_params(BLS48_277_PARAMS.modulo_as_strhex)).unwrap()
-                                                        };
-                                         if FP_BLS48_277.get().is_none() 
-                                                        {   FP_BLS48_277.set(PrimeField::new(&BLS48_277_FIELD_PARAMS.get().unwrap())).unwrap()
-                                                        };
-                                         if BLS48_277_FROB_CONSTS.get().is_none() 
-                                                        {   BLS48_277_FROB_CONSTS.set(build_frob_params(BLS48_277_PARAMS, &FP_BLS48_277.get().unwrap())).unwrap()
-                                                        };               
-                                         FP48_BLS48_277.set(Fp48Field_3::new(&FP_BLS48_277.get().unwrap(), Some(&BLS48_277_FROB_CONSTS.get().unwrap()))).unwrap();                                                                                                 
-                                      }
-    FP48_BLS48_277.get().unwrap()
-}
-
-pub fn gt_bls48_277() -> &'static GTField<5,24>
-{  
-        if FP48_BLS48_277.get().is_none() { let _ = fp48_bls48_277(); };
-        GT_BLS48_277.set(GTField::Fp48_3(FP48_BLS48_277.get().unwrap())).unwrap();
-        GT_BLS48_277.get().unwrap()       }
\ No newline at end of file
+
+// Marker types of the base (Fp) and scalar (Fr) fields, whose parameters are evaluated at compile time
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_575Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_575Fr;
+impl FieldConfig<9> for Bls48_575Fp { const PARAMS : FieldParams<'static,9> = build_field_params(BLS48_575_PARAMS.modulo_as_strhex); }
+impl FieldConfig<9> for Bls48_575Fr { const PARAMS : FieldParams<'static,9> = build_field_params(BLS48_575_PARAMS.r_as_strhex); }
+
+static BLS48_575_FROB_CONSTS   : ExFieldConsts<24,9,Bls48_575Fp> = build_frob_params(BLS48_575_PARAMS);
+static FP_BLS48_575            : PrimeField<9,Bls48_575Fp> = PrimeField::new();
+static FP8_BLS48_575           : Fp8Field_1<24,9,Bls48_575Fp> = Fp8Field_1::const_new(PrimeField::new(), &BLS48_575_FROB_CONSTS);
+static EXFP8_BLS48_575         : ExtG2Field<9,Bls48_575Fp,24> = ExtG2Field::Fp8_1(&FP8_BLS48_575);
+static BLS48_575_GLV           : LatticeDecomposer<9> = LatticeDecomposer::new(&glv_basis(BLS48_575_PARAMS.lambda, BLS48_575_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x189f01f058090d6e, 0x3517e4c854a73cf9, 0x1cb6d35996a9146a, 0x13acdd5350c53462, 0x408cde9c880461e, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x821e2906381570e, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_575_GLS           : LatticeDecomposer<9> = LatticeDecomposer::new(&gls_basis::<9,256>(BLS48_575_PARAMS.u),
+                                                                               &[(true, [0xc391580baf6419f6, 0xf9da8ed29afc5a82, 0x12070873efad2ea5, 0xa1bcbe8bbe2dfa80, 0x15fd8da7d17df2c5, 0xb5eef5fed1c9f86f, 0x46ad04e4c38d9bcc, 0x943b17794fb0f68, 0x100803817]),
+                                                                                 (false, [0x65f5eb4e556bafea, 0x904ffb77a58196fa, 0x7eef303433b00ea9, 0xd3c5a19be8eddec, 0x515c6ee955d4be6f, 0x3a10a97bb46ca532, 0xe9af22eb599350d5, 0x100b06635dabcc0, 0x1]),
+                                                                                 (true, [0x6ac27ed04f649c51, 0x112da6272583de13, 0xaca7849ae3949ecc, 0x1ed58b4ae78037ae, 0x9423f1b5786704f3, 0x566df56b298c20d4, 0xafe8daa1439307b1, 0x10181690d, 0x0]),
+                                                                                 (false, [0x34ccacc9352a1d6c, 0xe22b1a12dfa1c5fa, 0x8106c53d9440f334, 0xfee31fd0ef6b99cb, 0x9258ee616b0d7eba, 0xe5d9c3575e9a257, 0x202622db1ade1d7, 0x1, 0x0]),
+                                                                                 (true, [0xf39d013af6a1246b, 0x559569a0b1a17ba2, 0x8bcb7a4fc149719c, 0x8970ffd3d75f30da, 0xba765e0f2c885b1b, 0x858dd8b0724361a3, 0x102839be6, 0x0, 0x0]),
+                                                                                 (false, [0xefc4a77b9c6ab235, 0x7cb3a63c59dccefc, 0x2008f0f29d89b94d, 0x595f09ab0d4b4360, 0x7a11fc24c2e4ae2a, 0x305165886190aac, 0x1, 0x0, 0x0]),
+                                                                                 (true, [0x3e05918949d7b77f, 0xe4bb10c72303de4c, 0xbd2f5feedf49bee8, 0x21b449a16610bd37, 0x1e142228cf543da0, 0x10386d1a4, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x107d1f5ff487b660, 0x3517e4c854a73cf8, 0x1cb6d35996a9146a, 0x13acdd5350c53462, 0x408cde9c880461e, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x20e3522df424524a, 0x1f4a54bf22b8f36d, 0x579b8fffe88f8bdb, 0x10a33becdf9a076d, 0x1048b0b4a, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x598f4e6f84a2d906, 0xee66d2fb7807a9d8, 0x6fd87aee9b393cd5, 0x50d89e5920f8d3d, 0x1, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0xfbb101487a1111df, 0x4cf0e948be8ae932, 0xf8acb1f40ba1f99a, 0x1059049dc, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x2eed1cf4de4b6313, 0x2f085e14e56a9955, 0x6134b5100bf3e3c, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x86fc83c4a62586c2, 0x76f114ac85b4bf9a, 0x106968e62, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xc358a14aa3eb831c, 0x71a133238599c85, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x3285fc0e6826451e, 0x1079dd9e1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x821e2906381570e, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_575_G1_CONSTS     : G1Consts<9,Bls48_575Fr,9,Bls48_575Fp,4> = build_g1_params(BLS48_575_PARAMS, &BLS48_575_GLV);
+static BLS48_575_G2_CONSTS     : G2Consts<24,9,Bls48_575Fr,9,Bls48_575Fp,7,G2Fp8_1<9,Bls48_575Fp,24>> = build_g2_params_fp8_1(BLS48_575_PARAMS, &BLS48_575_FROB_CONSTS, &BLS48_575_GLS);
+static FR_BLS48_575            : PrimeField<9,Bls48_575Fr> = PrimeField::new();
+static G1_BLS48_575            : G1Field<9,Bls48_575Fr,9,Bls48_575Fp,4> = G1Field { consts : &BLS48_575_G1_CONSTS, base_field : &FP_BLS48_575, fr_field : &FR_BLS48_575 };
+static G2_BLS48_575            : G2Field<24,9,Bls48_575Fr,9,Bls48_575Fp,7,G2Fp8_1<9,Bls48_575Fp,24>> = G2Field { consts : &BLS48_575_G2_CONSTS, base_field : &EXFP8_BLS48_575, fr_field : &FR_BLS48_575 };
+static FP48_BLS48_575          : Fp48Field_1<24,9,Bls48_575Fp> = Fp48Field_1::const_new(PrimeField::new(), &BLS48_575_FROB_CONSTS);
+static GT_BLS48_575            : GTField<9,Bls48_575Fp,24,GTFp48_1<9,Bls48_575Fp,24>> = GTField::new(GTExtField::Fp48_1(&FP48_BLS48_575));
+
+pub const fn fr_bls48_575() -> &'static PrimeField<9,Bls48_575Fr>
+{   &FR_BLS48_575
+}
+pub const fn fp8_bls48_575() -> &'static Fp8Field_1<24,9,Bls48_575Fp>
+{   &FP8_BLS48_575
+}
+
+pub const fn g1_bls48_575() -> &'static G1Field<9,Bls48_575Fr,9,Bls48_575Fp,4>
+{   &G1_BLS48_575
+}
+
+pub const fn g2_bls48_575() -> &'static G2Field<24,9,Bls48_575Fr,9,Bls48_575Fp,7,G2Fp8_1<9,Bls48_575Fp,24>>
+{   &G2_BLS48_575
+}
+
+pub const fn fp48_bls48_575() -> &'static Fp48Field_1<24, 9,Bls48_575Fp>
+{   &FP48_BLS48_575
+}
+
+pub const fn gt_bls48_575() -> &'static GTField<9,Bls48_575Fp,24,GTFp48_1<9,Bls48_575Fp,24>>
+{   &GT_BLS48_575
+}
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_573Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_573Fr;
+impl FieldConfig<9> for Bls48_573Fp { const PARAMS : FieldParams<'static,9> = build_field_params(BLS48_573_PARAMS.modulo_as_strhex); }
+impl FieldConfig<8> for Bls48_573Fr { const PARAMS : FieldParams<'static,8> = build_field_params(BLS48_573_PARAMS.r_as_strhex); }
+
+static BLS48_573_FROB_CONSTS   : ExFieldConsts<24,9,Bls48_573Fp> = build_frob_params(BLS48_573_PARAMS);
+static FP_BLS48_573            : PrimeField<9,Bls48_573Fp> = PrimeField::new();
+static FP8_BLS48_573           : Fp8Field_1<24,9,Bls48_573Fp> = Fp8Field_1::const_new(PrimeField::new(), &BLS48_573_FROB_CONSTS);
+static EXFP8_BLS48_573         : ExtG2Field<9,Bls48_573Fp,24> = ExtG2Field::Fp8_1(&FP8_BLS48_573);
+static BLS48_573_GLV           : LatticeDecomposer<8> = LatticeDecomposer::new(&glv_basis(BLS48_573_PARAMS.lambda, BLS48_573_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x9b9fe81547457596, 0x181c164168dec54f, 0xf5122f8a7f409d94, 0xef753116fc20c556, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_573_GLS           : LatticeDecomposer<8> = LatticeDecomposer::new(&gls_basis::<8,256>(BLS48_573_PARAMS.u),
+                                                                               &[(true, [0x84994474578fddca, 0xd2a867720011606b, 0xc2d7a59650921287, 0x6de4c7f7954bebc5, 0x8bcb30f93f0635f6, 0x2f2413af39cc359f, 0x94474578fddca2c5, 0x11606b849]),
+                                                                                 (false, [0x752993a60391a66c, 0x4a9b2dbfa9d4cb2a, 0x2087ab5035967964, 0x59dcba9f5e10872d, 0x6ed3effa9ead5cff, 0x636d1ff556ef3748, 0x2df2986cf6279886, 0x1]),
+                                                                                 (true, [0xd80a5d603cfe9fbe, 0xfe207c3be9b739cb, 0x47b6fe87483862e5, 0x1b8c0d6fdeb9e4c7, 0xc751a751e3a4d726, 0xc7cdce686cae2bb, 0x147ed5e93, 0x0]),
+                                                                                 (false, [0x7ee4c9eafdf944dd, 0x7a7c914884e7fd44, 0x132644ed325c6002, 0x57af302f5c0f2035, 0x71afe2eef3513cb, 0x64246054c39c1238, 0x1, 0x0]),
+                                                                                 (true, [0xa39e616c5c9af044, 0x2281d9ae831c278, 0x9bac6034360e9d54, 0x72aaa8fec6e0ace1, 0xd04ca4a3126851ec, 0x182c8d9d6, 0x0, 0x0]),
+                                                                                 (false, [0xcd81b0c1775b104f, 0x45c5ae906274f159, 0x51a8d501a5075d96, 0xf96ad03bacf691ba, 0xa41043b3e91c6d28, 0x1, 0x0, 0x0]),
+                                                                                 (true, [0x57b7fcd9f1af5d5f, 0xa47df3de513a4fa, 0x4e5bc71834931c9e, 0x61f74cb4a977d6b6, 0x1c834b04b, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x9b9fe81547457592, 0x181c164168dec54f, 0xf5122f8a7f409d94, 0xef753116fc20c556, 0x1, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x9c4861444cd64175, 0x2d8c64f382d13999, 0xbf02b42d28f92d26, 0x21a1644b8, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x5f824382d1700985, 0xdb0bb25fd5276c84, 0x48624e80e52a799f, 0x2, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x511c75b7d818fe6b, 0x6ed20dc74d1fe0e9, 0x27aaa183c, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xcaae845530e40659, 0xb1455f2edd4a1430, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x17d57565c27f2727, 0x2ec936d2d, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x2cfbbee4a5088aa1, 0x3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x372eeb871, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_573_G1_CONSTS     : G1Consts<8,Bls48_573Fr,9,Bls48_573Fp,7> = build_g1_params(BLS48_573_PARAMS, &BLS48_573_GLV);
+static BLS48_573_G2_CONSTS     : G2Consts<24,8,Bls48_573Fr,9,Bls48_573Fp,4,G2Fp8_1<9,Bls48_573Fp,24>> = build_g2_params_fp8_1(BLS48_573_PARAMS, &BLS48_573_FROB_CONSTS, &BLS48_573_GLS);
+static FR_BLS48_573            : PrimeField<8,Bls48_573Fr> = PrimeField::new();
+static G2_BLS48_573            : G2Field<24,8,Bls48_573Fr,9,Bls48_573Fp,4,G2Fp8_1<9,Bls48_573Fp,24>> = G2Field { consts : &BLS48_573_G2_CONSTS, base_field : &EXFP8_BLS48_573, fr_field : &FR_BLS48_573 };
+static G1_BLS48_573            : G1Field<8,Bls48_573Fr,9,Bls48_573Fp,7> = G1Field { consts : &BLS48_573_G1_CONSTS, base_field : &FP_BLS48_573, fr_field : &FR_BLS48_573 };
+static FP48_BLS48_573          : Fp48Field_1<24,9,Bls48_573Fp> = Fp48Field_1::const_new(PrimeField::new(), &BLS48_573_FROB_CONSTS);
+static GT_BLS48_573            : GTField<9,Bls48_573Fp,24,GTFp48_1<9,Bls48_573Fp,24>> = GTField::new(GTExtField::Fp48_1(&FP48_BLS48_573));
+
+pub const fn fr_bls48_573() -> &'static PrimeField<8,Bls48_573Fr>
+{   &FR_BLS48_573
+}
+pub const fn fp8_bls48_573() -> &'static Fp8Field_1<24,9,Bls48_573Fp>
+{   &FP8_BLS48_573
+}
+
+pub const fn g1_bls48_573() -> &'static G1Field<8,Bls48_573Fr,9,Bls48_573Fp,7>
+{   &G1_BLS48_573
+}
+
+pub const fn g2_bls48_573() -> &'static G2Field<24,8,Bls48_573Fr,9,Bls48_573Fp,4,G2Fp8_1<9,Bls48_573Fp,24>>
+{   &G2_BLS48_573
+}
+
+pub const fn fp48_bls48_573() -> &'static Fp48Field_1<24, 9,Bls48_573Fp>
+{   &FP48_BLS48_573
+}
+
+pub const fn gt_bls48_573() -> &'static GTField<9,Bls48_573Fp,24,GTFp48_1<9,Bls48_573Fp,24>>
+{   &GT_BLS48_573
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_581Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_581Fr;
+impl FieldConfig<10> for Bls48_581Fp { const PARAMS : FieldParams<'static,10> = build_field_params(BLS48_581_PARAMS.modulo_as_strhex); }
+impl FieldConfig<9> for Bls48_581Fr { const PARAMS : FieldParams<'static,9> = build_field_params(BLS48_581_PARAMS.r_as_strhex); }
+
+static BLS48_581_FROB_CONSTS   : ExFieldConsts<24,10,Bls48_581Fp> = build_frob_params(BLS48_581_PARAMS);
+static FP_BLS48_581            : PrimeField<10,Bls48_581Fp> = PrimeField::new();
+static FP8_BLS48_581           : Fp8Field_3<24,10,Bls48_581Fp> = Fp8Field_3::const_new(PrimeField::new(), &BLS48_581_FROB_CONSTS);
+static EXFP8_BLS48_581         : ExtG2Field<10,Bls48_581Fp,24> = ExtG2Field::Fp8_3(&FP8_BLS48_581);
+static BLS48_581_GLV           : LatticeDecomposer<9> = LatticeDecomposer::new(&glv_basis(BLS48_581_PARAMS.lambda, BLS48_581_PARAMS.r_as_strhex),
+                                                                               &[(false, [0xeefa5c9da4727ef9, 0xc9d52392c8917f13, 0x4656534f950c10ec, 0x8e3fb7a7200bbba0, 0x2af31a013bcc61ec, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x734ab62c89ad64b, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_581_GLS           : LatticeDecomposer<9> = LatticeDecomposer::new(&gls_basis::<9,256>(BLS48_581_PARAMS.u),
+                                                                               &[(true, [0x9cceb46ba06a8600, 0xd6dff035addf7654, 0xb0953f8b6a7046cc, 0xc8a9d8071d81a5a7, 0xfe327a490db46b38, 0xe39af162cb4690aa, 0xdc0c17bffae706a7, 0xb85834bcdbf291cd, 0xccccca8e]),
+                                                                                 (false, [0x63cb6f358d9807d2, 0x8c702abf017b53f4, 0x940bf654c2228072, 0xf792f1bea56d6f37, 0xb089413ebdd0b127, 0x8310c13fb7eb0db2, 0x455c99cb0eddc5cd, 0xa3d706a6e987eb1d, 0x0]),
+                                                                                 (true, [0x8b425601f37cdb80, 0x3ed91c95115c038a, 0x59d065f24d7899dc, 0x7fcf61a0e7f17d8e, 0x9036ccb2b39e68df, 0x7e368a376b2b90da, 0x519accf844c3864e, 0x83126a49, 0x0]),
+                                                                                 (false, [0x1aa9445680218256, 0x611509b30248a41, 0xd22bcef0bea6a385, 0xfa63a92686e2f481, 0x4255b6901b1b4b02, 0x41d168be4c5ed2be, 0x68db8714b9eb977a, 0x0, 0x0]),
+                                                                                 (true, [0xacf12b0b4e1ac161, 0xf8b3b1cee0590895, 0x624e03b44bda435b, 0x7e56ba590a009288, 0x467fb408fd7fe8d9, 0xd68916cc6f939ebc, 0x53e2d18b, 0x0, 0x0]),
+                                                                                 (false, [0xabeefb2a96dc1574, 0x9a3cd0ced47cd746, 0x38bbac0213c6a6bd, 0x7ab283bba8ac1e3f, 0xbf5398ccb5443f01, 0x431bda1a27f4b41e, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0xae7f9215cbf34a3c, 0xae878429a7856248, 0xeec8ff013b91ba26, 0x55512b1fa10854b9, 0x88d98ba38f2c36f, 0x35afe118, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xe7c5b13adbd7a8ae, 0xc9d52392c8917f13, 0x4656534f950c10ec, 0x8e3fb7a7200bbba0, 0x2af31a013bcc61ec, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x9dc5b4374a97ebe7, 0x8524dc1e9c8b16a6, 0xb1271feed69925f4, 0x78ed71dfde9c91c9, 0x225c146d, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x376624decd7ecfa1, 0xd5745d2546bb96ce, 0x5530c549c103139a, 0x1b7cdcd753894f83, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0xbd899a8679cc8fbe, 0x7c62235b3478620e, 0x6b779b7305adac35, 0x15fd7d3b, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xb89e815be1b84610, 0xfd578a7fd6eb1455, 0x119797316c3f129e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x84ab7feef0ca413, 0x49b266549c32633a, 0xe12df33, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x6b7b83b5f0682c66, 0xb424c097851941e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0xba4e10fe198e6118, 0x901d654, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x734ab62c89ad64b, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_581_G1_CONSTS     : G1Consts<9,Bls48_581Fr,10,Bls48_581Fp,3> = build_g1_params(BLS48_581_PARAMS, &BLS48_581_GLV);
+static BLS48_581_G2_CONSTS     : G2Consts<24,9,Bls48_581Fr,10,Bls48_581Fp,4,G2Fp8_3<10,Bls48_581Fp,24>> = build_g2_params_fp8_3(BLS48_581_PARAMS, &BLS48_581_FROB_CONSTS, &BLS48_581_GLS);
+static FR_BLS48_581            : PrimeField<9,Bls48_581Fr> = PrimeField::new();
+static G2_BLS48_581            : G2Field<24,9,Bls48_581Fr,10,Bls48_581Fp,4,G2Fp8_3<10,Bls48_581Fp,24>> = G2Field { consts : &BLS48_581_G2_CONSTS, base_field : &EXFP8_BLS48_581, fr_field : &FR_BLS48_581 };
+static G1_BLS48_581            : G1Field<9,Bls48_581Fr,10,Bls48_581Fp,3> = G1Field { consts : &BLS48_581_G1_CONSTS, base_field : &FP_BLS48_581, fr_field : &FR_BLS48_581 };
+static FP48_BLS48_581          : Fp48Field_3<24,10,Bls48_581Fp> = Fp48Field_3::const_new(PrimeField::new(), &BLS48_581_FROB_CONSTS);
+static GT_BLS48_581            : GTField<10,Bls48_581Fp,24,GTFp48_3<10,Bls48_581Fp,24>> = GTField::new(GTExtField::Fp48_3(&FP48_BLS48_581));
+
+pub const fn fr_bls48_581() -> &'static PrimeField<9,Bls48_581Fr>
+{   &FR_BLS48_581
+}
+pub const fn fp8_bls48_581() -> &'static Fp8Field_3<24,10,Bls48_581Fp>
+{   &FP8_BLS48_581
+}
+
+pub const fn g1_bls48_581() -> &'static G1Field<9,Bls48_581Fr,10,Bls48_581Fp,3>
+{   &G1_BLS48_581
+}
+
+pub const fn g2_bls48_581() -> &'static G2Field<24,9,Bls48_581Fr,10,Bls48_581Fp,4,G2Fp8_3<10,Bls48_581Fp,24>>
+{   &G2_BLS48_581
+}
+
+pub const fn fp48_bls48_581() -> &'static Fp48Field_3<24, 10,Bls48_581Fp>
+{   &FP48_BLS48_581
+}
+
+pub const fn gt_bls48_581() -> &'static GTField<10,Bls48_581Fp,24,GTFp48_3<10,Bls48_581Fp,24>>
+{   &GT_BLS48_581
+}
+
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_571Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_571Fr;
+impl FieldConfig<9> for Bls48_571Fp { const PARAMS : FieldParams<'static,9> = build_field_params(BLS48_571_PARAMS.modulo_as_strhex); }
+impl FieldConfig<8> for Bls48_571Fr { const PARAMS : FieldParams<'static,8> = build_field_params(BLS48_571_PARAMS.r_as_strhex); }
+
+static BLS48_571_FROB_CONSTS   : ExFieldConsts<36,9,Bls48_571Fp> = build_frob_params(BLS48_571_PARAMS);
+static FP_BLS48_571            : PrimeField<9,Bls48_571Fp> = PrimeField::new();
+static FP8_BLS48_571           : Fp8Field_2<36,9,Bls48_571Fp> = Fp8Field_2::const_new(PrimeField::new(), &BLS48_571_FROB_CONSTS);
+static EXFP8_BLS48_571         : ExtG2Field<9,Bls48_571Fp,36> = ExtG2Field::Fp8_2(&FP8_BLS48_571);
+static BLS48_571_GLV           : LatticeDecomposer<8> = LatticeDecomposer::new(&glv_basis(BLS48_571_PARAMS.lambda, BLS48_571_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x48e3afe1eec110e6, 0xec8565d87faa591a, 0xe2b8fb9f4dff0d5d, 0xe9da217965afbaaa, 0x2, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_571_GLS           : LatticeDecomposer<8> = LatticeDecomposer::new(&gls_basis::<8,256>(BLS48_571_PARAMS.u),
+                                                                               &[(true, [0x34968fbdc71580a5, 0x74540e69ce4eeac9, 0x274bd6ee99f1ad28, 0xbda83d3678171dcc, 0x193695a8a3572aa9, 0xf1041d7d3cc6bde, 0xcebc04ba8a535f01, 0x1249c92a1]),
+                                                                                 (false, [0x748bbda2a060069e, 0x3b7c1fe3b36ea8e2, 0x8d59302b42f361e6, 0xace46b0401f9c441, 0x3c69d3b20815d4d9, 0x73bca0727191da21, 0x4e758e4428cdd603, 0x1]),
+                                                                                 (true, [0xaa4a86a98b9617e0, 0x41b988a9c2e5055d, 0xd414fe12b90f9aa2, 0x44804a8f0c0b795c, 0x5b013c97e5397a5a, 0xe13d3aaa46dd9097, 0x17e4aa57a, 0x0]),
+                                                                                 (false, [0x41f720a5bd22ab9d, 0xafea267f014cdac2, 0xfdf0b70e445f1617, 0x4143b140a9cbeda7, 0xae6e7bb18fc4ab12, 0xb4f6f53533d61600, 0x1, 0x0]),
+                                                                                 (true, [0x2c6765d5b55fdf64, 0x5c377a576c3b9fc7, 0xa6155d8bc4fceb15, 0xf6d51f6e84e79d3c, 0x2393fc5307b74d1f, 0x1f374f077, 0x0, 0x0]),
+                                                                                 (false, [0x8e834dcd2aea7e2a, 0xb3d5ac1ec4c0df54, 0x3ab1b0d3be4d7439, 0x78164d04c5d2103e, 0x3ae2db9ece3493ce, 0x2, 0x0, 0x0]),
+                                                                                 (true, [0x8280c636fff01e7a, 0xb33c522301174733, 0x4ee398578fae3a9c, 0x2eec26f9d96f52bb, 0x28c87ebbd, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x48e3afe1eec110dd, 0xec8565d87faa591a, 0xe2b8fb9f4dff0d5d, 0xe9da217965afbaaa, 0x2, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x8e3edc167188f8cf, 0xabbf389a0805683c, 0xb18e023effb5cc1d, 0x35484fa50, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x9c7c0c51d45a6d0a, 0xecf5a4fbd26ab9ec, 0xce7116db428ac1ef, 0x3, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x7efb02c6aabf7302, 0x6c95eb0cddf83fd2, 0x459ccf920, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x8b31b3584b216505, 0xf9170307af0f4e59, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x67a6f53823d10fb5, 0x5af28e2ae, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x7f44a0f6219f438e, 0x6, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x76d238e1b, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])]);
+static BLS48_571_G1_CONSTS     : G1Consts<8,Bls48_571Fr,9,Bls48_571Fp,3> = build_g1_params(BLS48_571_PARAMS, &BLS48_571_GLV);
+static BLS48_571_G2_CONSTS     : G2Consts<36,8,Bls48_571Fr,9,Bls48_571Fp,4,G2Fp8_2<9,Bls48_571Fp,36>> = build_g2_params_fp8_2(BLS48_571_PARAMS, &BLS48_571_FROB_CONSTS, &BLS48_571_GLS);
+static FR_BLS48_571            : PrimeField<8,Bls48_571Fr> = PrimeField::new();
+static G2_BLS48_571            : G2Field<36,8,Bls48_571Fr,9,Bls48_571Fp,4,G2Fp8_2<9,Bls48_571Fp,36>> = G2Field { consts : &BLS48_571_G2_CONSTS, base_field : &EXFP8_BLS48_571, fr_field : &FR_BLS48_571 };
+static G1_BLS48_571            : G1Field<8,Bls48_571Fr,9,Bls48_571Fp,3> = G1Field { consts : &BLS48_571_G1_CONSTS, base_field : &FP_BLS48_571, fr_field : &FR_BLS48_571 };
+static FP48_BLS48_571          : Fp48Field_2<36,9,Bls48_571Fp> = Fp48Field_2::const_new(PrimeField::new(), &BLS48_571_FROB_CONSTS);
+static GT_BLS48_571            : GTField<9,Bls48_571Fp,36,GTFp48_2<9,Bls48_571Fp,36>> = GTField::new(GTExtField::Fp48_2(&FP48_BLS48_571));
+
+pub const fn fr_bls48_571() -> &'static PrimeField<8,Bls48_571Fr>
+{   &FR_BLS48_571
+}
+pub const fn fp8_bls48_571() -> &'static Fp8Field_2<36,9,Bls48_571Fp>
+{   &FP8_BLS48_571
+}
+
+pub const fn g1_bls48_571() -> &'static G1Field<8,Bls48_571Fr,9,Bls48_571Fp,3>
+{   &G1_BLS48_571
+}
+
+pub const fn g2_bls48_571() -> &'static G2Field<36,8,Bls48_571Fr,9,Bls48_571Fp,4,G2Fp8_2<9,Bls48_571Fp,36>>
+{   &G2_BLS48_571
+}
+
+pub const fn fp48_bls48_571() -> &'static Fp48Field_2<36, 9,Bls48_571Fp>
+{   &FP48_BLS48_571
+}
+
+pub const fn gt_bls48_571() -> &'static GTField<9,Bls48_571Fp,36,GTFp48_2<9,Bls48_571Fp,36>>
+{   &GT_BLS48_571
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_287Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_287Fr;
+impl FieldConfig<5> for Bls48_287Fp { const PARAMS : FieldParams<'static,5> = build_field_params(BLS48_287_PARAMS.modulo_as_strhex); }
+impl FieldConfig<4> for Bls48_287Fr { const PARAMS : FieldParams<'static,4> = build_field_params(BLS48_287_PARAMS.r_as_strhex); }
+
+static BLS48_287_FROB_CONSTS   : ExFieldConsts<35,5,Bls48_287Fp> = build_frob_params(BLS48_287_PARAMS);
+static FP_BLS48_287            : PrimeField<5,Bls48_287Fp> = PrimeField::new();
+static FP8_BLS48_287           : Fp8Field_2<35,5,Bls48_287Fp> = Fp8Field_2::const_new(PrimeField::new(), &BLS48_287_FROB_CONSTS);
+static EXFP8_BLS48_287         : ExtG2Field<5,Bls48_287Fp,35> = ExtG2Field::Fp8_2(&FP8_BLS48_287);
+static BLS48_287_GLV           : LatticeDecomposer<4> = LatticeDecomposer::new(&glv_basis(BLS48_287_PARAMS.lambda, BLS48_287_PARAMS.r_as_strhex),
+                                                                               &[(false, [0x85c89656f166b301, 0xd93f6414f8c4157, 0x1, 0x0]),
+                                                                                 (false, [0x1, 0x0, 0x0, 0x0])]);
+static BLS48_287_GLS           : LatticeDecomposer<4> = LatticeDecomposer::new(&gls_basis::<4,256>(BLS48_287_PARAMS.u),
+                                                                               &[(true, [0xb719d4946cab221a, 0x4c86252134bcef72, 0x1c063236c64adf6c, 0x101a8bc2626e4]),
+                                                                                 (false, [0x284d2646ed7fed8d, 0xb76095bcfaa66304, 0x16711a77d3bdd131, 0x1035438fc]),
+                                                                                 (true, [0x1164d48376fd12ad, 0xfa78586b1c60716, 0x7b12f7b56f8db75a, 0x10502]),
+                                                                                 (false, [0x405c2950efeefaef, 0x814d34ecc3d17d1a, 0x6b387038786d7d5, 0x1]),
+                                                                                 (true, [0xc618cd3e7e55305d, 0xabe8e333bc350334, 0x10867616e2314, 0x0]),
+                                                                                 (false, [0x8f692d79c3f78860, 0xd48f0fbcd479fd4c, 0x10a1e0efa, 0x0]),
+                                                                                 (true, [0xdaf1e14c3b45f4d, 0x94595fe32023cf82, 0x10bd7, 0x0]),
+                                                                                 (false, [0x85c89656f166b2ff, 0xd93f6414f8c4157, 0x1, 0x0]),
+                                                                                 (true, [0xb19846cd4cc37ba5, 0x10f533972017a, 0x0, 0x0]),
+                                                                                 (false, [0xb40f832a7eda68cc, 0x1111562b2, 0x0, 0x0]),
+                                                                                 (true, [0x76d2932e21353c9c, 0x112da, 0x0, 0x0]),
+                                                                                 (false, [0x14a27aa8c5638340, 0x1, 0x0, 0x0]),
+                                                                                 (true, [0x1166d73147923, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1183b64fd, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x11a0c, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1, 0x0, 0x0, 0x0])]);
+static BLS48_287_G1_CONSTS     : G1Consts<4,Bls48_287Fr,5,Bls48_287Fp,4> = build_g1_params(BLS48_287_PARAMS, &BLS48_287_GLV);
+static BLS48_287_G2_CONSTS     : G2Consts<35,4,Bls48_287Fr,5,Bls48_287Fp,19,G2Fp8_2<5,Bls48_287Fp,35>> = build_g2_params_fp8_2(BLS48_287_PARAMS, &BLS48_287_FROB_CONSTS, &BLS48_287_GLS);
+static FR_BLS48_287            : PrimeField<4,Bls48_287Fr> = PrimeField::new();
+static G2_BLS48_287            : G2Field<35,4,Bls48_287Fr,5,Bls48_287Fp,19,G2Fp8_2<5,Bls48_287Fp,35>> = G2Field { consts : &BLS48_287_G2_CONSTS, base_field : &EXFP8_BLS48_287, fr_field : &FR_BLS48_287 };
+static G1_BLS48_287            : G1Field<4,Bls48_287Fr,5,Bls48_287Fp,4> = G1Field { consts : &BLS48_287_G1_CONSTS, base_field : &FP_BLS48_287, fr_field : &FR_BLS48_287 };
+static FP48_BLS48_287          : Fp48Field_2<35,5,Bls48_287Fp> = Fp48Field_2::const_new(PrimeField::new(), &BLS48_287_FROB_CONSTS);
+static GT_BLS48_287            : GTField<5,Bls48_287Fp,35,GTFp48_2<5,Bls48_287Fp,35>> = GTField::new(GTExtField::Fp48_2(&FP48_BLS48_287));
+
+pub const fn fr_bls48_287() -> &'static PrimeField<4,Bls48_287Fr>
+{   &FR_BLS48_287
+}
+pub const fn fp8_bls48_287() -> &'static Fp8Field_2<35,5,Bls48_287Fp>
+{   &FP8_BLS48_287
+}
+
+pub const fn g1_bls48_287() -> &'static G1Field<4,Bls48_287Fr,5,Bls48_287Fp,4>
+{   &G1_BLS48_287
+}
+
+pub const fn g2_bls48_287() -> &'static G2Field<35,4,Bls48_287Fr,5,Bls48_287Fp,19,G2Fp8_2<5,Bls48_287Fp,35>>
+{   &G2_BLS48_287
+}
+
+pub const fn fp48_bls48_287() -> &'static Fp48Field_2<35, 5,Bls48_287Fp>
+{   &FP48_BLS48_287
+}
+
+pub const fn gt_bls48_287() -> &'static GTField<5,Bls48_287Fp,35,GTFp48_2<5,Bls48_287Fp,35>>
+{   &GT_BLS48_287
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_277Fp;
+#[derive(Clone, Copy, Debug)]
+pub struct Bls48_277Fr;
+impl FieldConfig<5> for Bls48_277Fp { const PARAMS : FieldParams<'static,5> = build_field_params(BLS48_277_PARAMS.modulo_as_strhex); }
+impl FieldConfig<4> for Bls48_277Fr { const PARAMS : FieldParams<'static,4> = build_field_params(BLS48_277_PARAMS.r_as_strhex); }
+
+static BLS48_277_FROB_CONSTS   : ExFieldConsts<24,5,Bls48_277Fp> = build_frob_params(BLS48_277_PARAMS);
+static FP_BLS48_277            : PrimeField<5,Bls48_277Fp> = PrimeField::new();
+static FP8_BLS48_277           : Fp8Field_3<24,5,Bls48_277Fp> = Fp8Field_3::const_new(PrimeField::new(), &BLS48_277_FROB_CONSTS);
+static EXFP8_BLS48_277         : ExtG2Field<5,Bls48_277Fp,24> = ExtG2Field::Fp8_3(&FP8_BLS48_277);
+static BLS48_277_GLV           : LatticeDecomposer<4> = LatticeDecomposer::new(&glv_basis(BLS48_277_PARAMS.lambda, BLS48_277_PARAMS.r_as_strhex),
+                                                                               &[(false, [0xf6ee6303ab337306, 0x3566ecb3eec5eb74, 0x16, 0x0]),
+                                                                                 (false, [0x1ed, 0x0, 0x0, 0x0])]);
+static BLS48_277_GLS           : LatticeDecomposer<4> = LatticeDecomposer::new(&gls_basis::<4,256>(BLS48_277_PARAMS.u),
+                                                                               &[(true, [0x8d71d196a73a9121, 0xeb5f66c4b2477580, 0x2dacc10350aae987, 0x1792f843c689c]),
+                                                                                 (false, [0x9b139984b49e9d41, 0xf6d6421d52895057, 0xbe98c201d8f97738, 0x22bbcfc4b]),
+                                                                                 (true, [0x9550d2ce6b957b92, 0x568549cc13529db4, 0x764e7310f497889e, 0x332d0]),
+                                                                                 (false, [0x573b6d2085cad73d, 0xeedcc4ba121e50c6, 0xb66cf973f44f7f36, 0x4]),
+                                                                                 (true, [0xcbbb40cd6d95f32c, 0x7514e5a50bc0bfb6, 0x6f186688a6e39, 0x0]),
+                                                                                 (false, [0x3dd43c0351d9960b, 0xcd7fc0c3cedadd61, 0xa3af8ddcd, 0x0]),
+                                                                                 (true, [0x4bb3bd722a02ae60, 0x9b271f4ed48ea298, 0xf12be, 0x0]),
+                                                                                 (false, [0xf6ee6303ab337119, 0x3566ecb3eec5eb74, 0x16, 0x0]),
+                                                                                 (true, [0xe0a08bdeae9de683, 0x20b8c3d9411914, 0x0, 0x0]),
+                                                                                 (false, [0x69d5d3fc62865893, 0x30362b3de3, 0x0, 0x0]),
+                                                                                 (true, [0x8762f51344dc19d2, 0x4708b8, 0x0, 0x0]),
+                                                                                 (false, [0xa9070a83ce04146c, 0x68, 0x0, 0x0]),
+                                                                                 (true, [0x9a345876a10c2e, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0xe333b58a52, 0x0, 0x0, 0x0]),
+                                                                                 (true, [0x14ec152, 0x0, 0x0, 0x0]),
+                                                                                 (false, [0x1ed, 0x0, 0x0, 0x0])]);
+static BLS48_277_G1_CONSTS     : G1Consts<4,Bls48_277Fr,5,Bls48_277Fp,10> = build_g1_params(BLS48_277_PARAMS, &BLS48_277_GLV);
+static BLS48_277_G2_CONSTS     : G2Consts<24,4,Bls48_277Fr,5,Bls48_277Fp,4,G2Fp8_3<5,Bls48_277Fp,24>> = build_g2_params_fp8_3(BLS48_277_PARAMS, &BLS48_277_FROB_CONSTS, &BLS48_277_GLS);
+static FR_BLS48_277            : PrimeField<4,Bls48_277Fr> = PrimeField::new();
+static G2_BLS48_277            : G2Field<24,4,Bls48_277Fr,5,Bls48_277Fp,4,G2Fp8_3<5,Bls48_277Fp,24>> = G2Field { consts : &BLS48_277_G2_CONSTS, base_field : &EXFP8_BLS48_277, fr_field : &FR_BLS48_277 };
+static G1_BLS48_277            : G1Field<4,Bls48_277Fr,5,Bls48_277Fp,10> = G1Field { consts : &BLS48_277_G1_CONSTS, base_field : &FP_BLS48_277, fr_field : &FR_BLS48_277 };
+static FP48_BLS48_277          : Fp48Field_3<24,5,Bls48_277Fp> = Fp48Field_3::const_new(PrimeField::new(), &BLS48_277_FROB_CONSTS);
+static GT_BLS48_277            : GTField<5,Bls48_277Fp,24,GTFp48_3<5,Bls48_277Fp,24>> = GTField::new(GTExtField::Fp48_3(&FP48_BLS48_277));
+
+pub const fn fr_bls48_277() -> &'static PrimeField<4,Bls48_277Fr>
+{   &FR_BLS48_277
+}
+pub const fn fp8_bls48_277() -> &'static Fp8Field_3<24,5,Bls48_277Fp>
+{   &FP8_BLS48_277
+}
+
+pub const fn g1_bls48_277() -> &'static G1Field<4,Bls48_277Fr,5,Bls48_277Fp,10>
+{   &G1_BLS48_277
+}
+
+pub const fn g2_bls48_277() -> &'static G2Field<24,4,Bls48_277Fr,5,Bls48_277Fp,4,G2Fp8_3<5,Bls48_277Fp,24>>
+{   &G2_BLS48_277
+}
+
+pub const fn fp48_bls48_277() -> &'static Fp48Field_3<24, 5,Bls48_277Fp>
+{   &FP48_BLS48_277
+}
+
+pub const fn gt_bls48_277() -> &'static GTField<5,Bls48_277Fp,24,GTFp48_3<5,Bls48_277Fp,24>>
+{   &GT_BLS48_277
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use crate::CurvesNames;
+
+//  Hash-to-curve suite identifiers (CURVE_ID || "_" || HASH_ID || "_" || MAP_ID || "_" || ENCODING_TYPE || "_")
+//  https://www.rfc-editor.org/rfc/rfc9380.html#name-suite-id-naming-conventions
+//  Applications build their domain separation tags by appending the suite identifier to their own tag,
+//  e.g. "QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_"
+//  The HASH_ID is the default expander of the engine, chosen from its security level (Expander::for_security_level)
+
+#[derive(Debug)]
+pub struct HashSuite {
+    pub g1_ro : &'static str,   // hash_to_curve on G1 (random oracle)
+    pub g1_nu : &'static str,   // encode_to_curve on G1 (nonuniform)
+    pub g2_ro : &'static str,   // hash_to_curve on G2 (random oracle)
+    pub g2_nu : &'static str,   // encode_to_curve on G2 (nonuniform)
+}
+
+pub const BLS12_381_SUITE       : HashSuite = HashSuite { g1_ro : "BLS12381G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS12381G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS12381G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS12381G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS12_446_SUITE       : HashSuite = HashSuite { g1_ro : "BLS12446G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS12446G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS12446G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS12446G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS12_461_SUITE       : HashSuite = HashSuite { g1_ro : "BLS12461G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS12461G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS12461G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS12461G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS24_315_SUITE       : HashSuite = HashSuite { g1_ro : "BLS24315G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS24315G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS24315G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS24315G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS24_477_SUITE       : HashSuite = HashSuite { g1_ro : "BLS24477G1_XMD:SHA-384_SSWU_RO_", g1_nu : "BLS24477G1_XMD:SHA-384_SSWU_NU_",
+                                                       g2_ro : "BLS24477G2_XMD:SHA-384_SSWU_RO_", g2_nu : "BLS24477G2_XMD:SHA-384_SSWU_NU_" };
+pub const BLS24_479_SUITE       : HashSuite = HashSuite { g1_ro : "BLS24479G1_XMD:SHA-384_SSWU_RO_", g1_nu : "BLS24479G1_XMD:SHA-384_SSWU_NU_",
+                                                       g2_ro : "BLS24479G2_XMD:SHA-384_SSWU_RO_", g2_nu : "BLS24479G2_XMD:SHA-384_SSWU_NU_" };
+pub const BLS24_509_SUITE       : HashSuite = HashSuite { g1_ro : "BLS24509G1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS24509G1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS24509G2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS24509G2_XMD:SHA-512_SSWU_NU_" };
+pub const BLS24_509_SNARK_SUITE : HashSuite = HashSuite { g1_ro : "BLS24509SNARKG1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS24509SNARKG1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS24509SNARKG2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS24509SNARKG2_XMD:SHA-512_SSWU_NU_" };
+pub const BLS24_559_SUITE       : HashSuite = HashSuite { g1_ro : "BLS24559G1_XMD:SHA-384_SSWU_RO_", g1_nu : "BLS24559G1_XMD:SHA-384_SSWU_NU_",
+                                                       g2_ro : "BLS24559G2_XMD:SHA-384_SSWU_RO_", g2_nu : "BLS24559G2_XMD:SHA-384_SSWU_NU_" };
+pub const BLS48_277_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48277G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS48277G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS48277G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS48277G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS48_287_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48287G1_XMD:SHA-256_SSWU_RO_", g1_nu : "BLS48287G1_XMD:SHA-256_SSWU_NU_",
+                                                       g2_ro : "BLS48287G2_XMD:SHA-256_SSWU_RO_", g2_nu : "BLS48287G2_XMD:SHA-256_SSWU_NU_" };
+pub const BLS48_571_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48571G1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS48571G1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS48571G2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS48571G2_XMD:SHA-512_SSWU_NU_" };
+pub const BLS48_573_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48573G1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS48573G1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS48573G2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS48573G2_XMD:SHA-512_SSWU_NU_" };
+pub const BLS48_575_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48575G1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS48575G1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS48575G2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS48575G2_XMD:SHA-512_SSWU_NU_" };
+pub const BLS48_581_SUITE       : HashSuite = HashSuite { g1_ro : "BLS48581G1_XMD:SHA-512_SSWU_RO_", g1_nu : "BLS48581G1_XMD:SHA-512_SSWU_NU_",
+                                                       g2_ro : "BLS48581G2_XMD:SHA-512_SSWU_RO_", g2_nu : "BLS48581G2_XMD:SHA-512_SSWU_NU_" };
+
+impl CurvesNames {
+    pub fn hash_suite(&self) -> &'static HashSuite {
+        match self {
+            CurvesNames::Bls12_381       => &BLS12_381_SUITE,
+            CurvesNames::Bls12_446       => &BLS12_446_SUITE,
+            CurvesNames::Bls12_461       => &BLS12_461_SUITE,
+            CurvesNames::Bls24_315       => &BLS24_315_SUITE,
+            CurvesNames::Bls24_477       => &BLS24_477_SUITE,
+            CurvesNames::Bls24_479       => &BLS24_479_SUITE,
+            CurvesNames::Bls24_509       => &BLS24_509_SUITE,
+            CurvesNames::Bls24_509Snark  => &BLS24_509_SNARK_SUITE,
+            CurvesNames::Bls24_559       => &BLS24_559_SUITE,
+            CurvesNames::Bls48_277       => &BLS48_277_SUITE,
+            CurvesNames::Bls48_287       => &BLS48_287_SUITE,
+            CurvesNames::Bls48_571       => &BLS48_571_SUITE,
+            CurvesNames::Bls48_573       => &BLS48_573_SUITE,
+            CurvesNames::Bls48_575       => &BLS48_575_SUITE,
+            CurvesNames::Bls48_581       => &BLS48_581_SUITE,
+        }
+    }
+}
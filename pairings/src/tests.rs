@@ -1,6 +1,9 @@
-use std::{arch::x86_64::_rdtsc, time::{Duration, Instant}}; 
+use std::{arch::x86_64::_rdtsc, mem::size_of, time::{Duration, Instant}}; 
 
-use crate::{tools::arithmetic_interface::ArithmeticOperations, BLS24Curves, BLS48Curves, Bls12Curves, CurvesNames, Pairings, PairingsEngine, BLS12, BLS24, BLS48};
+use crate::{curves::{curve_arithmetics::EcPoint, fixed_base::FIXED_BASE_WINDOW, g1::{G1Element, G1FixedBaseTable}, g2::{G2Consts, G2Element, G2FixedBaseTable, G2Field, G2SwuIsogeniesConsts}, gt::{GTElement, GTExtElement, GTField, Gt}}, extensions::{g2_extfields::{ExtFieldG2Element, G2ExtElement}, towers::{DynamicTower, PairingTower}}, errors::{DecodingMode, PairingError}, fields::prime_fields::FieldConfig, tools::{arithmetic_interface::ArithmeticOperations, exponent::{has_long_zero_runs, Exponent, HardPartStrategy, COMPRESSED_SQUARING_RUN, COMPRESSED_SQUARING_RUN_FP12}, constant_time::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq}, codecs::{try_from_hex, zcash_extra_byte, PointFormat}, hashs::{expand_message_xmd, i2osp_pf, try_expand_message_xmd, Expander}}, BLS24Curves, BLS48Curves, Bls12Curves, CurvesNames, Pairings, PairingsEngine, BLS12, BLS24, BLS48, AFFINE_MILLER_THRESHOLD, PARALLEL_PAIRING_THRESHOLD};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Num, Zero};
+use once_cell::sync::OnceCell;
 
 fn measure_time<F>(f: F) -> Duration
 where
@@ -26,8 +29,8 @@ where
 }
 
 
-pub fn check_pairing_for_curve <const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>
-      (engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>)->bool
+pub fn check_pairing_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
       {
         let s1= engine.fr.random_element();
         let s2= engine.fr.random_element();
@@ -35,19 +38,785 @@ pub fn check_pairing_for_curve <const R:usize,const N:usize,const MAX_COEFS_COUN
         let q = engine.g2.random_point();
         let e1 = engine.paire(&(s1*p), &(s2*q));
         let e2 =engine.paire(&(s2*p), &(s1*q));
-        e1.equal(&e2) & !e1.equal(&engine.gt.one())
+        e1.equal(&e2) & !e1.is_one()
       }
 
-pub fn bench_pairing_for_curve <const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>
-      (engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>)
+pub fn check_decoding_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Valid encodings have to be decoded back, while every kind of malformed input has to be reported as an error 
+        let p = engine.g1.random_point();
+        let q = engine.g2.random_point();
+        let e = engine.paire(&p, &q);
+        let p_ok = engine.g1.try_from_bytearray(&p.to_compressed_bytearray()).is_ok_and(|x| x.equal(&p));
+        let q_ok = engine.g2.try_from_bytearray(&q.encode_to_compressed_bytearray()).is_ok_and(|x| x.equal(&q));
+        let e_ok = engine.gt.try_from_byte_array(&e.to_byte_array()).is_ok_and(|x| x.equal(&e));
+        let b64_ok = engine.g1.try_from_base64(&p.encode_to_base64()).is_ok() & engine.g2.try_from_base64(&q.encode_to_base64()).is_ok();
+
+        let mut truncated = p.to_compressed_bytearray();
+        truncated.pop();
+        let truncated_ok = matches!(engine.g1.try_from_bytearray(&truncated), Err(PairingError::BadLength {..}));
+        let mut badflags = p.to_compressed_bytearray();
+        badflags[0] |= 0x60;
+        let badflags_ok = engine.g1.try_from_bytearray(&badflags) == Err(PairingError::BadFlagBits);
+        let base_field = engine.g1.base_field;
+        let sizeinbytes = base_field.size_in_bytes();
+        let mut modulus = base_field.modulo_as_bigint().to_bytes_be();
+        while modulus.len() < sizeinbytes {modulus.insert(0, 0)};
+        if zcash_extra_byte(engine.g1.consts.base_field_numbits) {modulus.insert(0, 0x80)} else {modulus[0] |= 0x80};
+        let canonical_ok = engine.g1.try_from_bytearray(&modulus) == Err(PairingError::NonCanonicalFieldElement);
+        let a = p.to_affine();
+        let mut offcurve = i2osp_pf(&a.point.x, sizeinbytes);
+        offcurve.extend(i2osp_pf(&a.point.y.addto(&base_field.one()), sizeinbytes));
+        if zcash_extra_byte(engine.g1.consts.base_field_numbits) {offcurve.insert(0, 0)};
+        let oncurve_ok = engine.g1.try_from_bytearray(&offcurve) == Err(PairingError::NotOnCurve);
+        let outside = engine.g1.map_to_curve(base_field.random_element());
+        let subgroup_ok = engine.g1.try_from_bytearray(&outside.to_compressed_bytearray()) == Err(PairingError::NotInSubgroup);
+        let outside = engine.g2.map_to_curve(&engine.g2.random_element());
+        let subgroup2_ok = matches!(engine.g2.try_from_bytearray(&outside.encode_to_compressed_bytearray()), Err(PairingError::NotInSubgroup));
+        let mismatch_ok = matches!(engine.try_multi_paire(&[p, p], &[q]), Err(PairingError::LengthMismatch { left: 2, right: 1 }));
+        let base64_ok = engine.g1.try_from_base64("@@not base64@@").is_err() & engine.gt.try_from_base64("@@").is_err();
+        p_ok & q_ok & e_ok & b64_ok & truncated_ok & badflags_ok & canonical_ok & oncurve_ok & subgroup_ok & subgroup2_ok & mismatch_ok & base64_ok
+      }
+
+pub fn check_decoding_mode_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Each decoding mode accepts exactly the inputs passing its level of validation, and the default one is the full sub-group check
+        let (unchecked, oncurve, subgroup) = (DecodingMode::Unchecked, DecodingMode::OnCurve, DecodingMode::Subgroup);
+        let outside = engine.g1.map_to_curve(engine.g1.base_field.random_element()).to_compressed_bytearray();
+        let g1_ok = engine.g1.try_from_bytearray_with(&outside, unchecked).is_ok() & engine.g1.try_from_bytearray_with(&outside, oncurve).is_ok()
+                    & (engine.g1.try_from_bytearray_with(&outside, subgroup) == Err(PairingError::NotInSubgroup));
+        let a = engine.g1.random_point().to_affine();
+        let sizeinbytes = engine.g1.base_field.size_in_bytes();
+        let mut offcurve = i2osp_pf(&a.point.x, sizeinbytes);
+        offcurve.extend(i2osp_pf(&a.point.y.addto(&engine.g1.base_field.one()), sizeinbytes));
+        if zcash_extra_byte(engine.g1.consts.base_field_numbits) {offcurve.insert(0, 0)};
+        let g1_curve_ok = engine.g1.try_from_bytearray_with(&offcurve, unchecked).is_ok() 
+                          & (engine.g1.try_from_bytearray_with(&offcurve, oncurve) == Err(PairingError::NotOnCurve));
+        let outside = engine.g2.map_to_curve(&engine.g2.random_element()).encode_to_compressed_bytearray();
+        let g2_ok = engine.g2.try_from_bytearray_with(&outside, unchecked).is_ok() & engine.g2.try_from_bytearray_with(&outside, oncurve).is_ok()
+                    & matches!(engine.g2.try_from_bytearray_with(&outside, subgroup), Err(PairingError::NotInSubgroup));
+        let b = engine.g2.random_point().to_affine();
+        let mut offcurve = b.point.x.to_i2osp_bytearray();
+        offcurve.extend(b.point.y.addto(&b.point.y.one()).to_i2osp_bytearray());
+        if zcash_extra_byte(engine.g2.consts.base_field_numbits) {offcurve.insert(0, 0)};
+        let g2_curve_ok = engine.g2.try_from_bytearray_with(&offcurve, unchecked).is_ok() 
+                          & matches!(engine.g2.try_from_bytearray_with(&offcurve, oncurve), Err(PairingError::NotOnCurve));
+        // GT : a pairing value passes every check, a random element is not cyclotomic, and f^((p^(k/2)-1)*(p^(k/6)+1)) is cyclotomic 
+        // but (with overwhelming probability) outside the order-r sub-group
+        let e = engine.paire(&engine.g1.random_point(), &engine.g2.random_point()).to_byte_array();
+        let f = engine.gt.random_element();
+        let order = (f.content().len() / 6) as u8;
+        let g = f.conjugate().multiply(&f.invert());
+        let g = g.frobinus(order).multiply(&g);
+        let gt_ok = engine.gt.try_from_byte_array(&e).is_ok() & engine.gt.try_from_byte_array_with(&e, oncurve).is_ok()
+                    & engine.gt.try_from_byte_array_with(&f.to_byte_array(), unchecked).is_ok()
+                    & (engine.gt.try_from_byte_array_with(&f.to_byte_array(), oncurve).err() == Some(PairingError::NotCyclotomic))
+                    & (engine.gt.try_from_byte_array(&f.to_byte_array()).err() == Some(PairingError::NotCyclotomic))
+                    & engine.gt.try_from_byte_array_with(&g.to_byte_array(), oncurve).is_ok()
+                    & (engine.gt.try_from_byte_array(&g.to_byte_array()).err() == Some(PairingError::NotInSubgroup))
+                    & (engine.gt.try_from_base64(&g.encode_to_base64()).err() == Some(PairingError::NotInSubgroup));
+        g1_ok & g1_curve_ok & g2_ok & g2_curve_ok & gt_ok
+      }
+
+pub fn check_codec_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Every format (and its hexadecimal form) decodes back its own encodings, for both signs of y and for the infinity point
+        let formats = [PointFormat::ZcashCompressed, PointFormat::ZcashUncompressed, PointFormat::Eip2537, PointFormat::ArkworksCompressed, PointFormat::ArkworksUncompressed];
+        let p = engine.g1.random_point();
+        let q = engine.g2.random_point();
+        let (numbits, degree) = (engine.g1.consts.base_field_numbits, engine.g2.getorder());
+        let g1_ok = formats.iter().all(|&f| [p, -p, p.multiply_by_const(0)].iter().all(|a| { let bytes = a.encode(f);
+                                                                                            (bytes.len() == f.encoded_size(numbits, 1))
+                                                                                            & engine.g1.try_decode(&bytes, f).is_ok_and(|b| b.equal(a))
+                                                                                            & engine.g1.try_decode_hex(&a.encode_to_hex(f), f).is_ok_and(|b| b.equal(a)) }));
+        let g2_ok = formats.iter().all(|&f| [q, -q, q.multiply_by_const(0)].iter().all(|a| { let bytes = a.encode(f);
+                                                                                            (bytes.len() == f.encoded_size(numbits, degree))
+                                                                                            & engine.g2.try_decode(&bytes, f).is_ok_and(|b| b.equal(a))
+                                                                                            & engine.g2.try_decode_hex(&a.encode_to_hex(f), f).is_ok_and(|b| b.equal(a)) }));
+        // The ZCash flags get their own byte when the modulus has less than 3 free bits, including none (multiple of 8 bits)
+        let layout_ok = zcash_extra_byte(384) & zcash_extra_byte(446) & !zcash_extra_byte(381) & !zcash_extra_byte(315)
+                        & (PointFormat::ZcashCompressed.encoded_size(384, 1) == 49);
+        let legacy_ok = engine.g1.try_from_bytearray(&p.to_uncompressed_bytearray()).is_ok_and(|b| b.equal(&p))
+                        & engine.g2.try_from_bytearray(&q.encode_to_uncompressed_bytearray()).is_ok_and(|b| b.equal(&q));
+        // Malformed inputs : size of another format, both arkworks flags, non-zero EIP-2537 padding and invalid hexadecimal strings
+        let length_ok = matches!(engine.g1.try_decode(&p.encode(PointFormat::Eip2537), PointFormat::ZcashCompressed), Err(PairingError::BadLength {..}))
+                        & matches!(engine.g2.try_decode(&q.encode(PointFormat::ZcashCompressed), PointFormat::ArkworksUncompressed), Err(PairingError::BadLength {..}));
+        let mut flags = p.encode(PointFormat::ArkworksCompressed);
+        if let Some(last) = flags.last_mut() {*last |= 0xC0}
+        let mut compression = p.to_uncompressed_bytearray();
+        compression[0] |= 0x80;
+        let flags_ok = (engine.g1.try_decode(&flags, PointFormat::ArkworksCompressed).err() == Some(PairingError::BadFlagBits))
+                       & (engine.g1.try_decode(&compression, PointFormat::ZcashUncompressed).err() == Some(PairingError::BadFlagBits));
+        let mut padding = q.encode(PointFormat::Eip2537);
+        padding[0] ^= 1;
+        let padding_ok = match engine.g2.try_decode(&padding, PointFormat::Eip2537) {  Err(PairingError::NonCanonicalFieldElement) => true,
+                                                                                       // 64-byte coordinates (BLS24-509) have no padding
+                                                                                       Err(_) => numbits.div_ceil(8) % 64 == 0,
+                                                                                       Ok(_) => false };
+        let hex_ok = (engine.g1.try_decode_hex("0xzz", PointFormat::ZcashCompressed) == Err(PairingError::InvalidHex))
+                     & (engine.g1.try_decode_hex("abc", PointFormat::ZcashCompressed) == Err(PairingError::InvalidHex))
+                     & engine.g1.try_decode_hex(&("0x".to_owned() + &p.encode_to_hex(PointFormat::Eip2537).to_uppercase()), PointFormat::Eip2537).is_ok_and(|b| b.equal(&p));
+        g1_ok & g2_ok & layout_ok & legacy_ok & length_ok & flags_ok & padding_ok & hex_ok
+      }
+
+pub fn check_codec_vectors() -> bool
+      {
+        // Reference encodings of the BLS12-381 generators : ZCash compressed forms from the draft-irtf-cfrg-pairing-friendly-curves,
+        // and the other layouts built from the generators coordinates
+        let engine = BLS12::_381();
+        let hex = |s :&str| try_from_hex(s).unwrap();
+        let pad = |v :&[u8]| [vec![0u8; 16], v.to_vec()].concat();
+        let rev = |v :&[u8]| v.iter().rev().cloned().collect::<Vec<u8>>();
+        let (x, y) = (hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+                      hex("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"));
+        let g = engine.g1.default_generator();
+        let g1_ok = (g.encode_to_hex(PointFormat::ZcashCompressed) == "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb")
+                    & (g.encode(PointFormat::ZcashUncompressed) == [x.clone(), y.clone()].concat())
+                    & (g.encode(PointFormat::Eip2537) == [pad(&x), pad(&y)].concat())
+                    & (g.encode(PointFormat::ArkworksCompressed) == rev(&x))
+                    & (g.encode(PointFormat::ArkworksUncompressed) == [rev(&x), rev(&y)].concat())
+                    & ((-g).to_compressed_bytearray()[0] == 0xb7) & ((-g).encode(PointFormat::ArkworksCompressed)[47] == 0x97);
+        let (x0, x1) = (hex("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+                        hex("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"));
+        let (y0, y1) = (hex("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+                        hex("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"));
+        let h = engine.g2.default_generator();
+        let g2_ok = (h.encode_to_hex(PointFormat::ZcashCompressed) == "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+                                                                     024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8")
+                    & (h.encode(PointFormat::ZcashUncompressed) == [x1.clone(), x0.clone(), y1.clone(), y0.clone()].concat())
+                    & (h.encode(PointFormat::Eip2537) == [pad(&x0), pad(&x1), pad(&y0), pad(&y1)].concat())
+                    & (h.encode(PointFormat::ArkworksCompressed) == [rev(&x0), rev(&x1)].concat())
+                    & (h.encode(PointFormat::ArkworksUncompressed) == [rev(&x0), rev(&x1), rev(&y0), rev(&y1)].concat());
+        g1_ok & g2_ok
+      }
+
+pub fn check_addition_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Addition have to be complete : P+P, P+(-P) and P+Infinity give the right result on G1 and G2
+        let (p1, p2, p3) = (engine.g1.random_point(), engine.g1.random_point(), engine.g1.random_point());
+        let o1 = p1.multiply_by_const(0);
+        let g1_ok = (p1 + p1).equal(&p1.multiply_by_const(2)) & (p1 + (-p1)).point.is_infinit() & (p1 + o1).equal(&p1) 
+                    & (o1 + p1).equal(&p1) & (o1 + o1).point.is_infinit() & ((p1 + p2) + p3).equal(&(p1 + (p2 + p3)))
+                    & (p1 + p2).equal(&G1Element { point: p1.point.add_jacobian(&p2.point), consts: p1.consts })
+                    & ((p1 + (-p1)) + p2).equal(&p2) & (p1.multiply_by_const(3) + p1.multiply_by_const(-3)).point.is_infinit();
+        let (q1, q2, q3) = (engine.g2.random_point(), engine.g2.random_point(), engine.g2.random_point());
+        let o2 = q1.multiply_by_const(0);
+        let g2_ok = (q1 + q1).equal(&q1.double()) & (q1 + (-q1)).point.is_infinit() & (q1 + o2).equal(&q1) 
+                    & (o2 + q1).equal(&q1) & (o2 + o2).point.is_infinit() & ((q1 + q2) + q3).equal(&(q1 + (q2 + q3)))
+                    & (q1 + q2).equal(&G2Element { point: q1.point.add_jacobian(&q2.point), consts: q1.consts })
+                    & ((q1 + (-q1)) + q2).equal(&q2) & (q1.double() + q1.double().negate()).point.is_infinit();
+        g1_ok & g2_ok
+      }
+
+pub fn check_field_ops_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Constant-time inversions (base and G2 fields), Legendre symbol and square roots have to agree with their variable-time counterparts
+        let field = engine.g1.base_field;
+        let (a, b) = (field.random_element(), field.random_element());
+        let inv_ok = a.multiply(&a.invert()).is_one() & a.invert().equal(&a.invert_vartime()) & field.zero().invert().is_zero();
+        let a2 = a.sqr();
+        let sqrt_ok = bool::from(a2.is_square()) & a2.sqrt().is_some_and(|r| r.sqr().equal(&a2)) & field.zero().sqrt().is_some_and(|r| r.is_zero())
+                      & (bool::from(b.is_square()) == b.sqrt().is_some()) & !bool::from(engine.g1.consts.swu_consts.z.is_square()) & engine.g1.consts.swu_consts.z.sqrt().is_none();
+        let e = engine.fr.random_element();
+        let pow_ok = a.pow(&e, true).equal(&a.pow(&e, false));
+        let q = engine.g2.random_point().point;
+        let (mut batch, mut batch_vartime) = ([q.x, q.y.zero(), q.y], [q.x, q.y.zero(), q.y]);
+        <T::G2>::batch_invert(&mut batch);
+        <T::G2>::batch_invert_vartime(&mut batch_vartime);
+        let ext_inv_ok = q.x.invert_vartime().equal(&q.x.invert()) & batch.iter().zip(batch_vartime.iter()).all(|(x, y)| x.equal(y));
+        inv_ok & sqrt_ok & pow_ok & ext_inv_ok
+      }
+
+pub fn check_constant_time_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Constant-time comparisons, selections and negations have to agree with their branching counterparts on every type
+        let (yes, no) = (Choice::from(1u8), Choice::from(0u8));
+        let field = engine.g1.base_field;
+        let (a, b) = (field.random_element(), field.random_element());
+        let mut na = a;
+        na.conditional_negate(yes);
+        let fp_ok = bool::from(a.ct_eq(&a)) & !bool::from(a.ct_eq(&b)) & na.equal(&a.negate())
+                    & ConditionallySelectable::conditional_select(&a, &b, no).equal(&a) & ConditionallySelectable::conditional_select(&a, &b, yes).equal(&b)
+                    & bool::from(a.sqr().sqrt_ct().is_some()) & bool::from(engine.g1.consts.swu_consts.z.sqrt_ct().is_none())
+                    & a.invert_ct().unwrap().equal(&a.invert()) & bool::from(field.zero().invert_ct().is_none());
+        let (x, y) = (engine.g2.base_field.random_element(), engine.g2.base_field.random_element());
+        let mut nx = x;
+        nx.conditional_negate(yes);
+        let fpk_ok = bool::from(x.ct_eq(&x)) & bool::from(x.ct_ne(&y)) & nx.equal(&x.negate())
+                     & ConditionallySelectable::conditional_select(&x, &y, no).equal(&x) & ConditionallySelectable::conditional_select(&x, &y, yes).equal(&y)
+                     & bool::from(x.sqr().sqrt_ct().is_some());
+        let (f, g) = (engine.gt.random_element(), engine.gt.random_element());
+        let gt_ok = bool::from(f.ct_eq(&f)) & !bool::from(f.ct_eq(&g)) 
+                    & ConditionallySelectable::conditional_select(&f, &g, no).equal(&f) & ConditionallySelectable::conditional_select(&f, &g, yes).equal(&g);
+        let (p, q) = (engine.g1.random_point(), engine.g1.random_point());
+        let (o, k) = (p.multiply_by_const(0), engine.fr.random_element());
+        let mut np = p;
+        np.conditional_negate(yes);
+        let g1_ok = bool::from(p.point.ct_eq(&p.to_affine().point)) & !bool::from(p.point.ct_eq(&q.point)) & !bool::from(p.point.ct_eq(&o.point))
+                    & bool::from(o.point.ct_eq(&(p + (-p)).point)) & np.equal(&(-p))
+                    & G1Element::conditional_select(&p, &q, yes).equal(&q) & p.multiply(&k).equal(&p.glv_multiply(&k));
+        let (p2, q2) = (engine.g2.random_point(), engine.g2.random_point());
+        let mut np2 = p2;
+        np2.conditional_negate(yes);
+        let g2_ok = bool::from(p2.point.ct_eq(&p2.to_affine().point)) & !bool::from(p2.point.ct_eq(&q2.point)) & np2.equal(&(-p2))
+                    & G2Element::conditional_select(&p2, &q2, no).equal(&p2) & p2.multiply(&k).equal(&p2.multiply_gls(&k));
+        fp_ok & fpk_ok & gt_ok & g1_ok & g2_ok
+      }
+
+pub fn check_scalar_multiplication_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The limb-based decompositions have to give short sub-scalars with Σ v_i*λ^i = k (mod r), and the windowed, GLV and GLS
+        // multiplications have to agree with each other (and with the variable-time MSM) on random and edge scalars
+        let fr = engine.fr;
+        let to_fr = |(limbs, negative) :&([u64;R], Choice)| { let mut v = BigInt::zero();
+                                                               for l in limbs.iter().rev() { v = (v << 64) + BigInt::from(*l) }
+                                                               if bool::from(*negative) {fr.from_bigint(&-v)} else {fr.from_bigint(&v)}
+                                                             };
+        let short = |(limbs, _) :&([u64;R], Choice), bound :usize| limbs.iter().enumerate().rev().find(|(_, l)| **l != 0)
+                                                                          .map_or(0, |(i, l)| 64 * i + 64 - l.leading_zeros() as usize) <= bound;
+        let (one, lambda, u) = (fr.one(), engine.g1.consts.lambda, fr.from_bigint(&BigInt::from(engine.g2.consts.u)));
+        let mut scalars = vec![fr.zero(), one, one.double(), one.negate(), one.double().negate(), lambda, lambda.addto(&one), u, u.negate()];
+        scalars.extend((0..4).map(|_| fr.random_element()));
+        let (p, q) = (engine.g1.random_point(), engine.g2.random_point());
+        // The precomputed Babai constants have to be the ones of the (compile-time) lattice bases
+        let mut ok = engine.g1.consts.glv_decomposer.is_consistent() & engine.g2.consts.gls_decomposer.is_consistent()
+                     & (engine.g2.consts.gls_decomposer.dimension == 2 * engine.g2.consts.order);
+        for k in &scalars { let glv = engine.g1.consts.glv_decomposer.decompose(&k.to_canonical_limbs());
+                            ok &= to_fr(&glv[0]).addto(&to_fr(&glv[1]).multiply(&lambda)).equal(k) 
+                                  & glv.iter().all(|v| short(v, engine.g1.consts.glv_decomposer.bound_bits));
+                            let gls = engine.g2.consts.gls_decomposer.decompose(&k.to_canonical_limbs());
+                            let (mut sum, mut power) = (fr.zero(), one);
+                            for v in &gls { sum = sum.addto(&to_fr(v).multiply(&power));
+                                            power = power.multiply(&u);
+                                          }
+                            ok &= sum.equal(k) & gls.iter().all(|v| short(v, engine.g2.consts.gls_decomposer.bound_bits));
+                            let expected1 = engine.g1.msm_parallel(&[p], &[*k], 1);
+                            ok &= p.multiply(k).equal(&expected1) & p.glv_multiply(k).equal(&expected1);
+                            let expected2 = engine.g2.msm_parallel(&[q], &[*k], 1);
+                            ok &= q.multiply(k).equal(&expected2) & q.multiply_gls(k).equal(&expected2);
+                          }
+        ok & p.glv_multiply(&fr.zero()).point.is_infinit() & q.multiply_gls(&fr.zero()).point.is_infinit()
+      }
+
+pub fn check_hardened_multiplication_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Blinded and randomized multiplications have to give the same points as the regular ones, including for the scalars
+        // whose parity is flipped by the blinding (k = r-2 with an odd mask reaches the -P + (-P) correction)
+        let fr = engine.fr;
+        let (one, p, q) = (fr.one(), engine.g1.random_point(), engine.g2.random_point());
+        let mut scalars = vec![fr.zero(), one, one.double(), one.negate(), one.double().negate()];
+        scalars.push(fr.random_element());
+        let (lambda, mu) = (engine.g1.base_field.random_nonzero_element(), engine.g2.random_element());
+        let mut ok = p.point.randomize(&lambda).equal(&p.point) & q.point.randomize(&mu).equal(&q.point);
+        for k in &scalars { ok &= p.multiply_hardened(k).equal(&p.multiply(k)) & q.multiply_hardened(k).equal(&q.multiply(k));
+                            for blinding in [1u64, u64::MAX] {   ok &= p.point.multiply_hardened(k, blinding, &lambda).equal(&p.multiply(k).point)
+                                                                        & q.point.multiply_hardened(k, blinding, &mu).equal(&q.multiply(k).point);
+                                                                }
+                          }
+        ok
+      }
+
+pub fn check_msm_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Multi-scalar multiplications (Straus, Pippenger and threaded) have to match the naive sum of products,
+        // including repeated points, zero scalars and infinity points
+        let mut ok = true;
+        for (count, threads) in [(3, 1), (40, 3)] {
+            let mut points : Vec<G1Element<R,S,N,P,MAX_COEFS_COUNT1>> = (0..count).map(|_| engine.g1.random_point()).collect();
+            let mut scalars : Vec<_> = (0..count).map(|_| engine.fr.random_element()).collect();
+            points[1] = points[0];
+            scalars[2] = engine.fr.zero();
+            points.push(points[0].multiply_by_const(0));
+            scalars.push(engine.fr.random_element());
+            let expected = points.iter().zip(scalars.iter()).fold(points[0].multiply_by_const(0), |acc, (p, k)| acc + p.multiply(k));
+            ok &= engine.g1.msm_parallel(&points, &scalars, threads).equal(&expected);
+        }
+        for (count, threads) in [(1, 1), (4, 2)] {
+            let mut points : Vec<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>> = (0..count).map(|_| engine.g2.random_point()).collect();
+            let mut scalars : Vec<_> = (0..count).map(|_| engine.fr.random_element()).collect();
+            points.push(points[0]);
+            scalars.push(engine.fr.random_element());
+            let expected = points.iter().zip(scalars.iter()).fold(points[0].multiply_by_const(0), |acc, (p, k)| acc + p.multiply(k));
+            ok &= engine.g2.msm_parallel(&points, &scalars, threads).equal(&expected);
+        }
+        let p = engine.g1.random_point();
+        let mismatch_ok = matches!(engine.g1.try_msm(&[p, p], &[engine.fr.one()], 1), Err(PairingError::LengthMismatch { left: 2, right: 1 }));
+        ok & mismatch_ok & engine.g1.msm(&[], &[]).point.is_infinit()
+      }
+
+pub fn check_prepared_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Pairings using prepared (precomputed) G2 points have to match the regular ones, and survive serialization
+        let (p1, p2) = (engine.g1.random_point(), engine.g1.random_point());
+        let (q1, q2) = (engine.g2.random_point(), engine.g2.random_point());
+        let (pq1, pq2) = (engine.prepare_g2(&q1), engine.prepare_g2(&q2));
+        let loop_ok = engine.miller_loop_prepared(&p1, &pq1).equal(&engine.miller_loop(&p1, &q1));
+        let multi_ok = engine.multi_paire_prepared(&[p1, p2], &[pq1.clone(), pq2.clone()]).equal(&engine.multi_paire(&[p1, p2], &[q1, q2]));
+        let size_ok = pq1.lines.len() == engine.g2.prepared_lines_count();
+        let restored = engine.try_prepared_from_bytearray(&pq1.to_bytearray());
+        let serial_ok = restored.is_ok_and(|r| engine.multi_paire_prepared(&[p1], &[r]).equal(&engine.paire(&p1, &q1)));
+        let b64_ok = engine.try_prepared_from_base64(&pq2.encode_to_base64()).is_ok();
+        let mut truncated = pq1.to_bytearray();
+        truncated.pop();
+        let truncated_ok = matches!(engine.try_prepared_from_bytearray(&truncated), Err(PairingError::BadLength {..}));
+        let inf = engine.prepare_g2(&q1.multiply_by_const(0));
+        let inf_ok = engine.try_prepared_from_bytearray(&inf.to_bytearray()).is_ok_and(|r| r.infinity)
+                     & engine.multi_paire_prepared(&[p1, p2], &[inf, pq2.clone()]).equal(&engine.paire(&p2, &q2));
+        let mismatch_ok = matches!(engine.try_multi_paire_prepared(&[p1, p2], &[pq2]), Err(PairingError::LengthMismatch { left: 2, right: 1 }));
+        // Tampered lines are rejected unless the cache is trusted (Unchecked), and so is a point moved off the curve
+        let mut tampered = pq1.to_bytearray();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        let tampered_ok = (engine.try_prepared_from_bytearray(&tampered).err() == Some(PairingError::PreparedLinesMismatch))
+                          & (engine.try_prepared_from_bytearray_with(&tampered, DecodingMode::OnCurve).err() == Some(PairingError::PreparedLinesMismatch))
+                          & engine.try_prepared_from_bytearray_with(&tampered, DecodingMode::Unchecked).is_ok();
+        let mut offcurve = pq1.to_bytearray();
+        let elementsize = (offcurve.len() - 1) / (2 + 3 * engine.g2.prepared_lines_count());
+        offcurve[2 * elementsize] ^= 1;
+        let offcurve_ok = engine.try_prepared_from_bytearray(&offcurve).err() == Some(PairingError::NotOnCurve);
+        loop_ok & multi_ok & size_ok & serial_ok & b64_ok & truncated_ok & inf_ok & mismatch_ok & tampered_ok & offcurve_ok
+      }
+
+pub fn check_hashing_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Hashing to G1 and G2 has to be deterministic, separated by the DST, and land in the prime-order sub-groups
+        let suite = engine.curvename.hash_suite();
+        let (dst1, dst2) = (format!("QUUX-V01-CS02-with-{}", suite.g1_ro), format!("QUUX-V01-CS02-with-{}", suite.g2_ro));
+        let p1 = engine.g1.hash_to_curve(b"abc", dst1.as_bytes());
+        let p2 = engine.g1.encode_to_curve(b"abc", format!("QUUX-V01-CS02-with-{}", suite.g1_nu).as_bytes());
+        let q1 = engine.g2.hash_to_curve(b"abc", dst2.as_bytes());
+        let q2 = engine.g2.encode_to_curve(b"abc", format!("QUUX-V01-CS02-with-{}", suite.g2_nu).as_bytes());
+        let g1_ok = p1.is_on_curve() & p1.is_torsion() & p2.is_on_curve() & p2.is_torsion() & !p1.equal(&p2) 
+                    & p1.equal(&engine.g1.hash_to_curve(b"abc", dst1.as_bytes())) & !p1.equal(&engine.g1.hash_to_curve(b"abd", dst1.as_bytes()));
+        let g2_ok = q1.is_on_curve() & q1.is_torsion() & q2.is_on_curve() & q2.is_torsion() & !q1.equal(&q2) 
+                    & q1.equal(&engine.g2.hash_to_curve(b"abc", dst2.as_bytes())) & !q1.equal(&engine.g2.hash_to_curve(b"abc", dst1.as_bytes()));
+        // The suite identifiers advertise the default expander of the engine, which must match its security level
+        let expander = engine.g1.consts.expander;
+        let suite_ok = suite.g1_ro.contains(expander.hash_id()) & suite.g2_nu.contains(expander.hash_id()) 
+                       & (expander.security_level() >= engine.g1.consts.security_level) & (engine.g2.consts.expander == expander);
+        let p3 = engine.g1.hash_to_curve_with(b"abc", dst1.as_bytes(), Expander::XofShake256);
+        let q3 = engine.g2.hash_to_curve_with(b"abc", dst2.as_bytes(), Expander::XofShake256);
+        let xof_ok = p3.is_torsion() & !p3.equal(&p1) & q3.is_torsion() & !q3.equal(&q1);
+        g1_ok & g2_ok & suite_ok & xof_ok
+      }
+
+pub fn check_constants_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The SWU constants derived at compile time (G1) or precomputed in the parameters list (G2) have to match 
+        // their run-time evaluation : -1/Z, 1/(-1/Z - 1) and -B/A (https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-method)
+        // The comparison is made on the canonical values, since the run-time negation of 0 returns the (unreduced) modulus
+        let c1 = &engine.g1.consts.swu_consts;
+        let g1_ok = (c1.inv_z.to_big_uint() == c1.z.invert().negate().to_big_uint()) 
+                    & (c1.b_div_a.to_big_uint() == c1.swu_b.multiply(&c1.swu_a.invert()).negate().to_big_uint());
+        let c2 = &engine.g2.consts.swu_consts;
+        let inv_z = c2.z.invert().negate();
+        let g2_ok = (c2.inv_z.to_i2osp_bytearray() == inv_z.to_i2osp_bytearray()) 
+                    & (c2.j_inv_z.to_i2osp_bytearray() == inv_z.substract(&inv_z.one()).invert().to_i2osp_bytearray())
+                    & (c2.b_div_a.to_i2osp_bytearray() == c2.swu_b.multiply(&c2.swu_a.invert()).negate().to_i2osp_bytearray());
+        // The statically built generators have to lie in the prime-order sub-groups, and the pairing of both is non-degenerate
+        let (p, q) = (engine.g1.default_generator(), engine.g2.default_generator());
+        let gen_ok = p.is_on_curve() & p.is_torsion() & q.is_on_curve() & q.is_torsion() & !engine.paire(&p, &q).is_one();
+        g1_ok & g2_ok & gen_ok
+      }
+
+pub fn check_affine_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Normalized points convert back to the same Jacobian points, and the mixed addition covers P+Q, P+P, P+(-P) and the infinity point
+        let (p, q) = (engine.g1.random_point().multiply_by_const(3), engine.g1.random_point().multiply_by_const(5));
+        let (a, o) = (p.to_affine(), p.multiply_by_const(0));
+        let g1_ok = a.point.z.is_one() & a.to_projective().equal(&p) & a.is_on_curve() & a.is_torsion() & a.equal(&p.to_affine()) & !a.equal(&q.to_affine())
+                    & (q + a).equal(&q.addto(&p)) & (q - a).equal(&q.substract(&p)) & p.add_affine(&a).equal(&p.multiply_by_const(2))
+                    & p.add_affine(&a.negate()).point.is_infinit() & o.add_affine(&a).equal(&p) & p.add_affine(&o.to_affine()).equal(&p)
+                    & o.to_affine().is_infinit() & (-a).equal(&(-p).to_affine());
+        let g1_codec_ok = (a.to_compressed_bytearray() == p.to_compressed_bytearray()) & (a.to_uncompressed_bytearray() == p.to_uncompressed_bytearray())
+                          & engine.g1.try_decode_affine_with(&a.to_compressed_bytearray(), PointFormat::ZcashCompressed, DecodingMode::Subgroup).is_ok_and(|d| d.equal(&a))
+                          & engine.g1.try_decode_affine_with(&o.to_compressed_bytearray(), PointFormat::ZcashCompressed, DecodingMode::Subgroup).is_ok_and(|d| d.is_infinit());
+        let (p2, q2) = (engine.g2.random_point().multiply_by_const(3), engine.g2.random_point().multiply_by_const(5));
+        let (b, o2) = (p2.to_affine(), p2.multiply_by_const(0));
+        let g2_ok = b.point.z.is_one() & b.to_projective().equal(&p2) & b.is_on_curve() & b.is_torsion() & b.equal(&p2.to_affine()) & !b.equal(&q2.to_affine())
+                    & (q2 + b).equal(&q2.addto(&p2)) & (q2 - b).equal(&q2.substract(&p2)) & p2.add_affine(&b).equal(&p2.double())
+                    & p2.add_affine(&b.negate()).point.is_infinit() & o2.add_affine(&b).equal(&p2) & p2.add_affine(&o2.to_affine()).equal(&p2);
+        let g2_codec_ok = (b.encode_to_compressed_bytearray() == p2.encode_to_compressed_bytearray())
+                          & engine.g2.try_decode_affine_with(&b.encode_to_compressed_bytearray(), PointFormat::ZcashCompressed, DecodingMode::Subgroup).is_ok_and(|d| d.equal(&b));
+        // The pairing of normalized points is the one of their Jacobian representations
+        let e = engine.paire(&p, &p2);
+        let pairing_ok = engine.paire_affine(&a, &b).equal(&e) & engine.multi_paire_affine(&[a, q.to_affine()], &[b, b]).equal(&engine.multi_paire(&[p, q], &[p2, p2]));
+        g1_ok & g1_codec_ok & g2_ok & g2_codec_ok & pairing_ok
+      }
+
+fn batch_inversion_matches<T :ArithmeticOperations + ConstantTimeEq + Copy>(elements :&[T]) -> bool
+      {
+        // The batch inversion has to give the individual inverses, and leave the zero elements unchanged
+        let mut inverses = elements.to_vec();
+        T::batch_invert(&mut inverses);
+        elements.iter().zip(inverses.iter()).all(|(a, i)| if a.is_zero() {i.is_zero()} else {a.invert().ct_eq(i).into()})
+      }
+
+pub fn check_batch_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Montgomery's trick over Fr, Fp, the G2 extension and the GT tower, with zeros at the ends and in the middle
+        let mut fr : Vec<_> = (0..6).map(|_| engine.fr.random_element()).collect();
+        (fr[0], fr[3]) = (engine.fr.zero(), engine.fr.zero());
+        let fp : Vec<_> = (0..5).map(|i| if i == 4 {engine.g1.random_point().point.x.zero()} else {engine.g1.random_point().point.x}).collect();
+        let mut fg2 : Vec<_> = (0..4).map(|_| engine.g2.random_element()).collect();
+        fg2[1] = fg2[1].zero();
+        let mut fgt : Vec<_> = (0..3).map(|_| engine.gt.random_element()).collect();
+        fgt[2] = fgt[2].zero();
+        let inversion_ok = batch_inversion_matches(&fr) & batch_inversion_matches(&fp) & batch_inversion_matches(&fg2) & batch_inversion_matches(&fgt)
+                           & batch_inversion_matches(&fr[..0]) & batch_inversion_matches(&[engine.fr.zero()]);
+        // Batch normalization matches the individual one, for Jacobian, already normalized and infinity points
+        let mut p : Vec<_> = (0..4).map(|_| engine.g1.random_point().multiply_by_const(3)).collect();
+        (p[1], p[2]) = (p[1].multiply_by_const(0), p[2].to_affine().to_projective());
+        let mut q : Vec<_> = (0..4).map(|_| engine.g2.random_point().multiply_by_const(3)).collect();
+        (q[0], q[3]) = (q[0].multiply_by_const(0), q[3].to_affine().to_projective());
+        let g1_ok = G1Element::batch_to_affine(&p).iter().zip(p.iter()).all(|(a, x)| a.equal(&x.to_affine()) & (a.point.z.is_one() | a.is_infinit()));
+        let g2_ok = G2Element::batch_to_affine(&q).iter().zip(q.iter()).all(|(a, x)| a.equal(&x.to_affine()) & (a.point.z.is_one() | a.is_infinit()));
+        let empty_ok = G1Element::<R,S,N,P,MAX_COEFS_COUNT1>::batch_to_affine(&[]).is_empty();
+        // The variable-time normalizations (public points) give the same coordinates as the constant-time ones
+        let vartime_ok = G1Element::batch_to_affine_vartime(&p).iter().zip(p.iter()).all(|(a, x)| a.equal(&x.to_affine()) & a.equal(&x.to_affine_vartime()))
+                         & G2Element::batch_to_affine_vartime(&q).iter().zip(q.iter()).all(|(a, x)| a.equal(&x.to_affine()) & a.equal(&x.to_affine_vartime()));
+        // Multi-pairings (normalized with a single inversion) are unchanged, the pairs with an infinity point being ignored
+        let pairing_ok = engine.multi_paire(&p, &q).equal(&engine.paire(&p[2], &q[2]).multiply(&engine.paire(&p[3], &q[3])))
+                         & engine.multi_paire_prepared(&p[2..], &[engine.prepare_g2(&q[2]), engine.prepare_g2(&q[3])]).equal(&engine.multi_paire(&p[2..], &q[2..]));
+        inversion_ok & g1_ok & g2_ok & empty_ok & vartime_ok & pairing_ok
+      }
+
+pub fn check_fixed_base_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Fixed-base multiplications match the regular ones, for random, even, small and extreme (0, 1, r-1) scalars
+        let one = engine.fr.one();
+        let scalars = [engine.fr.random_element(), engine.fr.random_element(), engine.fr.zero(), one, one.addto(&one), one.negate(), one.negate().substract(&one)];
+        let (g1, g2) = (engine.g1.generator_table(), engine.g2.generator_table());
+        let generators_ok = scalars.iter().all(|k| g1.mul(k).equal(&engine.g1.default_generator().multiply(k)) 
+                                                   & g2.mul(k).equal(&engine.g2.default_generator().multiply(k)));
+        // The tables of the generators are built once, and any point (including the infinity one) accepts any window
+        let cached_ok = std::ptr::eq(g1, engine.g1.generator_table()) & std::ptr::eq(g2, engine.g2.generator_table()) & (g1.window == FIXED_BASE_WINDOW)
+                        & (g1.size() == (S::PARAMS.num_of_bits.div_ceil(FIXED_BASE_WINDOW as usize) + 1) << (FIXED_BASE_WINDOW - 1));
+        let (p, q) = (engine.g1.random_point(), engine.g2.random_point());
+        let windows_ok = [1u8, 3, 6].iter().all(|w| {  let table = G1FixedBaseTable::new(&p, *w);
+                                                        scalars.iter().all(|k| table.mul(k).equal(&p.multiply(k))) });
+        let table = G2FixedBaseTable::new(&q, 5);
+        let g2_ok = scalars.iter().all(|k| table.mul(k).equal(&q.multiply(k)));
+        let infinity_ok = G1FixedBaseTable::new(&p.multiply_by_const(0), 3).mul(&scalars[0]).point.is_infinit()
+                          & G2FixedBaseTable::new(&q.multiply_by_const(0), 3).mul(&scalars[1]).point.is_infinit();
+        generators_ok & cached_ok & windows_ok & g2_ok & infinity_ok
+      }
+
+pub fn check_parallel_pairing_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The parallel multi-pairing matches the serial one for any number of threads (including more threads than pairs),
+        // and for inputs below the threshold (serial fallback)
+        let (p, q) = (engine.g1.random_point(), engine.g2.random_point());
+        let count = PARALLEL_PAIRING_THRESHOLD + 3;
+        let p_list : Vec<_> = (1..=count).map(|i| p.multiply_by_const(i as i128)).collect();
+        let q_list : Vec<_> = (1..=count).map(|i| q.multiply_by_const(2 * i as i128 + 1)).collect();
+        let expected = engine.multi_paire(&p_list, &q_list);
+        let parallel_ok = [1, 2, 3, 4, count + 5].iter().all(|t| engine.multi_paire_parallel(&p_list, &q_list, *t) == expected);
+        let small_ok = engine.multi_paire_parallel(&p_list[..3], &q_list[..3], 4) == engine.multi_paire(&p_list[..3], &q_list[..3]);
+        let mismatch_ok = matches!(engine.try_multi_paire_parallel(&p_list, &q_list[1..], 2), Err(PairingError::LengthMismatch { left, right }) if left == count && right == count - 1);
+        parallel_ok & small_ok & mismatch_ok & engine.multi_paire_parallel(&[], &[], 4).is_one()
+      }
+
+pub fn check_accumulator_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The infinity point gives the neutral element of GT in all the pairing entry points
+        let (p1, p2) = (engine.g1.random_point(), engine.g1.random_point());
+        let (q1, q2) = (engine.g2.random_point(), engine.g2.random_point());
+        let (o1, o2) = (p1.multiply_by_const(0), q1.multiply_by_const(0));
+        let expected = engine.multi_paire(&[p1, p2], &[q1, q2]);
+        let identity_ok = engine.miller_loop(&o1, &q1).is_one() & engine.miller_loop(&p1, &o2).is_one() & engine.paire(&o1, &o2).is_one()
+                          & (engine.multi_paire(&[p1, o1, p2, p1], &[q1, q2, q2, o2]) == expected)
+                          & (engine.multi_paire_parallel(&[o1; 12], &[q1; 12], 3).is_one());
+        // The accumulator mixes regular and prepared pairs, and ignores the pairs with an infinity point
+        let prepared = engine.prepare_g2(&q2);
+        let infinity = engine.prepare_g2(&o2);
+        let mut accumulator = engine.accumulator();
+        let empty_ok = accumulator.is_empty() & accumulator.miller_loop().is_one();
+        accumulator.add_pair(&p1, &q1);
+        accumulator.add_pair(&o1, &q2);
+        accumulator.add_pair(&p2, &o2);
+        accumulator.add_prepared(&p2, &prepared);
+        accumulator.add_prepared(&p1, &infinity);
+        let accumulator_ok = empty_ok & (accumulator.len() == 2) & (accumulator.finalize() == expected);
+        // Pairing products equal to one : e(a*P,Q).e(P,-a*Q) = 1 and e(P,Q).e(-P,Q) = 1, but e(P,Q) != 1
+        let a = engine.fr.random_element();
+        let check_ok = engine.pairing_check(&[(p1.multiply(&a), q1), (p1, -q1.multiply(&a))]) & engine.pairing_check(&[(p1, q2), (-p1, q2), (o1, q1)])
+                       & !engine.pairing_check(&[(p1, q1)]) & !engine.pairing_check(&[(p1, q1), (p2, q2)]) & engine.pairing_check(&[]);
+        identity_ok & accumulator_ok & check_ok
+      }
+
+pub fn check_affine_miller_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The affine Miller loop (with batched inversions) and the Jacobian one agree after the final exponentiation,
+        // for a single pair, a few pairs and above the threshold, with infinity points in the lists
+        let (p, q) = (engine.g1.random_point(), engine.g2.random_point());
+        let count = AFFINE_MILLER_THRESHOLD + 2;
+        let mut p_list : Vec<_> = (1..=count).map(|i| p.multiply_by_const(i as i128)).collect();
+        let mut q_list : Vec<_> = (1..=count).map(|i| q.multiply_by_const(3 * i as i128 - 1)).collect();
+        (p_list[2], q_list[5]) = (p_list[2].multiply_by_const(0), q_list[5].multiply_by_const(0));
+        let (pa, qa) = (G1Element::batch_to_affine(&p_list), G2Element::batch_to_affine(&q_list));
+        let loops_ok = [1, 3, 7, count].iter()
+                           .all(|n| engine.multi_miller_loop_batch_affine(&pa[..*n], &qa[..*n]).final_exponentiation()
+                                    == engine.multi_miller_loop_jacobian(&pa[..*n], &qa[..*n]).final_exponentiation());
+        // Above the threshold, multi_paire selects the affine loop, and still matches the product of the single pairings
+        let expected = p_list.iter().zip(q_list.iter()).fold(engine.gt.one(), |f, (x, y)| f.multiply(&engine.paire(x, y)));
+        let selected_ok = engine.multi_paire(&p_list, &q_list).element() == expected;
+        // Points accepted without the sub-group check : on-curve points outside G2, then a point with y = 0 (no tangent slope at
+        // the first doubling), for which the affine loop has to give the same product as the Jacobian one
+        let mut outside = G2Element::batch_to_affine(&(0..count).map(|_| engine.g2.random_point_trys()).collect::<Vec<_>>());
+        let outside_ok = outside.iter().all(|q| q.is_on_curve() & !q.is_torsion())
+                         & (engine.multi_miller_loop_batch_affine(&pa, &outside).final_exponentiation()
+                            == engine.multi_miller_loop_jacobian(&pa, &outside).final_exponentiation());
+        let mut flat = outside[0].point.x.to_i2osp_bytearray();
+        flat.extend(outside[0].point.y.zero().to_i2osp_bytearray());
+        if zcash_extra_byte(engine.g2.consts.base_field_numbits) {flat.insert(0, 0)};
+        outside[3] = engine.g2.try_from_bytearray_with(&flat, DecodingMode::Unchecked).unwrap().to_affine();
+        let flat_ok = engine.multi_miller_loop_affine(&pa, &outside).final_exponentiation()
+                      == engine.multi_miller_loop_jacobian(&pa, &outside).final_exponentiation();
+        loops_ok & selected_ok & outside_ok & flat_ok & engine.multi_miller_loop_batch_affine(&pa[..0], &qa[..0]).is_one()
+      }
+
+pub fn check_compressed_squaring_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Compressed powers (Karabina's squarings and simultaneous decompression) match the regular cyclotomic powers in GT, for the
+        // NAF of |u| and |u-1| (used by the final exponentiation), and for small, dense and even exponents, including the neutral element
+        let g = engine.paire(&engine.g1.random_point(), &engine.g2.random_point()).to_gt_element();
+        let u = engine.g2.consts.u;
+        let exponents = [u.unsigned_abs(), (u - 1).unsigned_abs(), 0, 1, 2, 3, 0x10000, 0x9e3779b97f4a7c15f39cc0605cedc834];
+        let powers_ok = exponents.iter().all(|e| {  let naf = <u128 as Exponent<N>>::to_naf(e);
+                                                     g.compressed_power(&naf).equal(&g.pow(e)) & g.one().compressed_power(&naf).is_one() });
+        let mut sparse = [0i8; 24];
+        (sparse[0], sparse[23]) = (1, -1);
+        let runs_ok = has_long_zero_runs(&sparse, COMPRESSED_SQUARING_RUN_FP12) & !has_long_zero_runs(&[1, 0, -1, 0, 1, 0, 0, 1], COMPRESSED_SQUARING_RUN)
+                      & !has_long_zero_runs(&[], COMPRESSED_SQUARING_RUN);
+        powers_ok & runs_ok
+      }
+
+pub fn check_hard_part_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The decompositions of the hard part agree : Hayashida and Ghammam-Fouotsa both give the cube of the pairing, that is the
+        // cube of the naive exponentiation by Φk(p)/r. The engines built with another strategy give the same values
+        let (p, q) = (engine.g1.random_point(), engine.g2.random_point());
+        let f = engine.miller_loop(&p, &q);
+        let hayashida = f.final_exponentiation_with(HardPartStrategy::Hayashida);
+        let naive = f.final_exponentiation_with(HardPartStrategy::Naive);
+        let strategies_ok = f.final_exponentiation_with(HardPartStrategy::GhammamFouotsa).equal(&hayashida)
+                            & naive.multiply(&naive.unisqr()).equal(&hayashida) & !naive.is_one();
+        let engines_ok = engine.paire(&p, &q).element().equal(&hayashida) 
+                         & engine.with_hard_part(HardPartStrategy::GhammamFouotsa).paire(&p, &q).element().equal(&hayashida);
+        strategies_ok & engines_ok
+      }
+
+pub fn check_cyclotomic_gt_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // Pairing values use the cyclotomic algorithms directly, and agree with the generic operations (which test the membership
+        // on each call). The checked conversion accepts the pairing values and rejects the elements out of the cyclotomic sub-group
+        let g = engine.paire(&engine.g1.random_point(), &engine.g2.random_point());
+        let e = engine.fr.random_element();
+        let ops_ok = g.pow(&e).element().equal(&g.element().pow(&e)) & g.square().equal(&g.multiply(&g))
+                     & g.inverse().multiply(&g).is_one() & g.pow(&0u128).is_one() & g.pow(&1u128).equal(&g) & !g.is_one();
+        let random = engine.gt.random_element();
+        let checked = |x :&T::GT| Gt::<N,P,PRAMASIZE,T::GT>::try_from_element(x);
+        let conversions_ok = (checked(&g.element()) == Ok(g)) & (checked(&random).err() == Some(PairingError::NotCyclotomic))
+                             & checked(&Gt::from_miller_loop(&random, engine.hard_part).element()).is_ok()
+                             & Gt::<N,P,PRAMASIZE,T::GT>::try_from_gt_element(&g.to_gt_element()).is_ok();
+        ops_ok & conversions_ok
+      }
+
+pub fn check_gls_power_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The GLS exponentiation (Frobenius decomposition of the scalar) matches the cyclotomic powers, for random scalars and for 0, 1
+        // and -1 (whose sub-scalars are all even but the first one), including on the neutral element
+        let g = engine.paire(&engine.g1.random_point(), &engine.g2.random_point());
+        let scalars = [engine.fr.random_element(), engine.fr.random_element(), engine.fr.zero(), engine.fr.one(), engine.fr.one().negate()];
+        let decomposer = engine.g2.consts.gls_decomposer;
+        scalars.iter().all(|k| g.pow_gls(k, decomposer).equal(&g.pow(k)) & g.one().pow_gls(k, decomposer).is_one() & engine.pow_gls(&g, k).equal(&g.pow(k)))
+      }
+
+pub fn check_hash_to_curve_vectors() -> bool
+      {
+        // Test vectors from RFC 9380 (Appendix J.9.1, J.9.2, J.10.1 and K.1) for BLS12-381
+        let engine = BLS12::_381();
+        let big = |s :&str| BigUint::from_str_radix(s, 16).unwrap();
+        let expander_dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let xmd_ok = expand_message_xmd(b"", expander_dst, 0x20) == i2osp_big(&big("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"), 32)
+                     && expand_message_xmd(b"abc", expander_dst, 0x20) == i2osp_big(&big("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"), 32)
+                     && try_expand_message_xmd(b"abc", expander_dst, 256 * 32) == Err(PairingError::InvalidOutputLength);
+        // RFC 9380 Appendix K.3, K.4 and K.6 (SHA-512, SHAKE128 and SHAKE256 expanders)
+        let expanders = [(Expander::XmdSha512, "QUUX-V01-CS02-with-expander-SHA512-256", "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+                                                                                        "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc"),
+                         (Expander::XofShake128, "QUUX-V01-CS02-with-expander-SHAKE128", "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+                                                                                         "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"),
+                         (Expander::XofShake256, "QUUX-V01-CS02-with-expander-SHAKE256", "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+                                                                                         "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07")];
+        let expanders_ok = expanders.iter().all(|(e, dst, empty, abc)| e.expand(b"", dst.as_bytes(), 0x20) == i2osp_big(&big(empty), 32)
+                                                                        && e.expand(b"abc", dst.as_bytes(), 0x20) == i2osp_big(&big(abc), 32));
+        let g1_vectors = [("", "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                                "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265"),
+                          ("abc", "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                                  "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d"),
+                          ("abcdef0123456789", "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                                               "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709")];
+        let g1_ok = g1_vectors.iter().all(|(msg, x, y)| { let p = engine.g1.hash_to_curve(msg.as_bytes(), b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_").to_affine();
+                                                          p.point.x.to_big_uint() == big(x) && p.point.y.to_big_uint() == big(y) });
+        let p = engine.g1.encode_to_curve(b"", b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_").to_affine();
+        let g1_nu_ok = p.point.x.to_big_uint() == big("184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba")
+                       && p.point.y.to_big_uint() == big("04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3");
+        let q = engine.g2.hash_to_curve(b"", b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_").to_affine();
+        let coefs : Vec<BigUint> = q.point.x.content().iter().chain(q.point.y.content().iter()).map(|c| c.to_big_uint()).collect();
+        let g2_ok = coefs == [big("0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a"),
+                              big("05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d"),
+                              big("0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92"),
+                              big("12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6")];
+        xmd_ok & expanders_ok & g1_ok & g1_nu_ok & g2_ok
+      }
+
+fn dynamic_engine <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>) -> &'static Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, DynamicTower>
+{
+  // Same curve as the given engine, but with the run-time dispatched representations (enums over all the towers) for G2 and GT
+  let c = engine.g2.consts;
+  let dynamic = |x :&T::G2| x.to_g2_element();
+  let consts : &'static G2Consts<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,ExtFieldG2Element<N,P,PRAMASIZE>> = 
+        Box::leak(Box::new(G2Consts { b: dynamic(&c.b),
+                                      a: dynamic(&c.a),
+                                      swu_consts: G2SwuIsogeniesConsts { z: dynamic(&c.swu_consts.z),
+                                                                         swu_a: dynamic(&c.swu_consts.swu_a),
+                                                                         swu_b: dynamic(&c.swu_consts.swu_b),
+                                                                         xnum: c.swu_consts.xnum.map(|x| dynamic(&x)),
+                                                                         xden: c.swu_consts.xden.map(|x| dynamic(&x)),
+                                                                         ynum: c.swu_consts.ynum.map(|x| dynamic(&x)),
+                                                                         yden: c.swu_consts.yden.map(|x| dynamic(&x)),
+                                                                         inv_z: dynamic(&c.swu_consts.inv_z),
+                                                                         j_inv_z: dynamic(&c.swu_consts.j_inv_z),
+                                                                         b_div_a: dynamic(&c.swu_consts.b_div_a) },
+                                      extfieldparams: c.extfieldparams,
+                                      u: c.u,
+                                      lambda: c.lambda,
+                                      gls_decomposer: c.gls_decomposer,
+                                      generator_table: OnceCell::new(),
+                                      base_field_numbits: c.base_field_numbits,
+                                      security_level: c.security_level,
+                                      expander: c.expander,
+                                      twist_type: c.twist_type,
+                                      order: c.order,
+                                      default_generator: EcPoint { x: dynamic(&c.default_generator.x), 
+                                                                   y: dynamic(&c.default_generator.y), 
+                                                                   z: dynamic(&c.default_generator.z) } }));
+  let g2 = Box::leak(Box::new(G2Field { consts, base_field: engine.g2.base_field, fr_field: engine.g2.fr_field }));
+  let gt = Box::leak(Box::new(GTField::new(engine.gt.base_field)));
+  Box::leak(Box::new(Pairings { identifier: engine.identifier, curvename: engine.curvename, g1: engine.g1, g2, gt, fr: engine.fr, hard_part: engine.hard_part }))
+}
+
+fn to_dynamic_point <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, F: G2ExtElement<N,P,PRAMASIZE>>
+      (q :&G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,F>, 
+       consts :&'static G2Consts<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,ExtFieldG2Element<N,P,PRAMASIZE>>) -> G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,ExtFieldG2Element<N,P,PRAMASIZE>>
+{
+  G2Element { consts, point: EcPoint { x: q.point.x.to_g2_element(), y: q.point.y.to_g2_element(), z: q.point.z.to_g2_element() } }
+}
+
+pub fn check_tower_representation_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)->bool
+      {
+        // The engine monomorphized on the tower types of its family, and the run-time dispatched one, have to compute the same values
+        let dyn_engine = dynamic_engine(engine);
+        let p = engine.g1.random_point();
+        let q = engine.g2.random_point();
+        let dq = to_dynamic_point(&q, dyn_engine.g2.consts);
+        let s = engine.fr.random_element();
+        let miller_ok = engine.miller_loop(&p, &q).to_gt_element().equal(&dyn_engine.miller_loop(&p, &dq));
+        let e = engine.paire(&p, &q);
+        let pairing_ok = e.to_gt_element().equal(&dyn_engine.paire(&p, &dq));
+        let multi_ok = engine.multi_paire(&[p, s * p], &[q, -q]).to_gt_element().equal(&dyn_engine.multi_paire(&[p, s * p], &[dq, -dq]));
+        let prepared_ok = engine.miller_loop_prepared(&p, &engine.prepare_g2(&q)).to_gt_element()
+                                .equal(&dyn_engine.miller_loop_prepared(&p, &dyn_engine.prepare_g2(&dq)));
+        let gt_ok = e.pow(&s).to_gt_element().equal(&e.to_gt_element().pow(&s)) 
+                    & e.frobinus(1).to_gt_element().equal(&e.to_gt_element().frobinus(1))
+                    & (e.to_byte_array() == e.to_gt_element().to_byte_array());
+        let g2_ok = to_dynamic_point(&(s * q), dyn_engine.g2.consts).equal(&(s * dq))
+                    & to_dynamic_point(&q.multiply_gls(&s), dyn_engine.g2.consts).equal(&dq.multiply_gls(&s))
+                    & to_dynamic_point(&engine.g2.hash_to_curve(b"tower", b"DST"), dyn_engine.g2.consts).equal(&dyn_engine.g2.hash_to_curve(b"tower", b"DST"))
+                    & (q.encode_to_compressed_bytearray() == dq.encode_to_compressed_bytearray());
+        miller_ok & pairing_ok & multi_ok & prepared_ok & gt_ok & g2_ok
+      }
+
+pub fn bench_tower_representation_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)
+      {
+        // Memory footprint and runtime of the tower types of the family, against the run-time dispatched enums
+        println!("------- {} (tower types / enums) --------------------------------",engine.identifier);
+        let dyn_engine = dynamic_engine(engine);
+        println!("Size in bytes of a G2 coordinate : {} / {}", size_of::<T::G2>(), size_of::<ExtFieldG2Element<N,P,PRAMASIZE>>());
+        println!("Size in bytes of a G2 point : {} / {}", size_of::<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,T::G2>>(), 
+                                                          size_of::<G2Element<PRAMASIZE,R,S,N,P,MAX_COEFS_COUNT2,ExtFieldG2Element<N,P,PRAMASIZE>>>());
+        println!("Size in bytes of a GT element : {} / {}", size_of::<T::GT>(), size_of::<GTElement<N,P,PRAMASIZE>>());
+        let p = engine.g1.random_point();
+        let q = engine.g2.random_point();
+        let dq = to_dynamic_point(&q, dyn_engine.g2.consts);
+        let (x, y) = (engine.g2.random_element(), engine.g2.random_element());
+        let (dx, dy) = (x.to_g2_element(), y.to_g2_element());
+        let mut duration = measure_time(|| {let _ = x.multiply(&y);});
+        let mut dyn_duration = measure_time(|| {let _ = dx.multiply(&dy);});
+        println!("Time elapsed for multiplication on G2's field : {:?} / {:?}", duration, dyn_duration);
+        let (f, g) = (engine.gt.random_element(), engine.gt.random_element());
+        let (df, dg) = (f.to_gt_element(), g.to_gt_element());
+        duration = measure_time(|| {let _ = f.multiply(&g);});
+        dyn_duration = measure_time(|| {let _ = df.multiply(&dg);});
+        println!("Time elapsed for multiplication on GT's field : {:?} / {:?}", duration, dyn_duration);
+        duration = measure_time(|| {let _ = f.sqr();});
+        dyn_duration = measure_time(|| {let _ = df.sqr();});
+        println!("Time elapsed for squaring on GT's field : {:?} / {:?}", duration, dyn_duration);
+        duration = measure_time(|| {engine.miller_loop(&p, &q);});
+        dyn_duration = measure_time(|| {dyn_engine.miller_loop(&p, &dq);});
+        println!("Time elapsed for Miller loop : {:?} / {:?}", duration, dyn_duration);
+        let (e, de) = (engine.miller_loop(&p, &q), dyn_engine.miller_loop(&p, &dq));
+        duration = measure_time(|| {let _ = e.final_exponentiation();});
+        dyn_duration = measure_time(|| {let _ = de.final_exponentiation();});
+        println!("Time elapsed for Final Exponentiation : {:?} / {:?}", duration, dyn_duration);
+        duration = measure_time(|| {engine.paire(&p, &q);});
+        dyn_duration = measure_time(|| {dyn_engine.paire(&p, &dq);});
+        println!("Time elapsed for pairings : {:?} / {:?}", duration, dyn_duration);
+      }
+
+fn i2osp_big(x :&BigUint, len :usize) -> Vec<u8>
+{
+  let mut out = x.to_bytes_be();
+  while out.len() < len {out.insert(0, 0)};
+  out
+}
+
+pub fn bench_pairing_for_curve <const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)
       {
         println!("------- {} --------------------------------",engine.identifier);        
         let seed = engine.g1.base_field.random_element();
         let mut duration = measure_time(|| {let _ = engine.g1.map_to_curve(seed);} );
         let mut cycles = measure_cycles(|| {let _ = engine.g1.map_to_curve(seed);} );
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Mapping to curve on G1  (SSWU) : {:?}/{}", duration,(cycles/1000) as u32);
-        duration = measure_time(|| {let _ = engine.g1.hash_to_field("Some identity",0);} );
-        cycles = measure_cycles(|| {let _ = engine.g1.hash_to_field("Some identity",0);} );
+        duration = measure_time(|| {let _ = engine.g1.hash_to_curve(b"Some identity", engine.curvename.hash_suite().g1_ro.as_bytes());} );
+        cycles = measure_cycles(|| {let _ = engine.g1.hash_to_curve(b"Some identity", engine.curvename.hash_suite().g1_ro.as_bytes());} );
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Hashing to G1's Torsion sub-group : {:?}/{}", duration,(cycles/1000) as u32);
         let scalar = engine.fr.random_element();
         let p =engine.g1.random_point();
@@ -58,15 +827,15 @@ pub fn bench_pairing_for_curve <const R:usize,const N:usize,const MAX_COEFS_COUN
         cycles = measure_cycles(|| {let _ = p.glv_multiply(&scalar);});
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for multiplication with scalar on G1 (GLV multiplication) : {:?}/{}", duration,(cycles/1000) as u32);
         
-        let seed = engine.g2.base_field.random_element();
+        let seed = engine.g2.random_element();
         duration = measure_time(|| {let _ = engine.g2.map_to_curve(&seed);} );
         cycles = measure_cycles(|| {let _ = engine.g2.map_to_curve(&seed);} );
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Mapping to curve on G2  (SSWU) : {:?}/{}", duration,(cycles/1000) as u32);
         duration = measure_time(|| {let _ = engine.g2.random_point_trys();} );
         cycles = measure_cycles(|| {let _ = engine.g2.random_point_trys();} );
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Mapping to curve on G2  (Random) : {:?}/{}", duration,(cycles/1000) as u32);
-        duration = measure_time(|| {let _ = engine.g2.hash_to_field("Some identity",0);} );
-        cycles = measure_cycles(|| {let _ = engine.g2.hash_to_field("Some identity",0);} );
+        duration = measure_time(|| {let _ = engine.g2.hash_to_curve(b"Some identity", engine.curvename.hash_suite().g2_ro.as_bytes());} );
+        cycles = measure_cycles(|| {let _ = engine.g2.hash_to_curve(b"Some identity", engine.curvename.hash_suite().g2_ro.as_bytes());} );
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Hashing to G2's Torsion sub-group : {:?}/{}", duration,(cycles/1000) as u32);
         let scalar = engine.fr.random_element();
         let q =engine.g2.random_point();
@@ -89,47 +858,156 @@ pub fn bench_pairing_for_curve <const R:usize,const N:usize,const MAX_COEFS_COUN
         println!("Time elapsed / CPU cycles(*10^3 Cycle) for Final Exponentiation: {:?}/{}", duration,(cycles/1000) as u32);        
       }
 
+// Runs a per-curve check or bench on the engine matching the curve name.
+macro_rules! for_each_engine {
+  ($curve:expr, $f:ident) => {
+    match $curve {
+      CurvesNames::Bls12_381 => $f(BLS12::_381()),
+      CurvesNames::Bls12_446 => $f(BLS12::_446()),
+      CurvesNames::Bls12_461 => $f(BLS12::_461()),
+      CurvesNames::Bls24_315 => $f(BLS24::_315()),
+      CurvesNames::Bls24_477 => $f(BLS24::_477()),
+      CurvesNames::Bls24_479 => $f(BLS24::_479()),
+      CurvesNames::Bls24_509 => $f(BLS24::_509()),
+      CurvesNames::Bls24_509Snark => $f(BLS24::_509_snark()),
+      CurvesNames::Bls24_559 => $f(BLS24::_559()),
+      CurvesNames::Bls48_277 => $f(BLS48::_277()),
+      CurvesNames::Bls48_287 => $f(BLS48::_287()),
+      CurvesNames::Bls48_571 => $f(BLS48::_571()),
+      CurvesNames::Bls48_573 => $f(BLS48::_573()),
+      CurvesNames::Bls48_575 => $f(BLS48::_575()),
+      CurvesNames::Bls48_581 => $f(BLS48::_581()),
+    }
+  };
+}
+
 pub fn check_pairings(curve :&CurvesNames)->bool
 {
-  match curve {
-    CurvesNames::Bls12_381 => { check_pairing_for_curve(BLS12::_381())},
-    CurvesNames::Bls12_446 => { check_pairing_for_curve(BLS12::_446())},
-    CurvesNames::Bls12_461 => { check_pairing_for_curve(BLS12::_461())},
-    CurvesNames::Bls24_315 => { check_pairing_for_curve(BLS24::_315())},
-    CurvesNames::Bls24_477 => { check_pairing_for_curve(BLS24::_477())},
-    CurvesNames::Bls24_479 => { check_pairing_for_curve(BLS24::_479())},
-    CurvesNames::Bls24_509 => { check_pairing_for_curve(BLS24::_509())},
-    CurvesNames::Bls24_509Snark =>{ check_pairing_for_curve(BLS24::_509_snark())},
-    CurvesNames::Bls24_559 => { check_pairing_for_curve(BLS24::_559())},
-    CurvesNames::Bls48_277 => { check_pairing_for_curve(BLS48::_277())},
-    CurvesNames::Bls48_287 => { check_pairing_for_curve(BLS48::_287())} ,
-    CurvesNames::Bls48_571 => { check_pairing_for_curve(BLS48::_571())},
-    CurvesNames::Bls48_573 => { check_pairing_for_curve(BLS48::_573())},
-    CurvesNames::Bls48_575 => { check_pairing_for_curve(BLS48::_575())},
-    CurvesNames::Bls48_581 => { check_pairing_for_curve(BLS48::_581())},
-  } 
-  
+  for_each_engine!(curve, check_pairing_for_curve)
+}
+
+pub fn check_decodings(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_decoding_for_curve)
+}
+
+pub fn check_codecs(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_codec_for_curve)
+}
+
+pub fn check_decoding_modes(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_decoding_mode_for_curve)
+}
+
+pub fn check_additions(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_addition_for_curve)
+}
+
+pub fn check_field_ops(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_field_ops_for_curve)
+}
+
+pub fn check_constant_times(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_constant_time_for_curve)
+}
+
+pub fn check_hardened_multiplications(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_hardened_multiplication_for_curve)
+}
+
+pub fn check_scalar_multiplications(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_scalar_multiplication_for_curve)
+}
+
+pub fn check_msms(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_msm_for_curve)
+}
+
+pub fn check_prepared(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_prepared_for_curve)
+}
+
+pub fn check_hashings(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_hashing_for_curve)
+}
+
+pub fn check_affines(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_affine_for_curve)
+}
+
+pub fn check_batches(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_batch_for_curve)
+}
+
+pub fn check_fixed_bases(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_fixed_base_for_curve)
+}
+
+pub fn check_parallel_pairings(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_parallel_pairing_for_curve)
+}
+
+pub fn check_accumulators(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_accumulator_for_curve)
+}
+
+pub fn check_affine_miller_loops(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_affine_miller_for_curve)
+}
+
+pub fn check_compressed_squarings(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_compressed_squaring_for_curve)
+}
+
+pub fn check_hard_parts(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_hard_part_for_curve)
+}
+
+pub fn check_cyclotomic_gts(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_cyclotomic_gt_for_curve)
+}
+
+pub fn check_gls_powers(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_gls_power_for_curve)
+}
+
+pub fn check_constants(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_constants_for_curve)
+}
+
+pub fn check_tower_representations(curve :&CurvesNames)->bool
+{
+  for_each_engine!(curve, check_tower_representation_for_curve)
 }
 
 pub fn bench_pairings(curve :&CurvesNames)
 {
-  match curve {
-    CurvesNames::Bls12_381 => { bench_pairing_for_curve(BLS12::_381());},
-    CurvesNames::Bls12_446 => { bench_pairing_for_curve(BLS12::_446());},
-    CurvesNames::Bls12_461 => { bench_pairing_for_curve(BLS12::_461());},
-    CurvesNames::Bls24_315 => { bench_pairing_for_curve(BLS24::_315())},
-    CurvesNames::Bls24_477 => { bench_pairing_for_curve(BLS24::_477())},
-    CurvesNames::Bls24_479 => { bench_pairing_for_curve(BLS24::_479())},
-    CurvesNames::Bls24_509 => { bench_pairing_for_curve(BLS24::_509())},
-    CurvesNames::Bls24_509Snark =>{ bench_pairing_for_curve(BLS24::_509_snark())},
-    CurvesNames::Bls24_559 => { bench_pairing_for_curve(BLS24::_559())},
-    CurvesNames::Bls48_277 => { bench_pairing_for_curve(BLS48::_277())},
-    CurvesNames::Bls48_287 => { bench_pairing_for_curve(BLS48::_287())} ,
-    CurvesNames::Bls48_571 => { bench_pairing_for_curve(BLS48::_571())},
-    CurvesNames::Bls48_573 => { bench_pairing_for_curve(BLS48::_573())},
-    CurvesNames::Bls48_575 => { bench_pairing_for_curve(BLS48::_575())},
-    CurvesNames::Bls48_581 => { bench_pairing_for_curve(BLS48::_581())},
-  } 
-  
+  for_each_engine!(curve, bench_pairing_for_curve)
+}
+
+pub fn bench_tower_representations(curve :&CurvesNames)
+{
+  for_each_engine!(curve, bench_tower_representation_for_curve)
 }
 
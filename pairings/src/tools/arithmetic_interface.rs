@@ -9,6 +9,10 @@ pub trait ArithmeticOperations {
     fn multiply(&self, other: &Self) -> Self;
     fn sqr(&self) -> Self;
     fn invert(&self) -> Self;
+    fn invert_vartime(&self) -> Self where Self: Sized
+    {   // Variable-time inversion, for public values only (defaults to the constant-time one where no faster variant exists)
+        self.invert()
+    }
     fn negate(&self) -> Self;
     fn equal(&self, rhs :&Self) -> bool;
     fn is_zero(&self) -> bool;
@@ -17,4 +21,30 @@ pub trait ArithmeticOperations {
     fn to_hex_string(&self) -> String;
     fn one(&self) -> Self;
     fn zero(&self) -> Self;
+    fn batch_invert(elements :&mut [Self]) where Self: Sized + Copy
+    {   // Inversion of all the elements with a single field inversion, the zero elements being left unchanged
+        batch_invert_with(elements, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert())
+    }
+    fn batch_invert_vartime(elements :&mut [Self]) where Self: Sized + Copy
+    {   // Same as batch_invert, with the single inversion done in variable time (public values only)
+        batch_invert_with(elements, |a| a.is_zero(), |a, b| a.multiply(b), |a| a.invert_vartime())
+    }
+}
+
+pub fn batch_invert_with<T: Copy>(elements :&mut [T], is_zero :impl Fn(&T) -> bool, multiply :impl Fn(&T, &T) -> T, invert :impl Fn(&T) -> T)
+{
+    // Montgomery's trick : the prefix products a1*...*ai are accumulated, their total is inverted once, and each inverse is 
+    // recovered going backward with two multiplications (3(n-1) multiplications and one inversion instead of n inversions).
+    // Zero elements are skipped (not constant-time with respect to the positions of the zeros)
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut acc : Option<T> = None;
+    for e in elements.iter() {  prefix.push(acc);
+                                if !is_zero(e) { acc = Some(match acc { Some(a) => multiply(&a, e), None => *e }) }
+                             }
+    let Some(mut inv) = acc.map(|a| invert(&a)) else { return };
+    for (e, before) in elements.iter_mut().zip(prefix).rev() {  if is_zero(e) { continue }
+                                                                let next = multiply(&inv, e);
+                                                                *e = match before { Some(b) => multiply(&inv, &b), None => inv };
+                                                                inv = next;
+                                                             }
 }
\ No newline at end of file
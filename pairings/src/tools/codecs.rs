@@ -0,0 +1,150 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use crate::{errors::PairingError, fields::prime_fields::{FieldConfig, FieldElement, PrimeField}, tools::hashs::{i2osp_pf, os2ip}};
+
+//  Wire formats of G1/G2 points. The coordinates are handled as lists of base field coefficients (c0 first), so that the same
+//  layouts are shared by G1 (a single coefficient) and by G2 over Fp2, Fp4 or Fp8 :
+//  - ZCash (https://www.ietf.org/archive/id/draft-irtf-cfrg-pairing-friendly-curves-11.html#name-zcash-serialization-format-) :
+//    big-endian coefficients from the highest one (x.c1 || x.c0 over Fp2), the compression/infinity/sign bits being the 3 most
+//    significant bits of the first byte, or of an extra leading byte when the modulus leaves less than 3 free bits.
+//  - EIP-2537 (https://eips.ethereum.org/EIPS/eip-2537) : uncompressed big-endian coefficients from c0, each one left-padded with
+//    zeros to 64 bytes (to the next multiple of 64 bytes for moduli above 512 bits), the infinity point being encoded by zeros only.
+//  - arkworks (CanonicalSerialize of ark-ec short Weierstrass affine points) : little-endian coefficients from c0, with the flags
+//    (bit 7 : y is the largest root, bit 6 : infinity) in the last byte of the last coefficient, which gets one more byte when
+//    the modulus leaves less than 2 free bits.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointFormat {
+    ZcashCompressed,
+    ZcashUncompressed,
+    Eip2537,
+    ArkworksCompressed,
+    ArkworksUncompressed,
+}
+
+impl PointFormat {
+    pub fn is_compressed(&self) -> bool
+    {
+        matches!(self, PointFormat::ZcashCompressed | PointFormat::ArkworksCompressed)
+    }
+
+    pub fn encoded_size(&self, numbits :usize, degree :usize) -> usize
+    {   // Size of an encoded point over a base field of "numbits" bits, for coordinates of "degree" coefficients
+        let size = numbits.div_ceil(8);
+        let count = if self.is_compressed() {degree} else {2 * degree};
+        match self {    PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed => count * size + zcash_extra_byte(numbits) as usize,
+                        PointFormat::Eip2537 => count * size.div_ceil(64) * 64,
+                        PointFormat::ArkworksCompressed | PointFormat::ArkworksUncompressed => count * size + (numbits + 2).div_ceil(8) - size,
+                   }
+    }
+}
+
+pub fn zcash_extra_byte(numbits :usize) -> bool
+{   // The 3 ZCash flag bits need an extra leading byte when the first byte of the modulus has less than 3 free bits
+    // (none at all when numbits is a multiple of 8)
+    numbits.is_multiple_of(8) || numbits % 8 > 5
+}
+
+//  Coordinates read from an encoding, before any curve or sub-group check
+pub enum EncodedPoint<const N:usize, P: FieldConfig<N>> {
+    Infinity,
+    Affine(Vec<FieldElement<N,P>>, Vec<FieldElement<N,P>>),
+    Compressed(Vec<FieldElement<N,P>>, bool),             // x, and whether y is the lexicographically largest root
+}
+
+pub fn encode_point<const N:usize, P: FieldConfig<N>>(x :&[FieldElement<N,P>], y :&[FieldElement<N,P>], largest :bool, infinity :bool, format :PointFormat) -> Vec<u8>
+{   // x and y are the affine coordinates (ignored for the infinity point), "largest" tells if y is lexicographically larger than -y
+    let numbits = x[0].fieldparams.num_of_bits;
+    let size = numbits.div_ceil(8);
+    let coordinates = if format.is_compressed() {vec![x]} else {vec![x, y]};
+    let big_endian = |e :&FieldElement<N,P>| if infinity {vec![0u8; size]} else {i2osp_pf(e, size)};
+    let mut out = Vec::with_capacity(format.encoded_size(numbits, x.len()));
+    match format {  PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed =>
+                        {   for c in &coordinates { for e in c.iter().rev() {out.extend(big_endian(e))} }
+                            let compressed = format.is_compressed();
+                            let m_byte = ((compressed as u8) << 7) | ((infinity as u8) << 6) | (((compressed & !infinity & largest) as u8) << 5);
+                            if zcash_extra_byte(numbits) {out.insert(0, m_byte)} else {out[0] |= m_byte}
+                        },
+                    PointFormat::Eip2537 =>
+                        {   let padding = size.div_ceil(64) * 64 - size;
+                            for c in &coordinates { for e in c.iter() { out.extend(vec![0u8; padding]);
+                                                                        out.extend(big_endian(e));
+                                                                      }
+                                                  }
+                        },
+                    PointFormat::ArkworksCompressed | PointFormat::ArkworksUncompressed =>
+                        {   for c in &coordinates { for e in c.iter() { let mut le = big_endian(e);
+                                                                        le.reverse();
+                                                                        out.extend(le);
+                                                                      }
+                                                  }
+                            if (numbits + 2).div_ceil(8) > size {out.push(0)}
+                            let flags = if infinity {0x40} else if largest {0x80} else {0};
+                            if let Some(last) = out.last_mut() {*last |= flags}
+                        },
+                 }
+    out
+}
+
+pub fn decode_point<const N:usize, P: FieldConfig<N>>(field :&PrimeField<N,P>, degree :usize, input :&[u8], format :PointFormat) -> Result<EncodedPoint<N,P>, PairingError>
+{   // Parses the layout of the format (size, flags, padding and canonical coefficients), the caller doing the curve checks
+    let numbits = field.parametres.num_of_bits;
+    let size = numbits.div_ceil(8);
+    let expected = format.encoded_size(numbits, degree);
+    if input.len() != expected {return Err(PairingError::BadLength { expected, found: input.len() })}
+    let mut body = input.to_vec();
+    let (infinity, largest) = match format {
+        PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed =>
+            {   let m_byte = body[0] & 0xE0;
+                let (c_bit, i_bit, s_bit) = (m_byte & 0x80 !=0, m_byte & 0x40 !=0, m_byte & 0x20 !=0);
+                if (c_bit != format.is_compressed()) || (s_bit && (i_bit || !c_bit)) {return Err(PairingError::BadFlagBits)}
+                if zcash_extra_byte(numbits) { if body[0] & 0x1F != 0 {return Err(PairingError::BadFlagBits)}
+                                               body.remove(0);
+                                             }
+                else {body[0] &= 0x1F}
+                (i_bit, s_bit)
+            },
+        PointFormat::Eip2537 =>
+            {   let padded = size.div_ceil(64) * 64;
+                if body.chunks(padded).any(|c| c[..padded - size].iter().any(|&b| b != 0)) {return Err(PairingError::NonCanonicalFieldElement)}
+                body = body.chunks(padded).flat_map(|c| c[padded - size..].to_vec()).collect();
+                (body.iter().all(|&b| b == 0), false)
+            },
+        PointFormat::ArkworksCompressed | PointFormat::ArkworksUncompressed =>
+            {   let flags = body[expected - 1] & 0xC0;
+                body[expected - 1] &= 0x3F;
+                if flags == 0xC0 {return Err(PairingError::BadFlagBits)}
+                if (numbits + 2).div_ceil(8) > size && body.pop() != Some(0) {return Err(PairingError::BadFlagBits)}
+                (flags & 0x40 != 0, flags & 0x80 != 0)
+            },
+                                            };
+    if infinity { if body.iter().any(|&b| b != 0) {return Err(PairingError::InvalidInfinity)}
+                  return Ok(EncodedPoint::Infinity)
+                }
+    let mut coefficients = Vec::with_capacity(body.len() / size);
+    for chunk in body.chunks(size) {    let mut bytes = chunk.to_vec();
+                                        if let PointFormat::ArkworksCompressed | PointFormat::ArkworksUncompressed = format {bytes.reverse()}
+                                        coefficients.push(field.try_from_canonical_biguint(&os2ip(&bytes))?);
+                                   }
+    let mut coordinates :Vec<Vec<FieldElement<N,P>>> = coefficients.chunks(degree).map(|c| c.to_vec()).collect();
+    if let PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed = format { for c in coordinates.iter_mut() {c.reverse()} }
+    let x = coordinates.remove(0);
+    // The sign flag of uncompressed arkworks points is ignored, as done by arkworks itself
+    if format.is_compressed() {Ok(EncodedPoint::Compressed(x, largest))}
+    else {Ok(EncodedPoint::Affine(x, coordinates.remove(0)))}
+}
+
+pub fn to_hex(input :&[u8]) -> String
+{
+    input.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn try_from_hex(input :&str) -> Result<Vec<u8>, PairingError>
+{   // Lower or upper case digits, with an optional "0x" prefix
+    let digits = input.strip_prefix("0x").unwrap_or(input).as_bytes();
+    if !digits.len().is_multiple_of(2) {return Err(PairingError::InvalidHex)}
+    let nibble = |b :u8| (b as char).to_digit(16).ok_or(PairingError::InvalidHex);
+    digits.chunks(2).map(|pair| Ok(((nibble(pair[0])? << 4) | nibble(pair[1])?) as u8)).collect()
+}
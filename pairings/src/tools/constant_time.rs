@@ -0,0 +1,180 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use std::hint::black_box;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+use crate::tools::arithmetic_interface::ArithmeticOperations;
+
+//  Constant-time toolkit, following the design of the "subtle" crate (https://docs.rs/subtle):
+//  secret booleans are carried as a Choice (0 or 1), turned into all-zeros/all-ones masks,
+//  so that comparisons, selections and negations never branch on secret data.
+
+#[derive(Clone, Copy, Debug)]
+pub struct Choice(u8);
+
+impl Choice {
+    pub fn unwrap_u8(&self) -> u8
+    {
+        self.0
+    }
+
+    pub fn mask(&self) -> u64
+    {   // 0x00..00 for false, 0xFF..FF for true
+        0u64.wrapping_sub(self.0 as u64)
+    }
+
+    pub fn from_nonzero(x :u64) -> Choice
+    {   // 1 if x != 0, 0 otherwise, without comparison
+        Choice::from(((x | x.wrapping_neg()) >> 63) as u8)
+    }
+}
+
+impl From<u8> for Choice {
+    fn from(input: u8) -> Choice {
+        // The optimization barrier prevents the compiler from turning masks back into branches
+        Choice(black_box(input & 1))
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(input: bool) -> Choice {
+        Choice::from(input as u8)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(input: Choice) -> bool {
+        input.0 == 1
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+    fn bitand(self, rhs: Choice) -> Choice { Choice::from(self.0 & rhs.0) }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+    fn bitor(self, rhs: Choice) -> Choice { Choice::from(self.0 | rhs.0) }
+}
+
+impl BitXor for Choice {
+    type Output = Choice;
+    fn bitxor(self, rhs: Choice) -> Choice { Choice::from(self.0 ^ rhs.0) }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+    fn not(self) -> Choice { Choice::from(self.0 ^ 1) }
+}
+
+pub trait ConstantTimeEq {
+    fn ct_eq(&self, other: &Self) -> Choice;
+    fn ct_ne(&self, other: &Self) -> Choice { !self.ct_eq(other) }
+}
+
+pub trait ConditionallySelectable: Copy {
+    //  Returns a if choice is 0, b if choice is 1
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) { *self = Self::conditional_select(self, other, choice) }
+
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice)
+    {   let t = *a;
+        a.conditional_assign(b, choice);
+        b.conditional_assign(&t, choice);
+    }
+}
+
+pub trait ConditionallyNegatable {
+    //  Negates self if choice is 1, leaves it unchanged otherwise
+    fn conditional_negate(&mut self, choice: Choice);
+}
+
+impl <T :ConditionallySelectable + ArithmeticOperations> ConditionallyNegatable for T {
+    fn conditional_negate(&mut self, choice: Choice) {
+        let negated = self.negate();
+        self.conditional_assign(&negated, choice)
+    }
+}
+
+impl ConstantTimeEq for u64 {
+    fn ct_eq(&self, other: &u64) -> Choice { !Choice::from_nonzero(self ^ other) }
+}
+
+impl ConditionallySelectable for u64 {
+    fn conditional_select(a: &u64, b: &u64, choice: Choice) -> u64 { a ^ ((a ^ b) & choice.mask()) }
+}
+
+impl ConstantTimeEq for usize {
+    fn ct_eq(&self, other: &usize) -> Choice { (*self as u64).ct_eq(&(*other as u64)) }
+}
+
+pub fn ct_lookup<T :ConditionallySelectable>(table :&[T], index :usize) -> T
+{
+    //  Reads table[index] by scanning the whole table, so that the memory access pattern does not depend on the (secret) index
+    let mut result = table[0];
+    for (i, entry) in table.iter().enumerate().skip(1) { result.conditional_assign(entry, i.ct_eq(&index)) }
+    result
+}
+
+//  Optional value whose presence flag is a Choice: the value is always computed, and callers can select on the flag
+//  without branching. Converting to Option (or unwrapping) reveals the flag, and is meant for public results.
+#[derive(Clone, Copy, Debug)]
+pub struct CtOption<T> {
+    value: T,
+    is_some: Choice,
+}
+
+impl <T> CtOption<T> {
+    pub fn new(value: T, is_some: Choice) -> CtOption<T>
+    {
+        CtOption { value, is_some }
+    }
+
+    pub fn is_some(&self) -> Choice
+    {
+        self.is_some
+    }
+
+    pub fn is_none(&self) -> Choice
+    {
+        !self.is_some
+    }
+
+    pub fn unwrap(self) -> T
+    {
+        assert_eq!(self.is_some.unwrap_u8(), 1, "Called unwrap on a none CtOption");
+        self.value
+    }
+
+    pub fn expect(self, msg: &str) -> T
+    {
+        assert_eq!(self.is_some.unwrap_u8(), 1, "{}", msg);
+        self.value
+    }
+
+    pub fn map<U, F :FnOnce(T) -> U>(self, f: F) -> CtOption<U>
+    {   // f is evaluated even when the value is absent
+        CtOption::new(f(self.value), self.is_some)
+    }
+
+    pub fn into_option(self) -> Option<T>
+    {
+        if self.is_some.into() {Some(self.value)} else {None}
+    }
+}
+
+impl <T :ConditionallySelectable> CtOption<T> {
+    pub fn unwrap_or(self, default: T) -> T
+    {
+        T::conditional_select(&default, &self.value, self.is_some)
+    }
+}
+
+impl <T> From<CtOption<T>> for Option<T> {
+    fn from(input: CtOption<T>) -> Option<T> {
+        input.into_option()
+    }
+}
@@ -0,0 +1,198 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Signed, Zero};
+use crate::tools::constant_time::{Choice, ConditionallySelectable};
+
+//  Scalar decomposition for GLV/GLS multiplications over fixed-size limbs (no BigUint at runtime).
+//  A scalar k is written as k = v0 + v1*λ + ... + v(m-1)*λ^(m-1) (mod r) with short v_i, by rounding the vector (k,0,..,0)
+//  to a close vector of the lattice L = {(v_i) : Σ v_i*λ^i = 0 mod r} spanned by a short basis B (Babai rounding).
+//  The rational coefficients of B^-1 are precomputed as fixed-point integers g_j ~ 2^(64R)*x_j, so that the decomposition only needs
+//  fixed-length multiplications and additions on R limbs : https://eprint.iacr.org/2013/158.pdf (Section 4.1),
+//  "Efficient and Secure Algorithms for GLV-Based Scalar Multiplication..." by Faz-Hernández, Longa and Sánchez.
+
+//  The reduced bases and the Babai constants of the supported curves are const tables (parameters/preloadedconfs), so that no
+//  big integer arithmetic is done at runtime : the bases are derived from u, λ and r by const fn, and the Babai constants are
+//  generated by babai_constants (from the same bases), which is also used to check the tables.
+
+#[derive(Clone, Copy, Debug)]
+pub struct LatticeDecomposer<const R:usize> {
+    pub dimension :usize,
+    pub bound_bits :usize,
+    basis :&'static [(bool,[u64;R])],
+    babai :&'static [(bool,[u64;R])],
+}
+
+impl <const R:usize> LatticeDecomposer<R> {
+    pub const fn new(basis :&'static [(bool,[u64;R])], babai :&'static [(bool,[u64;R])]) -> LatticeDecomposer<R>
+    {   // basis holds the rows b_j of B (row-major, as (sign, |b_ji|)), and babai the fixed-point constants g_j of the first row of B^-1.
+        // Babai rounding gives sub-scalars bounded by the sum of the absolute values of the basis coefficients (per column)
+        let dimension = babai.len();
+        assert!(basis.len() == dimension * dimension, "Decomposition basis and Babai constants sizes do not match");
+        let mut bound = [0u64;R];
+        let mut i = 0;
+        while i < dimension {   let mut sum = [0u64;R];
+                                let mut j = 0;
+                                while j < dimension { sum = add_limbs(&sum, &basis[j * dimension + i].1); j += 1; }
+                                if is_lower(&bound, &sum) {bound = sum}
+                                i += 1;
+                            }
+        LatticeDecomposer { dimension, bound_bits : limbs_bits(&bound) + 1, basis, babai }
+    }
+
+    pub fn babai_constants(basis :&[Vec<BigInt>]) -> Vec<(bool,[u64;R])>
+    {   // basis[j] is the j-th row b_j of B. The first row of B^-1 is x_j = adj(B)[0][j] / det(B) = (-1)^j * M(j,0) / det(B),
+        // where M(j,0) is the minor obtained by removing the row j and the column 0 of B, and g_j = round(2^(64R) * |x_j|)
+        let dimension = basis.len();
+        let det = determinant(basis.to_vec());
+        assert!(!det.is_zero(), "Singular decomposition basis");
+        let mut babai = Vec::with_capacity(dimension);
+        for j in 0..dimension { let minor :Vec<Vec<BigInt>> = basis.iter().enumerate().filter(|(i, _)| *i != j)
+                                                                    .map(|(_, row)| row[1..].to_vec()).collect();
+                                let adj = if j & 1 == 0 {determinant(minor)} else {-determinant(minor)};
+                                let num = adj.abs() << (64 * R);
+                                let g = ((num << 1) + det.abs()) / (det.abs() << 1);
+                                babai.push(((adj.sign() == Sign::Minus) ^ (det.sign() == Sign::Minus), to_limbs::<R>(&g)));
+                              }
+        babai
+    }
+
+    pub fn is_consistent(&self) -> bool
+    {   // The Babai constants of the table have to be the ones of its basis
+        let basis :Vec<Vec<BigInt>> = self.basis.chunks(self.dimension)
+                                                .map(|row| row.iter().map(|(negative, b)| { let v = BigInt::from_slice(Sign::Plus, &to_u32_digits(b));
+                                                                                            if *negative {-v} else {v} }).collect())
+                                                .collect();
+        Self::babai_constants(&basis) == self.babai
+    }
+
+    pub fn decompose(&self, scalar :&[u64;R]) -> Vec<([u64;R], Choice)>
+    {   // Returns the sub-scalars as (|v_i|, v_i < 0). All the operations have a fixed length : c_j = round(k*g_j / 2^(64R)) is the high half
+        // of k*|g_j| + 2^(64R-1), then v = (k,0,..,0) - Σ c_j*b_j is computed modulo 2^(64R) (two's complement), since |v_i| < 2^(64R-1)
+        let c :Vec<[u64;R]> = self.babai.iter().map(|(_, g)| rounded_high(scalar, g)).collect();
+        let mut out = Vec::with_capacity(self.dimension);
+        for i in 0..self.dimension { let mut v = if i == 0 {*scalar} else {[0u64;R]};
+                                     for ((row, cj), (gneg, _)) in self.basis.chunks(self.dimension).zip(&c).zip(self.babai) {  let (bneg, b) = &row[i];
+                                                                                                                  let t = mul_low(cj, b);
+                                                                                                                  // The signs of the basis and of the Babai coefficients are public
+                                                                                                                  v = if gneg ^ bneg {add_limbs(&v, &t)} else {sub_limbs(&v, &t)};
+                                                                                                                }
+                                     let negative = Choice::from((v[R - 1] >> 63) as u8);
+                                     let opposite = sub_limbs(&[0u64;R], &v);
+                                     let mut abs = [0u64;R];
+                                     for l in 0..R {abs[l] = u64::conditional_select(&v[l], &opposite[l], negative)}
+                                     out.push((abs, negative));
+                                   }
+        out
+    }
+}
+
+fn determinant(mut m :Vec<Vec<BigInt>>) -> BigInt
+{   // Fraction-free Gaussian elimination (Bareiss algorithm), used only when building the constants
+    let n = m.len();
+    let mut sign = BigInt::one();
+    let mut previous = BigInt::one();
+    for k in 0..n { if m[k][k].is_zero() {  match (k + 1..n).find(|&i| !m[i][k].is_zero()) {   Some(i) => {m.swap(k, i);
+                                                                                                           sign = -sign;},
+                                                                                              None => return BigInt::zero()}
+                                         }
+                    for i in k + 1..n { for j in k + 1..n { let t = (&m[i][j] * &m[k][k] - &m[i][k] * &m[k][j]) / &previous;
+                                                            m[i][j] = t;
+                                                          }
+                                      }
+                    previous = m[k][k].clone();
+                  }
+    sign * &m[n - 1][n - 1]
+}
+
+fn to_limbs<const R:usize>(input :&BigInt) -> [u64;R]
+{
+    let digits = input.magnitude().to_u64_digits();
+    assert!(digits.len() <= R, "Decomposition constant too large for the scalar field");
+    let mut out = [0u64;R];
+    out[..digits.len()].copy_from_slice(&digits);
+    out
+}
+
+fn rounded_high<const R:usize>(a :&[u64;R], b :&[u64;R]) -> [u64;R]
+{   // High R limbs of a*b + 2^(64R-1)
+    let mut wide = vec![0u64; 2 * R];
+    for i in 0..R { let mut carry :u128 = 0;
+                    for j in 0..R { let t = (a[i] as u128) * (b[j] as u128) + (wide[i + j] as u128) + carry;
+                                    wide[i + j] = t as u64;
+                                    carry = t >> 64;
+                                  }
+                    wide[i + R] = carry as u64;
+                  }
+    let mut carry :u128 = 1 << 63;
+    for limb in wide.iter_mut().skip(R - 1) { let t = (*limb as u128) + carry;
+                                              *limb = t as u64;
+                                              carry = t >> 64;
+                                            }
+    let mut out = [0u64;R];
+    out.copy_from_slice(&wide[R..]);
+    out
+}
+
+fn mul_low<const R:usize>(a :&[u64;R], b :&[u64;R]) -> [u64;R]
+{   // a*b mod 2^(64R)
+    let mut out = [0u64;R];
+    for i in 0..R { let mut carry :u128 = 0;
+                    for j in 0..R - i { let t = (a[i] as u128) * (b[j] as u128) + (out[i + j] as u128) + carry;
+                                        out[i + j] = t as u64;
+                                        carry = t >> 64;
+                                      }
+                  }
+    out
+}
+
+const fn add_limbs<const R:usize>(a :&[u64;R], b :&[u64;R]) -> [u64;R]
+{
+    let mut out = [0u64;R];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < R {   let (s1, c1) = a[i].overflowing_add(b[i]);
+                    let (s2, c2) = s1.overflowing_add(carry);
+                    out[i] = s2;
+                    carry = (c1 | c2) as u64;
+                    i += 1;
+                }
+    out
+}
+
+const fn is_lower<const R:usize>(a :&[u64;R], b :&[u64;R]) -> bool
+{
+    let mut i = R;
+    while i > 0 { i -= 1;
+                  if a[i] != b[i] {return a[i] < b[i]}
+                }
+    false
+}
+
+const fn limbs_bits<const R:usize>(a :&[u64;R]) -> usize
+{
+    let mut i = R;
+    while i > 0 { i -= 1;
+                  if a[i] != 0 {return 64 * i + 64 - a[i].leading_zeros() as usize}
+                }
+    0
+}
+
+fn to_u32_digits<const R:usize>(a :&[u64;R]) -> Vec<u32>
+{
+    a.iter().flat_map(|l| [*l as u32, (*l >> 32) as u32]).collect()
+}
+
+fn sub_limbs<const R:usize>(a :&[u64;R], b :&[u64;R]) -> [u64;R]
+{
+    let mut out = [0u64;R];
+    let mut borrow = 0u64;
+    for i in 0..R { let (s1, b1) = a[i].overflowing_sub(b[i]);
+                    let (s2, b2) = s1.overflowing_sub(borrow);
+                    out[i] = s2;
+                    borrow = (b1 | b2) as u64;
+                  }
+    out
+}
@@ -3,9 +3,9 @@
 // During May 2024.
 
 use std::ops::BitAnd;
-use crate::fields::prime_fields::FieldElement;
+use crate::fields::prime_fields::{FieldConfig, FieldElement};
 use crate::fields::arithmetic;
-use num_bigint::{BigUint, ToBigUint};
+use num_bigint::{BigInt, BigUint, ToBigUint};
 use num_traits::{FromPrimitive, Zero};
 
 fn get_naf(x: BigUint) -> Vec<i8> {
@@ -26,13 +26,43 @@ fn get_naf(x: BigUint) -> Vec<i8> {
     result.reverse();
     result
 }
+
+pub const COMPRESSED_SQUARING_RUN : usize = 4;        // Mean length of the zero runs of a NAF from which the powers in Fp24 and Fp48 use compressed squarings
+pub const COMPRESSED_SQUARING_RUN_FP12 : usize = 8;   // Same for Fp12, where the shared inversion (in Fp2) weighs more against the squarings
+
+pub fn has_long_zero_runs(naf :&[i8], min_run :usize) -> bool {
+    // The compressed squarings save about a third of every squaring, but each non-zero digit costs a decompression (and the
+    // decompressions share one inversion) : they are worth it when the non-zero digits are rare (|u| of the BLS curves)
+    naf.len() >= min_run * naf.iter().filter(|d| **d != 0).count().max(1)
+}
+
+//  Decomposition used for the hard part of the final exponentiation, selected per engine. Hayashida and GhammamFouotsa both compute
+//  the cube of the pairing (3 is coprime to r, and a power of a pairing is a pairing), Naive computes the pairing itself
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HardPartStrategy {
+    #[default]
+    Hayashida,
+    GhammamFouotsa,
+    Naive,
+}
+
+pub fn bls_hard_exponent(u :i128, k :u32) -> BigUint {
+    // Exponent Φk(p)/r of the hard part for the BLS-k curves (k = 12, 24, 48), where Φk(x) = x^(k/3) - x^(k/6) + 1,
+    // r = Φk(u) and p = (u-1)^2*r/3 + u
+    let u = BigInt::from(u);
+    let v = u.pow(k / 6);
+    let r = &v * &v - &v + 1u8;
+    let p = (&u - 1u8).pow(2) * &r / 3u8 + &u;
+    let q = p.pow(k / 6);
+    ((&q * &q - &q + 1u8) / r).to_biguint().unwrap()
+}
 pub trait Exponent<const N:usize>{
     fn to_u64_array(&self) -> Option<[u64; N]>;
     fn to_naf(&self) -> Vec<i8>; 
     fn get_len(&self)->usize;
 }
 
-impl <const N:usize,const R:usize > Exponent<N> for FieldElement<R> {
+impl <const N:usize,const R:usize , S: FieldConfig<R>> Exponent<N> for FieldElement<R,S> {
     
     fn to_u64_array(&self) -> Option<[u64; N]> {
         let mut one :[u64;R] = [0;R];
@@ -5,8 +5,9 @@
 use num_bigint::{BigUint, ToBigInt};
 use std::ops::BitOr;
 use num_traits::Zero;
-use sha2::{Digest, Sha256};
-use crate::fields::{arithmetic, prime_fields::{FieldElement, PrimeField}};
+use sha2::{digest::{core_api::BlockSizeUser, ExtendableOutput, Update, XofReader}, Digest, Sha256, Sha384, Sha512};
+use sha3::{Shake128, Shake256};
+use crate::{errors::PairingError, fields::{arithmetic, prime_fields::{FieldConfig, FieldElement, PrimeField}}};
 
 
 //  I2OSP converts a nonnegative integer to an octet string of a specified length. RFC 3447, section 4.1 https://datatracker.ietf.org/doc/html/rfc3447#section-4.1
@@ -18,10 +19,10 @@ pub fn i2osp(x: usize, x_len: usize) -> Vec<u8> {
     octets
 }
 
-pub fn i2osp_pf<const N:usize>(x: &FieldElement<N>, x_len: usize) -> Vec<u8> {
+pub fn i2osp_pf<const N:usize, P: FieldConfig<N>>(x: &FieldElement<N,P>, x_len: usize) -> Vec<u8> {
     let mut one =[0u64;N];
     one[0] = 1;
-    let from_mont = arithmetic::mul(&x.mont_limbs, &one,x.fieldparams);    
+    let from_mont = arithmetic::mul(&x.mont_limbs, &one,&x.fieldparams);    
     let mut octets = Vec::<u8>::new();
     for num in from_mont {octets.extend(num.to_le_bytes())}
     octets.truncate(x_len);
@@ -43,44 +44,136 @@ pub fn os2ip(octets: &[u8]) -> BigUint {
 
 
 
+//  Expanders supported by hash_to_field: expand_message_xmd (Merkle-Damgard hash functions) and expand_message_xof (extendable-output functions).
+//  A hash function provides k bits of collision resistance only when its output is at least 2k bits, so the expander must follow the security level.
+//  https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expander {
+    XmdSha256,      // expand_message_xmd with SHA-256 (up to 128-bit security)
+    XmdSha384,      // expand_message_xmd with SHA-384 (up to 192-bit security)
+    XmdSha512,      // expand_message_xmd with SHA-512 (up to 256-bit security)
+    XofShake128,    // expand_message_xof with SHAKE128 (up to 128-bit security)
+    XofShake256,    // expand_message_xof with SHAKE256 (up to 256-bit security)
+}
+
+impl Expander {
+    pub const fn for_security_level(security_level :usize) -> Expander
+    {
+        // Smallest SHA-2 function with a 2k-bits output (https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd)
+        if security_level <= 128 {Expander::XmdSha256}
+        else {  if security_level <= 192 {Expander::XmdSha384}
+                else {Expander::XmdSha512}
+             }
+    }
+
+    pub fn security_level(&self) -> usize
+    {
+        match self {    Expander::XmdSha256 | Expander::XofShake128 => 128,
+                        Expander::XmdSha384 => 192,
+                        Expander::XmdSha512 | Expander::XofShake256 => 256,
+                   }
+    }
+
+    pub fn hash_id(&self) -> &'static str
+    {
+        // HASH_ID part of the suite identifiers (https://www.rfc-editor.org/rfc/rfc9380.html#name-suite-id-naming-conventions)
+        match self {    Expander::XmdSha256   => "XMD:SHA-256",
+                        Expander::XmdSha384   => "XMD:SHA-384",
+                        Expander::XmdSha512   => "XMD:SHA-512",
+                        Expander::XofShake128 => "XOF:SHAKE128",
+                        Expander::XofShake256 => "XOF:SHAKE256",
+                   }
+    }
+
+    pub fn expand(&self, message: &[u8], dst: &[u8], len: usize) -> Vec<u8>
+    {
+        match self.try_expand(message, dst, len) {  Ok(v) => v,
+                                                    Err(e) => panic!("{}",e)}
+    }
+
+    pub fn try_expand(&self, message: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>, PairingError>
+    {
+        match self {    Expander::XmdSha256   => try_expand_message_xmd_with::<Sha256>(message, dst, len),
+                        Expander::XmdSha384   => try_expand_message_xmd_with::<Sha384>(message, dst, len),
+                        Expander::XmdSha512   => try_expand_message_xmd_with::<Sha512>(message, dst, len),
+                        Expander::XofShake128 => try_expand_message_xof::<Shake128>(message, dst, len, 128),
+                        Expander::XofShake256 => try_expand_message_xof::<Shake256>(message, dst, len, 256),
+                   }
+    }
+}
+
 //  The expand_message_xmd function produces a pseudorandom byte string using a cryptographic hash function H that outputs b bits. 
-//  https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-06#name-expand_message_xmd 
+//  https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd 
 pub fn expand_message_xmd(message: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
-    let hash_length = 32; // Length of the hash function output in bytes (for example, SHA-256)
-
-    // Step 1: Initialize H_0
-    let mut h = Sha256::new();
-    h.update(dst);
-    h.update(&i2osp(0, 1)); // Append a single 0 byte to the destination string
-    h.update(&i2osp(hash_length, 1)); // Append the length of the output in bytes
-    h.update(&i2osp(len, 1)); // Append the desired output length in bytes
-    h.update(&i2osp(0, 1)); // Append a single 0 byte to indicate the start of the message
-    h.update(message);
-    let h_0 = h.finalize();
-
-    // Step 2: Expand H_0
-    let mut output = vec![];
-    for i in 0..(len / hash_length) + 1 {
-        let mut h_i = Sha256::new();
-        h_i.update(&h_0);
-        h_i.update(&i2osp(i, 1));
-        h_i.update(dst);
-        h_i.update(&i2osp(1, 1)); // Append a single 1 byte to indicate a non-empty string
-        output.extend_from_slice(&h_i.finalize());
-    }
+    match try_expand_message_xmd(message, dst, len) { Ok(v) => v,
+                                                       Err(e) => panic!("{}",e)}
+}
+
+pub fn try_expand_message_xmd(message: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>, PairingError> {
+    try_expand_message_xmd_with::<Sha256>(message, dst, len)
+}
+
+pub fn try_expand_message_xmd_with<H :Digest + BlockSizeUser>(message: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>, PairingError> {
+    let b_in_bytes = <H as Digest>::output_size();      // Output size of H
+    let s_in_bytes = <H as BlockSizeUser>::block_size();  // Input block size of H
+    let ell = len.div_ceil(b_in_bytes);
+    if ell > 255 || len > 65535 {return Err(PairingError::InvalidOutputLength)}
+    // Domain separation tags longer than 255 bytes are first hashed (https://www.rfc-editor.org/rfc/rfc9380.html#name-using-dsts-longer-than-255-)
+    let dst = if dst.len() > 255 { H::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize().to_vec() } 
+              else { dst.to_vec() };
+    let mut dst_prime = dst.clone();
+    dst_prime.extend(i2osp(dst.len(), 1));
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = H::new().chain_update(vec![0u8; s_in_bytes])
+                      .chain_update(message)
+                      .chain_update(i2osp(len, 2))
+                      .chain_update(i2osp(0, 1))
+                      .chain_update(&dst_prime).finalize();
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime), then b_i = H(strxor(b_0, b_(i-1)) || I2OSP(i, 1) || DST_prime)
+    let mut b_i = H::new().chain_update(&b_0).chain_update(i2osp(1, 1)).chain_update(&dst_prime).finalize();
+    let mut output = b_i.to_vec();
+    for i in 2..=ell {  let xored : Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+                        b_i = H::new().chain_update(xored).chain_update(i2osp(i, 1)).chain_update(&dst_prime).finalize();
+                        output.extend_from_slice(&b_i);
+                     }
     output.truncate(len);
-    output
+    Ok(output)
+}
+
+//  The expand_message_xof function produces a pseudorandom byte string using an extendable-output function H, 
+//  targeting a security level of k bits. https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xof
+pub fn expand_message_xof<H :Default + Update + ExtendableOutput>(message: &[u8], dst: &[u8], len: usize, security_level: usize) -> Vec<u8> {
+    match try_expand_message_xof::<H>(message, dst, len, security_level) {  Ok(v) => v,
+                                                                             Err(e) => panic!("{}",e)}
+}
+
+pub fn try_expand_message_xof<H :Default + Update + ExtendableOutput>(message: &[u8], dst: &[u8], len: usize, security_level: usize) -> Result<Vec<u8>, PairingError> {
+    if len > 65535 {return Err(PairingError::InvalidOutputLength)}
+    let xof = |parts :&[&[u8]], outlen :usize| {    let mut h = H::default();
+                                                    for part in parts {Update::update(&mut h, part)}
+                                                    let mut out = vec![0u8; outlen];
+                                                    h.finalize_xof().read(&mut out);
+                                                    out
+                                                };
+    // Oversized tags are reduced to ceil(2k/8) bytes (https://www.rfc-editor.org/rfc/rfc9380.html#name-using-dsts-longer-than-255-)
+    let dst = if dst.len() > 255 { xof(&[b"H2C-OVERSIZE-DST-", dst], (2 * security_level).div_ceil(8)) } 
+              else { dst.to_vec() };
+    let mut dst_prime = dst.clone();
+    dst_prime.extend(i2osp(dst.len(), 1));
+    // uniform_bytes = H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+    Ok(xof(&[message, &i2osp(len, 2), &dst_prime], len))
 }
 
 //   The hash_to_field function hashes a bytes/string msg of any length into one or more elements of a field F.
-//   https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-06#name-hashing-to-a-finite-field 
+//   Each base field coefficient is taken from L = ceil((ceil(log2(p)) + k) / 8) bytes, where k is the security level
+//   https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
 
-pub fn hash_bytes_to_field<const N:usize>(msg :&[u8],count :usize,field:&PrimeField<N>,dst:&[u8],sec_level:usize, ext_degree :usize) -> Vec<FieldElement<N>>
+pub fn hash_bytes_to_field<const N:usize, P: FieldConfig<N>>(msg :&[u8],count :usize,field:&PrimeField<N,P>,dst:&[u8],sec_level:usize, ext_degree :usize, expander :Expander) -> Vec<FieldElement<N,P>>
 {
-    let l = ((field.parametres.numlimbs * 64) + sec_level as usize) >> 3;    
+    let l = (field.parametres.num_of_bits + sec_level).div_ceil(8);    
     let byteslength = count * ext_degree * l;    
-    let prngbytes = expand_message_xmd(msg, dst, byteslength);
-    let mut result = Vec::<FieldElement<N>>::new();
+    let prngbytes = expander.expand(msg, dst, byteslength);
+    let mut result = Vec::<FieldElement<N,P>>::new();
     for i in 0..count*ext_degree { let elm_offset = l * i;
                                           let tv  = os2ip(&prngbytes[elm_offset..elm_offset+l]);
                                           result.push(field.from_bigint(&tv.to_bigint().unwrap()))
@@ -88,9 +181,8 @@ pub fn hash_bytes_to_field<const N:usize>(msg :&[u8],count :usize,field:&PrimeFi
     result
 }
 
-pub fn hash_string_to_field<const N:usize>(msg:&str,field:&PrimeField<N>,count :usize,sec_level:usize,ext_degree :usize)->Vec<FieldElement<N>>
+pub fn hash_string_to_field<const N:usize, P: FieldConfig<N>>(msg:&str,field:&PrimeField<N,P>,dst:&[u8],count :usize,sec_level:usize,ext_degree :usize,expander :Expander)->Vec<FieldElement<N,P>>
 {
     let bytes = msg.as_bytes();    
-    hash_bytes_to_field(bytes, count, field, &[0], sec_level, ext_degree)
+    hash_bytes_to_field(bytes, count, field, dst, sec_level, ext_degree, expander)
 }
-
@@ -5,4 +5,7 @@
 pub mod exponent;
 pub mod arithmetic_interface;
 pub mod recoders;
-pub mod hashs;
\ No newline at end of file
+pub mod hashs;
+pub mod constant_time;
+pub mod decomposition;
+pub mod codecs;
\ No newline at end of file
@@ -0,0 +1,135 @@
+// Code developed by FARAOUN Kamel Mohamed.
+// EEDIS-Laboratory. UDL-University. Algeria
+// During May 2024.
+
+use pairings::{tests::{check_accumulators, check_affine_miller_loops, check_additions, check_affines, check_batches, check_codec_vectors, check_codecs, check_compressed_squarings, check_constant_times, check_constants, check_cyclotomic_gts, check_decoding_modes, check_decodings, check_field_ops, check_fixed_bases, check_gls_powers, check_hard_parts, check_hardened_multiplications, check_hash_to_curve_vectors, check_hashings, check_msms, check_pairings, check_parallel_pairings, check_prepared, check_scalar_multiplications, check_tower_representations}, CurvesNames};
+
+#[test]
+fn pairings_are_bilinear() {
+    for curve in CurvesNames::all() { assert!(check_pairings(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn decoders_report_malformed_inputs() {
+    for curve in CurvesNames::all() { assert!(check_decodings(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn decoding_modes_apply_their_checks() {
+    for curve in CurvesNames::all() { assert!(check_decoding_modes(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn codecs_round_trip() {
+    for curve in CurvesNames::all() { assert!(check_codecs(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn codecs_match_reference_encodings() {
+    assert!(check_codec_vectors());
+}
+
+#[test]
+fn additions_are_complete() {
+    for curve in CurvesNames::all() { assert!(check_additions(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn msm_matches_naive_sum() {
+    for curve in CurvesNames::all() { assert!(check_msms(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn prepared_pairings_match_regular_ones() {
+    for curve in CurvesNames::all() { assert!(check_prepared(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn hash_to_curve_matches_rfc9380_vectors() {
+    assert!(check_hash_to_curve_vectors());
+}
+
+#[test]
+fn hashing_lands_in_subgroups() {
+    for curve in CurvesNames::all() { assert!(check_hashings(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn constant_time_field_ops_are_consistent() {
+    for curve in CurvesNames::all() { assert!(check_field_ops(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn constant_time_primitives_are_consistent() {
+    for curve in CurvesNames::all() { assert!(check_constant_times(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn scalar_multiplications_agree() {
+    for curve in CurvesNames::all() { assert!(check_scalar_multiplications(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn hardened_multiplications_match_regular_ones() {
+    for curve in CurvesNames::all() { assert!(check_hardened_multiplications(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn tower_types_match_dynamic_representation() {
+    for curve in CurvesNames::all() { assert!(check_tower_representations(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn static_constants_match_runtime_derivation() {
+    for curve in CurvesNames::all() { assert!(check_constants(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn affine_points_match_projective_ones() {
+    for curve in CurvesNames::all() { assert!(check_affines(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn batch_inversion_and_normalization() {
+    for curve in CurvesNames::all() { assert!(check_batches(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn fixed_base_tables_match_regular_multiplications() {
+    for curve in CurvesNames::all() { assert!(check_fixed_bases(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn parallel_multi_pairing_matches_serial_one() {
+    for curve in CurvesNames::all() { assert!(check_parallel_pairings(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn pairing_products_skip_identities() {
+    for curve in CurvesNames::all() { assert!(check_accumulators(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn affine_miller_loop_matches_jacobian_one() {
+    for curve in CurvesNames::all() { assert!(check_affine_miller_loops(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn compressed_squarings_match_cyclotomic_powers() {
+    for curve in CurvesNames::all() { assert!(check_compressed_squarings(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn hard_part_strategies_agree() {
+    for curve in CurvesNames::all() { assert!(check_hard_parts(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn pairing_values_use_cyclotomic_arithmetic() {
+    for curve in CurvesNames::all() { assert!(check_cyclotomic_gts(&curve), "{:?}", curve); }
+}
+
+#[test]
+fn gls_powers_match_cyclotomic_powers() {
+    for curve in CurvesNames::all() { assert!(check_gls_powers(&curve), "{:?}", curve); }
+}
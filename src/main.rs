@@ -1,57 +1,59 @@
 use std::io::{self};
 use base64::{engine::general_purpose, Engine};
-use pairings::{tests::{bench_pairings, check_pairings}, BLS24Curves, BLS48Curves, Bls12Curves, Pairings, PairingsEngine, BLS12, BLS24, BLS48};
+use pairings::{curves::gt::GTExtElement, extensions::towers::PairingTower, fields::prime_fields::FieldConfig, tests::{bench_pairings, check_pairings}, BLS24Curves, BLS48Curves, Bls12Curves, Pairings, PairingsEngine, BLS12, BLS24, BLS48};
 
 
-fn bls_signature_scheme<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>
-      (engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>){
+fn bls_signature_scheme<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>
+      (engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>){
   // BLS Signature scheme : public keys are in G2 while siugnatures are in G1
   // An reverted scheme can also be implemented (public keys in G1 and signatures in G2) 
   // according to the targted application resuirements. 
-  // ps: Hashing to elliptic curves support two modes :  
-  //      mode = 0 stands for Non-uniform-Encoding, while mode = 1 stands for Random Oracle Model encoding 
+  // ps: Hashing to elliptic curves supports both encode_to_curve (Non-uniform-Encoding) and hash_to_curve (Random Oracle Model encoding)
+  //     with a Domain Separation Tag built from the curve's hash-to-curve suite (RFC 9380)
   println!("----------------------------------------------------------------------------------------------");
   println!("Testing BLS signature scheme on {}",engine.identifier);
   // Key-paire genration :
   let sk = engine.fr.random_element();
-  let pk = sk * engine.g2.default_generator();
+  let pk = engine.g2.generator_table().mul(&sk);
   println!(" Secrete key (base64) = {}", sk.to_base64());
   println!(" Public Key  (base64) = {}", pk.encode_to_base64());       
   // BLS Signature : 
   let message = "This is a simple message to be signed. A message can be any arbitrary length string ....";
-  let hashed_message = engine.g1.hash_to_field(&message, 0);
+  let dst = format!("BLS_SIG_{}NUL_", engine.curvename.hash_suite().g1_ro);
+  let hashed_message = engine.g1.hash_to_curve(message.as_bytes(), dst.as_bytes());
   let signature = sk * hashed_message;
   println!(" Signatue is (base64): {}", signature.encode_to_base64());
   // BLS Verification :
-  let hashed_message = engine.g1.hash_to_field(&message, 0);
+  let hashed_message = engine.g1.hash_to_curve(message.as_bytes(), dst.as_bytes());
   let verification_result = engine.paire(&signature, &engine.g2.default_generator()) == engine.paire(&hashed_message, &pk);
   println!("Verification result : {}",if verification_result {"correct"} else {"incorrect"});
   // Faster way to do it using multi-pairings
-  let verification_result = engine.multi_paire(&[signature,hashed_message], &[-engine.g2.default_generator(),pk]) == engine.gt.one();
+  let verification_result = engine.pairing_check(&[(signature, -engine.g2.default_generator()), (hashed_message, pk)]);
   println!("Verification result : {}",if verification_result {"correct"} else {"incorrect"});       
   println!("----------------------------------------------------------------------------------------------\n");
 }
 
 
 
-fn  boneh_franklin_ibe<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>(
-                engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>){
+fn  boneh_franklin_ibe<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>(
+                engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>){
   println!("----------------------------------------------------------------------------------------------");
   println!("Testing Boneh-Franklin Identity Based Encryption scheme on {}",engine.identifier);
   // Generation of Master Keys (Setup):
   let msk =  engine.fr.random_element();
-  let mpk = msk * engine.g2.default_generator();
+  let mpk = engine.g2.generator_table().mul(&msk);
   println!("The Master secrete key : {} ",msk.to_base64());
   println!("The Master public key : {} \n",mpk.encode_to_base64());
 
   // Key extraction : generation of the user's secrete key for corresponding Identity :
   let user_identity ="ID-1";    
-  let id_sk = msk * engine.g1.hash_to_field(&user_identity, 0);
+  let dst = format!("BF-IBE-V01-with-{}", engine.curvename.hash_suite().g1_ro);
+  let id_sk = msk * engine.g1.hash_to_curve(user_identity.as_bytes(), dst.as_bytes());
   println!("User's secrete key for identity '{}' : {} \n",user_identity,id_sk.encode_to_base64()); 
   
   // Key confirmation : user can confirm the authenticity and corectness of the secrete key like follows: 
   let valide_secrete_key = engine.paire(&id_sk, &engine.g2.default_generator()) 
-                                 == engine.paire(&engine.g1.hash_to_field(&user_identity, 0), &mpk);
+                                 == engine.paire(&engine.g1.hash_to_curve(user_identity.as_bytes(), dst.as_bytes()), &mpk);
   println!("User's secrete key confirmation : {} ",if valide_secrete_key {"Valid key\n"} else {"Invalid key\n"}); 
 
   //  Encryption of a message to the user using its Identity :
@@ -59,8 +61,8 @@ fn  boneh_franklin_ibe<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,
   println!("Plaintext message : {}\n",message);
   let message_as_bytes: Vec<u8> = message.as_bytes().to_vec();
   let a = engine.fr.random_element();
-  let u = a * engine.g2.default_generator();
-  let key_stream = engine.paire(&engine.g1.hash_to_field(&user_identity, 0),&mpk)
+  let u = engine.g2.generator_table().mul(&a);
+  let key_stream = engine.paire(&engine.g1.hash_to_curve(user_identity.as_bytes(), dst.as_bytes()),&mpk)
                             .pow(&a).derive_hkdf(8*message_as_bytes.len(), None);
   let encrypted_data: Vec<u8> = key_stream.iter().zip(message_as_bytes.iter()).map(|(&x1, &x2)| x1 ^ x2).collect();    
   let encrypted_message =[u.encode_to_base64(),general_purpose::STANDARD.encode(encrypted_data)];
@@ -76,16 +78,16 @@ fn  boneh_franklin_ibe<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,
 
 } 
 
-fn check_and_benchmark<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>(
-  engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>)
+fn check_and_benchmark<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>(
+  engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>)
 {
   println!("Checking pairins for {} : {}",engine.identifier,if check_pairings(&engine.curvename) {"correct "}  else {"Incorrect"}) ;  
   println!("Benchmark pairins for {:?} :",engine.identifier);
   bench_pairings(&engine.curvename);
 }
 
-fn run_chosen_action<const R:usize,const N:usize,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize>(
-  engine :&Pairings <R, N, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2>,choice :u32)
+fn run_chosen_action<const R:usize, S: FieldConfig<R>,const N:usize, P: FieldConfig<N>,const MAX_COEFS_COUNT1:usize,const PRAMASIZE:usize, const MAX_COEFS_COUNT2 :usize, T: PairingTower<N,P,PRAMASIZE>>(
+  engine :&Pairings <R,S, N,P, MAX_COEFS_COUNT1, PRAMASIZE, MAX_COEFS_COUNT2, T>,choice :u32)
 {
   match choice {
     1 => {println!("Runtime Bench-marking\n");